@@ -24,11 +24,12 @@ use bp_messages::{
 	target_chain::FromBridgedChainMessagesProof, MessagePayload,
 };
 use bp_polkadot_core::parachains::ParaHash;
-use bp_runtime::{AccountIdOf, Chain, HashOf, Parachain};
+use bp_runtime::{AccountIdOf, Chain, HashOf, Parachain, UnverifiedStorageProofParams};
 use codec::Encode;
 use frame_support::weights::Weight;
 use pallet_bridge_messages::{
 	benchmarking::{MessageDeliveryProofParams, MessageProofParams},
+	compression::compress_payload,
 	messages_generation::{
 		encode_all_messages, encode_lane_data, prepare_message_delivery_storage_proof,
 		prepare_messages_storage_proof,
@@ -40,6 +41,8 @@ use sp_std::prelude::*;
 use xcm::latest::prelude::*;
 
 /// Prepare inbound bridge message according to given message proof parameters.
+///
+/// The message is compressed if the lane compression mode requires that.
 fn prepare_inbound_message<LaneId>(
 	params: &MessageProofParams<LaneId>,
 	successful_dispatch_message_generator: impl Fn(usize) -> MessagePayload,
@@ -48,7 +51,7 @@ fn prepare_inbound_message<LaneId>(
 
 	// if we don't need a correct message, then we may just return some random blob
 	if !params.is_successful_dispatch_expected {
-		return vec![0u8; expected_size];
+		return compress_payload(params.compression, vec![0u8; expected_size]);
 	}
 
 	// else let's prepare successful message.
@@ -59,7 +62,21 @@ fn prepare_inbound_message<LaneId>(
 		expected_size,
 		msg.len()
 	);
-	msg
+	compress_payload(params.compression, msg)
+}
+
+/// Returns storage proof parameters for given message proof parameters.
+///
+/// Size requirements are applied to the decompressed message, so compressed messages are
+/// never grown in the storage proof. Otherwise the proof size savings would be lost.
+fn messages_storage_proof_params<LaneId>(
+	params: &MessageProofParams<LaneId>,
+) -> UnverifiedStorageProofParams {
+	if params.compression.compresses_outbound() {
+		UnverifiedStorageProofParams::default()
+	} else {
+		params.proof_params
+	}
 }
 
 /// Prepare proof of messages for the `receive_messages_proof` call.
@@ -92,7 +109,7 @@ where
 		params.lane,
 		params.message_nonces.clone(),
 		params.outbound_lane_data.clone(),
-		params.proof_params,
+		messages_storage_proof_params(&params),
 		|_| prepare_inbound_message(&params, &message_generator),
 		encode_all_messages,
 		encode_lane_data,
@@ -142,7 +159,7 @@ where
 		params.lane,
 		params.message_nonces.clone(),
 		params.outbound_lane_data.clone(),
-		params.proof_params,
+		messages_storage_proof_params(&params),
 		|_| prepare_inbound_message(&params, &message_generator),
 		encode_all_messages,
 		encode_lane_data,
@@ -0,0 +1,134 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the BEEFY Pallet.
+//!
+//! The cost of `submit_header` depends on the number of items in the MMR proof of the leaf.
+//! The benchmark proves the first leaf of a MMR with `2^p` leaves, so the MMR has a single
+//! peak and the proof has exactly `p` items.
+
+use crate::*;
+
+use bp_beefy::{BeefyAuthoritySet, MmrLeafVersion};
+use bp_header_chain::StoredHeaderDataBuilder;
+use bp_runtime::BasicOperatingMode;
+use codec::Encode;
+use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Header as HeaderT, One, Zero};
+
+/// The maximal number of items in the MMR proof.
+///
+/// A proof of a leaf in a MMR with `2^32` leaves has `32` items.
+const MAX_MMR_PROOF_ITEMS: u32 = 32;
+
+/// Builds a header with given number and default contents.
+fn header<T: Config<I>, I: 'static>(number: BridgedBlockNumber<T, I>) -> BridgedHeader<T, I> {
+	HeaderT::new(
+		number,
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	)
+}
+
+/// Imports a commitment with the MMR root, proving the leaf, that proves `header`.
+fn prepare_benchmark_data<T: Config<I>, I: 'static>(
+	header: &BridgedHeader<T, I>,
+	proof_items: u32,
+) -> (BridgedBlockNumber<T, I>, BridgedBeefyMmrLeaf<T, I>, BridgedMmrProof<T, I>)
+where
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let mmr_leaf = BridgedBeefyMmrLeaf::<T, I> {
+		version: MmrLeafVersion::new(0, 0),
+		parent_number_and_hash: (*header.number(), header.hash()),
+		beefy_next_authority_set: BeefyAuthoritySet {
+			id: 0,
+			len: 0,
+			keyset_commitment: BridgedMmrHashing::<T, I>::hash(&[]),
+		},
+		leaf_extra: Default::default(),
+	};
+
+	// the leaf is the leftmost one, so every proof item is the right sibling of the subtree
+	let items = (0..proof_items)
+		.map(|i| BridgedMmrHashing::<T, I>::hash(&i.encode()))
+		.collect::<Vec<_>>();
+	let mmr_root =
+		items
+			.iter()
+			.fold(BridgedMmrHashing::<T, I>::hash(&mmr_leaf.encode()), |node, sibling| {
+				BridgedMmrHashing::<T, I>::hash(&[node.as_ref(), sibling.as_ref()].concat())
+			});
+	let mmr_proof =
+		BridgedMmrProof::<T, I> { leaf_indices: vec![0], leaf_count: 1u64 << proof_items, items };
+
+	let commitment_block_number = *header.number() + One::one();
+	ImportedCommitments::<T, I>::insert(
+		commitment_block_number,
+		ImportedCommitment::<T, I> {
+			parent_number_and_hash: (*header.number(), header.hash()),
+			mmr_root,
+		},
+	);
+
+	(commitment_block_number, mmr_leaf, mmr_proof)
+}
+
+benchmarks_instance_pallet! {
+	where_clause {
+		where
+			BridgedMmrHashing<T, I>: 'static + Send + Sync,
+			BridgedBeefyMmrLeafExtra<T, I>: Default,
+	}
+
+	// The worst case is when the best header is updated and the oldest header is pruned.
+	submit_header {
+		let p in 0 .. MAX_MMR_PROOF_ITEMS;
+		let caller: T::AccountId = whitelisted_caller();
+		// the pallet is halted at genesis unless it is initialized there
+		PalletOperatingMode::<T, I>::put(BasicOperatingMode::Normal);
+
+		let old_header = header::<T, I>(Zero::zero());
+		let old_hash = old_header.hash();
+		let pointer = T::HeadersToKeep::get() - 1;
+		BestFinalized::<T, I>::put(HeaderId(Zero::zero(), old_hash));
+		ImportedHeaders::<T, I>::insert(old_hash, old_header.build());
+		ImportedHashes::<T, I>::insert(pointer, old_hash);
+		ImportedHashesPointer::<T, I>::put(pointer);
+
+		let header = header::<T, I>(One::one());
+		let hash = header.hash();
+		let (commitment_block_number, mmr_leaf, mmr_proof) =
+			prepare_benchmark_data::<T, I>(&header, p);
+	}: submit_header(
+		RawOrigin::Signed(caller),
+		commitment_block_number,
+		Box::new(header),
+		Box::new(mmr_leaf),
+		mmr_proof
+	)
+	verify {
+		assert_eq!(BestFinalized::<T, I>::get(), Some(HeaderId(One::one(), hash)));
+		assert!(ImportedHeaders::<T, I>::contains_key(hash));
+		assert!(!ImportedHeaders::<T, I>::contains_key(old_hash));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime)
+}
@@ -28,18 +28,32 @@
 //!
 //! Given the header hash, other pallets are able to verify header-based proofs
 //! (e.g. storage proofs, transaction inclusion proofs, etc.).
+//!
+//! Headers, proven by MMR leaves against the imported commitments, may be imported using
+//! the `submit_header` call. The pallet implements `HeaderChain` for imported headers, so
+//! it may be used to verify message proofs by the bridge messages pallet.
 
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bp_beefy::{ChainWithBeefy, InitializationData};
+use bp_header_chain::{HeaderChain, StoredHeaderData};
+use bp_runtime::{HashOf, HeaderId};
 use sp_std::{boxed::Box, prelude::*};
 
+pub use bp_beefy::ImportedCommitmentsInfoData;
 // Re-export in crate namespace for `construct_runtime!`
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 mod utils;
 
+/// Module, containing weights for this pallet.
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -54,6 +68,13 @@ pub type BridgedChain<T, I> = <T as Config<I>>::BridgedChain;
 pub type BridgedBlockNumber<T, I> = bp_runtime::BlockNumberOf<BridgedChain<T, I>>;
 /// Block hash, used by configured bridged chain.
 pub type BridgedBlockHash<T, I> = bp_runtime::HashOf<BridgedChain<T, I>>;
+/// Block id, used by configured bridged chain.
+pub type BridgedBlockId<T, I> = HeaderId<BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>>;
+/// Header, used by configured bridged chain.
+pub type BridgedHeader<T, I> = bp_runtime::HeaderOf<BridgedChain<T, I>>;
+/// Header data, stored by the pallet for every imported header.
+pub type BridgedStoredHeaderData<T, I> =
+	StoredHeaderData<BridgedBlockNumber<T, I>, BridgedBlockHash<T, I>>;
 
 /// Pallet initialization data.
 pub type InitializationDataOf<T, I> =
@@ -83,22 +104,14 @@ pub type ImportedCommitment<T, I> = bp_beefy::ImportedCommitment<
 	BridgedMmrHash<T, I>,
 >;
 
-/// Some high level info about the imported commitments.
-#[derive(codec::Encode, codec::Decode, scale_info::TypeInfo)]
-pub struct ImportedCommitmentsInfoData<BlockNumber> {
-	/// Best known block number, provided in a BEEFY commitment. However this is not
-	/// the best proven block. The best proven block is this block's parent.
-	best_block_number: BlockNumber,
-	/// The head of the `ImportedBlockNumbers` ring buffer.
-	next_block_number_index: u32,
-}
-
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
 	use super::*;
+	use bp_header_chain::StoredHeaderDataBuilder;
 	use bp_runtime::{BasicOperatingMode, OwnedBridgeModule};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Header as HeaderT;
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
@@ -119,8 +132,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type CommitmentsToKeep: Get<u32>;
 
+		/// Maximal number of imported headers to keep in the storage.
+		///
+		/// Like `CommitmentsToKeep`, the setting does not relate to block numbers.
+		#[pallet::constant]
+		type HeadersToKeep: Get<u32>;
+
 		/// The chain we are bridging to here.
 		type BridgedChain: ChainWithBeefy;
+
+		/// Weights gathered through benchmarking.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -271,6 +293,70 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Submit a header, proven by the MMR leaf against the MMR root of the imported
+		/// commitment for the `commitment_block_number` block.
+		///
+		/// The leaf, inserted at some block, contains number and hash of its parent, so it
+		/// proves the parent of the block where it has been inserted. The state root of
+		/// the imported header may then be used to verify storage proofs, e.g. message proofs.
+		///
+		/// Only headers newer than the best imported header are accepted.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit_header(mmr_proof.items.len() as u32))]
+		pub fn submit_header(
+			origin: OriginFor<T>,
+			commitment_block_number: BridgedBlockNumber<T, I>,
+			header: Box<BridgedHeader<T, I>>,
+			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
+			mmr_proof: BridgedMmrProof<T, I>,
+		) -> DispatchResult {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			ensure_signed(origin)?;
+
+			ensure!(Self::request_count() < T::MaxRequests::get(), <Error<T, I>>::TooManyRequests);
+
+			// Only accept headers newer than the best one, which haven't been imported yet.
+			let hash = header.hash();
+			ensure!(!ImportedHeaders::<T, I>::contains_key(hash), Error::<T, I>::DuplicateHeader);
+			ensure!(
+				BestFinalized::<T, I>::get().map_or(true, |best| *header.number() > best.0),
+				Error::<T, I>::OldHeader
+			);
+
+			// Verify mmr leaf against the imported commitment and the header against the leaf.
+			let commitment = ImportedCommitments::<T, I>::get(commitment_block_number)
+				.ok_or(Error::<T, I>::UnknownCommitment)?;
+			utils::verify_beefy_mmr_leaf::<T, I>(&mmr_leaf, mmr_proof, commitment.mmr_root)?;
+			ensure!(
+				mmr_leaf.parent_number_and_hash == (*header.number(), hash),
+				Error::<T, I>::InvalidHeader
+			);
+
+			// Update request count.
+			RequestCount::<T, I>::mutate(|count| *count += 1);
+
+			// Import header.
+			let index = ImportedHashesPointer::<T, I>::get();
+			let to_prune = ImportedHashes::<T, I>::try_get(index);
+			BestFinalized::<T, I>::put(HeaderId(*header.number(), hash));
+			ImportedHeaders::<T, I>::insert(hash, header.build());
+			ImportedHashes::<T, I>::insert(index, hash);
+			ImportedHashesPointer::<T, I>::put((index + 1) % T::HeadersToKeep::get());
+			if let Ok(old_hash) = to_prune {
+				tracing::debug!(target: LOG_TARGET, ?old_hash, "Pruning old header.");
+				ImportedHeaders::<T, I>::remove(old_hash);
+			}
+
+			tracing::info!(
+				target: LOG_TARGET,
+				number=?header.number(),
+				?hash,
+				"Successfully imported header",
+			);
+
+			Ok(())
+		}
 	}
 
 	/// The current number of requests which have written to storage.
@@ -303,6 +389,27 @@ pub mod pallet {
 	pub type ImportedCommitments<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, BridgedBlockNumber<T, I>, ImportedCommitment<T, I>>;
 
+	/// The best header, imported by the `submit_header` call.
+	#[pallet::storage]
+	pub type BestFinalized<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BridgedBlockId<T, I>, OptionQuery>;
+
+	/// A ring buffer containing the hashes of the headers that we have imported, ordered by
+	/// the insertion time.
+	#[pallet::storage]
+	pub(super) type ImportedHashes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, BridgedBlockHash<T, I>>;
+
+	/// The head of the `ImportedHashes` ring buffer.
+	#[pallet::storage]
+	pub(super) type ImportedHashesPointer<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// Relevant fields of the headers that we have imported and haven't been pruned yet.
+	#[pallet::storage]
+	pub type ImportedHeaders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, BridgedBlockHash<T, I>, BridgedStoredHeaderData<T, I>>;
+
 	/// The current BEEFY authority set at the bridged chain.
 	#[pallet::storage]
 	pub type CurrentAuthoritySetInfo<T: Config<I>, I: 'static = ()> =
@@ -380,6 +487,14 @@ pub mod pallet {
 		MmrProofVerificationFailed,
 		/// The validators are not matching the merkle tree root of the authority set.
 		InvalidValidatorSetRoot,
+		/// The commitment, which MMR root is used to verify the MMR leaf, is not imported.
+		UnknownCommitment,
+		/// The header is not the one, proven by the MMR leaf.
+		InvalidHeader,
+		/// The header has already been imported.
+		DuplicateHeader,
+		/// The header is not newer than the best header known to the pallet.
+		OldHeader,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
@@ -407,6 +522,22 @@ pub mod pallet {
 		pub fn request_count() -> u32 {
 			RequestCount::<T, I>::get()
 		}
+
+		/// Returns the id of the best imported header.
+		pub fn best_finalized() -> Option<BridgedBlockId<T, I>> {
+			BestFinalized::<T, I>::get()
+		}
+	}
+}
+
+/// Bridge BEEFY pallet as header chain.
+pub type BeefyChainHeaders<T, I> = Pallet<T, I>;
+
+impl<T: Config<I>, I: 'static> HeaderChain<BridgedChain<T, I>> for BeefyChainHeaders<T, I> {
+	fn finalized_header_state_root(
+		header_hash: HashOf<BridgedChain<T, I>>,
+	) -> Option<HashOf<BridgedChain<T, I>>> {
+		ImportedHeaders::<T, I>::get(header_hash).map(|h| h.state_root)
 	}
 }
 
@@ -419,7 +550,7 @@ mod tests {
 	use mock::*;
 	use mock_chain::*;
 	use sp_consensus_beefy::mmr::BeefyAuthoritySet;
-	use sp_runtime::DispatchError;
+	use sp_runtime::{traits::Header as HeaderT, DispatchError};
 
 	fn next_block() {
 		use frame_support::traits::OnInitialize;
@@ -651,5 +782,119 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn submit_header_works() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_default_headers(1).append_finalized_header();
+			assert_ok!(import_commitment(chain.header(2)));
+
+			let header = chain.header(1).header;
+			assert_ok!(import_header(2, header.clone(), chain.header(2)));
+			assert_eq!(BestFinalized::<TestRuntime>::get(), Some(HeaderId(1, header.hash())));
+			assert_eq!(
+				BeefyChainHeaders::<TestRuntime, ()>::finalized_header_state_root(header.hash()),
+				Some(*header.state_root()),
+			);
+		})
+	}
+
+	#[test]
+	fn fails_to_import_header_if_commitment_is_unknown() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_default_headers(1).append_finalized_header();
+			assert_noop!(
+				import_header(2, chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::UnknownCommitment,
+			);
+		})
+	}
+
+	#[test]
+	fn fails_to_import_header_if_mmr_proof_is_invalid() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1)
+				.append_default_headers(1)
+				.append_finalized_header()
+				.append_default_headers(1);
+			assert_ok!(import_commitment(chain.header(2)));
+			// the leaf of header#3 is not in the MMR at header#2
+			assert_noop!(
+				import_header(2, chain.header(2).header, chain.header(3)),
+				Error::<TestRuntime, ()>::MmrProofVerificationFailed,
+			);
+		})
+	}
+
+	#[test]
+	fn fails_to_import_header_if_it_is_not_proven_by_mmr_leaf() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_default_headers(1).append_finalized_header();
+			assert_ok!(import_commitment(chain.header(2)));
+			assert_noop!(
+				import_header(2, chain.header(2).header, chain.header(2)),
+				Error::<TestRuntime, ()>::InvalidHeader,
+			);
+		})
+	}
+
+	#[test]
+	fn fails_to_import_header_if_it_is_already_imported() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_default_headers(1).append_finalized_header();
+			assert_ok!(import_commitment(chain.header(2)));
+			assert_ok!(import_header(2, chain.header(1).header, chain.header(2)));
+			assert_noop!(
+				import_header(2, chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::DuplicateHeader,
+			);
+		})
+	}
+
+	#[test]
+	fn fails_to_import_header_if_it_is_not_newer_than_best_header() {
+		run_test_with_initialize(1, || {
+			let chain = ChainBuilder::new(1).append_finalized_headers(3);
+			assert_ok!(import_commitment(chain.header(2)));
+			assert_ok!(import_commitment(chain.header(3)));
+			assert_ok!(import_header(3, chain.header(2).header, chain.header(3)));
+			assert_noop!(
+				import_header(2, chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::OldHeader,
+			);
+		})
+	}
+
+	#[test]
+	fn header_pruning_works() {
+		run_test_with_initialize(1, || {
+			let headers_to_keep = <<TestRuntime as Config>::HeadersToKeep as Get<u32>>::get()
+				as TestBridgedBlockNumber;
+			let chain = ChainBuilder::new(1).append_finalized_headers(headers_to_keep as usize + 2);
+			for number in 2..headers_to_keep + 3 {
+				assert_ok!(import_commitment(chain.header(number)));
+				assert_ok!(import_header(
+					number,
+					chain.header(number - 1).header,
+					chain.header(number)
+				));
+			}
+
+			// the side effect of the last import is that the header#1 is pruned
+			assert!(!ImportedHeaders::<TestRuntime>::contains_key(chain.header(1).header.hash()));
+			for number in 2..headers_to_keep + 2 {
+				assert!(ImportedHeaders::<TestRuntime>::contains_key(
+					chain.header(number).header.hash()
+				));
+			}
+			assert_eq!(
+				BestFinalized::<TestRuntime>::get(),
+				Some(HeaderId(
+					headers_to_keep + 1,
+					chain.header(headers_to_keep + 1).header.hash()
+				)),
+			);
+		})
+	}
+
 	generate_owned_bridge_module_tests!(BasicOperatingMode::Normal, BasicOperatingMode::Halted);
 }
@@ -75,6 +75,8 @@ impl beefy::Config for TestRuntime {
 	type MaxRequests = frame_support::traits::ConstU32<16>;
 	type BridgedChain = TestBridgedChain;
 	type CommitmentsToKeep = frame_support::traits::ConstU32<16>;
+	type HeadersToKeep = frame_support::traits::ConstU32<2>;
+	type WeightInfo = ();
 }
 
 #[derive(Debug)]
@@ -104,6 +106,8 @@ impl Chain for TestBridgedChain {
 }
 
 impl ChainWithBeefy for TestBridgedChain {
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str = "BridgeBeefy";
+
 	type MmrHashing = Keccak256;
 	type MmrHash = <Keccak256 as Hash>::Output;
 	type BeefyMmrLeafExtra = ();
@@ -111,9 +115,14 @@ impl ChainWithBeefy for TestBridgedChain {
 	type AuthorityIdToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 }
 
+/// Return test externalities to use in tests.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	sp_io::TestExternalities::new(Default::default())
+}
+
 /// Run test within test runtime.
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
-	sp_io::TestExternalities::new(Default::default()).execute_with(test)
+	new_test_ext().execute_with(test)
 }
 
 /// Initialize pallet and run test.
@@ -151,6 +160,22 @@ pub fn import_commitment(
 	)
 }
 
+/// Import parent of given header, proven by the leaf of the header against the MMR root of
+/// the commitment for the `commitment_block_number` block.
+pub fn import_header(
+	commitment_block_number: TestBridgedBlockNumber,
+	parent: TestBridgedHeader,
+	header: crate::mock_chain::HeaderAndCommitment,
+) -> sp_runtime::DispatchResult {
+	crate::Pallet::<TestRuntime>::submit_header(
+		RuntimeOrigin::signed(1),
+		commitment_block_number,
+		Box::new(parent),
+		Box::new(header.leaf),
+		header.leaf_proof,
+	)
+}
+
 pub fn validator_pairs(index: u32, count: u32) -> Vec<BeefyPair> {
 	(index..index + count)
 		.map(|index| {
@@ -0,0 +1,120 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for pallet_bridge_beefy
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/release/wbuild/kitchensink-runtime/kitchensink_runtime.compact.compressed.wasm
+// --pallet=pallet_bridge_beefy
+// --output=bridges/modules/beefy/src/weights.rs
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=substrate/.maintain/frame-weight-template.hbs
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+
+#![allow(clippy::all)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_bridge_beefy.
+pub trait WeightInfo {
+	fn submit_header(p: u32) -> Weight;
+}
+
+/// Weights for `pallet_bridge_beefy` that are generated using one of the Bridge testnets.
+///
+/// Those weights are test only and must never be used in production.
+pub struct BridgeWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
+	/// Storage: `BridgeBeefy::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgeBeefy::PalletOperatingMode` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::RequestCount` (r:1 w:1)
+	/// Proof: `BridgeBeefy::RequestCount` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHeaders` (r:1 w:2)
+	/// Proof: `BridgeBeefy::ImportedHeaders` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::BestFinalized` (r:1 w:1)
+	/// Proof: `BridgeBeefy::BestFinalized` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedCommitments` (r:1 w:0)
+	/// Proof: `BridgeBeefy::ImportedCommitments` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHashesPointer` (r:1 w:1)
+	/// Proof: `BridgeBeefy::ImportedHashesPointer` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHashes` (r:1 w:1)
+	/// Proof: `BridgeBeefy::ImportedHashes` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) The range of component `p` is `[0, 32]`.
+	fn submit_header(p: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `554`
+		//  Estimated: `4019`
+		// Minimum execution time: 33_018_000 picoseconds.
+		Weight::from_parts(38_553_605, 4019)
+			// Standard Error: 1_236
+			.saturating_add(Weight::from_parts(821_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `BridgeBeefy::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgeBeefy::PalletOperatingMode` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::RequestCount` (r:1 w:1)
+	/// Proof: `BridgeBeefy::RequestCount` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHeaders` (r:1 w:2)
+	/// Proof: `BridgeBeefy::ImportedHeaders` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::BestFinalized` (r:1 w:1)
+	/// Proof: `BridgeBeefy::BestFinalized` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedCommitments` (r:1 w:0)
+	/// Proof: `BridgeBeefy::ImportedCommitments` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHashesPointer` (r:1 w:1)
+	/// Proof: `BridgeBeefy::ImportedHashesPointer` (`max_values`: Some(1), `max_size`: None, mode:
+	/// `Measured`) Storage: `BridgeBeefy::ImportedHashes` (r:1 w:1)
+	/// Proof: `BridgeBeefy::ImportedHashes` (`max_values`: None, `max_size`: None, mode:
+	/// `Measured`) The range of component `p` is `[0, 32]`.
+	fn submit_header(p: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `554`
+		//  Estimated: `4019`
+		// Minimum execution time: 33_018_000 picoseconds.
+		Weight::from_parts(38_553_605, 4019)
+			// Standard Error: 1_236
+			.saturating_add(Weight::from_parts(821_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
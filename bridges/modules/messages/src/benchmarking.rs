@@ -19,8 +19,9 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{
-	active_outbound_lane, weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, BridgedChainOf, Call,
-	InboundLanes, OutboundLanes,
+	active_outbound_lane, compression::compress_payload,
+	weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, BridgedChainOf, Call, InboundLanes,
+	LaneCompression, LanesCompression, OutboundLanes, WeightInfoExt,
 };
 
 use bp_messages::{
@@ -29,7 +30,7 @@ use bp_messages::{
 	InboundLaneData, LaneState, MessageNonce, OutboundLaneData, UnrewardedRelayer,
 	UnrewardedRelayersState,
 };
-use bp_runtime::{AccountIdOf, HashOf, UnverifiedStorageProofParams};
+use bp_runtime::{AccountIdOf, HashOf, Size, UnverifiedStorageProofParams};
 use codec::Decode;
 use frame_benchmarking::{account, v2::*};
 use frame_support::weights::Weight;
@@ -56,6 +57,11 @@ pub struct MessageProofParams<LaneId> {
 	/// `receive_single_message_proof_with_dispatch` benchmark. If you don't need it, just
 	/// return `true` from the `is_message_successfully_dispatched`.
 	pub is_successful_dispatch_expected: bool,
+	/// Compression mode of the lane. If it compresses outbound messages, then all messages
+	/// in the proof must be compressed the same way as the bridged chain would do that. In
+	/// this case, the message (not the proof) size requirement is applied to the decompressed
+	/// message payload.
+	pub compression: LaneCompression,
 	/// Proof size requirements.
 	pub proof_params: UnverifiedStorageProofParams,
 }
@@ -214,6 +220,7 @@ mod benchmarks {
 			message_nonces: setup.nonces(),
 			outbound_lane_data: None,
 			is_successful_dispatch_expected: false,
+			compression: LaneCompression::Disabled,
 			proof_params: UnverifiedStorageProofParams::from_db_size(
 				EXPECTED_DEFAULT_MESSAGE_LENGTH,
 			),
@@ -247,6 +254,7 @@ mod benchmarks {
 			message_nonces: setup.nonces(),
 			outbound_lane_data: None,
 			is_successful_dispatch_expected: false,
+			compression: LaneCompression::Disabled,
 			proof_params: UnverifiedStorageProofParams::from_db_size(
 				EXPECTED_DEFAULT_MESSAGE_LENGTH,
 			),
@@ -290,6 +298,7 @@ mod benchmarks {
 				latest_generated_nonce: setup.last_nonce(),
 			}),
 			is_successful_dispatch_expected: false,
+			compression: LaneCompression::Disabled,
 			proof_params: UnverifiedStorageProofParams::from_db_size(
 				EXPECTED_DEFAULT_MESSAGE_LENGTH,
 			),
@@ -327,6 +336,7 @@ mod benchmarks {
 			message_nonces: setup.nonces(),
 			outbound_lane_data: None,
 			is_successful_dispatch_expected: false,
+			compression: LaneCompression::Disabled,
 			proof_params: UnverifiedStorageProofParams::from_db_size(n),
 		});
 
@@ -343,6 +353,105 @@ mod benchmarks {
 		setup.check_last_nonce();
 	}
 
+	// Benchmark `receive_messages_proof` extrinsic with single compressed `n`-bytes message and
+	// following conditions:
+	// * the lane is configured to use compression;
+	// * `n` is the size of message payload after decompression;
+	// * everything else is the same as in the `receive_single_n_bytes_message_proof` benchmark.
+	//
+	// The difference between this and the `receive_single_n_bytes_message_proof` benchmark
+	// results is used to compute the decompression cost. Additionally, we compare the proof of
+	// compressed message with the proof of the same uncompressed message here, to show that the
+	// compression makes message delivery cheaper.
+	#[benchmark]
+	fn receive_single_n_bytes_compressed_message_proof(
+		/// Decompressed message size in bytes
+		n: Linear<1, { 16 * 1024 }>,
+	) {
+		// setup code
+		let setup = ReceiveMessagesProofSetup::<T, I>::new(1);
+		let proof_params = |compression| MessageProofParams {
+			lane: T::bench_lane_id(),
+			message_nonces: setup.nonces(),
+			outbound_lane_data: None,
+			is_successful_dispatch_expected: false,
+			compression,
+			proof_params: UnverifiedStorageProofParams::from_db_size(n),
+		};
+		let (uncompressed_proof, uncompressed_dispatch_weight) =
+			T::prepare_message_proof(proof_params(LaneCompression::Disabled));
+		LanesCompression::<T, I>::insert(T::bench_lane_id(), LaneCompression::Deflate);
+		let (proof, dispatch_weight) =
+			T::prepare_message_proof(proof_params(LaneCompression::Deflate));
+
+		// compressed message has smaller proof, so the relayer pays less for the delivery
+		let uncompressed_proof_size = uncompressed_proof.size();
+		let compressed_proof_size = proof.size();
+		tracing::info!(
+			target: crate::LOG_TARGET,
+			message_size=%n,
+			%uncompressed_proof_size,
+			%compressed_proof_size,
+			"Compared message proof sizes"
+		);
+		assert!(compressed_proof_size <= uncompressed_proof_size);
+		assert!(T::WeightInfo::receive_messages_proof_weight(&proof, 1, Weight::zero()).all_lte(
+			T::WeightInfo::receive_messages_proof_weight(&uncompressed_proof, 1, Weight::zero(),)
+		));
+		assert!(uncompressed_dispatch_weight.all_lte(dispatch_weight));
+
+		#[extrinsic_call]
+		receive_messages_proof(
+			RawOrigin::Signed(setup.relayer_id_on_tgt()),
+			setup.relayer_id_on_src(),
+			Box::new(proof),
+			setup.msgs_count,
+			dispatch_weight,
+		);
+
+		// verification code
+		setup.check_last_nonce();
+	}
+
+	// Benchmark compression of single outbound `n`-bytes message payload.
+	//
+	// Compression happens when the message is sent over the lane with compression enabled, so its
+	// cost is paid by the message sender.
+	#[benchmark]
+	fn compress_single_n_bytes_message(
+		/// Message size in bytes
+		n: Linear<1, { 16 * 1024 }>,
+	) {
+		// payloads that can't be compressed are the worst case for the compressor
+		let mut seed = n;
+		let payload: Vec<u8> = (0..n)
+			.map(|_| {
+				seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+				(seed >> 16) as u8
+			})
+			.collect();
+
+		#[block]
+		{
+			compress_payload(LaneCompression::Deflate, payload);
+		}
+	}
+
+	// Benchmark `set_lane_compression` extrinsic, called by the root.
+	#[benchmark]
+	fn set_lane_compression() {
+		// compression is set for both lane directions, so both lanes must exist
+		let lane_id = T::bench_lane_id();
+		InboundLanes::<T, I>::insert(lane_id, InboundLaneData::opened());
+		OutboundLanes::<T, I>::insert(lane_id, OutboundLaneData::opened());
+
+		#[extrinsic_call]
+		set_lane_compression(RawOrigin::Root, lane_id, LaneCompression::Deflate);
+
+		// verification code
+		assert_eq!(LanesCompression::<T, I>::get(lane_id), LaneCompression::Deflate);
+	}
+
 	// Benchmark `receive_messages_delivery_proof` extrinsic with following conditions:
 	// * single relayer is rewarded for relaying single message;
 	// * relayer account does not exist (in practice it needs to exist in production environment).
@@ -525,6 +634,7 @@ mod benchmarks {
 			message_nonces: setup.nonces(),
 			outbound_lane_data: None,
 			is_successful_dispatch_expected: true,
+			compression: LaneCompression::Disabled,
 			proof_params: UnverifiedStorageProofParams::from_db_size(n),
 		});
 
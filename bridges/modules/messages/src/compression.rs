@@ -0,0 +1,251 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Optional compression of message payloads.
+//!
+//! Every message payload is stored at the source chain and then is delivered to the target
+//! chain inside a storage proof. So compressing payloads decreases both the size of the
+//! source chain state and the size of messages delivery transactions at the target chain.
+//!
+//! Compression is configured per lane and both chains must agree on it. Since we can't switch
+//! both chains at once, there's an intermediate [`LaneCompression::DecompressInbound`] mode.
+//! To enable compression at the lane, the lane owner must:
+//!
+//! 1) switch the lane to the [`LaneCompression::DecompressInbound`] mode at both chains;
+//!
+//! 2) switch the lane to the [`LaneCompression::Deflate`] mode at both chains.
+//!
+//! Disabling compression is done in the reverse order. The lane must stay in the
+//! [`LaneCompression::DecompressInbound`] mode until all compressed messages are delivered.
+//!
+//! Compressed payloads are starting with the [`COMPRESSED_PAYLOAD_PREFIX`]. Payloads that
+//! are not worth compressing are stored as is. If uncompressed payload is starting with the
+//! prefix itself, it is always compressed, so the target chain is never confused.
+//!
+//! Decompressed payload is not trusted more than the regular (uncompressed) payload - it is
+//! decoded and dispatched exactly the same way. The size of decompressed payload is limited by
+//! the [`bp_messages::ChainWithMessages::maximal_incoming_message_size`] of this chain, so
+//! a malformed payload may not cause unbounded memory usage during decompression.
+
+use bp_messages::MessagePayload;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Prefix of every compressed message payload.
+///
+/// The first byte is chosen so that it doesn't collide with the encoding of versioned XCM
+/// programs, which are the main kind of payloads that are sent over bridges.
+pub const COMPRESSED_PAYLOAD_PREFIX: [u8; 4] = *b"\xffdfl";
+
+/// Deflate compression level.
+///
+/// The same level must be used by all nodes of the chain to get the same compressed payload.
+/// But the level is not known to the bridged chain, so it may be changed at any time.
+const DEFLATE_COMPRESSION_LEVEL: u8 = 6;
+
+/// Message payloads compression mode of the lane.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Encode,
+	Eq,
+	MaxEncodedLen,
+	PartialEq,
+	TypeInfo,
+)]
+pub enum LaneCompression {
+	/// Outbound payloads are not compressed and inbound payloads are not decompressed.
+	#[default]
+	Disabled,
+	/// Outbound payloads are not compressed, but compressed inbound payloads are
+	/// decompressed.
+	DecompressInbound,
+	/// Outbound payloads are compressed using deflate and compressed inbound payloads are
+	/// decompressed.
+	Deflate,
+}
+
+impl LaneCompression {
+	/// Returns true if outbound payloads are compressed.
+	pub fn compresses_outbound(&self) -> bool {
+		matches!(self, LaneCompression::Deflate)
+	}
+
+	/// Returns true if compressed inbound payloads are decompressed.
+	pub fn decompresses_inbound(&self) -> bool {
+		matches!(self, LaneCompression::DecompressInbound | LaneCompression::Deflate)
+	}
+}
+
+/// Payload decompression error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecompressionError {
+	/// Compressed payload is malformed.
+	MalformedPayload,
+	/// Decompressed payload is larger than the maximal allowed size.
+	PayloadTooLarge,
+}
+
+impl From<DecompressionError> for codec::Error {
+	fn from(error: DecompressionError) -> Self {
+		match error {
+			DecompressionError::MalformedPayload => "Malformed compressed payload".into(),
+			DecompressionError::PayloadTooLarge => "Decompressed payload is too large".into(),
+		}
+	}
+}
+
+/// Compress outbound message payload, if it is required by the lane compression mode.
+///
+/// The payload is only compressed if compressed payload is smaller than the original one, or if
+/// the original payload starts with the [`COMPRESSED_PAYLOAD_PREFIX`].
+pub fn compress_payload(compression: LaneCompression, payload: Vec<u8>) -> Vec<u8> {
+	if !compression.compresses_outbound() {
+		return payload;
+	}
+
+	let compressed = miniz_oxide::deflate::compress_to_vec(&payload, DEFLATE_COMPRESSION_LEVEL);
+	let compressed_len = COMPRESSED_PAYLOAD_PREFIX.len().saturating_add(compressed.len());
+	if compressed_len >= payload.len() && !payload.starts_with(&COMPRESSED_PAYLOAD_PREFIX) {
+		return payload;
+	}
+
+	let mut prefixed = Vec::with_capacity(compressed_len);
+	prefixed.extend_from_slice(&COMPRESSED_PAYLOAD_PREFIX);
+	prefixed.extend(compressed);
+	prefixed
+}
+
+/// Decompress inbound message payload, if it is compressed and the lane compression mode
+/// allows that.
+///
+/// Returns error if payload is malformed or if decompressed payload is larger than
+/// `max_decompressed_size` bytes.
+pub fn decompress_payload(
+	compression: LaneCompression,
+	payload: MessagePayload,
+	max_decompressed_size: u32,
+) -> Result<MessagePayload, DecompressionError> {
+	if !compression.decompresses_inbound() {
+		return Ok(payload);
+	}
+
+	let Some(compressed) = payload.strip_prefix(&COMPRESSED_PAYLOAD_PREFIX[..]) else {
+		return Ok(payload);
+	};
+
+	miniz_oxide::inflate::decompress_to_vec_with_limit(compressed, max_decompressed_size as usize)
+		.map_err(|e| match e.status {
+			miniz_oxide::inflate::TINFLStatus::HasMoreOutput => DecompressionError::PayloadTooLarge,
+			_ => DecompressionError::MalformedPayload,
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MAX_SIZE: u32 = 1024;
+
+	fn compressible_payload() -> Vec<u8> {
+		vec![42u8; 512]
+	}
+
+	#[test]
+	fn payload_is_not_compressed_when_compression_is_disabled() {
+		for compression in [LaneCompression::Disabled, LaneCompression::DecompressInbound] {
+			assert_eq!(
+				compress_payload(compression, compressible_payload()),
+				compressible_payload()
+			);
+		}
+	}
+
+	#[test]
+	fn compressed_payload_is_decompressed() {
+		let compressed = compress_payload(LaneCompression::Deflate, compressible_payload());
+		assert!(compressed.starts_with(&COMPRESSED_PAYLOAD_PREFIX));
+		assert!(compressed.len() < compressible_payload().len());
+
+		for compression in [LaneCompression::DecompressInbound, LaneCompression::Deflate] {
+			assert_eq!(
+				decompress_payload(compression, compressed.clone(), MAX_SIZE),
+				Ok(compressible_payload()),
+			);
+		}
+	}
+
+	#[test]
+	fn compressed_payload_is_not_decompressed_when_compression_is_disabled() {
+		let compressed = compress_payload(LaneCompression::Deflate, compressible_payload());
+		assert_eq!(
+			decompress_payload(LaneCompression::Disabled, compressed.clone(), MAX_SIZE),
+			Ok(compressed),
+		);
+	}
+
+	#[test]
+	fn incompressible_payload_is_stored_as_is() {
+		let payload = vec![1, 2, 3];
+		assert_eq!(compress_payload(LaneCompression::Deflate, payload.clone()), payload);
+		assert_eq!(
+			decompress_payload(LaneCompression::Deflate, payload.clone(), MAX_SIZE),
+			Ok(payload)
+		);
+	}
+
+	#[test]
+	fn payload_starting_with_prefix_is_always_compressed() {
+		let payload = COMPRESSED_PAYLOAD_PREFIX.to_vec();
+		let compressed = compress_payload(LaneCompression::Deflate, payload.clone());
+		assert_ne!(compressed, payload);
+		assert_eq!(decompress_payload(LaneCompression::Deflate, compressed, MAX_SIZE), Ok(payload));
+	}
+
+	#[test]
+	fn compression_is_deterministic() {
+		assert_eq!(
+			compress_payload(LaneCompression::Deflate, compressible_payload()),
+			compress_payload(LaneCompression::Deflate, compressible_payload()),
+		);
+	}
+
+	#[test]
+	fn decompression_fails_if_payload_is_too_large() {
+		// 1MB of zeroes is compressed into ~1KB
+		let compressed = compress_payload(LaneCompression::Deflate, vec![0u8; 1024 * 1024]);
+		assert!(compressed.len() < MAX_SIZE as usize * 2);
+		assert_eq!(
+			decompress_payload(LaneCompression::Deflate, compressed, MAX_SIZE),
+			Err(DecompressionError::PayloadTooLarge),
+		);
+	}
+
+	#[test]
+	fn decompression_fails_if_payload_is_malformed() {
+		let mut malformed = COMPRESSED_PAYLOAD_PREFIX.to_vec();
+		malformed.extend_from_slice(&[0xff; 16]);
+		assert_eq!(
+			decompress_payload(LaneCompression::Deflate, malformed, MAX_SIZE),
+			Err(DecompressionError::MalformedPayload),
+		);
+	}
+}
@@ -16,12 +16,17 @@
 
 //! Everything about incoming messages receival.
 
-use crate::{BridgedChainOf, Config};
+use crate::{
+	compression::{
+		decompress_payload, DecompressionError, LaneCompression, COMPRESSED_PAYLOAD_PREFIX,
+	},
+	BridgedChainOf, Config,
+};
 
 use bp_messages::{
 	target_chain::{DispatchMessage, DispatchMessageData, MessageDispatch},
-	ChainWithMessages, DeliveredMessages, InboundLaneData, LaneState, MessageKey, MessageNonce,
-	OutboundLaneData, ReceptionResult, UnrewardedRelayer,
+	ChainWithMessages, DeliveredMessages, InboundLaneData, LaneState, Message, MessageKey,
+	MessageNonce, MessagePayload, OutboundLaneData, ReceptionResult, UnrewardedRelayer,
 };
 use bp_runtime::AccountIdOf;
 use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
@@ -42,6 +47,10 @@ pub trait InboundLaneStorage {
 	fn max_unrewarded_relayer_entries(&self) -> MessageNonce;
 	/// Return maximal number of unconfirmed messages in inbound lane.
 	fn max_unconfirmed_messages(&self) -> MessageNonce;
+	/// Return compression mode of the lane.
+	fn compression(&self) -> LaneCompression;
+	/// Return maximal size of decompressed message payload.
+	fn max_decompressed_payload_size(&self) -> u32;
 	/// Get lane data from the storage.
 	fn data(&self) -> InboundLaneData<Self::Relayer>;
 	/// Update lane data in the storage.
@@ -182,6 +191,31 @@ impl<S: InboundLaneStorage> InboundLane<S> {
 		Some(outbound_lane_data.latest_received_nonce)
 	}
 
+	/// Prepare inbound message for dispatch.
+	///
+	/// Decompresses message payload (if the lane compression mode allows that) and decodes
+	/// it. At most `decompression_limit` bytes are decompressed, so the caller may ensure that
+	/// decompression is paid for. Returns `None` if decompressed payload would be larger than
+	/// this limit. Otherwise returns prepared message and the number of decompressed bytes.
+	pub fn prepare_message<DispatchPayload: Decode>(
+		&self,
+		message: Message<S::LaneId>,
+		decompression_limit: u32,
+	) -> Option<(DispatchMessage<DispatchPayload, S::LaneId>, u32)> {
+		let compression = self.storage.compression();
+		let max_decompressed_payload_size = self.storage.max_decompressed_payload_size();
+		let limit = decompression_limit.min(max_decompressed_payload_size);
+		let is_compressed = is_compressed_payload(compression, &message.payload);
+		let payload = decompress_payload(compression, message.payload, limit);
+		if limit < max_decompressed_payload_size &&
+			payload == Err(DecompressionError::PayloadTooLarge)
+		{
+			return None;
+		}
+
+		Some(into_dispatch_message(message.key, is_compressed, payload, limit))
+	}
+
 	/// Receive new message.
 	pub fn receive_message<Dispatch: MessageDispatch<LaneId = S::LaneId>>(
 		&mut self,
@@ -234,6 +268,45 @@ impl<S: InboundLaneStorage> InboundLane<S> {
 	}
 }
 
+/// Decompress (if required) and decode inbound message payload.
+///
+/// Returns message with decoded payload and the number of bytes that have been decompressed.
+/// If decompression has failed, the message is still returned, but with the decoding error.
+/// Since we may not know how many bytes have been decompressed before failure, we assume that
+/// it is the `max_decompressed_payload_size`.
+pub fn decode_inbound_message<DispatchPayload: Decode, LaneId: Encode>(
+	compression: LaneCompression,
+	max_decompressed_payload_size: u32,
+	message: Message<LaneId>,
+) -> (DispatchMessage<DispatchPayload, LaneId>, u32) {
+	let is_compressed = is_compressed_payload(compression, &message.payload);
+	let payload = decompress_payload(compression, message.payload, max_decompressed_payload_size);
+	into_dispatch_message(message.key, is_compressed, payload, max_decompressed_payload_size)
+}
+
+/// Returns true if payload is compressed and it needs to be decompressed before dispatch.
+fn is_compressed_payload(compression: LaneCompression, payload: &MessagePayload) -> bool {
+	compression.decompresses_inbound() && payload.starts_with(&COMPRESSED_PAYLOAD_PREFIX)
+}
+
+/// Decode decompressed message payload.
+fn into_dispatch_message<DispatchPayload: Decode, LaneId: Encode>(
+	key: MessageKey<LaneId>,
+	is_compressed: bool,
+	payload: Result<MessagePayload, DecompressionError>,
+	decompression_limit: u32,
+) -> (DispatchMessage<DispatchPayload, LaneId>, u32) {
+	let (data, decompressed_bytes) = match payload {
+		Ok(payload) => {
+			let decompressed_bytes = if is_compressed { payload.len() as u32 } else { 0 };
+			(payload.into(), decompressed_bytes)
+		},
+		Err(error) => (DispatchMessageData { payload: Err(error.into()) }, decompression_limit),
+	};
+
+	(DispatchMessage { key, data }, decompressed_bytes)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -567,4 +640,97 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn prepare_message_decompresses_payload_when_compression_is_enabled() {
+		run_test(|| {
+			let mut payload = REGULAR_PAYLOAD;
+			payload.extra = vec![42; 1024];
+			let compressed_payload =
+				crate::compression::compress_payload(LaneCompression::Deflate, payload.encode());
+			let compressed = Message {
+				key: MessageKey { lane_id: test_lane_id(), nonce: 1 },
+				payload: compressed_payload,
+			};
+
+			// when compression is disabled, payload is not decompressed
+			let lane = active_inbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			let (prepared, decompressed_bytes) =
+				lane.prepare_message::<TestPayload>(compressed.clone(), u32::MAX).unwrap();
+			assert!(prepared.data.payload.is_err());
+			assert_eq!(decompressed_bytes, 0);
+
+			// when compression is enabled, payload is decompressed
+			crate::LanesCompression::<TestRuntime, ()>::insert(
+				test_lane_id(),
+				LaneCompression::DecompressInbound,
+			);
+			let lane = active_inbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			let (prepared, decompressed_bytes) =
+				lane.prepare_message::<TestPayload>(compressed, u32::MAX).unwrap();
+			assert_eq!(prepared.data.payload, Ok(payload.clone()));
+			assert_eq!(decompressed_bytes, payload.encode().len() as u32);
+
+			// uncompressed payloads are still accepted
+			let (prepared, decompressed_bytes) =
+				lane.prepare_message::<TestPayload>(message(2, REGULAR_PAYLOAD), 0).unwrap();
+			assert_eq!(prepared.data.payload, Ok(REGULAR_PAYLOAD));
+			assert_eq!(decompressed_bytes, 0);
+		});
+	}
+
+	#[test]
+	fn prepare_message_does_not_decompress_more_than_allowed() {
+		run_test(|| {
+			crate::LanesCompression::<TestRuntime, ()>::insert(
+				test_lane_id(),
+				LaneCompression::Deflate,
+			);
+			let lane = active_inbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			let mut payload = REGULAR_PAYLOAD;
+			payload.extra = vec![42; 1024];
+			let decompressed_size = payload.encode().len() as u32;
+			let compressed = Message {
+				key: MessageKey { lane_id: test_lane_id(), nonce: 1 },
+				payload: crate::compression::compress_payload(
+					LaneCompression::Deflate,
+					payload.encode(),
+				),
+			};
+
+			assert!(lane
+				.prepare_message::<TestPayload>(compressed.clone(), decompressed_size - 1)
+				.is_none());
+			let (prepared, decompressed_bytes) =
+				lane.prepare_message::<TestPayload>(compressed, decompressed_size).unwrap();
+			assert_eq!(prepared.data.payload, Ok(payload));
+			assert_eq!(decompressed_bytes, decompressed_size);
+		});
+	}
+
+	#[test]
+	fn prepare_message_rejects_too_large_decompressed_payload() {
+		run_test(|| {
+			crate::LanesCompression::<TestRuntime, ()>::insert(
+				test_lane_id(),
+				LaneCompression::Deflate,
+			);
+			let lane = active_inbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			let max_size = lane.storage().max_decompressed_payload_size();
+			let mut payload = REGULAR_PAYLOAD;
+			payload.extra = vec![0; max_size as usize + 1];
+			let compressed = Message {
+				key: MessageKey { lane_id: test_lane_id(), nonce: 1 },
+				payload: crate::compression::compress_payload(
+					LaneCompression::Deflate,
+					payload.encode(),
+				),
+			};
+
+			let (prepared, decompressed_bytes) =
+				lane.prepare_message::<TestPayload>(compressed, u32::MAX).unwrap();
+			assert!(prepared.data.payload.is_err());
+			assert_eq!(decompressed_bytes, max_size);
+		});
+	}
 }
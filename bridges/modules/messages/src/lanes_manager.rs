@@ -15,9 +15,9 @@
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	BridgedChainOf, Config, InboundLane, InboundLaneStorage, InboundLanes, OutboundLane,
-	OutboundLaneStorage, OutboundLanes, OutboundMessages, StoredInboundLaneData,
-	StoredMessagePayload,
+	compression::LaneCompression, BridgedChainOf, Config, InboundLane, InboundLaneStorage,
+	InboundLanes, LanesCompression, OutboundLane, OutboundLaneStorage, OutboundLanes,
+	OutboundMessages, StoredInboundLaneData, StoredMessagePayload, ThisChainOf,
 };
 
 use bp_messages::{
@@ -210,6 +210,14 @@ impl<T: Config<I>, I: 'static> InboundLaneStorage for RuntimeInboundLaneStorage<
 		BridgedChainOf::<T, I>::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX
 	}
 
+	fn compression(&self) -> LaneCompression {
+		LanesCompression::<T, I>::get(self.lane_id)
+	}
+
+	fn max_decompressed_payload_size(&self) -> u32 {
+		ThisChainOf::<T, I>::maximal_incoming_message_size()
+	}
+
 	fn data(&self) -> InboundLaneData<AccountIdOf<BridgedChainOf<T, I>>> {
 		self.cached_data.clone()
 	}
@@ -220,7 +228,8 @@ impl<T: Config<I>, I: 'static> InboundLaneStorage for RuntimeInboundLaneStorage<
 	}
 
 	fn purge(self) {
-		InboundLanes::<T, I>::remove(self.lane_id)
+		InboundLanes::<T, I>::remove(self.lane_id);
+		LanesCompression::<T, I>::remove(self.lane_id);
 	}
 }
 
@@ -262,6 +271,10 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 		OutboundLanes::<T, I>::insert(self.lane_id, data)
 	}
 
+	fn compression(&self) -> LaneCompression {
+		LanesCompression::<T, I>::get(self.lane_id)
+	}
+
 	#[cfg(test)]
 	fn message(&self, nonce: &MessageNonce) -> Option<Self::StoredMessagePayload> {
 		OutboundMessages::<T, I>::get(MessageKey { lane_id: self.lane_id, nonce: *nonce })
@@ -280,6 +293,7 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 	}
 
 	fn purge(self) {
-		OutboundLanes::<T, I>::remove(self.lane_id)
+		OutboundLanes::<T, I>::remove(self.lane_id);
+		LanesCompression::<T, I>::remove(self.lane_id);
 	}
 }
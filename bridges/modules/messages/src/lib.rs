@@ -36,6 +36,7 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use compression::LaneCompression;
 pub use inbound_lane::{InboundLane, InboundLaneStorage, StoredInboundLaneData};
 pub use lanes_manager::{
 	LanesManager, LanesManagerError, RuntimeInboundLaneStorage, RuntimeOutboundLaneStorage,
@@ -56,13 +57,10 @@ use bp_messages::{
 		DeliveryConfirmationPayments, FromBridgedChainMessagesDeliveryProof, OnMessagesDelivered,
 		SendMessageArtifacts,
 	},
-	target_chain::{
-		DeliveryPayments, DispatchMessage, FromBridgedChainMessagesProof, MessageDispatch,
-		ProvedLaneMessages, ProvedMessages,
-	},
-	ChainWithMessages, DeliveredMessages, InboundLaneData, InboundMessageDetails, MessageKey,
-	MessageNonce, MessagePayload, MessagesOperatingMode, OutboundLaneData, OutboundMessageDetails,
-	UnrewardedRelayersState, VerificationError,
+	target_chain::{DeliveryPayments, FromBridgedChainMessagesProof, MessageDispatch},
+	ChainWithMessages, DeliveredMessages, InboundLaneData, InboundMessageDetails, Message,
+	MessageKey, MessageNonce, MessagePayload, MessagesOperatingMode, OutboundLaneData,
+	OutboundMessageDetails, UnrewardedRelayersState, VerificationError,
 };
 use bp_runtime::{
 	AccountIdOf, BasicOperatingMode, HashOf, OwnedBridgeModule, PreComputedSize, RangeInclusiveExt,
@@ -80,6 +78,7 @@ mod proofs;
 mod tests;
 mod weights_ext;
 
+pub mod compression;
 pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -246,14 +245,17 @@ pub mod pallet {
 			let mut actual_weight = declared_weight;
 
 			// verify messages proof && convert proof into messages
+			//
+			// `receive_messages_proof` weight formula and
+			// `MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX` check guarantees that the
+			// `message_count` is sane and Vec<Message> may be allocated. (tx with too many
+			// messages will either be rejected from the pool, or will fail earlier)
 			let (lane_id, lane_data) =
-				verify_and_decode_messages_proof::<T, I>(*proof, messages_count).map_err(
-					|err| {
-						tracing::trace!(target: LOG_TARGET, error=?err, "Rejecting invalid messages proof");
+				proofs::verify_messages_proof::<T, I>(*proof, messages_count).map_err(|err| {
+					tracing::trace!(target: LOG_TARGET, error=?err, "Rejecting invalid messages proof");
 
-						Error::<T, I>::InvalidMessagesProof
-					},
-				)?;
+					Error::<T, I>::InvalidMessagesProof
+				})?;
 
 			// dispatch messages and (optionally) update lane(s) state(s)
 			let mut total_messages = 0;
@@ -283,14 +285,35 @@ pub mod pallet {
 
 			let mut messages_received_status =
 				ReceivedMessages::new(lane_id, Vec::with_capacity(lane_data.messages.len()));
-			for mut message in lane_data.messages {
+			for message in lane_data.messages {
 				debug_assert_eq!(message.key.lane_id, lane_id);
 				total_messages += 1;
 
+				// decompress (if required) and decode the message payload. Decompression is
+				// paid by the relayer, as a part of message dispatch weight. So we never
+				// decompress more bytes than the relayer has paid for
+				let decompression_limit =
+					T::WeightInfo::max_decompressed_size(dispatch_weight_left);
+				let Some((mut message, decompressed_bytes)) =
+					lane.prepare_message(message, decompression_limit)
+				else {
+					tracing::trace!(
+						target: LOG_TARGET,
+						?lane_id,
+						left=%dispatch_weight_left,
+						"Cannot decompress any more messages"
+					);
+
+					fail!(Error::<T, I>::InsufficientDispatchWeight);
+				};
+
 				// ensure that relayer has declared enough weight for dispatching next message
 				// on this lane. We can't dispatch lane messages out-of-order, so if declared
 				// weight is not enough, let's move to next lane
-				let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message);
+				let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message)
+					.saturating_add(T::WeightInfo::message_decompression_weight(
+						decompressed_bytes,
+					));
 				if message_dispatch_weight.any_gt(dispatch_weight_left) {
 					tracing::trace!(
 						target: LOG_TARGET,
@@ -444,6 +467,38 @@ pub mod pallet {
 
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
 		}
+
+		/// Change message payloads compression mode of the lane.
+		///
+		/// Both chains must agree on the compression mode, so it shall be changed in several
+		/// steps. See the [`compression`] module documentation for details.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::WeightInfo::set_lane_compression(), DispatchClass::Operational))]
+		pub fn set_lane_compression(
+			origin: OriginFor<T>,
+			lane_id: T::LaneId,
+			compression: LaneCompression,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+
+			// compression is set for both lane directions, so both lanes must exist
+			let lanes_manager = LanesManager::<T, I>::new();
+			lanes_manager
+				.any_state_inbound_lane(lane_id)
+				.map_err(Error::<T, I>::LanesManager)?;
+			lanes_manager
+				.any_state_outbound_lane(lane_id)
+				.map_err(Error::<T, I>::LanesManager)?;
+
+			LanesCompression::<T, I>::insert(lane_id, compression);
+			tracing::info!(target: LOG_TARGET, ?lane_id, ?compression, "Changed lane compression");
+
+			Self::deposit_event(Event::LaneCompressionChanged { lane_id, compression });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -471,6 +526,13 @@ pub mod pallet {
 			/// Delivered messages.
 			messages: DeliveredMessages,
 		},
+		/// Message payloads compression mode of the lane has been changed.
+		LaneCompressionChanged {
+			/// Lane, which compression mode has been changed.
+			lane_id: T::LaneId,
+			/// New compression mode.
+			compression: LaneCompression,
+		},
 	}
 
 	#[pallet::error]
@@ -538,6 +600,13 @@ pub mod pallet {
 	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, MessageKey<T::LaneId>, StoredMessagePayload<T, I>>;
 
+	/// Map of lane id => message payloads compression mode of the lane.
+	///
+	/// The same mode is used for both inbound and outbound lanes with the same id.
+	#[pallet::storage]
+	pub type LanesCompression<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::LaneId, LaneCompression, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -590,12 +659,19 @@ pub mod pallet {
 			payload: MessagePayload,
 			outbound_details: OutboundMessageDetails,
 		) -> InboundMessageDetails {
-			let mut dispatch_message = DispatchMessage {
-				key: MessageKey { lane_id: lane, nonce: outbound_details.nonce },
-				data: payload.into(),
-			};
+			let (mut dispatch_message, decompressed_bytes) = inbound_lane::decode_inbound_message(
+				LanesCompression::<T, I>::get(lane),
+				T::ThisChain::maximal_incoming_message_size(),
+				Message {
+					key: MessageKey { lane_id: lane, nonce: outbound_details.nonce },
+					payload,
+				},
+			);
 			InboundMessageDetails {
-				dispatch_weight: T::MessageDispatch::dispatch_weight(&mut dispatch_message),
+				dispatch_weight: T::MessageDispatch::dispatch_weight(&mut dispatch_message)
+					.saturating_add(T::WeightInfo::message_decompression_weight(
+						decompressed_bytes,
+					)),
 			}
 		}
 
@@ -695,13 +771,21 @@ where
 		// check lane
 		let lane = active_outbound_lane::<T, I>(lane_id)?;
 
-		Ok(SendMessageArgs {
-			lane_id,
-			lane,
-			payload: StoredMessagePayload::<T, I>::try_from(message.encode()).map_err(|_| {
+		// the limit is checked before compression, because the bridged chain would refuse
+		// to decompress larger payloads
+		let payload = message.encode();
+		ensure!(
+			payload.len() <= T::BridgedChain::maximal_incoming_message_size() as usize,
+			Error::<T, I>::MessageRejectedByPallet(VerificationError::MessageTooLarge)
+		);
+		// the payload may be compressed here. The sender must pay for that - see the
+		// `WeightInfoExt::message_compression_weight`
+		let payload = StoredMessagePayload::<T, I>::try_from(lane.prepare_message_payload(payload))
+			.map_err(|_| {
 				Error::<T, I>::MessageRejectedByPallet(VerificationError::MessageTooLarge)
-			})?,
-		})
+			})?;
+
+		Ok(SendMessageArgs { lane_id, lane, payload })
 	}
 
 	fn send_message(args: SendMessageArgs<T, I>) -> SendMessageArtifacts {
@@ -767,25 +851,3 @@ fn any_state_outbound_lane<T: Config<I>, I: 'static>(
 		.any_state_outbound_lane(lane_id)
 		.map_err(Error::LanesManager)
 }
-
-/// Verify messages proof and return proved messages with decoded payload.
-fn verify_and_decode_messages_proof<T: Config<I>, I: 'static>(
-	proof: FromBridgedChainMessagesProof<HashOf<BridgedChainOf<T, I>>, T::LaneId>,
-	messages_count: u32,
-) -> Result<
-	ProvedMessages<T::LaneId, DispatchMessage<T::InboundPayload, T::LaneId>>,
-	VerificationError,
-> {
-	// `receive_messages_proof` weight formula and `MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX`
-	// check guarantees that the `message_count` is sane and Vec<Message> may be allocated.
-	// (tx with too many messages will either be rejected from the pool, or will fail earlier)
-	proofs::verify_messages_proof::<T, I>(proof, messages_count).map(|(lane, lane_data)| {
-		(
-			lane,
-			ProvedLaneMessages {
-				lane_state: lane_data.lane_state,
-				messages: lane_data.messages.into_iter().map(Into::into).collect(),
-			},
-		)
-	})
-}
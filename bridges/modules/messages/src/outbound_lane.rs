@@ -16,7 +16,10 @@
 
 //! Everything about outgoing messages sending.
 
-use crate::{Config, LOG_TARGET};
+use crate::{
+	compression::{compress_payload, LaneCompression},
+	Config, LOG_TARGET,
+};
 
 use bp_messages::{
 	ChainWithMessages, DeliveredMessages, LaneState, MessageNonce, OutboundLaneData,
@@ -25,7 +28,9 @@ use bp_messages::{
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{traits::Get, BoundedVec, PalletError};
 use scale_info::TypeInfo;
-use sp_std::{collections::vec_deque::VecDeque, marker::PhantomData, ops::RangeInclusive};
+use sp_std::{
+	collections::vec_deque::VecDeque, marker::PhantomData, ops::RangeInclusive, vec::Vec,
+};
 use Debug;
 
 /// Outbound lane storage.
@@ -41,6 +46,8 @@ pub trait OutboundLaneStorage {
 	fn data(&self) -> OutboundLaneData;
 	/// Update lane data in the storage.
 	fn set_data(&mut self, data: OutboundLaneData);
+	/// Return compression mode of the lane.
+	fn compression(&self) -> LaneCompression;
 	/// Returns saved outbound message payload.
 	#[cfg(test)]
 	fn message(&self, nonce: &MessageNonce) -> Option<Self::StoredMessagePayload>;
@@ -115,6 +122,13 @@ impl<S: OutboundLaneStorage> OutboundLane<S> {
 		data.oldest_unpruned_nonce..=data.latest_generated_nonce
 	}
 
+	/// Prepare encoded message payload for storing.
+	///
+	/// Compresses the payload if the lane compression mode requires that.
+	pub fn prepare_message_payload(&self, message_payload: Vec<u8>) -> Vec<u8> {
+		compress_payload(self.storage.compression(), message_payload)
+	}
+
 	/// Send message over lane.
 	///
 	/// Returns new message nonce.
@@ -424,4 +438,25 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn prepare_message_payload_compresses_payload_when_compression_is_enabled() {
+		run_test(|| {
+			let mut payload = REGULAR_PAYLOAD;
+			payload.extra = vec![42; 1024];
+			let payload = payload.encode();
+
+			let lane = active_outbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			assert_eq!(lane.prepare_message_payload(payload.clone()), payload);
+
+			crate::LanesCompression::<TestRuntime, ()>::insert(
+				test_lane_id(),
+				LaneCompression::Deflate,
+			);
+			let lane = active_outbound_lane::<TestRuntime, _>(test_lane_id()).unwrap();
+			let prepared = lane.prepare_message_payload(payload.clone());
+			assert!(prepared.len() < payload.len());
+			assert!(prepared.starts_with(&crate::compression::COMPRESSED_PAYLOAD_PREFIX));
+		});
+	}
 }
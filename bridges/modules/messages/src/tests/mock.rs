@@ -217,11 +217,33 @@ impl crate::benchmarking::Config<()> for TestRuntime {
 	) -> (FromBridgedChainMessagesProof<BridgedHeaderHash, Self::LaneId>, Weight) {
 		use bp_runtime::RangeInclusiveExt;
 
-		let dispatch_weight =
-			REGULAR_PAYLOAD.declared_weight * params.message_nonces.saturating_len();
+		let compression = params.compression;
+		// in mock run we only care about benchmarks correctness, but proofs of compressed and
+		// uncompressed messages are compared, so the message must have the requested size
+		let mut payload = REGULAR_PAYLOAD;
+		payload.extra = vec![0; params.proof_params.db_size.unwrap_or(0) as usize];
+		let message_dispatch_weight = if compression.compresses_outbound() {
+			payload.declared_weight.saturating_add(
+				<TestWeightInfo as crate::WeightInfoExt>::message_decompression_weight(
+					payload.encode().len() as u32,
+				),
+			)
+		} else {
+			payload.declared_weight
+		};
+		let dispatch_weight = message_dispatch_weight * params.message_nonces.saturating_len();
 		(
 			*prepare_messages_proof(
-				params.message_nonces.into_iter().map(|n| message(n, REGULAR_PAYLOAD)).collect(),
+				params
+					.message_nonces
+					.into_iter()
+					.map(|n| {
+						let mut message = message(n, payload.clone());
+						message.payload =
+							crate::compression::compress_payload(compression, message.payload);
+						message
+					})
+					.collect(),
 				params.outbound_lane_data,
 			),
 			dispatch_weight,
@@ -22,17 +22,18 @@ use crate::{
 	outbound_lane::ReceptionConfirmationError,
 	tests::mock::{RuntimeEvent as TestEvent, *},
 	weights_ext::WeightInfoExt,
-	Call, Config, Error, Event, InboundLanes, LanesManagerError, OutboundLanes, OutboundMessages,
-	Pallet, PalletOperatingMode, PalletOwner, StoredInboundLaneData,
+	Call, Config, Error, Event, InboundLanes, LaneCompression, LanesCompression, LanesManagerError,
+	OutboundLanes, OutboundMessages, Pallet, PalletOperatingMode, PalletOwner,
+	StoredInboundLaneData,
 };
 
 use bp_messages::{
 	source_chain::{FromBridgedChainMessagesDeliveryProof, MessagesBridge},
 	target_chain::{FromBridgedChainMessagesProof, MessageDispatch},
 	BridgeMessagesCall, ChainWithMessages, DeliveredMessages, InboundLaneData,
-	InboundMessageDetails, LaneIdType, LaneState, MessageKey, MessageNonce, MessagesOperatingMode,
-	OutboundLaneData, OutboundMessageDetails, UnrewardedRelayer, UnrewardedRelayersState,
-	VerificationError,
+	InboundMessageDetails, LaneIdType, LaneState, Message, MessageKey, MessageNonce,
+	MessagesOperatingMode, OutboundLaneData, OutboundMessageDetails, UnrewardedRelayer,
+	UnrewardedRelayersState, VerificationError,
 };
 use bp_runtime::{BasicOperatingMode, PreComputedSize, RangeInclusiveExt, Size};
 use bp_test_utils::generate_owned_bridge_module_tests;
@@ -1230,3 +1231,167 @@ fn do_try_state_for_outbound_lanes_works() {
 		assert_ok!(Pallet::<TestRuntime>::do_try_state());
 	})
 }
+
+#[test]
+fn set_lane_compression_works() {
+	run_test(|| {
+		get_ready_for_events();
+
+		assert_noop!(
+			Pallet::<TestRuntime>::set_lane_compression(
+				RuntimeOrigin::signed(1),
+				test_lane_id(),
+				LaneCompression::Deflate,
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			Pallet::<TestRuntime>::set_lane_compression(
+				RuntimeOrigin::root(),
+				unknown_lane_id(),
+				LaneCompression::Deflate,
+			),
+			Error::<TestRuntime, ()>::LanesManager(LanesManagerError::UnknownInboundLane),
+		);
+
+		assert_ok!(Pallet::<TestRuntime>::set_lane_compression(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			LaneCompression::Deflate,
+		));
+		assert_eq!(LanesCompression::<TestRuntime>::get(test_lane_id()), LaneCompression::Deflate);
+		assert_eq!(
+			System::<TestRuntime>::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::LaneCompressionChanged {
+					lane_id: test_lane_id(),
+					compression: LaneCompression::Deflate,
+				}),
+				topics: vec![],
+			}],
+		);
+	});
+}
+
+#[test]
+fn compressed_message_is_sent_and_received() {
+	run_test(|| {
+		LanesCompression::<TestRuntime>::insert(test_lane_id(), LaneCompression::Deflate);
+
+		// send message over the lane with compression
+		let mut payload = REGULAR_PAYLOAD;
+		payload.extra = vec![42; 1024];
+		let valid_message = Pallet::<TestRuntime, ()>::validate_message(test_lane_id(), &payload)
+			.expect("validate_message has failed");
+		let nonce = Pallet::<TestRuntime, ()>::send_message(valid_message).nonce;
+
+		// stored payload is compressed
+		let stored_payload =
+			Pallet::<TestRuntime>::outbound_message_data(test_lane_id(), nonce).unwrap();
+		assert!(stored_payload.len() < payload.encode().len());
+
+		// decompression weight is added to the message dispatch weight
+		let dispatch_weight = Pallet::<TestRuntime>::inbound_message_data(
+			test_lane_id(),
+			stored_payload.clone(),
+			OutboundMessageDetails { nonce, dispatch_weight: Weight::zero(), size: 0 },
+		)
+		.dispatch_weight;
+		assert_eq!(
+			dispatch_weight,
+			payload
+				.declared_weight
+				.saturating_add(TestWeightInfo::message_decompression_weight(
+					payload.encode().len() as u32
+				)),
+		);
+
+		// compressed message is decompressed and dispatched at the target chain
+		let message =
+			Message { key: MessageKey { lane_id: test_lane_id(), nonce }, payload: stored_payload };
+		let proof = prepare_messages_proof(vec![message], None);
+		assert_noop!(
+			Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof.clone(),
+				1,
+				payload.declared_weight,
+			),
+			Error::<TestRuntime, ()>::InsufficientDispatchWeight,
+		);
+		assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			RuntimeOrigin::signed(1),
+			TEST_RELAYER_A,
+			proof,
+			1,
+			dispatch_weight,
+		));
+		assert!(TestDeliveryPayments::is_reward_paid(1));
+		assert_eq!(
+			InboundLanes::<TestRuntime>::get(test_lane_id())
+				.unwrap()
+				.0
+				.last_delivered_nonce(),
+			1
+		);
+	});
+}
+
+#[test]
+fn compressed_message_is_decompressed_only_if_relayer_has_paid_for_that() {
+	run_test(|| {
+		let mut payload = REGULAR_PAYLOAD;
+		payload.extra = vec![0; 16 * 1024];
+		let decompressed_size = payload.encode().len() as u32;
+		let decompression_weight = TestWeightInfo::message_decompression_weight(decompressed_size);
+		assert_eq!(TestWeightInfo::max_decompressed_size(decompression_weight), decompressed_size);
+		assert_eq!(
+			TestWeightInfo::max_decompressed_size(decompression_weight.saturating_div(2)),
+			decompressed_size / 2,
+		);
+
+		// relayer has only paid for decompressing the half of the message
+		LanesCompression::<TestRuntime>::insert(test_lane_id(), LaneCompression::Deflate);
+		let message = Message {
+			key: MessageKey { lane_id: test_lane_id(), nonce: 1 },
+			payload: crate::compression::compress_payload(
+				LaneCompression::Deflate,
+				payload.encode(),
+			),
+		};
+		let proof = prepare_messages_proof(vec![message], None);
+		assert_noop!(
+			Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof.clone(),
+				1,
+				payload.declared_weight.saturating_add(decompression_weight.saturating_div(2)),
+			),
+			Error::<TestRuntime, ()>::InsufficientDispatchWeight,
+		);
+		assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			RuntimeOrigin::signed(1),
+			TEST_RELAYER_A,
+			proof,
+			1,
+			payload.declared_weight.saturating_add(decompression_weight),
+		));
+	});
+}
+
+#[test]
+fn send_message_rejects_too_large_message_even_if_compressed_message_fits() {
+	run_test(|| {
+		LanesCompression::<TestRuntime>::insert(test_lane_id(), LaneCompression::Deflate);
+
+		let mut payload = REGULAR_PAYLOAD;
+		payload.extra = vec![0; BridgedChain::maximal_incoming_message_size() as usize];
+		assert_noop!(
+			Pallet::<TestRuntime, ()>::validate_message(test_lane_id(), &payload),
+			Error::<TestRuntime, ()>::MessageRejectedByPallet(VerificationError::MessageTooLarge),
+		);
+	});
+}
@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight;
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight;
 	fn receive_single_n_bytes_message_proof_with_dispatch(n: u32) -> Weight;
+	fn receive_single_n_bytes_compressed_message_proof(n: u32) -> Weight;
+	fn compress_single_n_bytes_message(n: u32) -> Weight;
+	fn set_lane_compression() -> Weight;
 }
 
 /// Weights for `pallet_bridge_messages` that are generated using one of the Bridge testnets.
@@ -293,6 +296,53 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages InboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages LanesCompression (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages LanesCompression (max_values: None, max_size: Some(49), added:
+	/// 2524, mode: MaxEncodedLen)
+	///
+	/// The range of component `n` is `[1, 16384]`.
+	fn receive_single_n_bytes_compressed_message_proof(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `701`
+		//  Estimated: `55197`
+		// Minimum execution time: 41_317 nanoseconds.
+		Weight::from_parts(43_502_875, 55197)
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(1_861, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 16384]`.
+	fn compress_single_n_bytes_message(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_804 nanoseconds.
+		Weight::from_parts(7_126_412, 0)
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(4_417, 0).saturating_mul(n.into()))
+	}
+	fn set_lane_compression() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -527,4 +577,51 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages InboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeUnknownMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeUnknownMessages LanesCompression (r:1 w:0)
+	///
+	/// Proof: BridgeUnknownMessages LanesCompression (max_values: None, max_size: Some(49), added:
+	/// 2524, mode: MaxEncodedLen)
+	///
+	/// The range of component `n` is `[1, 16384]`.
+	fn receive_single_n_bytes_compressed_message_proof(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `701`
+		//  Estimated: `55197`
+		// Minimum execution time: 41_317 nanoseconds.
+		Weight::from_parts(43_502_875, 55197)
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(1_861, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 16384]`.
+	fn compress_single_n_bytes_message(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_804 nanoseconds.
+		Weight::from_parts(7_126_412, 0)
+			// Standard Error: 21
+			.saturating_add(Weight::from_parts(4_417, 0).saturating_mul(n.into()))
+	}
+	fn set_lane_compression() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -21,6 +21,7 @@ use crate::weights::WeightInfo;
 use bp_messages::{MessageNonce, UnrewardedRelayersState};
 use bp_runtime::{PreComputedSize, Size};
 use frame_support::weights::Weight;
+use sp_runtime::SaturatedConversion;
 
 /// Size of the message being delivered in benchmarks.
 pub const EXPECTED_DEFAULT_MESSAGE_LENGTH: u32 = 128;
@@ -59,6 +60,8 @@ pub fn ensure_weights_are_correct<W: WeightInfoExt>() {
 	// it may or may not cause additional db reads, so proof size may vary
 	assert_ne!(W::storage_proof_size_overhead(1).ref_time(), 0);
 	assert_eq!(W::storage_proof_size_overhead(1).proof_size(), 0);
+	assert_eq!(W::message_decompression_weight(1).proof_size(), 0);
+	assert_eq!(W::message_compression_weight(1).proof_size(), 0);
 
 	// verify `receive_message_proof` weight
 	let receive_messages_proof_weight =
@@ -428,6 +431,41 @@ pub trait WeightInfoExt: WeightInfo {
 		Self::receive_single_n_bytes_message_proof_with_dispatch(message_size_in_bytes)
 			.saturating_sub(Self::receive_single_n_bytes_message_proof(message_size_in_bytes))
 	}
+
+	/// Returns weight of decompressing message payload of given (decompressed) size.
+	///
+	/// It is added to the dispatch weight of every compressed message, so it is paid by the
+	/// relayer that is delivering the message.
+	fn message_decompression_weight(decompressed_size: u32) -> Weight {
+		if decompressed_size == 0 {
+			return Weight::zero();
+		}
+
+		let byte_weight = Self::receive_single_n_bytes_compressed_message_proof(2)
+			.saturating_sub(Self::receive_single_n_bytes_compressed_message_proof(1));
+		byte_weight.saturating_mul(decompressed_size as _)
+	}
+
+	/// Returns maximal number of bytes that may be decompressed with given weight.
+	///
+	/// It is used to limit decompression of inbound messages by the dispatch weight that has been
+	/// declared (and paid) by the relayer.
+	fn max_decompressed_size(weight: Weight) -> u32 {
+		let byte_weight = Self::message_decompression_weight(1);
+		if byte_weight.ref_time() == 0 {
+			return u32::MAX;
+		}
+
+		(weight.ref_time() / byte_weight.ref_time()).saturated_into()
+	}
+
+	/// Returns weight of compressing outbound message payload of given size.
+	///
+	/// It must be charged to the message sender when the message is sent over the lane with
+	/// compression enabled.
+	fn message_compression_weight(payload_size: u32) -> Weight {
+		Self::compress_single_n_bytes_message(payload_size)
+	}
 }
 
 impl WeightInfoExt for () {
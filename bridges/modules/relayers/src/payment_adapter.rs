@@ -74,6 +74,18 @@ where
 	}
 }
 
+impl<T, MI, RI, DeliveryReward> DeliveryConfirmationPaymentsAdapter<T, MI, RI, DeliveryReward>
+where
+	T: Config<RI>,
+	RI: 'static,
+	DeliveryReward: Get<T::RewardBalance>,
+{
+	/// Returns reward that is registered for a relayer for every delivered message.
+	pub fn reward_per_message() -> T::RewardBalance {
+		DeliveryReward::get()
+	}
+}
+
 // Update rewards to given relayers, optionally rewarding confirmation relayer.
 fn register_relayers_rewards<
 	T: Config<RI> + pallet_bridge_messages::Config<MI>,
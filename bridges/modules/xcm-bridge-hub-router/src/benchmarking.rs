@@ -18,7 +18,7 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Bridge, BridgeState, Call};
+use crate::{Bridge, BridgeQueue, BridgeQueueState, BridgeState, Call};
 use frame_benchmarking::{benchmarks_instance_pallet, BenchmarkError};
 use frame_support::traits::{EnsureOrigin, Get, Hooks, UnfilteredDispatchable};
 use polkadot_runtime_parachains::FeeTracker;
@@ -77,4 +77,15 @@ benchmarks_instance_pallet! {
 	verify {
 		assert!(Bridge::<T, I>::get().is_congested);
 	}
+
+	report_bridge_queue_state {
+		let origin: T::RuntimeOrigin = T::BridgeHubOrigin::try_successful_origin().expect("expected valid BridgeHubOrigin");
+		let bridge_id = Default::default();
+		let queue_state = BridgeQueueState { outbound_lane_backlog: 1_024, relayer_reward_per_message: 1_000 };
+
+		let call = Call::<T, I>::report_bridge_queue_state { bridge_id, queue_state };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(BridgeQueue::<T, I>::get(), queue_state);
+	}
 }
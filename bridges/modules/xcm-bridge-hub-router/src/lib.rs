@@ -24,6 +24,12 @@
 //! for sovereign-paid bridging or `UnpaidRemoteExporter` for unpaid bridging (e.g. between
 //! system parachains where the bridge hub waives fees).
 //!
+//! On top of that, the bridge hub may report the state of its queues (the outbound lane
+//! backlog and the reward that it pays to relayers for every delivered message). The bridge
+//! fee is then adjusted using the [`Config::FeeCurve`], so that senders pay what the delivery
+//! actually costs. Since the fee is computed when the message is validated, it is also
+//! returned by the `XcmPaymentApi::query_delivery_fees`.
+//!
 //! All other bridge hub queues offer some backpressure mechanisms. So if at least one
 //! of all queues is congested, it will eventually lead to the growth of the queue at
 //! this chain.
@@ -36,7 +42,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bp_xcm_bridge_hub_router::MINIMAL_DELIVERY_FEE_FACTOR;
-pub use bp_xcm_bridge_hub_router::{BridgeState, XcmChannelStatusProvider};
+pub use bp_xcm_bridge_hub_router::{
+	BridgeFeeCurve, BridgeQueueState, BridgeState, LinearBacklogFeeCurve, XcmChannelStatusProvider,
+};
 use codec::Encode;
 use frame_support::traits::Get;
 use polkadot_runtime_parachains::FeeTracker;
@@ -116,6 +124,10 @@ pub mod pallet {
 		type ByteFee: Get<u128>;
 		/// Asset that is used to paid bridge fee.
 		type FeeAsset: Get<AssetId>;
+		/// Bridge fee pricing curve. It adjusts the fee, computed from `T::Bridges` and
+		/// `Self::ByteFee`, using the bridge queue state, reported by the bridge hub. Use `()`
+		/// to ignore the reported state.
+		type FeeCurve: BridgeFeeCurve;
 	}
 
 	#[pallet::pallet]
@@ -183,6 +195,29 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Notification about the bridge queue state change.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::report_bridge_queue_state())]
+		pub fn report_bridge_queue_state(
+			origin: OriginFor<T>,
+			// this argument is not currently used, but to ease future migration, we'll keep it
+			// here
+			bridge_id: H256,
+			queue_state: BridgeQueueState,
+		) -> DispatchResult {
+			T::BridgeHubOrigin::ensure_origin(origin)?;
+
+			tracing::info!(
+				target: LOG_TARGET,
+				from=?bridge_id,
+				?queue_state,
+				"Received bridge queue state"
+			);
+
+			BridgeQueue::<T, I>::put(queue_state);
+			Ok(())
+		}
 	}
 
 	/// Bridge that we are using.
@@ -195,12 +230,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Bridge<T: Config<I>, I: 'static = ()> = StorageValue<_, BridgeState, ValueQuery>;
 
+	/// The last queue state of the bridge that we are using, reported by the bridge hub.
+	///
+	/// **bridges-v1** assumptions from the [`Bridge`] apply here as well.
+	#[pallet::storage]
+	pub type BridgeQueue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BridgeQueueState, ValueQuery>;
+
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Bridge that we are using.
 		pub fn bridge() -> BridgeState {
 			Bridge::<T, I>::get()
 		}
 
+		/// The last reported queue state of the bridge that we are using.
+		pub fn bridge_queue_state() -> BridgeQueueState {
+			BridgeQueue::<T, I>::get()
+		}
+
 		/// Called when new message is sent (queued to local outbound XCM queue) over the bridge.
 		pub(crate) fn on_message_sent_to_bridge(message_size: u32) {
 			tracing::trace!(
@@ -325,7 +372,8 @@ impl<T: Config<I>, I: 'static> ExporterFor for Pallet<T, I> {
 		// `Config::ToBridgeHubSender`
 		let message_size = message.encoded_size();
 		let message_fee = (message_size as u128).saturating_mul(T::ByteFee::get());
-		let fee_sum = base_fee.saturating_add(message_fee);
+		let queue_state = Self::bridge_queue_state();
+		let fee_sum = T::FeeCurve::bridge_fee(base_fee.saturating_add(message_fee), &queue_state);
 		let fee_factor = Self::get_fee_factor(());
 		let fee = fee_factor.saturating_mul_int(fee_sum);
 
@@ -336,6 +384,7 @@ impl<T: Config<I>, I: 'static> ExporterFor for Pallet<T, I> {
 			to=?(network, remote_location),
 			bridge_fee=?fee,
 			%fee_factor,
+			?queue_state,
 			"Going to send message ({message_size} bytes) over bridge."
 		);
 
@@ -463,8 +512,9 @@ impl<T: Config<I>, I: 'static> FeeTracker for Pallet<T, I> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use mock::*;
+	use sp_runtime::DispatchError;
 
 	use frame_support::traits::Hooks;
 	use frame_system::{EventRecord, Phase};
@@ -668,6 +718,85 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn report_bridge_queue_state_works() {
+		run_test(|| {
+			let queue_state =
+				BridgeQueueState { outbound_lane_backlog: 42, relayer_reward_per_message: 100 };
+
+			assert_noop!(
+				XcmBridgeHubRouter::report_bridge_queue_state(
+					RuntimeOrigin::signed(1),
+					Default::default(),
+					queue_state,
+				),
+				DispatchError::BadOrigin,
+			);
+
+			assert_ok!(XcmBridgeHubRouter::report_bridge_queue_state(
+				RuntimeOrigin::root(),
+				Default::default(),
+				queue_state,
+			));
+			assert_eq!(XcmBridgeHubRouter::bridge_queue_state(), queue_state);
+		});
+	}
+
+	#[test]
+	fn delivery_price_depends_on_reported_queue_state() {
+		run_test(|| {
+			let dest = Location::new(2, [GlobalConsensus(BridgedNetworkId::get())]);
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let static_fee = BASE_FEE + BYTE_FEE * (xcm.encoded_size() as u128);
+			let delivery_price = |queue_state: BridgeQueueState| {
+				BridgeQueue::<TestRuntime, ()>::put(queue_state);
+				XcmBridgeHubRouter::validate(&mut Some(dest.clone()), &mut Some(xcm.clone()))
+					.unwrap()
+					.1
+			};
+
+			// backlog, which is below the free threshold, doesn't change anything
+			assert_eq!(
+				delivery_price(BridgeQueueState {
+					outbound_lane_backlog: FREE_BACKLOG,
+					relayer_reward_per_message: 0,
+				}),
+				(BridgeFeeAsset::get(), static_fee + HRMP_FEE).into(),
+			);
+
+			// every next queued message increases the fee
+			let queue_state = BridgeQueueState {
+				outbound_lane_backlog: FREE_BACKLOG + 50,
+				relayer_reward_per_message: 0,
+			};
+			let expected_bridge_fee = TestFeeCurve::bridge_fee(static_fee, &queue_state);
+			assert_eq!(expected_bridge_fee, static_fee * 3 / 2);
+			assert_eq!(
+				delivery_price(queue_state),
+				(BridgeFeeAsset::get(), expected_bridge_fee + HRMP_FEE).into(),
+			);
+
+			// the bridge fee is never lower than the relayer reward
+			let relayer_reward_per_message = static_fee * 2;
+			assert_eq!(
+				delivery_price(BridgeQueueState {
+					outbound_lane_backlog: 0,
+					relayer_reward_per_message
+				}),
+				(BridgeFeeAsset::get(), relayer_reward_per_message + HRMP_FEE).into(),
+			);
+
+			// and the curve is applied before the congestion fee factor
+			let factor = FixedU128::from_rational(125, 100);
+			Bridge::<TestRuntime, ()>::put(uncongested_bridge(factor));
+			assert_eq!(
+				delivery_price(queue_state),
+				(BridgeFeeAsset::get(), factor.saturating_mul_int(expected_bridge_fee) + HRMP_FEE)
+					.into(),
+			);
+		});
+	}
+
 	#[test]
 	fn sent_message_doesnt_increase_factor_if_queue_is_uncongested() {
 		run_test(|| {
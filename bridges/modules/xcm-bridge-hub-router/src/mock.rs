@@ -18,13 +18,16 @@
 
 use crate as pallet_xcm_bridge_hub_router;
 
-use bp_xcm_bridge_hub_router::XcmChannelStatusProvider;
+use bp_xcm_bridge_hub_router::{LinearBacklogFeeCurve, XcmChannelStatusProvider};
 use codec::Encode;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{ConstBool, Contains, Equals},
 };
-use sp_runtime::{traits::ConstU128, BuildStorage};
+use sp_runtime::{
+	traits::{ConstU128, ConstU64},
+	BuildStorage, FixedU128,
+};
 use sp_std::cell::RefCell;
 use xcm::prelude::*;
 use xcm_builder::{InspectMessageQueues, NetworkExportTable, NetworkExportTableItem};
@@ -37,6 +40,8 @@ pub const HRMP_FEE: u128 = 500;
 pub const BASE_FEE: u128 = 1_000_000;
 /// Byte bridge fee.
 pub const BYTE_FEE: u128 = 1_000;
+/// Number of queued messages that don't affect the bridge fee.
+pub const FREE_BACKLOG: u64 = 100;
 
 construct_runtime! {
 	pub enum TestRuntime
@@ -52,6 +57,8 @@ parameter_types! {
 	pub UniversalLocation: InteriorLocation = [GlobalConsensus(ThisNetworkId::get()), Parachain(1000)].into();
 	pub SiblingBridgeHubLocation: Location = ParentThen([Parachain(1002)].into()).into();
 	pub BridgeFeeAsset: AssetId = Location::parent().into();
+	pub FeeFactorPerQueuedMessage: FixedU128 = FixedU128::from_rational(1, 100);
+	pub MaxBacklogFeeFactor: FixedU128 = FixedU128::from_u32(3);
 	pub BridgeTable: Vec<NetworkExportTableItem>
 		= vec![
 			NetworkExportTableItem::new(
@@ -88,8 +95,13 @@ impl pallet_xcm_bridge_hub_router::Config<()> for TestRuntime {
 
 	type ByteFee = ConstU128<BYTE_FEE>;
 	type FeeAsset = BridgeFeeAsset;
+	type FeeCurve = TestFeeCurve;
 }
 
+/// Bridge fee curve, used in tests.
+pub type TestFeeCurve =
+	LinearBacklogFeeCurve<ConstU64<FREE_BACKLOG>, FeeFactorPerQueuedMessage, MaxBacklogFeeFactor>;
+
 pub struct LatestOrNoneForLocationVersionChecker<Location>(sp_std::marker::PhantomData<Location>);
 impl<LocationValue: Contains<Location>> GetVersion
 	for LatestOrNoneForLocationVersionChecker<LocationValue>
@@ -53,6 +53,7 @@ pub trait WeightInfo {
 	fn on_initialize_when_non_congested() -> Weight;
 	fn on_initialize_when_congested() -> Weight;
 	fn report_bridge_status() -> Weight;
+	fn report_bridge_queue_state() -> Weight;
 }
 
 /// Weights for `pallet_xcm_bridge_hub_router` that are generated using one of the Bridge testnets.
@@ -98,6 +99,17 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::BridgeQueue` (r:0 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::BridgeQueue` (`max_values`: Some(1), `max_size`: Some(24),
+	/// added: 519, mode: `MaxEncodedLen`)
+	fn report_bridge_queue_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_593 nanoseconds.
+		Weight::from_parts(8_454_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -146,4 +158,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::BridgeQueue` (r:0 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::BridgeQueue` (`max_values`: Some(1), `max_size`: Some(24),
+	/// added: 519, mode: `MaxEncodedLen`)
+	fn report_bridge_queue_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_593 nanoseconds.
+		Weight::from_parts(8_454_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
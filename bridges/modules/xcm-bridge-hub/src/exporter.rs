@@ -45,6 +45,10 @@ const OUTBOUND_LANE_CONGESTED_THRESHOLD: MessageNonce = 8_192;
 /// queue drops to this count, before sending resuming the bridge.
 const OUTBOUND_LANE_UNCONGESTED_THRESHOLD: MessageNonce = 1_024;
 
+/// The state of the outbound bridge queue is reported to the bridge origin every time
+/// this number of messages is enqueued and every time delivery of messages is confirmed.
+const QUEUE_STATE_REPORT_INTERVAL: MessageNonce = 128;
+
 /// An easy way to access `HaulBlobExporter`.
 pub type PalletAsHaulBlobExporter<T, I> = HaulBlobExporter<
 	DummyHaulBlob,
@@ -219,6 +223,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		bridge: BridgeOf<T, I>,
 		enqueued_messages: MessageNonce,
 	) {
+		// report the queue state to the bridge origin once in a while
+		if enqueued_messages.is_multiple_of(QUEUE_STATE_REPORT_INTERVAL) {
+			Self::report_bridge_queue_state(bridge_id, &bridge, enqueued_messages);
+		}
+
 		// if the bridge queue is not congested, we don't want to do anything
 		let is_congested = enqueued_messages > OUTBOUND_LANE_CONGESTED_THRESHOLD;
 		if !is_congested {
@@ -283,6 +292,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Must be called whenever we receive a message delivery confirmation.
 	fn on_bridge_messages_delivered(lane_id: T::LaneId, enqueued_messages: MessageNonce) {
+		// report the new queue state to the bridge origin
+		let bridge = Self::bridge_by_lane_id(&lane_id);
+		if let Some((bridge_id, ref bridge)) = bridge {
+			Self::report_bridge_queue_state(bridge_id, bridge, enqueued_messages);
+		}
+
 		// if the bridge queue is still congested, we don't want to do anything
 		let is_congested = enqueued_messages > OUTBOUND_LANE_UNCONGESTED_THRESHOLD;
 		if is_congested {
@@ -291,7 +306,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		// if we have not suspended the bridge before (or it is closed), we don't want to do
 		// anything
-		let (bridge_id, bridge) = match Self::bridge_by_lane_id(&lane_id) {
+		let (bridge_id, bridge) = match bridge {
 			Some(bridge) if bridge.1.state == BridgeState::Suspended => bridge,
 			_ => {
 				// if there is no bridge or it has been closed, then we don't need to send resume
@@ -349,6 +364,60 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			bridge.state = BridgeState::Opened;
 		});
 	}
+
+	/// Report the outbound bridge queue state to the bridge origin.
+	fn report_bridge_queue_state(
+		bridge_id: BridgeId,
+		bridge: &BridgeOf<T, I>,
+		outbound_lane_backlog: MessageNonce,
+	) {
+		// nobody is interested in the state of the closed bridge
+		if bridge.state == BridgeState::Closed {
+			return;
+		}
+
+		let bridge_origin_relative_location =
+			match (*bridge.bridge_origin_relative_location).clone().try_into() {
+				Ok(bridge_origin_relative_location) => bridge_origin_relative_location,
+				Err(_) => {
+					tracing::debug!(
+						target: LOG_TARGET,
+						?bridge_id,
+						origin_location=?bridge.bridge_origin_relative_location,
+						"Failed to convert"
+					);
+
+					return;
+				},
+			};
+
+		let report_result = T::LocalXcmChannelManager::report_bridge_queue_state(
+			&bridge_origin_relative_location,
+			bridge_id,
+			outbound_lane_backlog,
+		);
+		match report_result {
+			Ok(_) => {
+				tracing::trace!(
+					target: LOG_TARGET,
+					?bridge_id,
+					%outbound_lane_backlog,
+					originated_by=?bridge_origin_relative_location,
+					"Reported queue state"
+				);
+			},
+			Err(e) => {
+				tracing::debug!(
+					target: LOG_TARGET,
+					error=?e,
+					?bridge_id,
+					%outbound_lane_backlog,
+					originated_by=?bridge_origin_relative_location,
+					"Failed to report queue state"
+				);
+			},
+		}
+	}
 }
 
 /// Dummy implementation of the `HaulBlob` trait that is never called.
@@ -567,6 +636,47 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn exporter_reports_queue_state_once_in_a_while() {
+		run_test(|| {
+			let (bridge_id, _) = open_lane_and_send_regular_message();
+			for _ in 2..QUEUE_STATE_REPORT_INTERVAL {
+				open_lane_and_send_regular_message();
+			}
+			assert_eq!(TestLocalXcmChannelManager::reported_queue_state(&bridge_id), None);
+
+			open_lane_and_send_regular_message();
+			assert_eq!(
+				TestLocalXcmChannelManager::reported_queue_state(&bridge_id),
+				Some(QUEUE_STATE_REPORT_INTERVAL),
+			);
+			assert_eq!(
+				pallet_xcm_bridge_hub_router::BridgeQueue::<
+					TestRuntime,
+					XcmOverBridgeWrappedWithExportMessageRouterInstance,
+				>::get()
+				.outbound_lane_backlog,
+				QUEUE_STATE_REPORT_INTERVAL,
+			);
+		});
+	}
+
+	#[test]
+	fn queue_state_is_reported_when_messages_are_delivered() {
+		run_test(|| {
+			let (bridge_id, lane_id) = open_lane_and_send_regular_message();
+			XcmOverBridge::on_bridge_messages_delivered(lane_id, 5);
+			assert_eq!(TestLocalXcmChannelManager::reported_queue_state(&bridge_id), Some(5));
+
+			// state of the closed bridge is not reported
+			Bridges::<TestRuntime, ()>::mutate_extant(bridge_id, |bridge| {
+				bridge.state = BridgeState::Closed;
+			});
+			XcmOverBridge::on_bridge_messages_delivered(lane_id, 3);
+			assert_eq!(TestLocalXcmChannelManager::reported_queue_state(&bridge_id), Some(5));
+		});
+	}
+
 	#[test]
 	fn export_fails_if_argument_is_missing() {
 		run_test(|| {
@@ -151,7 +151,7 @@ pub use bp_xcm_bridge_hub::{
 };
 use frame_support::{traits::fungible::MutateHold, DefaultNoBound};
 use frame_system::Config as SystemConfig;
-use pallet_bridge_messages::{Config as BridgeMessagesConfig, LanesManagerError};
+use pallet_bridge_messages::{Config as BridgeMessagesConfig, LanesManagerError, WeightInfoExt};
 use sp_runtime::traits::Zero;
 use sp_std::{boxed::Box, vec::Vec};
 use xcm::prelude::*;
@@ -260,6 +260,9 @@ pub mod pallet {
 	/// An alias for the associated lanes manager.
 	pub type LanesManagerOf<T, I> =
 		pallet_bridge_messages::LanesManager<T, <T as Config<I>>::BridgeMessagesPalletInstance>;
+	/// An alias for weights of the associated messages pallet.
+	pub type MessagesWeightInfoOf<T, I> =
+		<T as BridgeMessagesConfig<<T as Config<I>>::BridgeMessagesPalletInstance>>::WeightInfo;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(migration::STORAGE_VERSION)]
@@ -604,6 +607,15 @@ pub mod pallet {
 			LaneToBridge::<T, I>::get(lane_id)
 				.and_then(|bridge_id| Self::bridge(&bridge_id).map(|bridge| (bridge_id, bridge)))
 		}
+
+		/// Returns weight of exporting the message of given size, which is not included into
+		/// the `ExportMessage` XCM instruction benchmark.
+		///
+		/// Message payload is compressed if compression is enabled at the bridge lane. The lane
+		/// is unknown when the instruction is weighed, so compression is always paid for.
+		pub fn export_message_extra_weight(message_size: u32) -> Weight {
+			MessagesWeightInfoOf::<T, I>::message_compression_weight(message_size)
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -24,6 +24,7 @@ use bp_messages::{
 };
 use bp_runtime::{messages::MessageDispatchResult, Chain, ChainId, HashOf};
 use bp_xcm_bridge_hub::{BridgeId, LocalXcmChannelManager};
+use bp_xcm_bridge_hub_router::{BridgeQueueState, XcmBridgeHubRouterCall};
 use codec::{Decode, Encode};
 use frame_support::{
 	assert_ok, derive_impl, parameter_types,
@@ -131,6 +132,15 @@ impl pallet_bridge_messages::WeightInfo for TestMessagesWeights {
 	fn receive_single_n_bytes_message_proof_with_dispatch(_n: u32) -> Weight {
 		Weight::from_parts(1, 0)
 	}
+	fn receive_single_n_bytes_compressed_message_proof(_n: u32) -> Weight {
+		Weight::zero()
+	}
+	fn compress_single_n_bytes_message(_n: u32) -> Weight {
+		Weight::zero()
+	}
+	fn set_lane_compression() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl pallet_bridge_messages::WeightInfoExt for TestMessagesWeights {
@@ -235,6 +245,7 @@ impl pallet_xcm_bridge_hub_router::Config<XcmOverBridgeWrappedWithExportMessageR
 
 	type ByteFee = ConstU128<0>;
 	type FeeAsset = BridgeFeeAsset;
+	type FeeCurve = ();
 }
 
 pub struct XcmConfig;
@@ -472,8 +483,15 @@ impl TestLocalXcmChannelManager {
 		frame_support::storage::unhashed::get_or_default(&Self::resumed_key(bridge))
 	}
 
-	fn build_congestion_message(bridge: &BridgeId, is_congested: bool) -> Vec<Instruction<()>> {
-		use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
+	pub fn reported_queue_state(bridge: &BridgeId) -> Option<MessageNonce> {
+		frame_support::storage::unhashed::get(&Self::queue_state_key(bridge))
+	}
+
+	fn queue_state_key(bridge: &BridgeId) -> Vec<u8> {
+		[b"TestLocalXcmChannelManager.QueueState", bridge.encode().as_slice()].concat()
+	}
+
+	fn build_router_message(call: XcmBridgeHubRouterCall) -> Vec<Instruction<()>> {
 		#[allow(clippy::large_enum_variant)]
 		#[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, scale_info::TypeInfo)]
 		enum Call {
@@ -486,14 +504,7 @@ impl TestLocalXcmChannelManager {
 			Transact {
 				origin_kind: OriginKind::Xcm,
 				fallback_max_weight: None,
-				call: Call::XcmOverBridgeWrappedWithExportMessageRouter(
-					XcmBridgeHubRouterCall::report_bridge_status {
-						bridge_id: bridge.inner(),
-						is_congested,
-					}
-				)
-				.encode()
-				.into(),
+				call: Call::XcmOverBridgeWrappedWithExportMessageRouter(call).encode().into(),
 			},
 			ExpectTransactStatus(MaybeErrorCode::Success),
 		]
@@ -509,7 +520,11 @@ impl TestLocalXcmChannelManager {
 		ExecuteXcmOverSendXcm::set_origin_for_execute(BridgeHubLocation::get());
 		let result = send_xcm::<ExecuteXcmOverSendXcm>(
 			local_origin.clone(),
-			Self::build_congestion_message(&bridge, is_congested).into(),
+			Self::build_router_message(XcmBridgeHubRouterCall::report_bridge_status {
+				bridge_id: bridge.inner(),
+				is_congested,
+			})
+			.into(),
 		);
 
 		if result.is_ok() {
@@ -534,6 +549,35 @@ impl LocalXcmChannelManager for TestLocalXcmChannelManager {
 	fn resume_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error> {
 		Self::report_bridge_status(local_origin, &bridge, false, Self::resumed_key(&bridge))
 	}
+
+	fn report_bridge_queue_state(
+		local_origin: &Location,
+		bridge: BridgeId,
+		outbound_lane_backlog: MessageNonce,
+	) -> Result<(), Self::Error> {
+		// send as BridgeHub would send to sibling chain
+		ExecuteXcmOverSendXcm::set_origin_for_execute(BridgeHubLocation::get());
+		let result = send_xcm::<ExecuteXcmOverSendXcm>(
+			local_origin.clone(),
+			Self::build_router_message(XcmBridgeHubRouterCall::report_bridge_queue_state {
+				bridge_id: bridge.inner(),
+				queue_state: BridgeQueueState {
+					outbound_lane_backlog,
+					relayer_reward_per_message: 0,
+				},
+			})
+			.into(),
+		);
+
+		if result.is_ok() {
+			frame_support::storage::unhashed::put(
+				&Self::queue_state_key(&bridge),
+				&outbound_lane_backlog,
+			);
+		}
+
+		result.map(|_| ())
+	}
 }
 
 impl pallet_xcm_bridge_hub_router::XcmChannelStatusProvider for TestLocalXcmChannelManager {
@@ -46,12 +46,21 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 
+pub mod storage_keys;
+
 /// Substrate-based chain with BEEFY && MMR pallets deployed.
 ///
 /// Both BEEFY and MMR pallets and their clients may be configured to use different
 /// primitives. Some of types can be configured in low-level pallets, but are constrained
 /// when BEEFY+MMR bundle is used.
 pub trait ChainWithBeefy: Chain {
+	/// Name of the bridge BEEFY pallet (used in `construct_runtime` macro call) that is deployed
+	/// at some other chain to bridge with this `ChainWithBeefy`.
+	///
+	/// We assume that all chains that are bridging with this `ChainWithBeefy` are using
+	/// the same name.
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str;
+
 	/// The hashing algorithm used to build the MMR.
 	///
 	/// The same algorithm is also used to compute merkle roots in BEEFY
@@ -134,6 +143,16 @@ pub struct InitializationData<BlockNumber, Hash> {
 	pub authority_set: BeefyAuthoritySet<Hash>,
 }
 
+/// Some high level info about the imported commitments.
+#[derive(Encode, Decode, Debug, PartialEq, TypeInfo)]
+pub struct ImportedCommitmentsInfoData<BlockNumber> {
+	/// Best known block number, provided in a BEEFY commitment. However this is not
+	/// the best proven block. The best proven block is this block's parent.
+	pub best_block_number: BlockNumber,
+	/// The head of the `ImportedBlockNumbers` ring buffer.
+	pub next_block_number_index: u32,
+}
+
 /// Basic data, stored by the pallet for every imported commitment.
 #[derive(Encode, Decode, Debug, PartialEq, TypeInfo)]
 pub struct ImportedCommitment<BlockNumber, BlockHash, MmrHash> {
@@ -18,10 +18,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_core::H256;
-use sp_runtime::FixedU128;
+use sp_core::{Get, H256};
+use sp_runtime::{FixedPointNumber, FixedU128, Saturating};
 use xcm::latest::prelude::Location;
 
 /// Minimal delivery fee factor.
@@ -57,6 +58,84 @@ impl Default for BridgeState {
 	}
 }
 
+/// State of the bridge queues, reported by the bridge hub.
+#[derive(
+	Clone,
+	Copy,
+	Decode,
+	DecodeWithMemTracking,
+	Default,
+	Encode,
+	Eq,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Debug,
+)]
+pub struct BridgeQueueState {
+	/// Number of messages, queued at the outbound lane of the bridge hub and not yet
+	/// delivered to the bridged chain.
+	pub outbound_lane_backlog: u64,
+	/// Reward that the bridge hub pays to relayers for delivering a single message, in
+	/// the units of the bridge fee asset.
+	pub relayer_reward_per_message: u128,
+}
+
+/// Bridge fee pricing curve.
+pub trait BridgeFeeCurve {
+	/// Returns the bridge fee, given the fee that is computed from static configuration
+	/// (base fee and per-byte fee) and the last queue state, reported by the bridge hub.
+	fn bridge_fee(static_fee: u128, queue_state: &BridgeQueueState) -> u128;
+}
+
+/// Static pricing: the reported queue state is ignored.
+impl BridgeFeeCurve for () {
+	fn bridge_fee(static_fee: u128, _queue_state: &BridgeQueueState) -> u128 {
+		static_fee
+	}
+}
+
+/// Bridge fee curve that grows linearly with the outbound lane backlog.
+///
+/// The fee is never lower than the relayer reward per message, so that the bridge hub is
+/// compensated for what it pays to relayers. First `FreeBacklog` queued messages don't
+/// affect the fee. Every next message increases the fee by `FactorPerMessage`, until the
+/// fee reaches `MaxFactor` times the base.
+pub struct LinearBacklogFeeCurve<FreeBacklog, FactorPerMessage, MaxFactor>(
+	PhantomData<(FreeBacklog, FactorPerMessage, MaxFactor)>,
+);
+
+impl<FreeBacklog, FactorPerMessage, MaxFactor>
+	LinearBacklogFeeCurve<FreeBacklog, FactorPerMessage, MaxFactor>
+where
+	FreeBacklog: Get<u64>,
+	FactorPerMessage: Get<FixedU128>,
+	MaxFactor: Get<FixedU128>,
+{
+	/// Returns the multiplier that corresponds to given outbound lane backlog.
+	pub fn backlog_factor(outbound_lane_backlog: u64) -> FixedU128 {
+		let extra_messages = outbound_lane_backlog.saturating_sub(FreeBacklog::get());
+		let factor = MINIMAL_DELIVERY_FEE_FACTOR.saturating_add(
+			FactorPerMessage::get()
+				.saturating_mul(FixedU128::saturating_from_integer(extra_messages)),
+		);
+		factor.min(MaxFactor::get()).max(MINIMAL_DELIVERY_FEE_FACTOR)
+	}
+}
+
+impl<FreeBacklog, FactorPerMessage, MaxFactor> BridgeFeeCurve
+	for LinearBacklogFeeCurve<FreeBacklog, FactorPerMessage, MaxFactor>
+where
+	FreeBacklog: Get<u64>,
+	FactorPerMessage: Get<FixedU128>,
+	MaxFactor: Get<FixedU128>,
+{
+	fn bridge_fee(static_fee: u128, queue_state: &BridgeQueueState) -> u128 {
+		let base_fee = static_fee.max(queue_state.relayer_reward_per_message);
+		Self::backlog_factor(queue_state.outbound_lane_backlog).saturating_mul_int(base_fee)
+	}
+}
+
 /// A minimized version of `pallet-xcm-bridge-hub-router::Call` that can be used without a runtime.
 #[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, TypeInfo)]
 #[allow(non_camel_case_types)]
@@ -64,4 +143,58 @@ pub enum XcmBridgeHubRouterCall {
 	/// `pallet-xcm-bridge-hub-router::Call::report_bridge_status`
 	#[codec(index = 0)]
 	report_bridge_status { bridge_id: H256, is_congested: bool },
+	/// `pallet-xcm-bridge-hub-router::Call::report_bridge_queue_state`
+	#[codec(index = 1)]
+	report_bridge_queue_state { bridge_id: H256, queue_state: BridgeQueueState },
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::ConstU64;
+
+	sp_core::parameter_types! {
+		pub const FactorPerMessage: FixedU128 = FixedU128::from_rational(1, 100);
+		pub const MaxFactor: FixedU128 = FixedU128::from_u32(3);
+	}
+
+	type TestFeeCurve = LinearBacklogFeeCurve<ConstU64<100>, FactorPerMessage, MaxFactor>;
+
+	fn queue_state(
+		outbound_lane_backlog: u64,
+		relayer_reward_per_message: u128,
+	) -> BridgeQueueState {
+		BridgeQueueState { outbound_lane_backlog, relayer_reward_per_message }
+	}
+
+	#[test]
+	fn static_fee_curve_ignores_queue_state() {
+		assert_eq!(<() as BridgeFeeCurve>::bridge_fee(1_000, &queue_state(10_000, 5_000)), 1_000);
+	}
+
+	#[test]
+	fn linear_fee_curve_ignores_free_backlog() {
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(0, 0)), 1_000);
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(100, 0)), 1_000);
+	}
+
+	#[test]
+	fn linear_fee_curve_grows_with_backlog() {
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(101, 0)), 1_010);
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(150, 0)), 1_500);
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(300, 0)), 3_000);
+	}
+
+	#[test]
+	fn linear_fee_curve_is_capped() {
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(301, 0)), 3_000);
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(u64::MAX, 0)), 3_000);
+	}
+
+	#[test]
+	fn linear_fee_curve_covers_relayer_reward() {
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(0, 2_000)), 2_000);
+		assert_eq!(TestFeeCurve::bridge_fee(1_000, &queue_state(150, 2_000)), 3_000);
+		assert_eq!(TestFeeCurve::bridge_fee(3_000, &queue_state(0, 2_000)), 3_000);
+	}
 }
@@ -19,7 +19,7 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bp_messages::LaneIdType;
+use bp_messages::{LaneIdType, MessageNonce};
 use bp_runtime::{AccountIdOf, BalanceOf, Chain};
 pub use call_info::XcmBridgeHubCall;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
@@ -120,6 +120,19 @@ pub trait LocalXcmChannelManager {
 	/// The `local_origin` is guaranteed to be in the same consensus. However, it may point to
 	/// something below the chain level - like the contract or pallet instance, for example.
 	fn resume_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error>;
+
+	/// Report the number of messages, queued at the outbound lane of the bridge, opened by
+	/// given origin.
+	///
+	/// The `local_origin` is guaranteed to be in the same consensus. However, it may point to
+	/// something below the chain level - like the contract or pallet instance, for example.
+	fn report_bridge_queue_state(
+		_local_origin: &Location,
+		_bridge: BridgeId,
+		_outbound_lane_backlog: MessageNonce,
+	) -> Result<(), Self::Error> {
+		Ok(())
+	}
 }
 
 impl LocalXcmChannelManager for () {
@@ -0,0 +1,228 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Initialize Substrate -> Substrate BEEFY bridge.
+//!
+//! Initialization is a transaction that calls `initialize()` function of the bridge BEEFY
+//! pallet. This transaction brings the block of the next BEEFY commitment, generated by the
+//! source chain, and the BEEFY authority set at this block to the target chain. The
+//! commitments sync starts with this block.
+
+use crate::error::Error;
+
+use bp_beefy::{
+	storage_keys::imported_commitments_info_key, ChainWithBeefy, InitializationData, MmrHashOf,
+};
+use bp_runtime::{BasicOperatingMode, HeaderIdOf};
+use codec::Decode;
+use futures::StreamExt;
+use relay_substrate_client::{
+	AccountKeyPairOf, BlockNumberOf, Chain, ChainWithTransactions, Client, Error as SubstrateError,
+	HashOf, UnsignedTransaction,
+};
+use relay_utils::{TrackedTransactionStatus, TransactionTracker};
+use sp_consensus_beefy::{mmr::BeefyAuthoritySet, VersionedFinalityProof};
+use sp_core::Pair;
+
+/// Name of the runtime API method, returning the current BEEFY authority set info.
+pub const BEEFY_MMR_AUTHORITY_SET_PROOF_METHOD: &str = "BeefyMmrApi_authority_set_proof";
+
+/// BEEFY pallet initialization data, used by the given source chain.
+pub type BeefyInitializationDataOf<C> = InitializationData<BlockNumberOf<C>, MmrHashOf<C>>;
+
+/// Submit BEEFY bridge initialization transaction.
+pub async fn initialize<
+	SourceChain: Chain + ChainWithBeefy,
+	TargetChain: ChainWithTransactions,
+	F,
+>(
+	source_client: impl Client<SourceChain>,
+	target_client: impl Client<TargetChain>,
+	target_signer: AccountKeyPairOf<TargetChain>,
+	prepare_initialize_transaction: F,
+	dry_run: bool,
+) where
+	F: FnOnce(
+			TargetChain::Nonce,
+			BeefyInitializationDataOf<SourceChain>,
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Send
+		+ 'static,
+	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
+	MmrHashOf<SourceChain>: Send,
+{
+	let result = do_initialize::<SourceChain, _, _>(
+		source_client,
+		target_client,
+		target_signer,
+		prepare_initialize_transaction,
+		dry_run,
+	)
+	.await;
+
+	match result {
+		Ok(Some(tx_status)) => match tx_status {
+			TrackedTransactionStatus::Lost => {
+				tracing::error!(
+					target: "bridge",
+					source=%SourceChain::NAME,
+					target=%TargetChain::NAME,
+					?tx_status,
+					"Failed to execute BEEFY bridge initialization transaction."
+				)
+			},
+			TrackedTransactionStatus::Finalized(_) => {
+				tracing::info!(
+					target: "bridge",
+					source=%SourceChain::NAME,
+					target=%TargetChain::NAME,
+					?tx_status,
+					"Successfully executed BEEFY bridge initialization transaction."
+				)
+			},
+		},
+		Ok(None) => (),
+		Err(err) => tracing::error!(
+			target: "bridge",
+			error=?err,
+			source=%SourceChain::NAME,
+			target=%TargetChain::NAME,
+			"Failed to submit BEEFY bridge initialization transaction"
+		),
+	}
+}
+
+/// Craft and submit initialization transaction, returning any error that may occur.
+async fn do_initialize<SourceChain: Chain + ChainWithBeefy, TargetChain: ChainWithTransactions, F>(
+	source_client: impl Client<SourceChain>,
+	target_client: impl Client<TargetChain>,
+	target_signer: AccountKeyPairOf<TargetChain>,
+	prepare_initialize_transaction: F,
+	dry_run: bool,
+) -> Result<
+	Option<TrackedTransactionStatus<HeaderIdOf<TargetChain>>>,
+	Error<HashOf<SourceChain>, BlockNumberOf<SourceChain>>,
+>
+where
+	F: FnOnce(
+			TargetChain::Nonce,
+			BeefyInitializationDataOf<SourceChain>,
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Send
+		+ 'static,
+	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
+	MmrHashOf<SourceChain>: Send,
+{
+	let is_initialized = is_initialized::<SourceChain, TargetChain>(&target_client)
+		.await
+		.map_err(|e| Error::IsInitializedRetrieve(SourceChain::NAME, TargetChain::NAME, e))?;
+	if is_initialized {
+		tracing::info!(
+			target: "bridge",
+			source=%SourceChain::NAME,
+			target=%TargetChain::NAME,
+			"BEEFY bridge is already initialized. Skipping"
+		);
+		if !dry_run {
+			return Ok(None);
+		}
+	}
+
+	let initialization_data = prepare_initialization_data(source_client).await?;
+	tracing::info!(
+		target: "bridge",
+		source=%SourceChain::NAME,
+		target=%TargetChain::NAME,
+		?initialization_data,
+		"Prepared initialization data for BEEFY bridge"
+	);
+
+	let tx_status = target_client
+		.submit_and_watch_signed_extrinsic(&target_signer, move |_, transaction_nonce| {
+			let tx = prepare_initialize_transaction(transaction_nonce, initialization_data);
+			if dry_run {
+				Err(SubstrateError::Custom(
+					"Not submitting extrinsic in `dry-run` mode!".to_string(),
+				))
+			} else {
+				tx
+			}
+		})
+		.await
+		.map_err(|err| Error::SubmitTransaction(TargetChain::NAME, err))?
+		.wait()
+		.await;
+
+	Ok(Some(tx_status))
+}
+
+/// Returns `Ok(true)` if the bridge BEEFY pallet at the target chain is initialized.
+async fn is_initialized<SourceChain: ChainWithBeefy, TargetChain: Chain>(
+	target_client: &impl Client<TargetChain>,
+) -> Result<bool, SubstrateError> {
+	Ok(target_client
+		.raw_storage_value(
+			target_client.best_header_hash().await?,
+			imported_commitments_info_key(SourceChain::WITH_CHAIN_BEEFY_PALLET_NAME),
+		)
+		.await?
+		.is_some())
+}
+
+/// Prepare initialization data for the bridge BEEFY pallet.
+///
+/// Waits for the next BEEFY commitment, generated by the source chain, and reads the BEEFY
+/// authority set at its block.
+pub async fn prepare_initialization_data<SourceChain: Chain + ChainWithBeefy>(
+	source_client: impl Client<SourceChain>,
+) -> Result<
+	BeefyInitializationDataOf<SourceChain>,
+	Error<HashOf<SourceChain>, BlockNumberOf<SourceChain>>,
+> {
+	// In ideal world we just need to get best finalized block and the authority set at it.
+	// But BEEFY finality lags behind GRANDPA finality, so we need to wait for the commitment.
+	let mut justifications = source_client
+		.subscribe_beefy_finality_justifications()
+		.await
+		.map_err(|err| Error::Subscribe(SourceChain::NAME, err))?;
+	let justification = justifications
+		.next()
+		.await
+		.ok_or(Error::ReadJustificationStreamEnded(SourceChain::NAME))?;
+	let VersionedFinalityProof::V1(commitment) = VersionedFinalityProof::<
+		BlockNumberOf<SourceChain>,
+		bp_beefy::BeefyValidatorSignatureOf<SourceChain>,
+	>::decode(&mut &justification.0[..])
+	.map_err(|err| Error::DecodeJustification(SourceChain::NAME, err))?;
+
+	let best_block_number = commitment.commitment.block_number;
+	let retrieve_authority_set = || async {
+		let at = source_client.header_hash_by_number(best_block_number).await?;
+		source_client
+			.state_call(at, BEEFY_MMR_AUTHORITY_SET_PROOF_METHOD.into(), ())
+			.await
+	};
+	let authority_set: BeefyAuthoritySet<MmrHashOf<SourceChain>> =
+		retrieve_authority_set().await.map_err(|err| {
+			Error::RetrieveBeefyAuthoritySet(SourceChain::NAME, best_block_number, err)
+		})?;
+
+	Ok(InitializationData {
+		operating_mode: BasicOperatingMode::Normal,
+		best_block_number,
+		authority_set,
+	})
+}
@@ -0,0 +1,565 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Types and functions intended to ease adding of new Substrate -> Substrate
+//! BEEFY commitments synchronization pipelines.
+//!
+//! Unlike GRANDPA, the bridge BEEFY pallet at the target chain does not import source chain
+//! headers. Instead, it imports signed BEEFY commitments, along with the latest MMR leaf at
+//! the commitment block. The leaf contains the hash of the commitment block parent, so the
+//! best block that is proven by the imported commitment is the parent of the commitment block.
+//!
+//! Headers, required to verify message proofs, are imported separately, along with the MMR leaf
+//! that is inserted at their child and its proof against the MMR root of imported commitment.
+
+use crate::{
+	beefy::{source::SubstrateBeefySource, target::SubstrateBeefyTarget},
+	TransactionParams,
+};
+
+use bp_beefy::{
+	BeefyAuthoritySetOf, BeefyMmrLeafOf, BeefySignedCommitmentOf, ChainWithBeefy, MmrProofOf,
+	ValidatorSetId,
+};
+use finality_relay::HeadersToRelay;
+use futures::StreamExt;
+use num_traits::{One, Saturating};
+use pallet_bridge_beefy::{Call as BridgeBeefyCall, Config as BridgeBeefyConfig};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain, ChainWithTransactions, Client,
+	Error as SubstrateError, HashOf, HeaderOf,
+};
+use relay_utils::{
+	FailedClient, MaybeConnectionError, TrackedTransactionStatus, TransactionTracker,
+};
+use sp_core::Pair;
+use sp_runtime::{traits::Header as HeaderT, RuntimeAppPublic};
+use std::{fmt::Debug, marker::PhantomData};
+
+pub use crate::finality::source::RequiredHeaderNumberRef;
+
+pub mod initialize;
+pub mod source;
+pub mod target;
+
+/// Substrate -> Substrate BEEFY commitments synchronization pipeline.
+pub trait SubstrateBeefyPipeline: 'static + Clone + Debug + Send + Sync {
+	/// Chain with BEEFY, which commitments are relayed.
+	type SourceChain: Chain
+		+ ChainWithBeefy<
+			MmrHash: Send + Sync,
+			BeefyMmrLeafExtra: Send + Sync,
+			AuthorityId: Send + Sync + RuntimeAppPublic<Signature: Send + Sync>,
+		>;
+	/// Chain with the bridge BEEFY pallet, where commitments are submitted.
+	type TargetChain: ChainWithTransactions;
+
+	/// How submit commitment call is built?
+	type SubmitBeefyCommitmentCallBuilder: SubmitBeefyCommitmentCallBuilder<Self>;
+}
+
+/// Signed BEEFY commitment with everything required to import it at the target chain.
+#[derive(Clone, Debug)]
+pub struct BeefyCommitmentProof<C: ChainWithBeefy> {
+	/// Signed BEEFY commitment.
+	pub commitment: BeefySignedCommitmentOf<C>,
+	/// Validator set that has signed the commitment.
+	pub validator_set: BeefyAuthoritySetOf<C>,
+	/// The latest MMR leaf at the commitment block.
+	pub mmr_leaf: BeefyMmrLeafOf<C>,
+	/// Proof of `mmr_leaf` membership in the MMR, which root is signed by the commitment.
+	pub mmr_proof: MmrProofOf<C>,
+}
+
+impl<C: ChainWithBeefy> BeefyCommitmentProof<C> {
+	/// Returns number of the block, which commitment is proved.
+	pub fn block_number(&self) -> bp_runtime::BlockNumberOf<C> {
+		self.commitment.commitment.block_number
+	}
+
+	/// Returns number and hash of the best source chain block, proven by this commitment.
+	pub fn proven_block(&self) -> (bp_runtime::BlockNumberOf<C>, bp_runtime::HashOf<C>) {
+		self.mmr_leaf.parent_number_and_hash
+	}
+
+	/// Returns true if importing this commitment changes the current authority set at the
+	/// target chain, which currently has the `current_set_id` set.
+	pub fn is_authority_set_change(&self, current_set_id: ValidatorSetId) -> bool {
+		self.mmr_leaf.beefy_next_authority_set.id > current_set_id
+	}
+
+	/// Returns proof of given header, which must be the best block, proven by this commitment.
+	pub fn proven_header_proof(&self, header: HeaderOf<C>) -> BeefyHeaderProof<C> {
+		BeefyHeaderProof {
+			commitment_block_number: self.block_number(),
+			header,
+			mmr_leaf: self.mmr_leaf.clone(),
+			mmr_proof: self.mmr_proof.clone(),
+		}
+	}
+}
+
+/// Source chain header with everything required to import it at the target chain.
+#[derive(Clone, Debug)]
+pub struct BeefyHeaderProof<C: ChainWithBeefy> {
+	/// Number of the block with imported commitment, which MMR root is used to verify the leaf.
+	pub commitment_block_number: bp_runtime::BlockNumberOf<C>,
+	/// The header to import.
+	pub header: HeaderOf<C>,
+	/// MMR leaf, inserted at the child of the header.
+	pub mmr_leaf: BeefyMmrLeafOf<C>,
+	/// Proof of `mmr_leaf` membership in the MMR at the commitment block.
+	pub mmr_proof: MmrProofOf<C>,
+}
+
+/// Different ways of building `submit_commitment` calls.
+pub trait SubmitBeefyCommitmentCallBuilder<P: SubstrateBeefyPipeline> {
+	/// Given the commitment proof, build call of `submit_commitment` function of bridge BEEFY
+	/// module at the target chain.
+	fn build_submit_commitment_call(
+		proof: BeefyCommitmentProof<P::SourceChain>,
+	) -> CallOf<P::TargetChain>;
+
+	/// Given the header proof, build call of `submit_header` function of bridge BEEFY module
+	/// at the target chain.
+	fn build_submit_header_call(proof: BeefyHeaderProof<P::SourceChain>) -> CallOf<P::TargetChain>;
+}
+
+/// Building `submit_commitment` call when you have direct access to the target chain runtime.
+pub struct DirectSubmitBeefyCommitmentCallBuilder<P, R, I> {
+	_phantom: PhantomData<(P, R, I)>,
+}
+
+impl<P, R, I> SubmitBeefyCommitmentCallBuilder<P>
+	for DirectSubmitBeefyCommitmentCallBuilder<P, R, I>
+where
+	P: SubstrateBeefyPipeline,
+	R: BridgeBeefyConfig<I>,
+	I: 'static,
+	R::BridgedChain: ChainWithBeefy<
+		BlockNumber = BlockNumberOf<P::SourceChain>,
+		Hash = HashOf<P::SourceChain>,
+		Header = HeaderOf<P::SourceChain>,
+		MmrHash = bp_beefy::MmrHashOf<P::SourceChain>,
+		BeefyMmrLeafExtra = bp_beefy::BeefyMmrLeafExtraOf<P::SourceChain>,
+		AuthorityId = bp_beefy::BeefyAuthorityIdOf<P::SourceChain>,
+	>,
+	bp_beefy::MmrHashingOf<R::BridgedChain>: 'static + Send + Sync,
+	CallOf<P::TargetChain>: From<BridgeBeefyCall<R, I>>,
+{
+	fn build_submit_commitment_call(
+		proof: BeefyCommitmentProof<P::SourceChain>,
+	) -> CallOf<P::TargetChain> {
+		BridgeBeefyCall::<R, I>::submit_commitment {
+			commitment: proof.commitment,
+			validator_set: proof.validator_set,
+			mmr_leaf: Box::new(proof.mmr_leaf),
+			mmr_proof: proof.mmr_proof,
+		}
+		.into()
+	}
+
+	fn build_submit_header_call(proof: BeefyHeaderProof<P::SourceChain>) -> CallOf<P::TargetChain> {
+		BridgeBeefyCall::<R, I>::submit_header {
+			commitment_block_number: proof.commitment_block_number,
+			header: Box::new(proof.header),
+			mmr_leaf: Box::new(proof.mmr_leaf),
+			mmr_proof: proof.mmr_proof,
+		}
+		.into()
+	}
+}
+
+/// Run Substrate-to-Substrate BEEFY commitments sync loop.
+///
+/// With `HeadersToRelay::All`, every commitment that may be imported by the target chain is
+/// submitted. Otherwise, only commitments that change the authority set at the target chain
+/// are submitted, so that the bridge never gets stuck. The BEEFY pallet does not support free
+/// commitments, so `HeadersToRelay::Free` is the same as `HeadersToRelay::Mandatory`.
+pub async fn run<P: SubstrateBeefyPipeline>(
+	source_client: impl Client<P::SourceChain>,
+	target_client: impl Client<P::TargetChain>,
+	headers_to_relay: HeadersToRelay,
+	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+) -> anyhow::Result<()>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	tracing::info!(
+		target: "bridge",
+		source=%P::SourceChain::NAME,
+		target=%P::TargetChain::NAME,
+		?headers_to_relay,
+		"Starting BEEFY commitments relay"
+	);
+
+	relay_utils::relay_loop(
+		SubstrateBeefySource::<P, _>::new(source_client),
+		SubstrateBeefyTarget::<P, _>::new(target_client, transaction_params),
+	)
+	.run(
+		format!("{}_to_{}_BEEFY", P::SourceChain::NAME, P::TargetChain::NAME),
+		move |source_client, target_client, _| {
+			run_until_connection_lost(source_client, target_client, headers_to_relay, None)
+		},
+	)
+	.await
+	.map_err(|e| anyhow::format_err!("{}", e))
+}
+
+/// Relay BEEFY commitments until connection to either source, or target node is lost.
+///
+/// If `required_header_number` is given, commitments are also submitted until the source
+/// header with that number is proven at the target chain.
+pub(crate) async fn run_until_connection_lost<
+	P: SubstrateBeefyPipeline,
+	SourceClnt: Client<P::SourceChain>,
+	TargetClnt: Client<P::TargetChain>,
+>(
+	source: SubstrateBeefySource<P, SourceClnt>,
+	target: SubstrateBeefyTarget<P, TargetClnt>,
+	headers_to_relay: HeadersToRelay,
+	required_header_number: Option<RequiredHeaderNumberRef<P::SourceChain>>,
+) -> Result<(), FailedClient>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	let mut commitments = source.commitments().await.map_err(|e| {
+		tracing::error!(
+			target: "bridge",
+			error=?e,
+			source=%P::SourceChain::NAME,
+			"Failed to subscribe to BEEFY justifications"
+		);
+		FailedClient::Source
+	})?;
+
+	loop {
+		let commitment = commitments.next().await.ok_or_else(|| {
+			tracing::error!(
+				target: "bridge",
+				source=%P::SourceChain::NAME,
+				"BEEFY justifications stream has ended unexpectedly"
+			);
+			FailedClient::Source
+		})?;
+
+		let is_required = |best_proven| {
+			let required_header_number = required_header_number.clone();
+			async move {
+				match required_header_number {
+					Some(required_header_number) => {
+						*required_header_number.lock().await > best_proven
+					},
+					None => false,
+				}
+			}
+		};
+		let result =
+			relay_commitment(&source, &target, headers_to_relay, commitment, is_required).await;
+		if let Err((failed_client, error)) = result {
+			tracing::error!(
+				target: "bridge",
+				error=?error,
+				source=%P::SourceChain::NAME,
+				target=%P::TargetChain::NAME,
+				"Failed to relay BEEFY commitment"
+			);
+
+			if error.is_connection_error() {
+				return Err(failed_client);
+			}
+		}
+	}
+}
+
+/// What to do with the BEEFY commitment, received from the source chain.
+#[derive(Debug, PartialEq)]
+enum CommitmentAction {
+	/// The commitment is already imported by the target chain, or may never be imported there.
+	Skip,
+	/// The commitment may be imported by the target chain.
+	Import,
+	/// The commitment is signed by the authority set that is not yet known to the target chain,
+	/// so the commitment that hands off from the current authority set needs to be imported
+	/// first.
+	ImportHandoff,
+}
+
+/// Select what to do with the BEEFY commitment, given the state of the target chain.
+fn select_commitment_action<BlockNumber: PartialOrd>(
+	best_imported: BlockNumber,
+	current_set_id: ValidatorSetId,
+	commitment_block_number: BlockNumber,
+	commitment_set_id: ValidatorSetId,
+) -> CommitmentAction {
+	if commitment_block_number <= best_imported || commitment_set_id < current_set_id {
+		CommitmentAction::Skip
+	} else if commitment_set_id == current_set_id {
+		CommitmentAction::Import
+	} else {
+		CommitmentAction::ImportHandoff
+	}
+}
+
+/// Returns true if the commitment that may be imported by the target chain needs to be
+/// submitted.
+fn should_submit_commitment(
+	headers_to_relay: HeadersToRelay,
+	is_authority_set_change: bool,
+	is_required: bool,
+) -> bool {
+	is_required ||
+		match headers_to_relay {
+			HeadersToRelay::All => true,
+			HeadersToRelay::Mandatory | HeadersToRelay::Free => is_authority_set_change,
+		}
+}
+
+/// Returns proofs of the commitments that hand off from the `current_set_id` authority set
+/// to the authority set that has signed given commitment, followed by the proof of the given
+/// commitment, if the target chain may import it after the handoffs.
+pub(crate) async fn prove_commitment_with_handoffs<P, SourceClnt>(
+	source: &SubstrateBeefySource<P, SourceClnt>,
+	mut best_imported: BlockNumberOf<P::SourceChain>,
+	mut current_set_id: ValidatorSetId,
+	commitment: BeefySignedCommitmentOf<P::SourceChain>,
+) -> Result<
+	(Vec<BeefyCommitmentProof<P::SourceChain>>, Option<BeefyCommitmentProof<P::SourceChain>>),
+	SubstrateError,
+>
+where
+	P: SubstrateBeefyPipeline,
+	SourceClnt: Client<P::SourceChain>,
+{
+	let block_number = commitment.commitment.block_number;
+	let commitment_set_id = commitment.commitment.validator_set_id;
+	let mut handoffs = Vec::new();
+	loop {
+		match select_commitment_action(
+			best_imported,
+			current_set_id,
+			block_number,
+			commitment_set_id,
+		) {
+			CommitmentAction::Skip => {
+				if block_number > best_imported {
+					tracing::debug!(
+						target: "bridge",
+						source=%P::SourceChain::NAME,
+						target=%P::TargetChain::NAME,
+						%block_number,
+						%commitment_set_id,
+						%current_set_id,
+						"Skipping BEEFY commitment, signed by unexpected authority set"
+					);
+				}
+				return Ok((handoffs, None));
+			},
+			CommitmentAction::Import => break,
+			CommitmentAction::ImportHandoff => {
+				// the target chain needs to import the commitment, signed by its current
+				// authority set that is handing off to the next set
+				let handoff = source
+					.session_start_commitment(
+						current_set_id,
+						best_imported.saturating_add(One::one()),
+						block_number.saturating_sub(One::one()),
+					)
+					.await?
+					.ok_or_else(|| {
+						SubstrateError::Custom(format!(
+							"Failed to find {} BEEFY commitment, signed by the authority set \
+							{current_set_id}",
+							P::SourceChain::NAME,
+						))
+					})?;
+				let handoff = source.prove_commitment(handoff).await?;
+				let next_set_id = handoff.mmr_leaf.beefy_next_authority_set.id;
+				if next_set_id <= current_set_id {
+					return Err(SubstrateError::Custom(format!(
+						"{} BEEFY commitment for block {} does not hand off from the authority \
+						set {current_set_id}",
+						P::SourceChain::NAME,
+						handoff.block_number(),
+					)));
+				}
+
+				best_imported = handoff.block_number();
+				current_set_id = next_set_id;
+				handoffs.push(handoff);
+			},
+		}
+	}
+
+	let proof = source.prove_commitment(commitment).await?;
+	Ok((handoffs, Some(proof)))
+}
+
+/// Submit given commitment to the target chain if required.
+///
+/// If the commitment is signed by the authority set that is not yet known to the target chain,
+/// the commitments that hand off to that set are submitted first. If the commitment is
+/// submitted because some source header is required at the target chain, the best header,
+/// proven by the commitment, is also submitted.
+async fn relay_commitment<P, SourceClnt, TargetClnt, IsRequired, IsRequiredFuture>(
+	source: &SubstrateBeefySource<P, SourceClnt>,
+	target: &SubstrateBeefyTarget<P, TargetClnt>,
+	headers_to_relay: HeadersToRelay,
+	commitment: BeefySignedCommitmentOf<P::SourceChain>,
+	is_required: IsRequired,
+) -> Result<(), (FailedClient, SubstrateError)>
+where
+	P: SubstrateBeefyPipeline,
+	SourceClnt: Client<P::SourceChain>,
+	TargetClnt: Client<P::TargetChain>,
+	IsRequired: Fn(BlockNumberOf<P::SourceChain>) -> IsRequiredFuture,
+	IsRequiredFuture: std::future::Future<Output = bool>,
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	let source_err = |e| (FailedClient::Source, e);
+	let target_err = |e| (FailedClient::Target, e);
+
+	let best_imported = target.best_imported_block_number().await.map_err(target_err)?;
+	if commitment.commitment.block_number <= best_imported {
+		return Ok(());
+	}
+
+	let mut current_set_id = target.current_authority_set_info().await.map_err(target_err)?.id;
+	let (handoffs, proof) =
+		prove_commitment_with_handoffs(source, best_imported, current_set_id, commitment)
+			.await
+			.map_err(source_err)?;
+	for handoff in handoffs {
+		current_set_id = handoff.mmr_leaf.beefy_next_authority_set.id;
+		submit_commitment(target, handoff).await.map_err(target_err)?;
+	}
+	let Some(proof) = proof else { return Ok(()) };
+
+	let is_required = is_required(best_imported.saturating_sub(One::one())).await;
+	let is_set_change = proof.is_authority_set_change(current_set_id);
+	if !should_submit_commitment(headers_to_relay, is_set_change, is_required) {
+		return Ok(());
+	}
+
+	let header_proof = if is_required {
+		let (proven_number, _) = proof.proven_block();
+		let header = source.client().header_by_number(proven_number).await.map_err(source_err)?;
+		Some(proof.proven_header_proof(header))
+	} else {
+		None
+	};
+	submit_commitment(target, proof).await.map_err(target_err)?;
+	if let Some(header_proof) = header_proof {
+		submit_header(target, header_proof).await.map_err(target_err)?;
+	}
+
+	Ok(())
+}
+
+/// Submit commitment to the target chain and wait until the transaction is finalized.
+async fn submit_commitment<P: SubstrateBeefyPipeline, TargetClnt: Client<P::TargetChain>>(
+	target: &SubstrateBeefyTarget<P, TargetClnt>,
+	proof: BeefyCommitmentProof<P::SourceChain>,
+) -> Result<(), SubstrateError>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	let block_number = proof.block_number();
+	let tracker = target.submit_commitment(proof).await?;
+	wait_transaction::<P, _>(tracker, format!("BEEFY commitment for block {block_number}")).await
+}
+
+/// Submit header to the target chain and wait until the transaction is finalized.
+async fn submit_header<P: SubstrateBeefyPipeline, TargetClnt: Client<P::TargetChain>>(
+	target: &SubstrateBeefyTarget<P, TargetClnt>,
+	proof: BeefyHeaderProof<P::SourceChain>,
+) -> Result<(), SubstrateError>
+where
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+{
+	let header_number = *proof.header.number();
+	let tracker = target.submit_header(proof).await?;
+	wait_transaction::<P, _>(tracker, format!("header {header_number}")).await
+}
+
+/// Wait until the transaction with given subject is finalized.
+async fn wait_transaction<P: SubstrateBeefyPipeline, TargetClnt: Client<P::TargetChain>>(
+	tracker: relay_substrate_client::TransactionTracker<P::TargetChain, TargetClnt>,
+	subject: String,
+) -> Result<(), SubstrateError> {
+	match tracker.wait().await {
+		TrackedTransactionStatus::Finalized(_) => {
+			tracing::info!(
+				target: "bridge",
+				source=%P::SourceChain::NAME,
+				target=%P::TargetChain::NAME,
+				%subject,
+				"Submitted transaction"
+			);
+			Ok(())
+		},
+		TrackedTransactionStatus::Lost => Err(SubstrateError::Custom(format!(
+			"Transaction with {} {subject} has been lost",
+			P::SourceChain::NAME,
+		))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn commitment_is_skipped_if_already_imported() {
+		assert_eq!(select_commitment_action(100, 1, 100, 1), CommitmentAction::Skip);
+		assert_eq!(select_commitment_action(100, 1, 50, 1), CommitmentAction::Skip);
+		assert_eq!(select_commitment_action(100, 1, 50, 2), CommitmentAction::Skip);
+	}
+
+	#[test]
+	fn commitment_is_skipped_if_signed_by_previous_authority_set() {
+		assert_eq!(select_commitment_action(100, 2, 150, 1), CommitmentAction::Skip);
+	}
+
+	#[test]
+	fn commitment_is_imported_if_signed_by_current_authority_set() {
+		assert_eq!(select_commitment_action(100, 1, 101, 1), CommitmentAction::Import);
+	}
+
+	#[test]
+	fn handoffs_are_imported_until_commitment_authority_set_is_reached() {
+		// the target chain is two authority sets behind
+		assert_eq!(select_commitment_action(10, 0, 100, 2), CommitmentAction::ImportHandoff);
+		// after the first handoff (commitment for the block 30, signed by the set 0) is imported
+		assert_eq!(select_commitment_action(30, 1, 100, 2), CommitmentAction::ImportHandoff);
+		// after the second handoff (commitment for the block 60, signed by the set 1) is imported
+		assert_eq!(select_commitment_action(60, 2, 100, 2), CommitmentAction::Import);
+	}
+
+	#[test]
+	fn only_required_commitments_are_submitted() {
+		for headers_to_relay in
+			[HeadersToRelay::All, HeadersToRelay::Mandatory, HeadersToRelay::Free]
+		{
+			assert!(should_submit_commitment(headers_to_relay, true, false));
+			assert!(should_submit_commitment(headers_to_relay, false, true));
+		}
+		assert!(should_submit_commitment(HeadersToRelay::All, false, false));
+		assert!(!should_submit_commitment(HeadersToRelay::Mandatory, false, false));
+		assert!(!should_submit_commitment(HeadersToRelay::Free, false, false));
+	}
+}
@@ -0,0 +1,347 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Substrate client as the source of BEEFY commitments.
+
+use crate::beefy::{BeefyCommitmentProof, BeefyHeaderProof, SubstrateBeefyPipeline};
+
+use async_trait::async_trait;
+use bp_beefy::{
+	BeefyAuthoritySetOf, BeefyMmrLeafOf, BeefySignedCommitmentOf, BeefyValidatorSignatureOf,
+	MmrProofOf, ValidatorSetId, BEEFY_ENGINE_ID,
+};
+use codec::Decode;
+use futures::{
+	stream::{BoxStream, StreamExt},
+	Future,
+};
+use num_traits::{One, Saturating};
+use relay_substrate_client::{BlockNumberOf, BlockWithJustification, Chain, Client, Error};
+use relay_utils::relay_loop::Client as RelayClient;
+use sp_consensus_beefy::VersionedFinalityProof;
+use sp_mmr_primitives::{EncodableOpaqueLeaf, Error as MmrError};
+use sp_runtime::traits::AtLeast32BitUnsigned;
+use std::marker::PhantomData;
+
+/// Name of the runtime API method, returning the current BEEFY validator set.
+pub const BEEFY_VALIDATOR_SET_METHOD: &str = "BeefyApi_validator_set";
+/// Name of the runtime API method, generating MMR leaves proof.
+pub const MMR_GENERATE_PROOF_METHOD: &str = "MmrApi_generate_proof";
+
+/// Substrate node as the source of BEEFY commitments.
+pub struct SubstrateBeefySource<P: SubstrateBeefyPipeline, SourceClnt> {
+	client: SourceClnt,
+	_phantom: PhantomData<P>,
+}
+
+impl<P: SubstrateBeefyPipeline, SourceClnt: Client<P::SourceChain>>
+	SubstrateBeefySource<P, SourceClnt>
+{
+	/// Create new BEEFY commitments source using given client.
+	pub fn new(client: SourceClnt) -> Self {
+		SubstrateBeefySource { client, _phantom: Default::default() }
+	}
+
+	/// Returns reference to the underlying RPC client.
+	pub fn client(&self) -> &SourceClnt {
+		&self.client
+	}
+
+	/// Returns stream of signed BEEFY commitments, generated by the source chain validators.
+	///
+	/// Justifications that we fail to decode are logged and skipped.
+	pub async fn commitments(
+		&self,
+	) -> Result<BoxStream<'static, BeefySignedCommitmentOf<P::SourceChain>>, Error> {
+		Ok(self
+			.client
+			.subscribe_beefy_finality_justifications()
+			.await?
+			.filter_map(|justification| async move {
+				decode_commitment::<P>(&justification.0)
+					.map_err(|e| {
+						tracing::warn!(
+							target: "bridge",
+							error=?e,
+							source=%P::SourceChain::NAME,
+							"Failed to decode BEEFY justification"
+						);
+					})
+					.ok()
+			})
+			.boxed())
+	}
+
+	/// Returns signed BEEFY commitment for given block, if it is stored in the block
+	/// justifications.
+	///
+	/// The source chain only stores justifications of mandatory BEEFY blocks, so for all other
+	/// blocks the commitment is only available through the justifications subscription.
+	pub async fn commitment_at(
+		&self,
+		block_number: BlockNumberOf<P::SourceChain>,
+	) -> Result<Option<BeefySignedCommitmentOf<P::SourceChain>>, Error> {
+		let hash = self.client.header_hash_by_number(block_number).await?;
+		let block = self.client.block_by_hash(hash).await?;
+		block
+			.justification(BEEFY_ENGINE_ID)
+			.map(|justification| {
+				decode_commitment::<P>(justification).map_err(|e| {
+					Error::Custom(format!(
+						"Failed to decode {} BEEFY justification of block {block_number}: {e:?}",
+						P::SourceChain::NAME,
+					))
+				})
+			})
+			.transpose()
+	}
+
+	/// Returns the commitment for the first block of the session of the authority set with given
+	/// id, if the session has started in the `[range_begin; range_end]` range.
+	///
+	/// The first block of the session is the mandatory BEEFY block, signed by the session
+	/// authority set, so its justification is stored by the source chain. The block is found
+	/// using binary search over authority set ids, so only a logarithmic number of blocks in
+	/// the range is visited.
+	pub async fn session_start_commitment(
+		&self,
+		set_id: ValidatorSetId,
+		range_begin: BlockNumberOf<P::SourceChain>,
+		range_end: BlockNumberOf<P::SourceChain>,
+	) -> Result<Option<BeefySignedCommitmentOf<P::SourceChain>>, Error> {
+		let session_start = first_block_where(range_begin, range_end, |block_number| async move {
+			Ok(self.validator_set_id_at(block_number).await?.is_some_and(|id| id >= set_id))
+		})
+		.await?;
+		let Some(session_start) = session_start else { return Ok(None) };
+
+		Ok(self
+			.commitment_at(session_start)
+			.await?
+			.filter(|commitment| commitment.commitment.validator_set_id == set_id))
+	}
+
+	/// Returns id of the BEEFY validator set at given block, if BEEFY is active there.
+	pub async fn validator_set_id_at(
+		&self,
+		block_number: BlockNumberOf<P::SourceChain>,
+	) -> Result<Option<ValidatorSetId>, Error> {
+		let at = self.client.header_hash_by_number(block_number).await?;
+		let validator_set: Option<BeefyAuthoritySetOf<P::SourceChain>> =
+			self.client.state_call(at, BEEFY_VALIDATOR_SET_METHOD.into(), ()).await?;
+		Ok(validator_set.map(|set| set.id()))
+	}
+
+	/// Returns the validator set that has signed given commitment.
+	pub async fn validator_set(
+		&self,
+		commitment: &BeefySignedCommitmentOf<P::SourceChain>,
+	) -> Result<BeefyAuthoritySetOf<P::SourceChain>, Error> {
+		// first block of the session is signed by the new validator set, so the set is read at
+		// the commitment block. If the set has changed right after the commitment block, we'll
+		// find the set at its parent
+		let block_number = commitment.commitment.block_number;
+		let set_id = commitment.commitment.validator_set_id;
+		for at in [block_number, block_number.saturating_sub(One::one())] {
+			let at = self.client.header_hash_by_number(at).await?;
+			let validator_set: Option<BeefyAuthoritySetOf<P::SourceChain>> =
+				self.client.state_call(at, BEEFY_VALIDATOR_SET_METHOD.into(), ()).await?;
+			if let Some(validator_set) = validator_set.filter(|set| set.id() == set_id) {
+				return Ok(validator_set);
+			}
+		}
+
+		Err(Error::Custom(format!(
+			"Failed to find {} BEEFY validator set {set_id} that has signed commitment for block \
+			{block_number}",
+			P::SourceChain::NAME,
+		)))
+	}
+
+	/// Returns MMR leaf, inserted at block `block_number`, and its membership proof in the MMR
+	/// at block `best_known_block_number`.
+	///
+	/// The leaf, inserted at some block, contains number and hash of its parent block. So if
+	/// the `best_known_block_number` commitment has been imported by the target chain, the
+	/// returned leaf may be used to prove the parent of `block_number` there. That is what
+	/// the message delivery transactions need, when message proofs are verified against
+	/// headers, proven by BEEFY.
+	pub async fn prove_mmr_leaf(
+		&self,
+		block_number: BlockNumberOf<P::SourceChain>,
+		best_known_block_number: BlockNumberOf<P::SourceChain>,
+	) -> Result<(BeefyMmrLeafOf<P::SourceChain>, MmrProofOf<P::SourceChain>), Error> {
+		let at = self.client.header_hash_by_number(best_known_block_number).await?;
+		let result: Result<(Vec<EncodableOpaqueLeaf>, MmrProofOf<P::SourceChain>), MmrError> = self
+			.client
+			.state_call(
+				at,
+				MMR_GENERATE_PROOF_METHOD.into(),
+				(vec![block_number], Some(best_known_block_number)),
+			)
+			.await?;
+		let (mut leaves, proof) = result.map_err(|e| {
+			Error::Custom(format!(
+				"Failed to generate {} MMR proof of block {block_number} leaf at block \
+				{best_known_block_number}: {e:?}",
+				P::SourceChain::NAME,
+			))
+		})?;
+		let leaf = leaves.pop().ok_or_else(|| {
+			Error::Custom(format!(
+				"{} MMR proof of block {block_number} leaf has no leaves",
+				P::SourceChain::NAME,
+			))
+		})?;
+		let leaf = BeefyMmrLeafOf::<P::SourceChain>::decode(&mut &leaf.into_opaque_leaf().0[..])
+			.map_err(|e| {
+				Error::Custom(format!(
+					"Failed to decode {} MMR leaf of block {block_number}: {e:?}",
+					P::SourceChain::NAME,
+				))
+			})?;
+
+		Ok((leaf, proof))
+	}
+
+	/// Returns everything that the target chain needs to import given commitment.
+	pub async fn prove_commitment(
+		&self,
+		commitment: BeefySignedCommitmentOf<P::SourceChain>,
+	) -> Result<BeefyCommitmentProof<P::SourceChain>, Error> {
+		let block_number = commitment.commitment.block_number;
+		let validator_set = self.validator_set(&commitment).await?;
+		let (mmr_leaf, mmr_proof) = self.prove_mmr_leaf(block_number, block_number).await?;
+		Ok(BeefyCommitmentProof { commitment, validator_set, mmr_leaf, mmr_proof })
+	}
+
+	/// Returns everything that the target chain needs to import header with given number,
+	/// if the commitment for `commitment_block_number` has been imported there.
+	pub async fn prove_header(
+		&self,
+		header_number: BlockNumberOf<P::SourceChain>,
+		commitment_block_number: BlockNumberOf<P::SourceChain>,
+	) -> Result<BeefyHeaderProof<P::SourceChain>, Error> {
+		let header = self.client.header_by_number(header_number).await?;
+		let (mmr_leaf, mmr_proof) = self
+			.prove_mmr_leaf(header_number.saturating_add(One::one()), commitment_block_number)
+			.await?;
+		Ok(BeefyHeaderProof { commitment_block_number, header, mmr_leaf, mmr_proof })
+	}
+}
+
+impl<P: SubstrateBeefyPipeline, SourceClnt: Clone> Clone for SubstrateBeefySource<P, SourceClnt> {
+	fn clone(&self) -> Self {
+		SubstrateBeefySource { client: self.client.clone(), _phantom: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<P: SubstrateBeefyPipeline, SourceClnt: Client<P::SourceChain>> RelayClient
+	for SubstrateBeefySource<P, SourceClnt>
+{
+	type Error = Error;
+
+	async fn reconnect(&mut self) -> Result<(), Error> {
+		self.client.reconnect().await
+	}
+}
+
+/// Decode signed BEEFY commitment from the encoded BEEFY justification.
+fn decode_commitment<P: SubstrateBeefyPipeline>(
+	justification: &[u8],
+) -> Result<BeefySignedCommitmentOf<P::SourceChain>, codec::Error> {
+	let VersionedFinalityProof::V1(commitment) = VersionedFinalityProof::<
+		BlockNumberOf<P::SourceChain>,
+		BeefyValidatorSignatureOf<P::SourceChain>,
+	>::decode(&mut &justification[..])?;
+	Ok(commitment)
+}
+
+/// Returns the first block in the `[range_begin; range_end]` range that matches the `predicate`.
+///
+/// The predicate must be monotonic: if it matches some block, it also matches all its
+/// descendants.
+async fn first_block_where<BlockNumber, Predicate, PredicateFuture>(
+	range_begin: BlockNumber,
+	range_end: BlockNumber,
+	predicate: Predicate,
+) -> Result<Option<BlockNumber>, Error>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	Predicate: Fn(BlockNumber) -> PredicateFuture,
+	PredicateFuture: Future<Output = Result<bool, Error>>,
+{
+	if range_begin > range_end || !predicate(range_end).await? {
+		return Ok(None);
+	}
+
+	let (mut low, mut high) = (range_begin, range_end);
+	while low < high {
+		let middle = low + (high - low) / 2u8.into();
+		if predicate(middle).await? {
+			high = middle;
+		} else {
+			low = middle + One::one();
+		}
+	}
+
+	Ok(Some(low))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	async fn session_start(
+		range_begin: u32,
+		range_end: u32,
+		session_start: u32,
+	) -> (Option<u32>, usize) {
+		let visited = AtomicUsize::new(0);
+		let result = first_block_where(range_begin, range_end, |block_number| {
+			visited.fetch_add(1, Ordering::Relaxed);
+			futures::future::ready(Ok(block_number >= session_start))
+		})
+		.await
+		.unwrap();
+		(result, visited.into_inner())
+	}
+
+	#[tokio::test]
+	async fn first_block_where_finds_session_start() {
+		for start in [1, 2, 499, 500, 501, 999, 1000] {
+			assert_eq!(session_start(1, 1000, start).await.0, Some(start));
+		}
+		// the session has started before the range
+		assert_eq!(session_start(100, 1000, 50).await.0, Some(100));
+		assert_eq!(session_start(100, 100, 50).await.0, Some(100));
+	}
+
+	#[tokio::test]
+	async fn first_block_where_returns_none_if_session_has_not_started_in_range() {
+		assert_eq!(session_start(1, 1000, 1001).await, (None, 1));
+		assert_eq!(session_start(1000, 1, 1).await, (None, 0));
+	}
+
+	#[tokio::test]
+	async fn first_block_where_visits_logarithmic_number_of_blocks() {
+		let (result, visited) = session_start(1, 14_400, 7_777).await;
+		assert_eq!(result, Some(7_777));
+		assert!(visited <= 16, "visited {visited} blocks");
+	}
+}
@@ -0,0 +1,171 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Substrate client as the target of BEEFY commitments.
+
+use crate::{
+	beefy::{
+		BeefyCommitmentProof, BeefyHeaderProof, SubmitBeefyCommitmentCallBuilder,
+		SubstrateBeefyPipeline,
+	},
+	TransactionParams,
+};
+
+use async_trait::async_trait;
+use bp_beefy::{
+	storage_keys::{
+		current_authority_set_info_key, imported_commitments_info_key, pallet_operating_mode_key,
+	},
+	BeefyAuthoritySetInfoOf, ChainWithBeefy, ImportedCommitmentsInfoData,
+};
+use bp_runtime::{BasicOperatingMode, OperatingMode};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Client, Error, TransactionEra,
+	TransactionTracker, UnsignedTransaction,
+};
+use relay_utils::relay_loop::Client as RelayClient;
+use sp_core::Pair;
+
+/// Substrate client as the target of BEEFY commitments.
+pub struct SubstrateBeefyTarget<P: SubstrateBeefyPipeline, TargetClnt> {
+	client: TargetClnt,
+	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+}
+
+impl<P: SubstrateBeefyPipeline, TargetClnt: Client<P::TargetChain>>
+	SubstrateBeefyTarget<P, TargetClnt>
+{
+	/// Create new BEEFY commitments target.
+	pub fn new(
+		client: TargetClnt,
+		transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	) -> Self {
+		SubstrateBeefyTarget { client, transaction_params }
+	}
+
+	/// Returns reference to the underlying RPC client.
+	pub fn client(&self) -> &TargetClnt {
+		&self.client
+	}
+
+	/// Returns number of the best source block with imported commitment.
+	///
+	/// Note that the best source block, proven by this commitment, is its parent.
+	pub async fn best_imported_block_number(&self) -> Result<BlockNumberOf<P::SourceChain>, Error> {
+		// we can't relay commitments if target node is out of sync, because it may have
+		// already imported (some of) commitments that we're going to relay
+		self.client.ensure_synced().await?;
+
+		let at = self.client.best_header_hash().await?;
+		let operating_mode: Option<BasicOperatingMode> = self
+			.client
+			.storage_value(
+				at,
+				pallet_operating_mode_key(P::SourceChain::WITH_CHAIN_BEEFY_PALLET_NAME),
+			)
+			.await?;
+		if operating_mode.map(|mode| mode.is_halted()).unwrap_or(false) {
+			return Err(Error::BridgePalletIsHalted);
+		}
+
+		let info: Option<ImportedCommitmentsInfoData<BlockNumberOf<P::SourceChain>>> = self
+			.client
+			.storage_value(
+				at,
+				imported_commitments_info_key(P::SourceChain::WITH_CHAIN_BEEFY_PALLET_NAME),
+			)
+			.await?;
+		info.map(|info| info.best_block_number)
+			.ok_or(Error::BridgePalletIsNotInitialized)
+	}
+
+	/// Returns info on the authority set that is expected to sign the next imported commitment.
+	pub async fn current_authority_set_info(
+		&self,
+	) -> Result<BeefyAuthoritySetInfoOf<P::SourceChain>, Error> {
+		self.client
+			.storage_value(
+				self.client.best_header_hash().await?,
+				current_authority_set_info_key(P::SourceChain::WITH_CHAIN_BEEFY_PALLET_NAME),
+			)
+			.await?
+			.ok_or(Error::BridgePalletIsNotInitialized)
+	}
+
+	/// Submit commitment to the target chain.
+	pub async fn submit_commitment(
+		&self,
+		proof: BeefyCommitmentProof<P::SourceChain>,
+	) -> Result<TransactionTracker<P::TargetChain, TargetClnt>, Error>
+	where
+		AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+	{
+		self.submit_call(P::SubmitBeefyCommitmentCallBuilder::build_submit_commitment_call(proof))
+			.await
+	}
+
+	/// Submit header to the target chain.
+	pub async fn submit_header(
+		&self,
+		proof: BeefyHeaderProof<P::SourceChain>,
+	) -> Result<TransactionTracker<P::TargetChain, TargetClnt>, Error>
+	where
+		AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+	{
+		self.submit_call(P::SubmitBeefyCommitmentCallBuilder::build_submit_header_call(proof))
+			.await
+	}
+
+	/// Submit transaction with given call to the target chain.
+	async fn submit_call(
+		&self,
+		call: CallOf<P::TargetChain>,
+	) -> Result<TransactionTracker<P::TargetChain, TargetClnt>, Error>
+	where
+		AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+	{
+		let mortality = self.transaction_params.mortality;
+		self.client
+			.submit_and_watch_signed_extrinsic(
+				&self.transaction_params.signer,
+				move |best_block_id, transaction_nonce| {
+					Ok(UnsignedTransaction::new(call.into(), transaction_nonce)
+						.era(TransactionEra::new(best_block_id, mortality)))
+				},
+			)
+			.await
+	}
+}
+
+impl<P: SubstrateBeefyPipeline, TargetClnt: Clone> Clone for SubstrateBeefyTarget<P, TargetClnt> {
+	fn clone(&self) -> Self {
+		SubstrateBeefyTarget {
+			client: self.client.clone(),
+			transaction_params: self.transaction_params.clone(),
+		}
+	}
+}
+
+#[async_trait]
+impl<P: SubstrateBeefyPipeline, TargetClnt: Client<P::TargetChain>> RelayClient
+	for SubstrateBeefyTarget<P, TargetClnt>
+{
+	type Error = Error;
+
+	async fn reconnect(&mut self) -> Result<(), Error> {
+		self.client.reconnect().await
+	}
+}
@@ -18,7 +18,7 @@
 
 use crate::{
 	cli::{bridge::*, chain_schema::*, HexLaneId, PrometheusParams},
-	messages::MessagesRelayParams,
+	messages::{dry_run::MessagesDeliveryDryRunParams, MessagesRelayParams},
 	TransactionParams,
 };
 
@@ -100,6 +100,40 @@ pub struct RelayMessagesDeliveryConfirmationParams {
 	target: TargetConnectionParams,
 }
 
+/// Messages delivery dry-run params.
+#[derive(Parser)]
+pub struct DryRunMessagesParams {
+	/// Number of the source chain header that we will use to prepare a messages proof.
+	/// If not specified, the best source header, known to the target chain, is used.
+	#[arg(long)]
+	at_source_block: Option<u128>,
+	/// Number of the target chain header that we will execute the delivery transaction at.
+	/// If not specified, the best target header is used.
+	#[arg(long)]
+	at_target_block: Option<u128>,
+	/// Hex-encoded lane id that should be served by the relay.
+	#[arg(long)]
+	lane: HexLaneId,
+	/// Nonce (inclusive) of the first message to deliver. If not specified, all undelivered
+	/// messages that fit into single transaction are delivered.
+	#[arg(long, requires = "messages_end")]
+	messages_start: Option<MessageNonce>,
+	/// Nonce (inclusive) of the last message to deliver.
+	#[arg(long, requires = "messages_start")]
+	messages_end: Option<MessageNonce>,
+	/// Whether the outbound lane state proof should be included into transaction.
+	#[arg(long)]
+	outbound_state_proof_required: bool,
+	#[command(flatten)]
+	source: SourceConnectionParams,
+	#[command(flatten)]
+	source_sign: SourceSigningParams,
+	#[command(flatten)]
+	target: TargetConnectionParams,
+	#[command(flatten)]
+	target_sign: TargetSigningParams,
+}
+
 /// Trait used for relaying messages between 2 chains.
 #[async_trait]
 pub trait MessagesRelayer: MessagesCliBridge
@@ -221,6 +255,53 @@ where
 		.await
 	}
 
+	/// Build the messages delivery transaction and execute it at the target chain state,
+	/// without submitting it.
+	async fn dry_run_messages(data: DryRunMessagesParams) -> anyhow::Result<()> {
+		let source_client = data.source.into_client::<Self::Source>().await?;
+		let target_client = data.target.into_client::<Self::Target>().await?;
+		let source_sign = data.source_sign.to_keypair::<Self::Source>()?;
+		let source_transactions_mortality = data.source_sign.transactions_mortality()?;
+		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
+		let target_transactions_mortality = data.target_sign.transactions_mortality()?;
+		let lane_id = HexLaneId::try_convert(data.lane).map_err(|invalid_lane_id| {
+			anyhow::format_err!("Invalid laneId: {:?}!", invalid_lane_id)
+		})?;
+		let nonces = match (data.messages_start, data.messages_end) {
+			(Some(messages_start), Some(messages_end)) => Some(messages_start..=messages_end),
+			_ => None,
+		};
+
+		let result = crate::messages::dry_run::dry_run_messages_delivery::<Self::MessagesLane>(
+			MessagesDeliveryDryRunParams {
+				source_client,
+				source_transaction_params: TransactionParams {
+					signer: source_sign,
+					mortality: source_transactions_mortality,
+				},
+				target_client,
+				target_transaction_params: TransactionParams {
+					signer: target_sign,
+					mortality: target_transactions_mortality,
+				},
+				lane_id,
+				at_source_block: data.at_source_block.map(|n| n.unique_saturated_into()),
+				at_target_block: data.at_target_block.map(|n| n.unique_saturated_into()),
+				nonces,
+				outbound_state_proof_required: data.outbound_state_proof_required,
+			},
+		)
+		.await
+		.map_err(|e| anyhow::format_err!("{}", e))?;
+
+		if !result.is_success() {
+			tracing::error!(target: "bridge", "Messages delivery dry-run has failed: {result}");
+			return Err(anyhow::format_err!("Messages delivery transaction has failed"));
+		}
+		tracing::info!(target: "bridge", "Messages delivery dry-run has succeeded: {result}");
+		Ok(())
+	}
+
 	/// Add relay guards if required.
 	async fn start_relay_guards(
 		target_client: &impl Client<Self::Target>,
@@ -25,6 +25,7 @@ use std::marker::PhantomData;
 // to avoid `finality_relay` dependency in other crates
 pub use finality_relay::HeadersToRelay;
 
+pub mod beefy;
 pub mod cli;
 pub mod equivocation;
 pub mod error;
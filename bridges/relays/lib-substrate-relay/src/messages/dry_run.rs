@@ -0,0 +1,490 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-run of the messages delivery transaction.
+//!
+//! When the messages relay fails to deliver messages (e.g. because of weight mismatch or
+//! invalid proof), we want to find the reason without running the live relay. The dry-run
+//! builds exactly the same `receive_messages_proof` call that the relay would submit and
+//! executes the signed transaction against the target runtime, without submitting it. The
+//! same call is also dry-run using the `DryRunApi` of the target runtime to get the dispatch
+//! result of every delivered message from the `MessagesReceived` event.
+//!
+//! Both source and target nodes may be dev nodes or nodes, started from chain snapshots. If
+//! blocks are given, the messages proof is generated at the source block and the transaction is
+//! executed on top of the target block, so the failed delivery may be replayed later.
+
+use crate::{
+	finality_base::best_synced_header_id,
+	messages::{
+		messages_delivery_params, select_delivery_transaction_limits_rpc,
+		source::SubstrateMessagesSource,
+		target::{make_messages_delivery_call, SubstrateMessagesTarget},
+		MessageLaneAdapter, MessagesReceivedEventDecoder, SubstrateMessageLane,
+	},
+	TransactionParams,
+};
+
+use bp_messages::{ChainWithMessages as _, MessageNonce, ReceivedMessages, ReceptionResult};
+use bp_runtime::{Chain as _, HeaderIdProvider};
+use codec::Decode;
+use frame_support::weights::Weight;
+use messages_relay::{
+	message_lane_loop::{
+		MessageProofParameters, SourceClient as MessageLaneSourceClient,
+		TargetClient as MessageLaneTargetClient,
+	},
+	select_messages_range,
+};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, BalanceOf, BlockNumberOf, Chain, ChainWithTransactions, Client,
+	Error as SubstrateError, HeaderIdOf, SignParam, UnsignedTransaction,
+};
+use sp_core::{Bytes, Pair};
+use sp_runtime::{transaction_validity::TransactionValidity, ApplyExtrinsicResult};
+use sp_version::RuntimeVersion;
+use std::{fmt, ops::RangeInclusive};
+use xcm_runtime_apis::dry_run::{CallDryRunEffects, Error as DryRunApiError};
+
+/// Name of the runtime API method, returning the runtime version.
+pub const CORE_VERSION_METHOD: &str = "Core_version";
+/// Name of the runtime API method, returning the account nonce.
+pub const ACCOUNT_NONCE_METHOD: &str = "AccountNonceApi_account_nonce";
+/// Name of the runtime API method, applying the extrinsic.
+pub const APPLY_EXTRINSIC_METHOD: &str = "BlockBuilder_apply_extrinsic";
+/// Name of the runtime API method, dry-running the call.
+pub const DRY_RUN_CALL_METHOD: &str = "DryRunApi_dry_run_call";
+
+/// Event type of the target chain runtime.
+pub type RuntimeEventOf<P> =
+	<<P as SubstrateMessageLane>::MessagesReceivedEventDecoder as MessagesReceivedEventDecoder<
+		P,
+	>>::RuntimeEvent;
+/// Message dispatch result type of the target chain runtime.
+pub type DispatchLevelResultOf<P> =
+	<<P as SubstrateMessageLane>::MessagesReceivedEventDecoder as MessagesReceivedEventDecoder<
+		P,
+	>>::DispatchLevelResult;
+
+/// Messages delivery dry-run parameters.
+pub struct MessagesDeliveryDryRunParams<P: SubstrateMessageLane, SourceClnt, TargetClnt> {
+	/// Messages source client.
+	pub source_client: SourceClnt,
+	/// Source transaction params. The relayer account at the source chain is derived from them.
+	pub source_transaction_params: TransactionParams<AccountKeyPairOf<P::SourceChain>>,
+	/// Messages target client.
+	pub target_client: TargetClnt,
+	/// Target transaction params, used to sign the delivery transaction.
+	pub target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+	/// Identifier of lane, which messages are delivered.
+	pub lane_id: P::LaneId,
+	/// Number of the source block to generate messages proof at. If `None`, the best source
+	/// block, known to the target chain at `at_target_block`, is used.
+	pub at_source_block: Option<BlockNumberOf<P::SourceChain>>,
+	/// Number of the target block to execute the delivery transaction at. If `None`, the best
+	/// target block is used.
+	pub at_target_block: Option<BlockNumberOf<P::TargetChain>>,
+	/// Nonces of messages to deliver. If `None`, undelivered messages are selected the same way
+	/// the messages relay selects them for the single delivery transaction.
+	pub nonces: Option<RangeInclusive<MessageNonce>>,
+	/// Whether the outbound lane state proof should be included into transaction.
+	pub outbound_state_proof_required: bool,
+}
+
+/// Result of the messages delivery dry-run.
+#[derive(Debug)]
+pub struct MessagesDeliveryDryRun<P: SubstrateMessageLane> {
+	/// Identifier of lane, which messages are delivered.
+	pub lane_id: P::LaneId,
+	/// Source block, where messages proof has been generated.
+	pub at_source_block: HeaderIdOf<P::SourceChain>,
+	/// Target block, where the delivery transaction has been executed.
+	pub at_target_block: HeaderIdOf<P::TargetChain>,
+	/// Nonces of delivered messages.
+	pub nonces: RangeInclusive<MessageNonce>,
+	/// Cumulative dispatch weight of delivered messages, declared in the call.
+	pub declared_dispatch_weight: Weight,
+	/// Encoded `receive_messages_proof` call.
+	pub encoded_call: Bytes,
+	/// Size of the encoded delivery transaction.
+	pub transaction_size: usize,
+	/// Weight of the delivery transaction, computed by the target runtime.
+	pub transaction_weight: Result<Weight, SubstrateError>,
+	/// Transaction validity, reported by the target runtime transaction pool API.
+	pub validity: Result<TransactionValidity, SubstrateError>,
+	/// Result of applying the delivery transaction to the target chain state.
+	pub apply_result: Result<ApplyExtrinsicResult, SubstrateError>,
+	/// Reception results of delivered messages, extracted from the `MessagesReceived` events.
+	pub reception_results:
+		Result<Vec<(MessageNonce, ReceptionResult<DispatchLevelResultOf<P>>)>, SubstrateError>,
+}
+
+impl<P: SubstrateMessageLane> MessagesDeliveryDryRun<P> {
+	/// Returns true if the delivery transaction is valid and has been dispatched successfully
+	/// and none of delivered messages has been rejected by the target chain.
+	pub fn is_success(&self) -> bool {
+		let no_rejected_messages = match self.reception_results {
+			Ok(ref results) => results
+				.iter()
+				.all(|(_, result)| matches!(result, ReceptionResult::Dispatched(_))),
+			Err(_) => true,
+		};
+		matches!(self.validity, Ok(Ok(_))) &&
+			matches!(self.apply_result, Ok(Ok(Ok(())))) &&
+			no_rejected_messages
+	}
+}
+
+impl<P: SubstrateMessageLane> fmt::Display for MessagesDeliveryDryRun<P> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"{} -> {} messages {:?} of lane {:?}",
+			P::SourceChain::NAME,
+			P::TargetChain::NAME,
+			self.nonces,
+			self.lane_id,
+		)?;
+		writeln!(
+			f,
+			"  proof generated at {} block: {:?}",
+			P::SourceChain::NAME,
+			self.at_source_block
+		)?;
+		writeln!(f, "  executed at {} block: {:?}", P::TargetChain::NAME, self.at_target_block)?;
+		writeln!(f, "  declared dispatch weight: {}", self.declared_dispatch_weight)?;
+		writeln!(f, "  call: 0x{}", hex::encode(&self.encoded_call.0))?;
+		writeln!(f, "  transaction size: {}", self.transaction_size)?;
+		match self.transaction_weight {
+			Ok(ref weight) => writeln!(f, "  transaction weight: {weight}")?,
+			Err(ref e) => writeln!(f, "  transaction weight: failed to compute: {e:?}")?,
+		}
+		match self.validity {
+			Ok(Ok(ref valid)) => writeln!(f, "  validity: valid, priority {}", valid.priority)?,
+			Ok(Err(ref e)) => writeln!(f, "  validity: rejected: {e:?}")?,
+			Err(ref e) => writeln!(f, "  validity: failed to validate: {e:?}")?,
+		}
+		match self.apply_result {
+			Ok(Ok(Ok(()))) => writeln!(f, "  dispatch result: success")?,
+			Ok(Ok(Err(ref e))) => writeln!(f, "  dispatch result: failed: {e:?}")?,
+			Ok(Err(ref e)) => writeln!(f, "  dispatch result: rejected: {e:?}")?,
+			Err(ref e) => writeln!(f, "  dispatch result: failed to apply: {e:?}")?,
+		}
+		match self.reception_results {
+			Ok(ref results) => {
+				for (nonce, result) in results {
+					match result {
+						ReceptionResult::Dispatched(result) => writeln!(
+							f,
+							"  message {nonce}: dispatched: {:?}, unspent weight: {}",
+							result.dispatch_level_result, result.unspent_weight,
+						)?,
+						result => writeln!(f, "  message {nonce}: rejected: {result:?}")?,
+					}
+				}
+				Ok(())
+			},
+			Err(ref e) => writeln!(f, "  messages reception results: failed to dry-run: {e:?}"),
+		}
+	}
+}
+
+/// Build the messages delivery transaction and execute it against the target runtime, without
+/// submitting it.
+///
+/// Note that the transaction is applied on top of the `at_target_block` state, without
+/// initializing a new block. So the block-level checks (e.g. the block weight limit) are
+/// performed against the already finalized `at_target_block`.
+pub async fn dry_run_messages_delivery<P: SubstrateMessageLane>(
+	params: MessagesDeliveryDryRunParams<
+		P,
+		impl Client<P::SourceChain>,
+		impl Client<P::TargetChain>,
+	>,
+) -> Result<MessagesDeliveryDryRun<P>, SubstrateError>
+where
+	AccountIdOf<P::SourceChain>: From<<AccountKeyPairOf<P::SourceChain> as Pair>::Public>,
+	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
+	BalanceOf<P::SourceChain>: TryFrom<BalanceOf<P::TargetChain>>,
+{
+	let source_client = params.source_client;
+	let target_client = params.target_client;
+	let target_signer = params.target_transaction_params.signer.clone();
+	let relayer_id_at_source: AccountIdOf<P::SourceChain> =
+		params.source_transaction_params.signer.public().into();
+
+	// select blocks to generate proof at and to execute transaction at
+	let at_target_block = match params.at_target_block {
+		Some(at_target_block) => target_client.header_by_number(at_target_block).await?.id(),
+		None => target_client.best_header().await?.id(),
+	};
+	let at_source_block = match params.at_source_block {
+		Some(at_source_block) => source_client.header_by_number(at_source_block).await?.id(),
+		None => best_synced_header_id::<P::SourceChain, P::TargetChain>(
+			&target_client,
+			at_target_block.hash(),
+		)
+		.await?
+		.ok_or(SubstrateError::BridgePalletIsNotInitialized)?,
+	};
+
+	let source = SubstrateMessagesSource::<P, _, _>::new(
+		source_client.clone(),
+		target_client.clone(),
+		params.lane_id,
+		params.source_transaction_params,
+		None,
+	);
+	let target = SubstrateMessagesTarget::<P, _, _>::new(
+		target_client.clone(),
+		source_client,
+		params.lane_id,
+		relayer_id_at_source.clone(),
+		Some(params.target_transaction_params),
+		None,
+	);
+
+	// select messages to deliver
+	let nonces = match params.nonces {
+		Some(nonces) => nonces,
+		None => {
+			let (_, latest_received_nonce) = target.latest_received_nonce(at_target_block).await?;
+			let (_, latest_generated_nonce) =
+				source.latest_generated_nonce(at_source_block).await?;
+			let limits = select_delivery_transaction_limits_rpc::<P>(
+				&target_client,
+				relayer_id_at_source.clone(),
+				&target_signer,
+				P::TargetChain::max_extrinsic_weight(),
+				P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
+			)
+			.await
+			.map_err(|e| SubstrateError::Custom(format!("{e:?}")))?;
+			let delivery_params = messages_delivery_params::<P>(limits);
+			let max_nonce = std::cmp::min(
+				latest_generated_nonce,
+				latest_received_nonce
+					.saturating_add(delivery_params.max_unconfirmed_nonces_at_target),
+			);
+			let messages = if max_nonce > latest_received_nonce {
+				source
+					.generated_message_details(
+						at_source_block,
+						latest_received_nonce + 1..=max_nonce,
+					)
+					.await?
+			} else {
+				Default::default()
+			};
+			select_messages_range::<MessageLaneAdapter<P>>(
+				at_source_block,
+				messages,
+				latest_received_nonce,
+				&delivery_params,
+			)
+			.await
+			.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"There are no undelivered {} -> {} messages at blocks {:?} and {:?}",
+					P::SourceChain::NAME,
+					P::TargetChain::NAME,
+					at_source_block,
+					at_target_block,
+				))
+			})?
+		},
+	};
+
+	// build exactly the same call as the relay does
+	let declared_dispatch_weight = source
+		.generated_message_details(at_source_block, nonces.clone())
+		.await?
+		.values()
+		.fold(Weight::zero(), |total, details| total.saturating_add(details.dispatch_weight));
+	let (_, nonces, proof) = source
+		.prove_messages(
+			at_source_block,
+			nonces,
+			MessageProofParameters {
+				outbound_state_proof_required: params.outbound_state_proof_required,
+				dispatch_weight: declared_dispatch_weight,
+			},
+		)
+		.await?;
+	let call = make_messages_delivery_call::<P>(relayer_id_at_source, nonces.clone(), proof, false);
+	let encoded_call = Bytes(codec::Encode::encode(&call));
+
+	// sign transaction, using the runtime version and the signer nonce at the target block
+	let at = at_target_block.hash();
+	let runtime_version: RuntimeVersion =
+		target_client.state_call(at, CORE_VERSION_METHOD.into(), ()).await?;
+	let signer_id: AccountIdOf<P::TargetChain> = target_signer.public().into();
+	let signer_nonce = target_client
+		.state_call(at, ACCOUNT_NONCE_METHOD.into(), signer_id.clone())
+		.await?;
+	let transaction = P::TargetChain::sign_transaction(
+		SignParam {
+			spec_version: runtime_version.spec_version,
+			transaction_version: runtime_version.transaction_version,
+			genesis_hash: target_client.genesis_hash(),
+			signer: target_signer,
+		},
+		UnsignedTransaction::new(call.clone().into(), signer_nonce),
+	)?;
+	let transaction_size = codec::Encode::encoded_size(&transaction);
+
+	// and finally execute it
+	let transaction_weight = target_client.estimate_extrinsic_weight(at, transaction.clone()).await;
+	let validity = target_client.validate_transaction(at, transaction.clone()).await;
+	let apply_result = target_client
+		.raw_state_call(at, APPLY_EXTRINSIC_METHOD.into(), transaction)
+		.await
+		.and_then(|result| {
+			ApplyExtrinsicResult::decode(&mut &result.0[..]).map_err(|e| {
+				SubstrateError::Custom(format!(
+					"Failed to decode {} `{APPLY_EXTRINSIC_METHOD}` result: {e:?}",
+					P::TargetChain::NAME,
+				))
+			})
+		});
+
+	let reception_results = target_client
+		.state_call::<_, Result<CallDryRunEffects<RuntimeEventOf<P>>, DryRunApiError>>(
+			at,
+			DRY_RUN_CALL_METHOD.into(),
+			(P::MessagesReceivedEventDecoder::signed_origin(signer_id), call, xcm::latest::VERSION),
+		)
+		.await
+		.and_then(|effects| {
+			effects.map_err(|e| {
+				SubstrateError::Custom(format!(
+					"{} `{DRY_RUN_CALL_METHOD}` has failed: {e:?}",
+					P::TargetChain::NAME,
+				))
+			})
+		})
+		.map(|effects| reception_results::<P>(effects.emitted_events));
+
+	Ok(MessagesDeliveryDryRun {
+		lane_id: params.lane_id,
+		at_source_block,
+		at_target_block,
+		nonces,
+		declared_dispatch_weight,
+		encoded_call,
+		transaction_size,
+		transaction_weight,
+		validity,
+		apply_result,
+		reception_results,
+	})
+}
+
+/// Returns reception results of messages, received by the target chain, from the runtime events.
+fn reception_results<P: SubstrateMessageLane>(
+	events: Vec<RuntimeEventOf<P>>,
+) -> Vec<(MessageNonce, ReceptionResult<DispatchLevelResultOf<P>>)> {
+	events
+		.into_iter()
+		.filter_map(P::MessagesReceivedEventDecoder::messages_received)
+		.flat_map(|received: ReceivedMessages<_, _>| received.receive_results)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::messages::tests::{
+		mock::{OriginCaller, RuntimeEvent, TestLaneIdType, TestRuntime},
+		relayer::ThisChainToBridgedChainMessageLane,
+	};
+	use bp_messages::LaneIdType;
+	use bp_runtime::messages::MessageDispatchResult;
+	use codec::Encode;
+	use relay_utils::HeaderId;
+	use sp_runtime::transaction_validity::ValidTransaction;
+
+	type TestLane = ThisChainToBridgedChainMessageLane;
+
+	fn dispatched(unspent_weight: u64) -> ReceptionResult<()> {
+		ReceptionResult::Dispatched(MessageDispatchResult {
+			unspent_weight: Weight::from_parts(unspent_weight, 0),
+			dispatch_level_result: (),
+		})
+	}
+
+	fn messages_received(
+		receive_results: Vec<(MessageNonce, ReceptionResult<()>)>,
+	) -> RuntimeEvent {
+		RuntimeEvent::Messages(pallet_bridge_messages::Event::MessagesReceived(
+			ReceivedMessages::new(TestLaneIdType::try_new(1, 2).unwrap(), receive_results),
+		))
+	}
+
+	fn dry_run(
+		reception_results: Result<Vec<(MessageNonce, ReceptionResult<()>)>, SubstrateError>,
+	) -> MessagesDeliveryDryRun<TestLane> {
+		MessagesDeliveryDryRun {
+			lane_id: TestLaneIdType::try_new(1, 2).unwrap(),
+			at_source_block: HeaderId(1, Default::default()),
+			at_target_block: HeaderId(1, Default::default()),
+			nonces: 1..=2,
+			declared_dispatch_weight: Weight::zero(),
+			encoded_call: Bytes(vec![]),
+			transaction_size: 0,
+			transaction_weight: Ok(Weight::zero()),
+			validity: Ok(Ok(ValidTransaction::default())),
+			apply_result: Ok(Ok(Ok(()))),
+			reception_results,
+		}
+	}
+
+	#[test]
+	fn signed_origin_is_system_signed_origin() {
+		assert_eq!(
+			<TestLane as SubstrateMessageLane>::MessagesReceivedEventDecoder::signed_origin(42)
+				.encode(),
+			OriginCaller::system(frame_system::RawOrigin::<u64>::Signed(42)).encode(),
+		);
+	}
+
+	#[test]
+	fn reception_results_are_read_from_messages_received_events() {
+		let events = vec![
+			RuntimeEvent::System(frame_system::Event::<TestRuntime>::NewAccount { account: 42 }),
+			messages_received(vec![(1, dispatched(10)), (2, ReceptionResult::InvalidNonce)]),
+			RuntimeEvent::System(frame_system::Event::<TestRuntime>::KilledAccount { account: 42 }),
+		];
+		// the dry-run api returns encoded events
+		let events = Decode::decode(&mut &events.encode()[..]).unwrap();
+
+		assert_eq!(
+			reception_results::<TestLane>(events),
+			vec![(1, dispatched(10)), (2, ReceptionResult::InvalidNonce)],
+		);
+		assert_eq!(reception_results::<TestLane>(vec![]), vec![]);
+	}
+
+	#[test]
+	fn dry_run_fails_if_any_message_is_rejected() {
+		assert!(dry_run(Ok(vec![(1, dispatched(0)), (2, dispatched(0))])).is_success());
+		assert!(dry_run(Err(SubstrateError::Custom("no DryRunApi".into()))).is_success());
+
+		let result = dry_run(Ok(vec![(1, dispatched(0)), (2, ReceptionResult::InvalidNonce)]));
+		assert!(!result.is_success());
+		assert!(result.to_string().contains("message 2: rejected: InvalidNonce"));
+	}
+}
@@ -26,13 +26,20 @@ use crate::{
 };
 
 use bp_messages::{
-	target_chain::FromBridgedChainMessagesProof, ChainWithMessages as _, MessageNonce,
+	target_chain::{FromBridgedChainMessagesProof, MessageDispatch},
+	ChainWithMessages as _, MessageNonce, ReceivedMessages,
 };
 use bp_runtime::{AccountIdOf, EncodedOrDecodedCall, HeaderIdOf, TransactionEra, WeightExtraOps};
-use codec::{Codec, Encode, EncodeLike};
-use frame_support::{dispatch::GetDispatchInfo, weights::Weight};
-use messages_relay::{message_lane::MessageLane, message_lane_loop::BatchTransaction, Labeled};
-use pallet_bridge_messages::{Call as BridgeMessagesCall, Config as BridgeMessagesConfig};
+use codec::{Codec, Decode, Encode, EncodeLike};
+use frame_support::{dispatch::GetDispatchInfo, traits::OriginTrait, weights::Weight};
+use messages_relay::{
+	message_lane::MessageLane,
+	message_lane_loop::{BatchTransaction, MessageDeliveryParams},
+	Labeled,
+};
+use pallet_bridge_messages::{
+	Call as BridgeMessagesCall, Config as BridgeMessagesConfig, Event as BridgeMessagesEvent,
+};
 use relay_substrate_client::{
 	transaction_stall_timeout, AccountKeyPairOf, BalanceOf, BlockNumberOf, CallOf, Chain,
 	ChainBase, ChainWithMessages, ChainWithTransactions, Client, Error as SubstrateError, HashOf,
@@ -46,6 +53,7 @@ use sp_core::Pair;
 use sp_runtime::traits::Zero;
 use std::{fmt::Debug, marker::PhantomData, ops::RangeInclusive, sync::Arc};
 
+pub mod dry_run;
 pub mod metrics;
 pub mod source;
 pub mod target;
@@ -73,6 +81,8 @@ pub trait SubstrateMessageLane: 'static + Clone + Debug + Send + Sync {
 	type ReceiveMessagesProofCallBuilder: ReceiveMessagesProofCallBuilder<Self>;
 	/// How receive messages delivery proof call is built?
 	type ReceiveMessagesDeliveryProofCallBuilder: ReceiveMessagesDeliveryProofCallBuilder<Self>;
+	/// How `MessagesReceived` events of the target chain are decoded?
+	type MessagesReceivedEventDecoder: MessagesReceivedEventDecoder<Self>;
 
 	/// How batch calls are built at the source chain?
 	type SourceBatchCallBuilder: BatchCallBuilderConstructor<CallOf<Self::SourceChain>>;
@@ -206,21 +216,20 @@ where
 	AccountIdOf<P::TargetChain>: From<<AccountKeyPairOf<P::TargetChain> as Pair>::Public>,
 	BalanceOf<P::SourceChain>: TryFrom<BalanceOf<P::TargetChain>>,
 {
-	// 2/3 is reserved for proofs and tx overhead
-	let max_messages_size_in_single_batch = P::TargetChain::max_extrinsic_size() / 3;
 	let limits = match params.limits {
 		Some(limits) => limits,
 		None => {
-			select_delivery_transaction_limits_rpc(
-				&params,
+			select_delivery_transaction_limits_rpc::<P>(
+				&params.target_client,
+				params.source_transaction_params.signer.public().into(),
+				&params.target_transaction_params.signer,
 				P::TargetChain::max_extrinsic_weight(),
 				P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
 			)
 			.await?
 		},
 	};
-	let (max_messages_in_single_batch, max_messages_weight_in_single_batch) =
-		(limits.max_messages_in_single_batch / 2, limits.max_messages_weight_in_single_batch / 2);
+	let delivery_params = messages_delivery_params::<P>(limits);
 
 	let source_client = params.source_client;
 	let target_client = params.target_client;
@@ -232,9 +241,9 @@ where
 		source=%P::SourceChain::NAME,
 		target=%P::TargetChain::NAME,
 		?relayer_id_at_source,
-		%max_messages_in_single_batch,
-		%max_messages_size_in_single_batch,
-		%max_messages_weight_in_single_batch,
+		max_messages_in_single_batch=%delivery_params.max_messages_in_single_batch,
+		max_messages_size_in_single_batch=%delivery_params.max_messages_size_in_single_batch,
+		max_messages_weight_in_single_batch=%delivery_params.max_messages_weight_in_single_batch,
 		source_tx_mortality=?params.source_transaction_params.mortality,
 		source_tx_stall_timeout_as_mins=%transaction_stall_timeout(
 			params.source_transaction_params.mortality,
@@ -256,15 +265,7 @@ where
 			source_tick: P::SourceChain::AVERAGE_BLOCK_INTERVAL,
 			target_tick: P::TargetChain::AVERAGE_BLOCK_INTERVAL,
 			reconnect_delay: relay_utils::relay_loop::RECONNECT_DELAY,
-			delivery_params: messages_relay::message_lane_loop::MessageDeliveryParams {
-				max_unrewarded_relayer_entries_at_target:
-					P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
-				max_unconfirmed_nonces_at_target:
-					P::SourceChain::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX,
-				max_messages_in_single_batch,
-				max_messages_weight_in_single_batch,
-				max_messages_size_in_single_batch,
-			},
+			delivery_params,
 		},
 		SubstrateMessagesSource::<P, _, _>::new(
 			source_client.clone(),
@@ -558,18 +559,66 @@ macro_rules! generate_receive_message_delivery_proof_call_builder {
 	};
 }
 
-/// Returns maximal number of messages and their maximal cumulative dispatch weight.
-async fn select_delivery_transaction_limits_rpc<P, SourceClnt, TargetClnt>(
-	params: &MessagesRelayParams<P, SourceClnt, TargetClnt>,
-	max_extrinsic_weight: Weight,
-	max_unconfirmed_messages_at_inbound_lane: MessageNonce,
-) -> anyhow::Result<MessagesRelayLimits>
+/// Different ways of decoding `MessagesReceived` events, deposited by the bridge messages module
+/// at the target chain.
+pub trait MessagesReceivedEventDecoder<P: SubstrateMessageLane> {
+	/// Origin caller (`OriginCaller`) type of the target chain runtime.
+	type OriginCaller: Encode + Send;
+	/// Event (`RuntimeEvent`) type of the target chain runtime.
+	type RuntimeEvent: Decode + Send;
+	/// Message dispatch result, reported by the target chain runtime.
+	type DispatchLevelResult: Debug + Send;
+
+	/// Returns origin of the call, signed by given target chain account.
+	fn signed_origin(account: AccountIdOf<P::TargetChain>) -> Self::OriginCaller;
+
+	/// If given event is the `MessagesReceived` event, return received messages.
+	fn messages_received(
+		event: Self::RuntimeEvent,
+	) -> Option<ReceivedMessages<Self::DispatchLevelResult, P::LaneId>>;
+}
+
+/// Decoding `MessagesReceived` events when you have direct access to the target chain runtime.
+pub struct DirectMessagesReceivedEventDecoder<P, R, I> {
+	_phantom: PhantomData<(P, R, I)>,
+}
+
+impl<P, R, I> MessagesReceivedEventDecoder<P> for DirectMessagesReceivedEventDecoder<P, R, I>
 where
 	P: SubstrateMessageLane,
-	SourceClnt: Client<P::SourceChain>,
-	TargetClnt: Client<P::TargetChain>,
-	AccountIdOf<P::SourceChain>: From<<AccountKeyPairOf<P::SourceChain> as Pair>::Public>,
+	R: BridgeMessagesConfig<I, LaneId = P::LaneId>,
+	R: frame_system::Config<AccountId = AccountIdOf<P::TargetChain>>,
+	I: 'static,
+	<R::RuntimeOrigin as OriginTrait>::PalletsOrigin: Encode,
+	<R as frame_system::Config>::RuntimeEvent: TryInto<BridgeMessagesEvent<R, I>> + Send,
+	<R::MessageDispatch as MessageDispatch>::DispatchLevelResult: Send,
 {
+	type OriginCaller = <R::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+	type RuntimeEvent = <R as frame_system::Config>::RuntimeEvent;
+	type DispatchLevelResult = <R::MessageDispatch as MessageDispatch>::DispatchLevelResult;
+
+	fn signed_origin(account: AccountIdOf<P::TargetChain>) -> Self::OriginCaller {
+		R::RuntimeOrigin::signed(account).into_caller()
+	}
+
+	fn messages_received(
+		event: Self::RuntimeEvent,
+	) -> Option<ReceivedMessages<Self::DispatchLevelResult, P::LaneId>> {
+		match event.try_into() {
+			Ok(BridgeMessagesEvent::MessagesReceived(received)) => Some(received),
+			_ => None,
+		}
+	}
+}
+
+/// Returns maximal number of messages and their maximal cumulative dispatch weight.
+pub(crate) async fn select_delivery_transaction_limits_rpc<P: SubstrateMessageLane>(
+	target_client: &impl Client<P::TargetChain>,
+	relayer_id_at_source: AccountIdOf<P::SourceChain>,
+	target_signer: &AccountKeyPairOf<P::TargetChain>,
+	max_extrinsic_weight: Weight,
+	max_unconfirmed_messages_at_inbound_lane: MessageNonce,
+) -> anyhow::Result<MessagesRelayLimits> {
 	// We may try to guess accurate value, based on maximal number of messages and per-message
 	// weight overhead, but the relay loop isn't using this info in a super-accurate way anyway.
 	// So just a rough guess: let's say 1/3 of max tx weight is for tx itself and the rest is
@@ -583,10 +632,10 @@ where
 	let weight_for_messages_dispatch = max_extrinsic_weight - weight_for_delivery_tx;
 
 	// weight of empty message delivery with outbound lane state
-	let best_target_block_hash = params.target_client.best_header_hash().await?;
-	let delivery_tx_with_zero_messages = dummy_messages_delivery_transaction::<P, _, _>(params, 0)?;
-	let delivery_tx_with_zero_messages_weight = params
-		.target_client
+	let best_target_block_hash = target_client.best_header_hash().await?;
+	let delivery_tx_with_zero_messages =
+		dummy_messages_delivery_transaction::<P>(relayer_id_at_source.clone(), target_signer, 0)?;
+	let delivery_tx_with_zero_messages_weight = target_client
 		.estimate_extrinsic_weight(best_target_block_hash, delivery_tx_with_zero_messages)
 		.await
 		.map_err(|e| {
@@ -594,9 +643,9 @@ where
 		})?;
 
 	// weight of single message delivery with outbound lane state
-	let delivery_tx_with_one_message = dummy_messages_delivery_transaction::<P, _, _>(params, 1)?;
-	let delivery_tx_with_one_message_weight = params
-		.target_client
+	let delivery_tx_with_one_message =
+		dummy_messages_delivery_transaction::<P>(relayer_id_at_source, target_signer, 1)?;
+	let delivery_tx_with_one_message_weight = target_client
 		.estimate_extrinsic_weight(best_target_block_hash, delivery_tx_with_one_message)
 		.await
 		.map_err(|e| {
@@ -631,14 +680,28 @@ where
 	})
 }
 
+/// Returns parameters of the messages delivery race for given delivery transaction limits.
+pub(crate) fn messages_delivery_params<P: SubstrateMessageLane>(
+	limits: MessagesRelayLimits,
+) -> MessageDeliveryParams {
+	MessageDeliveryParams {
+		max_unrewarded_relayer_entries_at_target:
+			P::SourceChain::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX,
+		max_unconfirmed_nonces_at_target:
+			P::SourceChain::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX,
+		max_messages_in_single_batch: limits.max_messages_in_single_batch / 2,
+		max_messages_weight_in_single_batch: limits.max_messages_weight_in_single_batch / 2,
+		// 2/3 is reserved for proofs and tx overhead
+		max_messages_size_in_single_batch: P::TargetChain::max_extrinsic_size() / 3,
+	}
+}
+
 /// Returns dummy message delivery transaction with zero messages and `1kb` proof.
-fn dummy_messages_delivery_transaction<P: SubstrateMessageLane, SourceClnt, TargetClnt>(
-	params: &MessagesRelayParams<P, SourceClnt, TargetClnt>,
+fn dummy_messages_delivery_transaction<P: SubstrateMessageLane>(
+	relayer_id_at_source: AccountIdOf<P::SourceChain>,
+	target_signer: &AccountKeyPairOf<P::TargetChain>,
 	messages: u32,
-) -> anyhow::Result<<P::TargetChain as ChainWithTransactions>::SignedTransaction>
-where
-	AccountIdOf<P::SourceChain>: From<<AccountKeyPairOf<P::SourceChain> as Pair>::Public>,
-{
+) -> anyhow::Result<<P::TargetChain as ChainWithTransactions>::SignedTransaction> {
 	// we don't care about any call values here, because all that the estimation RPC does
 	// is calls `GetDispatchInfo::get_dispatch_info` for the wrapped call. So we only are
 	// interested in values that affect call weight - e.g. number of messages and the
@@ -646,7 +709,7 @@ where
 
 	let dummy_messages_delivery_call =
 		P::ReceiveMessagesProofCallBuilder::build_receive_messages_proof_call(
-			params.source_transaction_params.signer.public().into(),
+			relayer_id_at_source,
 			(
 				Weight::zero(),
 				FromBridgedChainMessagesProof {
@@ -666,7 +729,7 @@ where
 			spec_version: 0,
 			transaction_version: 0,
 			genesis_hash: Default::default(),
-			signer: params.target_transaction_params.signer.clone(),
+			signer: target_signer.clone(),
 		},
 		UnsignedTransaction {
 			call: EncodedOrDecodedCall::Decoded(dummy_messages_delivery_call),
@@ -818,7 +881,7 @@ mod tests {
 
 	// mock runtime with `pallet_bridge_messages`
 	#[allow(unexpected_cfgs)]
-	mod mock {
+	pub(crate) mod mock {
 		use super::super::*;
 		use bp_messages::{target_chain::ForbidInboundMessages, HashedLaneId};
 		use bp_runtime::ChainId;
@@ -931,7 +994,7 @@ mod tests {
 	}
 
 	// relayer configuration
-	mod relayer {
+	pub(crate) mod relayer {
 		use super::*;
 		use crate::{
 			messages::{
@@ -1024,6 +1087,8 @@ mod tests {
 				ThisChainToBridgedChainMessageLaneReceiveMessagesProofCallBuilder;
 			type ReceiveMessagesDeliveryProofCallBuilder =
 				ThisChainToBridgedChainMessageLaneReceiveMessagesDeliveryProofCallBuilder;
+			type MessagesReceivedEventDecoder =
+				DirectMessagesReceivedEventDecoder<Self, mock::TestRuntime, ()>;
 			type SourceBatchCallBuilder = UtilityPalletBatchCallBuilder<ThisChain>;
 			type TargetBatchCallBuilder = UtilityPalletBatchCallBuilder<BridgedChain>;
 		}
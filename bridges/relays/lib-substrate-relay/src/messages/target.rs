@@ -345,7 +345,7 @@ where
 }
 
 /// Make messages delivery call from given proof.
-fn make_messages_delivery_call<P: SubstrateMessageLane>(
+pub(crate) fn make_messages_delivery_call<P: SubstrateMessageLane>(
 	relayer_id_at_source: AccountIdOf<P::SourceChain>,
 	nonces: RangeInclusive<MessageNonce>,
 	proof: SubstrateMessagesProof<P::SourceChain, P::LaneId>,
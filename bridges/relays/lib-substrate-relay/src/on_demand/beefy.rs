@@ -0,0 +1,229 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! On-demand Substrate -> Substrate BEEFY commitments relay.
+
+use crate::{
+	beefy::{
+		prove_commitment_with_handoffs, run_until_connection_lost, source::SubstrateBeefySource,
+		target::SubstrateBeefyTarget, RequiredHeaderNumberRef, SubmitBeefyCommitmentCallBuilder,
+		SubstrateBeefyPipeline,
+	},
+	on_demand::OnDemandRelay,
+	TransactionParams,
+};
+
+use async_trait::async_trait;
+use bp_runtime::HeaderIdProvider;
+use finality_relay::HeadersToRelay;
+use futures::StreamExt;
+use num_traits::{One, Saturating, Zero};
+use relay_substrate_client::{
+	AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain, Client, Error as SubstrateError,
+	HeaderIdOf,
+};
+use relay_utils::STALL_TIMEOUT;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// On-demand Substrate <-> Substrate BEEFY commitments relay.
+///
+/// This relay may be requested to sync more commitments, whenever some other relay (e.g.
+/// messages relay) needs it to continue its regular work. Otherwise, it only relays commitments
+/// that change the authority set at the target chain.
+///
+/// The block with imported commitment is not proven at the target chain - it is its parent.
+/// Headers, required by other relays, are imported along with the MMR leaf proofs, generated by
+/// the [`SubstrateBeefySource::prove_header`], against the best imported commitment.
+#[derive(Clone)]
+pub struct OnDemandBeefyRelay<P: SubstrateBeefyPipeline, SourceClnt, TargetClnt> {
+	/// Relay task name.
+	relay_task_name: String,
+	/// Shared reference to maximal required proven header number.
+	required_header_number: RequiredHeaderNumberRef<P::SourceChain>,
+	/// Source of BEEFY commitments.
+	source: SubstrateBeefySource<P, SourceClnt>,
+	/// Target of BEEFY commitments.
+	target: SubstrateBeefyTarget<P, TargetClnt>,
+}
+
+impl<
+		P: SubstrateBeefyPipeline,
+		SourceClnt: Client<P::SourceChain>,
+		TargetClnt: Client<P::TargetChain>,
+	> OnDemandBeefyRelay<P, SourceClnt, TargetClnt>
+{
+	/// Create new on-demand BEEFY commitments relay.
+	pub fn new(
+		source_client: SourceClnt,
+		target_client: TargetClnt,
+		target_transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
+		headers_to_relay: HeadersToRelay,
+	) -> Self
+	where
+		AccountIdOf<P::TargetChain>:
+			From<<AccountKeyPairOf<P::TargetChain> as sp_core::Pair>::Public>,
+	{
+		let relay_task_name = on_demand_beefy_relay_name::<P::SourceChain, P::TargetChain>();
+		let required_header_number = Arc::new(Mutex::new(Zero::zero()));
+		let source = SubstrateBeefySource::new(source_client);
+		let target = SubstrateBeefyTarget::new(target_client, target_transaction_params);
+		let this = OnDemandBeefyRelay {
+			relay_task_name: relay_task_name.clone(),
+			required_header_number: required_header_number.clone(),
+			source: source.clone(),
+			target: target.clone(),
+		};
+		tokio::spawn(async move {
+			let result = relay_utils::relay_loop(source, target)
+				.run(relay_task_name.clone(), move |source, target, _| {
+					run_until_connection_lost(
+						source,
+						target,
+						headers_to_relay,
+						Some(required_header_number.clone()),
+					)
+				})
+				.await;
+			if let Err(error) = result {
+				tracing::error!(
+					target: "bridge",
+					%relay_task_name,
+					?error,
+					"On-demand BEEFY relay has stopped"
+				);
+			}
+		});
+
+		this
+	}
+}
+
+#[async_trait]
+impl<
+		P: SubstrateBeefyPipeline,
+		SourceClnt: Client<P::SourceChain>,
+		TargetClnt: Client<P::TargetChain>,
+	> OnDemandRelay<P::SourceChain, P::TargetChain> for OnDemandBeefyRelay<P, SourceClnt, TargetClnt>
+{
+	async fn reconnect(&self) -> Result<(), SubstrateError> {
+		// using clone is fine here (to avoid mut requirement), because clone on Client clones
+		// internal references
+		self.source.client().clone().reconnect().await?;
+		self.target.client().clone().reconnect().await
+	}
+
+	async fn require_more_headers(&self, required_header: BlockNumberOf<P::SourceChain>) {
+		let mut required_header_number = self.required_header_number.lock().await;
+		if required_header > *required_header_number {
+			tracing::trace!(
+				target: "bridge",
+				relay_task_name=%self.relay_task_name,
+				source=%P::SourceChain::NAME,
+				%required_header,
+				"More headers required. Going to sync up"
+			);
+
+			*required_header_number = required_header;
+		}
+	}
+
+	async fn prove_header(
+		&self,
+		required_header: BlockNumberOf<P::SourceChain>,
+	) -> Result<(HeaderIdOf<P::SourceChain>, Vec<CallOf<P::TargetChain>>), SubstrateError> {
+		// header is proven by the MMR leaf, inserted at its child, so the commitment for the
+		// child (or any of its descendants) must be imported by the target chain. If it has not
+		// been imported yet, we are waiting for it in the justifications stream, because
+		// justifications of non-mandatory blocks are not stored by the source chain
+		let best_imported = self.target.best_imported_block_number().await?;
+		let required_commitment = required_header.saturating_add(One::one());
+		let mut calls = Vec::with_capacity(3);
+		let commitment_block_number = if best_imported >= required_commitment {
+			best_imported
+		} else {
+			let current_set_id = self.target.current_authority_set_info().await?.id;
+			let mut commitments = self.source.commitments().await?;
+			let commitment = tokio::time::timeout(STALL_TIMEOUT, async {
+				while let Some(commitment) = commitments.next().await {
+					if commitment.commitment.block_number >= required_commitment &&
+						commitment.commitment.validator_set_id >= current_set_id
+					{
+						return Some(commitment);
+					}
+				}
+				None
+			})
+			.await
+			.ok()
+			.flatten()
+			.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"Failed to receive {} BEEFY commitment for block {required_commitment} or \
+					its descendants",
+					P::SourceChain::NAME,
+				))
+			})?;
+
+			let block_number = commitment.commitment.block_number;
+			let commitment_set_id = commitment.commitment.validator_set_id;
+			let (handoffs, proof) = prove_commitment_with_handoffs(
+				&self.source,
+				best_imported,
+				current_set_id,
+				commitment,
+			)
+			.await?;
+			let proof = proof.ok_or_else(|| {
+				SubstrateError::Custom(format!(
+					"{} BEEFY commitment for block {block_number} is signed by the authority set \
+					{commitment_set_id}, which is not known to the {}",
+					P::SourceChain::NAME,
+					P::TargetChain::NAME,
+				))
+			})?;
+			calls.extend(
+				handoffs
+					.into_iter()
+					.map(P::SubmitBeefyCommitmentCallBuilder::build_submit_commitment_call),
+			);
+			calls.push(P::SubmitBeefyCommitmentCallBuilder::build_submit_commitment_call(proof));
+			block_number
+		};
+
+		let header_proof =
+			self.source.prove_header(required_header, commitment_block_number).await?;
+		let header_id = header_proof.header.id();
+		calls.push(P::SubmitBeefyCommitmentCallBuilder::build_submit_header_call(header_proof));
+
+		tracing::debug!(
+			target: "bridge",
+			relay_task_name=%self.relay_task_name,
+			source=%P::SourceChain::NAME,
+			%required_header,
+			%commitment_block_number,
+			calls=%calls.len(),
+			"Requested to prove head. Selected BEEFY commitment",
+		);
+
+		Ok((header_id, calls))
+	}
+}
+
+/// On-demand BEEFY relay task name.
+fn on_demand_beefy_relay_name<SourceChain: Chain, TargetChain: Chain>() -> String {
+	format!("{}-to-{}-on-demand-beefy", SourceChain::NAME, TargetChain::NAME)
+}
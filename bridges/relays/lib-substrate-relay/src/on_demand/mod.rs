@@ -20,6 +20,7 @@
 use async_trait::async_trait;
 use relay_substrate_client::{BlockNumberOf, CallOf, Chain, Error as SubstrateError, HeaderIdOf};
 
+pub mod beefy;
 pub mod headers;
 pub mod parachains;
 
@@ -36,6 +36,6 @@ mod message_race_loop;
 mod message_race_receiving;
 mod message_race_strategy;
 
-pub use message_race_delivery::relay_messages_range;
+pub use message_race_delivery::{relay_messages_range, select_messages_range};
 pub use message_race_receiving::relay_messages_delivery_confirmation;
 pub use metrics::Labeled;
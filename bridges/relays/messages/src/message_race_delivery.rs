@@ -137,6 +137,31 @@ pub async fn relay_messages_range<P: MessageLane>(
 	}
 }
 
+/// Select range of messages that may be delivered in the single delivery transaction.
+///
+/// The same number, weight and size limits are applied as in the message delivery race.
+/// Messages with nonces that are less than or equal to the `best_target_nonce` are ignored.
+pub async fn select_messages_range<P: MessageLane>(
+	at: SourceHeaderIdOf<P>,
+	messages: MessageDetailsMap<P::SourceChainBalance>,
+	best_target_nonce: MessageNonce,
+	params: &MessageDeliveryParams,
+) -> Option<RangeInclusive<MessageNonce>> {
+	let messages = messages.greater_than(best_target_nonce)?;
+	MessageRaceLimits::decide(RelayMessagesBatchReference::<P> {
+		max_messages_in_this_batch: std::cmp::min(
+			params.max_messages_in_single_batch,
+			params.max_unconfirmed_nonces_at_target,
+		),
+		max_messages_weight_in_single_batch: params.max_messages_weight_in_single_batch,
+		max_messages_size_in_single_batch: params.max_messages_size_in_single_batch,
+		best_target_nonce,
+		nonces_queue: vec![(at, messages)].into_iter().collect(),
+		nonces_queue_range: 0..=0,
+	})
+	.await
+}
+
 /// Message delivery race.
 struct MessageDeliveryRace<P>(std::marker::PhantomData<P>);
 
@@ -1433,4 +1458,44 @@ mod tests {
 			Some(((22..=23), proof_parameters(false, 2)))
 		);
 	}
+
+	#[tokio::test]
+	async fn select_messages_range_applies_delivery_limits() {
+		let params = MessageDeliveryParams {
+			max_unrewarded_relayer_entries_at_target: 4,
+			max_unconfirmed_nonces_at_target: 4,
+			max_messages_in_single_batch: 4,
+			max_messages_weight_in_single_batch: Weight::from_parts(4, 0),
+			max_messages_size_in_single_batch: 4,
+		};
+		let messages = source_nonces(20..=30, 19, 0).new_nonces;
+		let select = |best_target_nonce, params| {
+			select_messages_range::<TestMessageLane>(
+				header_id(1),
+				messages.clone(),
+				best_target_nonce,
+				params,
+			)
+		};
+
+		// messages are limited by count
+		assert_eq!(select(19, &params).await, Some(20..=23));
+		// already delivered messages are ignored
+		assert_eq!(select(21, &params).await, Some(22..=25));
+		// messages are limited by weight
+		let limited = MessageDeliveryParams {
+			max_messages_weight_in_single_batch: Weight::from_parts(2, 0),
+			..params.clone()
+		};
+		assert_eq!(select(19, &limited).await, Some(20..=21));
+		// messages are limited by size
+		let limited =
+			MessageDeliveryParams { max_messages_size_in_single_batch: 3, ..params.clone() };
+		assert_eq!(select(19, &limited).await, Some(20..=22));
+		// zero limit doesn't select anything
+		let limited = MessageDeliveryParams { max_messages_in_single_batch: 0, ..params.clone() };
+		assert_eq!(select(19, &limited).await, None);
+		// nothing is selected if all messages are delivered
+		assert_eq!(select(30, &params).await, None);
+	}
 }
@@ -80,6 +80,27 @@ pub fn build_congestion_message<RuntimeCall>(
 	]
 }
 
+/// Builds an XCM program with the `report_bridge_queue_state` call for `ToWestendXcmRouter`.
+pub fn build_queue_state_message<RuntimeCall>(
+	bridge_id: sp_core::H256,
+	queue_state: bp_xcm_bridge_hub_router::BridgeQueueState,
+) -> alloc::vec::Vec<Instruction<RuntimeCall>> {
+	alloc::vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		Transact {
+			origin_kind: OriginKind::Xcm,
+			fallback_max_weight: Some(XcmBridgeHubRouterTransactCallMaxWeight::get()),
+			call: Call::ToWestendXcmRouter(XcmBridgeHubRouterCall::report_bridge_queue_state {
+				bridge_id,
+				queue_state,
+			})
+			.encode()
+			.into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+	]
+}
+
 /// Identifier of AssetHubRococo in the Rococo relay chain.
 pub const ASSET_HUB_ROCOCO_PARACHAIN_ID: u32 = 1000;
 
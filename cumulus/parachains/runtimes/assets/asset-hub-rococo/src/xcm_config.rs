@@ -46,7 +46,10 @@ use parachains_common::{
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::ExponentialPrice;
 use rococo_runtime_constants::system_parachain::ASSET_HUB_ID;
-use sp_runtime::traits::{AccountIdConversion, TryConvertInto};
+use sp_runtime::{
+	traits::{AccountIdConversion, TryConvertInto},
+	FixedU128,
+};
 use testnet_parachains_constants::rococo::snowbridge::{
 	EthereumNetwork, INBOUND_QUEUE_PALLET_INDEX,
 };
@@ -520,6 +523,16 @@ pub mod bridging {
 		/// Price of every byte of the Rococo -> Westend message. Can be adjusted via
 		/// governance `set_storage` call.
 		pub storage XcmBridgeHubRouterByteFee: Balance = TransactionByteFee::get();
+		/// Number of messages, queued at the outbound lane of the bridge hub, that don't affect the
+		/// bridge fee. Can be adjusted via governance `set_storage` call.
+		pub storage XcmBridgeHubRouterFreeBacklog: u64 = 1_024;
+		/// Increase of the bridge fee for every queued message above the
+		/// `XcmBridgeHubRouterFreeBacklog`. Can be adjusted via governance `set_storage` call.
+		pub storage XcmBridgeHubRouterFeeFactorPerMessage: FixedU128 =
+			FixedU128::from_rational(1, 1_000);
+		/// Maximal multiplier of the bridge fee. It is reached shortly before the outbound lane
+		/// at the bridge hub becomes congested. Can be adjusted via governance `set_storage` call.
+		pub storage XcmBridgeHubRouterMaxFeeFactor: FixedU128 = FixedU128::from_u32(8);
 
 		pub SiblingBridgeHubParaId: u32 = bp_bridge_hub_rococo::BRIDGE_HUB_ROCOCO_PARACHAIN_ID;
 		pub SiblingBridgeHub: Location = Location::new(1, [Parachain(SiblingBridgeHubParaId::get())]);
@@ -538,6 +551,13 @@ pub mod bridging {
 			.collect();
 	}
 
+	/// Bridge fee pricing curve, that grows with the outbound lane backlog at the bridge hub.
+	pub type XcmBridgeHubRouterFeeCurve = pallet_xcm_bridge_hub_router::LinearBacklogFeeCurve<
+		XcmBridgeHubRouterFreeBacklog,
+		XcmBridgeHubRouterFeeFactorPerMessage,
+		XcmBridgeHubRouterMaxFeeFactor,
+	>;
+
 	pub type NetworkExportTable = xcm_builder::NetworkExportTable<BridgeTable>;
 
 	pub type EthereumNetworkExportTable = xcm_builder::NetworkExportTable<EthereumBridgeTable>;
@@ -1042,6 +1042,31 @@ mod asset_hub_rococo_tests {
 		);
 	}
 
+	#[test]
+	fn test_report_bridge_queue_state_call_compatibility() {
+		// if this test fails, make sure `bp_asset_hub_rococo` has valid encoding
+		let queue_state = pallet_xcm_bridge_hub_router::BridgeQueueState {
+			outbound_lane_backlog: 1_024,
+			relayer_reward_per_message: 1_000,
+		};
+		assert_eq!(
+			RuntimeCall::ToWestendXcmRouter(
+				pallet_xcm_bridge_hub_router::Call::report_bridge_queue_state {
+					bridge_id: Default::default(),
+					queue_state,
+				}
+			)
+			.encode(),
+			bp_asset_hub_rococo::Call::ToWestendXcmRouter(
+				bp_asset_hub_rococo::XcmBridgeHubRouterCall::report_bridge_queue_state {
+					bridge_id: Default::default(),
+					queue_state,
+				}
+			)
+			.encode()
+		);
+	}
+
 	#[test]
 	fn check_sane_weight_report_bridge_status_for_westend() {
 		use pallet_xcm_bridge_hub_router::WeightInfo;
@@ -1057,6 +1082,49 @@ mod asset_hub_rococo_tests {
 		);
 	}
 
+	#[test]
+	fn bridge_fee_grows_with_reported_bridge_queue_backlog() {
+		use pallet_xcm_bridge_hub_router::BridgeQueueState;
+		use sp_runtime::FixedPointNumber;
+		use xcm_builder::ExporterFor;
+
+		ExtBuilder::<Runtime>::default().build().execute_with(|| {
+			let remote_location =
+				bridging::to_westend::AssetHubWestend::get().interior.split_global().unwrap().1;
+			let xcm: Xcm<()> = vec![ClearOrigin].into();
+			let bridge_fee = |outbound_lane_backlog| {
+				pallet_xcm_bridge_hub_router::BridgeQueue::<Runtime, ToWestendXcmRouterInstance>::put(
+					BridgeQueueState { outbound_lane_backlog, relayer_reward_per_message: 0 },
+				);
+				match pallet_xcm_bridge_hub_router::Pallet::<Runtime, ToWestendXcmRouterInstance>::exporter_for(
+					&bridging::to_westend::WestendNetwork::get(),
+					&remote_location,
+					&xcm,
+				) {
+					Some((_, Some(Asset { fun: Fungible(amount), .. }))) => amount,
+					unexpected => panic!("unexpected bridge fee: {unexpected:?}"),
+				}
+			};
+
+			let free_backlog = bridging::XcmBridgeHubRouterFreeBacklog::get();
+			let static_fee = bridge_fee(0);
+			assert!(static_fee > 0);
+
+			// backlog, which is below the free threshold, doesn't change anything
+			assert_eq!(bridge_fee(free_backlog), static_fee);
+
+			// every next queued message increases the fee
+			let increased_fee = bridge_fee(free_backlog + 100);
+			assert!(increased_fee > static_fee);
+			assert!(bridge_fee(free_backlog + 200) > increased_fee);
+
+			// but the fee is capped
+			let max_fee =
+				bridging::XcmBridgeHubRouterMaxFeeFactor::get().saturating_mul_int(static_fee);
+			assert_eq!(bridge_fee(u64::MAX), max_fee);
+		})
+	}
+
 	#[test]
 	fn reserve_transfer_native_asset_to_non_teleport_para_works() {
 		asset_test_utils::test_cases::reserve_transfer_native_asset_to_non_teleport_para_works::<
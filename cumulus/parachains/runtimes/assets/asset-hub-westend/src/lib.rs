@@ -334,6 +334,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Holder = AssetsHolder;
+	type Compliance = ();
 	type Freezer = AssetsFreezer;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
@@ -388,6 +389,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = PoolAssetsFreezer;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
@@ -650,6 +652,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type ApprovalDeposit = ForeignAssetsApprovalDeposit;
 	type StringLimit = ForeignAssetsAssetsStringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ForeignAssetsFreezer;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
@@ -1291,6 +1294,7 @@ impl pallet_nft_fractionalization::Config for Runtime {
 parameter_types! {
 	pub NftsPalletFeatures: PalletFeatures = PalletFeatures::all_enabled();
 	pub const NftsMaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const NftsMaxRoyalty: Perbill = Perbill::from_percent(25);
 	// re-use the Uniques deposits
 	pub const NftsCollectionDeposit: Balance = UniquesCollectionDeposit::get();
 	pub const NftsItemDeposit: Balance = UniquesItemDeposit::get();
@@ -1320,6 +1324,7 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = ConstU32<10>;
 	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
 	type MaxAttributesPerCall = ConstU32<10>;
+	type MaxRoyalty = NftsMaxRoyalty;
 	type Features = NftsPalletFeatures;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as Verify>::Signer;
@@ -1354,6 +1359,7 @@ impl pallet_xcm_bridge_hub_router::Config<ToRococoXcmRouterInstance> for Runtime
 
 	type ByteFee = xcm_config::bridging::XcmBridgeHubRouterByteFee;
 	type FeeAsset = xcm_config::bridging::XcmBridgeHubRouterFeeAssetId;
+	type FeeCurve = xcm_config::bridging::XcmBridgeHubRouterFeeCurve;
 }
 
 parameter_types! {
@@ -1966,6 +1972,8 @@ pub type Migrations = (
 	// v9420
 	pallet_nfts::migration::v1::MigrateToV1<Runtime>,
 	// unreleased
+	pallet_nfts::migration::v2::MigrateToV2<Runtime>,
+	// unreleased
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	// unreleased
 	pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
@@ -1984,6 +1992,9 @@ pub type Migrations = (
 		Runtime,
 		TrustBackedAssetsInstance,
 	>,
+	pallet_assets::migration::v2::MigrateV1ToV2<Runtime, TrustBackedAssetsInstance>,
+	pallet_assets::migration::v2::MigrateV1ToV2<Runtime, ForeignAssetsInstance>,
+	pallet_assets::migration::v2::MigrateV1ToV2<Runtime, PoolAssetsInstance>,
 	pallet_session::migrations::v1::MigrateV0ToV1<
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
@@ -2118,12 +2129,13 @@ impl frame_support::traits::OnRuntimeUpgrade for InitStorageVersions {
 		}
 
 		if ForeignAssets::on_chain_storage_version() == StorageVersion::new(0) {
-			ForeignAssets::in_code_storage_version().put::<ForeignAssets>();
+			// The assets pallets are moved to v2 by `MigrateV1ToV2` afterwards.
+			StorageVersion::new(1).put::<ForeignAssets>();
 			writes.saturating_inc();
 		}
 
 		if PoolAssets::on_chain_storage_version() == StorageVersion::new(0) {
-			PoolAssets::in_code_storage_version().put::<PoolAssets>();
+			StorageVersion::new(1).put::<PoolAssets>();
 			writes.saturating_inc();
 		}
 
@@ -2529,6 +2541,14 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl xcm_runtime_apis::fees::TransferQuoteApi<Block, RuntimeCall, OriginCaller> for Runtime {
+		fn quote_transfer(origin: OriginCaller, call: RuntimeCall, asset_id: VersionedAssetId, result_xcms_version: XcmVersion) -> Result<xcm_runtime_apis::fees::TransferQuote, XcmPaymentApiError> {
+			type Trader = <XcmConfig as xcm_executor::Config>::Trader;
+			type AssetExchanger = <XcmConfig as xcm_executor::Config>::AssetExchanger;
+			PolkadotXcm::quote_transfer::<Runtime, xcm_config::XcmRouter, Trader, AssetExchanger, OriginCaller, RuntimeCall>(origin, call, asset_id, result_xcms_version)
+		}
+	}
+
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			PolkadotXcm::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
@@ -2575,6 +2595,50 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl xcm_runtime_apis::trapped_assets::TrappedAssetsApi<Block> for Runtime {
+		fn trapped_assets(origin: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::trapped_assets::TrappedAssets>,
+			xcm_runtime_apis::trapped_assets::Error
+		> {
+			PolkadotXcm::trapped_assets(origin)
+		}
+	}
+
+	impl xcm_runtime_apis::topic_events::XcmTopicEventsApi<Block> for Runtime {
+		fn topic_events() -> Vec<xcm_runtime_apis::topic_events::XcmTopicEvent> {
+			use xcm_runtime_apis::topic_events::XcmTopicEventKind;
+
+			PolkadotXcm::topic_events(|event| match event {
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { message_id, .. }) => {
+					Some((Some(*message_id), XcmTopicEventKind::Sent))
+				},
+				RuntimeEvent::PolkadotXcm(
+					pallet_xcm::Event::SendFailed { message_id, .. } |
+					pallet_xcm::Event::ProcessXcmError { message_id, .. },
+				) => Some((Some(*message_id), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					id, success, ..
+				}) => Some((
+					Some(id.0),
+					if *success { XcmTopicEventKind::Processed } else { XcmTopicEventKind::Failed },
+				)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::ProcessingFailed {
+					id, ..
+				}) => Some((Some(id.0), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::OverweightEnqueued {
+					id, ..
+				}) => Some((Some(*id), XcmTopicEventKind::Queued)),
+				RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::XcmpMessageSent {
+					..
+				}) |
+				RuntimeEvent::ParachainSystem(
+					cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. },
+				) => Some((None, XcmTopicEventKind::Routed)),
+				_ => None,
+			})
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -531,6 +531,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 	type Filter = Nothing;
 	type WeightInfo = weights::pallet_nomination_pools::WeightInfo<Self>;
+	type AutoCompoundBatchSize = ConstU32<64>;
 }
 
 parameter_types! {
@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_assets`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2026-02-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `0855fd8aeaa3`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
 // --pallet=pallet_assets
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -53,79 +53,101 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
 	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::NextAssetId` (r:1 w:0)
 	/// Proof: `ForeignAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (r:1 w:1)
+	/// Proof: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::NextAssetIndex` (r:1 w:1)
+	/// Proof: `AssetsPrecompiles::NextAssetIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::AssetIndexToForeignAssetId` (r:0 w:1)
+	/// Proof: `AssetsPrecompiles::AssetIndexToForeignAssetId` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1348`
-		//  Estimated: `4273`
-		// Minimum execution time: 59_584_000 picoseconds.
-		Weight::from_parts(62_777_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `1352`
+		//  Estimated: `4274`
+		// Minimum execution time: 48_553_000 picoseconds.
+		Weight::from_parts(55_838_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::NextAssetId` (r:1 w:0)
 	/// Proof: `ForeignAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(602), added: 1097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (r:1 w:1)
+	/// Proof: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::NextAssetIndex` (r:1 w:1)
+	/// Proof: `AssetsPrecompiles::NextAssetIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::AssetIndexToForeignAssetId` (r:0 w:1)
+	/// Proof: `AssetsPrecompiles::AssetIndexToForeignAssetId` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
 	fn force_create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4`
-		//  Estimated: `4273`
-		// Minimum execution time: 14_724_000 picoseconds.
-		Weight::from_parts(15_329_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `8`
+		//  Estimated: `4274`
+		// Minimum execution time: 20_232_000 picoseconds.
+		Weight::from_parts(23_850_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:0)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn start_destroy() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `360`
-		//  Estimated: `4273`
-		// Minimum execution time: 27_139_000 picoseconds.
-		Weight::from_parts(28_917_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `395`
+		//  Estimated: `4274`
+		// Minimum execution time: 19_171_000 picoseconds.
+		Weight::from_parts(23_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1001 w:1000)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1000 w:1000)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1000 w:1000)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1000 w:1000)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(391), added: 2866, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1000 w:1000)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:0 w:1000)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:1 w:0)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotSupply` (r:1 w:0)
+	/// Proof: `ForeignAssets::SnapshotSupply` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[0, 1000]`.
 	/// The range of component `c` is `[0, 1000]`.
 	/// The range of component `c` is `[0, 1000]`.
 	fn destroy_accounts(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `68668 + c * (424 ±0)`
-		//  Estimated: `4273 + c * (3207 ±0)`
-		// Minimum execution time: 20_782_000 picoseconds.
-		Weight::from_parts(21_584_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			// Standard Error: 96_300
-			.saturating_add(Weight::from_parts(42_841_666, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+		//  Measured:  `137388 + c * (653 ±0)`
+		//  Estimated: `4452 + c * (3207 ±0)`
+		// Minimum execution time: 32_368_000 picoseconds.
+		Weight::from_parts(33_394_000, 0)
+			.saturating_add(Weight::from_parts(0, 4452))
+			// Standard Error: 177_680
+			.saturating_add(Weight::from_parts(74_005_939, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 3207).saturating_mul(c.into()))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Approvals` (r:1001 w:1000)
 	/// Proof: `ForeignAssets::Approvals` (`max_values`: None, `max_size`: Some(746), added: 3221, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 1000]`.
@@ -133,13 +155,13 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// The range of component `a` is `[0, 1000]`.
 	fn destroy_approvals(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `413 + a * (86 ±0)`
-		//  Estimated: `4273 + a * (3221 ±0)`
-		// Minimum execution time: 22_370_000 picoseconds.
-		Weight::from_parts(23_188_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			// Standard Error: 37_125
-			.saturating_add(Weight::from_parts(23_404_063, 0).saturating_mul(a.into()))
+		//  Measured:  `414 + a * (86 ±0)`
+		//  Estimated: `4274 + a * (3221 ±0)`
+		// Minimum execution time: 20_522_000 picoseconds.
+		Weight::from_parts(21_235_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			// Standard Error: 26_311
+			.saturating_add(Weight::from_parts(19_399_550, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
@@ -147,191 +169,221 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3221).saturating_mul(a.into()))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:0)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:1 w:0)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotSupply` (r:1 w:0)
+	/// Proof: `ForeignAssets::SnapshotSupply` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (r:1 w:1)
+	/// Proof: `AssetsPrecompiles::ForeignAssetIdToAssetIndex` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:0 w:1)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::AllowlistEnforced` (r:0 w:1)
+	/// Proof: `ForeignAssets::AllowlistEnforced` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Reserves` (r:0 w:1)
 	/// Proof: `ForeignAssets::Reserves` (`max_values`: None, `max_size`: Some(3634), added: 6109, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsPrecompiles::AssetIndexToForeignAssetId` (r:0 w:1)
+	/// Proof: `AssetsPrecompiles::AssetIndexToForeignAssetId` (`max_values`: None, `max_size`: Some(606), added: 3081, mode: `MaxEncodedLen`)
 	fn finish_destroy() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 19_731_000 picoseconds.
-		Weight::from_parts(20_321_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `350`
+		//  Estimated: `4452`
+		// Minimum execution time: 45_177_000 picoseconds.
+		Weight::from_parts(49_587_000, 0)
+			.saturating_add(Weight::from_parts(0, 4452))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 31_339_000 picoseconds.
-		Weight::from_parts(32_986_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `2322`
+		//  Estimated: `4274`
+		// Minimum execution time: 46_819_000 picoseconds.
+		Weight::from_parts(52_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(391), added: 2866, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:0 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `430`
-		//  Estimated: `4273`
-		// Minimum execution time: 71_271_000 picoseconds.
-		Weight::from_parts(74_620_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Measured:  `2258`
+		//  Estimated: `4274`
+		// Minimum execution time: 95_867_000 picoseconds.
+		Weight::from_parts(109_384_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:2 w:2)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:2)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(391), added: 2866, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1752`
+		//  Measured:  `5573`
 		//  Estimated: `7404`
-		// Minimum execution time: 98_412_000 picoseconds.
-		Weight::from_parts(100_816_000, 0)
+		// Minimum execution time: 139_231_000 picoseconds.
+		Weight::from_parts(151_247_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:2 w:2)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:0)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1752`
+		//  Measured:  `3780`
 		//  Estimated: `7404`
-		// Minimum execution time: 79_734_000 picoseconds.
-		Weight::from_parts(82_459_000, 0)
+		// Minimum execution time: 79_988_000 picoseconds.
+		Weight::from_parts(90_956_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:2 w:2)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:2)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(391), added: 2866, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1752`
+		//  Measured:  `5573`
 		//  Estimated: `7404`
-		// Minimum execution time: 101_289_000 picoseconds.
-		Weight::from_parts(105_933_000, 0)
+		// Minimum execution time: 138_829_000 picoseconds.
+		Weight::from_parts(152_883_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	fn freeze() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `350`
-		//  Estimated: `4273`
-		// Minimum execution time: 24_420_000 picoseconds.
-		Weight::from_parts(25_826_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `351`
+		//  Estimated: `4274`
+		// Minimum execution time: 21_227_000 picoseconds.
+		Weight::from_parts(23_708_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	fn thaw() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `350`
-		//  Estimated: `4273`
-		// Minimum execution time: 24_438_000 picoseconds.
-		Weight::from_parts(25_840_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `351`
+		//  Estimated: `4274`
+		// Minimum execution time: 19_921_000 picoseconds.
+		Weight::from_parts(23_159_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn freeze_asset() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `276`
-		//  Estimated: `4273`
-		// Minimum execution time: 18_003_000 picoseconds.
-		Weight::from_parts(18_679_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `277`
+		//  Estimated: `4274`
+		// Minimum execution time: 15_543_000 picoseconds.
+		Weight::from_parts(17_578_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn thaw_asset() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `276`
-		//  Estimated: `4273`
-		// Minimum execution time: 17_822_000 picoseconds.
-		Weight::from_parts(18_390_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `277`
+		//  Estimated: `4274`
+		// Minimum execution time: 15_822_000 picoseconds.
+		Weight::from_parts(17_832_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 19_406_000 picoseconds.
-		Weight::from_parts(20_707_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 14_983_000 picoseconds.
+		Weight::from_parts(17_347_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn set_team() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 16_940_000 picoseconds.
-		Weight::from_parts(18_452_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 13_216_000 picoseconds.
+		Weight::from_parts(15_030_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Reserves` (r:0 w:1)
 	/// Proof: `ForeignAssets::Reserves` (`max_values`: None, `max_size`: Some(3634), added: 6109, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[0, 5]`.
@@ -339,18 +391,18 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// The range of component `n` is `[0, 5]`.
 	fn set_reserves(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 18_753_000 picoseconds.
-		Weight::from_parts(20_523_011, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			// Standard Error: 8_765
-			.saturating_add(Weight::from_parts(454_824, 0).saturating_mul(n.into()))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 17_386_000 picoseconds.
+		Weight::from_parts(19_632_821, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			// Standard Error: 4_162
+			.saturating_add(Weight::from_parts(591_748, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:1)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[0, 50]`.
@@ -361,30 +413,30 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[0, 50]`.
 	fn set_metadata(_n: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 39_968_000 picoseconds.
-		Weight::from_parts(47_253_215, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 35_037_000 picoseconds.
+		Weight::from_parts(41_116_077, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:1)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
 	fn clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `406`
-		//  Estimated: `4273`
-		// Minimum execution time: 43_532_000 picoseconds.
-		Weight::from_parts(45_842_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `407`
+		//  Estimated: `4274`
+		// Minimum execution time: 34_773_000 picoseconds.
+		Weight::from_parts(38_817_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:1)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[0, 50]`.
@@ -396,59 +448,59 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	fn force_set_metadata(n: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `81`
-		//  Estimated: `4273`
-		// Minimum execution time: 16_624_000 picoseconds.
-		Weight::from_parts(17_729_669, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			// Standard Error: 1_092
-			.saturating_add(Weight::from_parts(6_120, 0).saturating_mul(n.into()))
-			// Standard Error: 1_092
-			.saturating_add(Weight::from_parts(5_451, 0).saturating_mul(s.into()))
+		//  Estimated: `4274`
+		// Minimum execution time: 14_482_000 picoseconds.
+		Weight::from_parts(16_060_221, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			// Standard Error: 186
+			.saturating_add(Weight::from_parts(1_224, 0).saturating_mul(n.into()))
+			// Standard Error: 186
+			.saturating_add(Weight::from_parts(1_610, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Metadata` (r:1 w:1)
 	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
 	fn force_clear_metadata() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `406`
-		//  Estimated: `4273`
-		// Minimum execution time: 41_784_000 picoseconds.
-		Weight::from_parts(42_986_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `407`
+		//  Estimated: `4274`
+		// Minimum execution time: 33_717_000 picoseconds.
+		Weight::from_parts(37_824_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn force_asset_status() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 15_346_000 picoseconds.
-		Weight::from_parts(16_320_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 12_056_000 picoseconds.
+		Weight::from_parts(14_054_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Approvals` (r:1 w:1)
 	/// Proof: `ForeignAssets::Approvals` (`max_values`: None, `max_size`: Some(746), added: 3221, mode: `MaxEncodedLen`)
 	fn approve_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `276`
-		//  Estimated: `4273`
-		// Minimum execution time: 42_979_000 picoseconds.
-		Weight::from_parts(44_376_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `277`
+		//  Estimated: `4274`
+		// Minimum execution time: 38_580_000 picoseconds.
+		Weight::from_parts(43_088_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Approvals` (r:1 w:1)
 	/// Proof: `ForeignAssets::Approvals` (`max_values`: None, `max_size`: Some(746), added: 3221, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:2 w:2)
@@ -457,169 +509,247 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1790`
+		//  Measured:  `3888`
 		//  Estimated: `7404`
-		// Minimum execution time: 131_934_000 picoseconds.
-		Weight::from_parts(137_154_000, 0)
+		// Minimum execution time: 123_508_000 picoseconds.
+		Weight::from_parts(136_133_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Approvals` (r:1 w:1)
 	/// Proof: `ForeignAssets::Approvals` (`max_values`: None, `max_size`: Some(746), added: 3221, mode: `MaxEncodedLen`)
 	fn cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `446`
-		//  Estimated: `4273`
-		// Minimum execution time: 47_232_000 picoseconds.
-		Weight::from_parts(49_272_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `447`
+		//  Estimated: `4274`
+		// Minimum execution time: 41_481_000 picoseconds.
+		Weight::from_parts(46_082_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Approvals` (r:1 w:1)
 	/// Proof: `ForeignAssets::Approvals` (`max_values`: None, `max_size`: Some(746), added: 3221, mode: `MaxEncodedLen`)
 	fn force_cancel_approval() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `446`
-		//  Estimated: `4273`
-		// Minimum execution time: 46_957_000 picoseconds.
-		Weight::from_parts(48_346_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `447`
+		//  Estimated: `4274`
+		// Minimum execution time: 41_675_000 picoseconds.
+		Weight::from_parts(48_143_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn set_min_balance() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 17_541_000 picoseconds.
-		Weight::from_parts(18_277_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 13_618_000 picoseconds.
+		Weight::from_parts(15_774_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn touch() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1549`
-		//  Estimated: `4273`
-		// Minimum execution time: 58_211_000 picoseconds.
-		Weight::from_parts(60_186_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `1550`
+		//  Estimated: `4274`
+		// Minimum execution time: 43_336_000 picoseconds.
+		Weight::from_parts(49_689_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn touch_other() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 41_622_000 picoseconds.
-		Weight::from_parts(43_046_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 36_527_000 picoseconds.
+		Weight::from_parts(42_853_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn refund() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1755`
-		//  Estimated: `4273`
-		// Minimum execution time: 74_419_000 picoseconds.
-		Weight::from_parts(77_715_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `1790`
+		//  Estimated: `4274`
+		// Minimum execution time: 58_908_000 picoseconds.
+		Weight::from_parts(68_543_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	fn refund_other() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `481`
-		//  Estimated: `4273`
-		// Minimum execution time: 64_008_000 picoseconds.
-		Weight::from_parts(66_457_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `516`
+		//  Estimated: `4274`
+		// Minimum execution time: 53_776_000 picoseconds.
+		Weight::from_parts(60_501_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	fn block() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `350`
-		//  Estimated: `4273`
-		// Minimum execution time: 23_822_000 picoseconds.
-		Weight::from_parts(24_526_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `351`
+		//  Estimated: `4274`
+		// Minimum execution time: 19_425_000 picoseconds.
+		Weight::from_parts(22_967_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:2 w:2)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(391), added: 2866, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
-	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(703), added: 3178, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(721), added: 3196, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:2)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1752`
+		//  Measured:  `5573`
 		//  Estimated: `7404`
-		// Minimum execution time: 118_962_000 picoseconds.
-		Weight::from_parts(123_414_000, 0)
+		// Minimum execution time: 144_796_000 picoseconds.
+		Weight::from_parts(168_418_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::AllowlistEnforced` (r:0 w:1)
+	/// Proof: `ForeignAssets::AllowlistEnforced` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	fn set_allowlist_enforced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 14_193_000 picoseconds.
+		Weight::from_parts(15_765_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:0 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `4274`
+		// Minimum execution time: 15_105_000 picoseconds.
+		Weight::from_parts(16_607_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `333`
+		//  Estimated: `4274`
+		// Minimum execution time: 16_551_000 picoseconds.
+		Weight::from_parts(19_969_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:1)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotSupply` (r:0 w:1)
+	/// Proof: `ForeignAssets::SnapshotSupply` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	fn snapshot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `277`
+		//  Estimated: `4274`
+		// Minimum execution time: 21_034_000 picoseconds.
+		Weight::from_parts(24_958_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:1 w:1)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
+	fn record_snapshot_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `547`
+		//  Estimated: `4452`
+		// Minimum execution time: 15_207_000 picoseconds.
+		Weight::from_parts(18_071_000, 0)
+			.saturating_add(Weight::from_parts(0, 4452))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	fn total_issuance() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `276`
-		//  Estimated: `4273`
-		// Minimum execution time: 7_387_000 picoseconds.
-		Weight::from_parts(7_839_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Measured:  `277`
+		//  Estimated: `4274`
+		// Minimum execution time: 6_985_000 picoseconds.
+		Weight::from_parts(8_689_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `ForeignAssets::Account` (r:1 w:0)
@@ -628,8 +758,8 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
 		//  Estimated: `4197`
-		// Minimum execution time: 9_156_000 picoseconds.
-		Weight::from_parts(9_759_000, 0)
+		// Minimum execution time: 6_909_000 picoseconds.
+		Weight::from_parts(8_213_000, 0)
 			.saturating_add(Weight::from_parts(0, 4197))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
@@ -639,22 +769,22 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `245`
 		//  Estimated: `4211`
-		// Minimum execution time: 13_442_000 picoseconds.
-		Weight::from_parts(14_180_000, 0)
+		// Minimum execution time: 9_840_000 picoseconds.
+		Weight::from_parts(11_747_000, 0)
 			.saturating_add(Weight::from_parts(0, 4211))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
-	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(809), added: 3284, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Reserves` (r:0 w:1)
 	/// Proof: `ForeignAssets::Reserves` (`max_values`: None, `max_size`: Some(3634), added: 6109, mode: `MaxEncodedLen`)
 	fn migration_v2_foreign_asset_set_reserve_weight() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `81`
-		//  Estimated: `4273`
-		// Minimum execution time: 12_621_000 picoseconds.
-		Weight::from_parts(13_500_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
+		//  Estimated: `4274`
+		// Minimum execution time: 9_367_000 picoseconds.
+		Weight::from_parts(10_837_000, 0)
+			.saturating_add(Weight::from_parts(0, 4274))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -664,8 +794,8 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `206`
 		//  Estimated: `4203`
-		// Minimum execution time: 7_850_000 picoseconds.
-		Weight::from_parts(8_412_000, 0)
+		// Minimum execution time: 8_396_000 picoseconds.
+		Weight::from_parts(9_588_000, 0)
 			.saturating_add(Weight::from_parts(0, 4203))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
@@ -195,14 +195,18 @@ impl<T: frame_system::Config> WeightInfo<T> {
 	}
 	// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
 	// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::AssetTrapKeysByOrigin` (r:1 w:1)
+	// Proof: `PolkadotXcm::AssetTrapKeysByOrigin` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::AssetTrapsByOrigin` (r:0 w:1)
+	// Proof: `PolkadotXcm::AssetTrapsByOrigin` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	pub fn claim_asset() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `23`
-		//  Estimated: `3488`
-		// Minimum execution time: 9_795_000 picoseconds.
-		Weight::from_parts(10_091_000, 3488)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `69`
+		//  Estimated: `3534`
+		// Minimum execution time: 20_848_000 picoseconds.
+		Weight::from_parts(25_349_000, 3534)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	pub fn trap() -> Weight {
 		// Proof Size summary in bytes:
@@ -1405,6 +1405,31 @@ fn test_report_bridge_status_call_compatibility() {
 	)
 }
 
+#[test]
+fn test_report_bridge_queue_state_call_compatibility() {
+	// if this test fails, make sure `bp_asset_hub_westend` has valid encoding
+	let queue_state = pallet_xcm_bridge_hub_router::BridgeQueueState {
+		outbound_lane_backlog: 1_024,
+		relayer_reward_per_message: 1_000,
+	};
+	assert_eq!(
+		RuntimeCall::ToRococoXcmRouter(
+			pallet_xcm_bridge_hub_router::Call::report_bridge_queue_state {
+				bridge_id: Default::default(),
+				queue_state,
+			}
+		)
+		.encode(),
+		bp_asset_hub_westend::Call::ToRococoXcmRouter(
+			bp_asset_hub_westend::XcmBridgeHubRouterCall::report_bridge_queue_state {
+				bridge_id: Default::default(),
+				queue_state,
+			}
+		)
+		.encode()
+	)
+}
+
 #[test]
 fn check_sane_weight_report_bridge_status() {
 	use pallet_xcm_bridge_hub_router::WeightInfo;
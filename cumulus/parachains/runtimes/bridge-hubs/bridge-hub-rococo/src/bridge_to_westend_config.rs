@@ -28,7 +28,7 @@ use crate::{
 };
 use bp_messages::{
 	source_chain::FromBridgedChainMessagesDeliveryProof,
-	target_chain::FromBridgedChainMessagesProof, LegacyLaneId,
+	target_chain::FromBridgedChainMessagesProof, LegacyLaneId, MessageNonce,
 };
 use bridge_hub_common::xcm_version::XcmVersionOfDestAndRemoteBridge;
 use pallet_xcm_bridge_hub::{BridgeId, XcmAsPlainPayload};
@@ -98,6 +98,15 @@ pub type OnBridgeHubRococoRefundBridgeHubWestendMessages = BridgeRelayersTransac
 >;
 bp_runtime::generate_static_str_provider!(OnBridgeHubRococoRefundBridgeHubWestendMessages);
 
+/// Registers relayer rewards for messages, delivered to BridgeHubWestend.
+pub type ToWestendDeliveryConfirmationPayments =
+	pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
+		Runtime,
+		WithBridgeHubWestendMessagesInstance,
+		RelayersForLegacyLaneIdsMessagesInstance,
+		DeliveryRewardInBalance,
+	>;
+
 /// Add XCM messages support for BridgeHubRococo to support Rococo->Westend XCM messages
 pub type WithBridgeHubWestendMessagesInstance = pallet_bridge_messages::Instance3;
 impl pallet_bridge_messages::Config<WithBridgeHubWestendMessagesInstance> for Runtime {
@@ -117,12 +126,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubWestendMessagesInstance> for Ru
 	type LaneId = LegacyLaneId;
 
 	type DeliveryPayments = ();
-	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
-		Runtime,
-		WithBridgeHubWestendMessagesInstance,
-		RelayersForLegacyLaneIdsMessagesInstance,
-		DeliveryRewardInBalance,
-	>;
+	type DeliveryConfirmationPayments = ToWestendDeliveryConfirmationPayments;
 
 	type MessageDispatch = XcmOverBridgeHubWestend;
 	type OnMessagesDelivered = XcmOverBridgeHubWestend;
@@ -194,6 +198,23 @@ impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
 		)
 		.map(|_| ())
 	}
+	fn report_bridge_queue_state(
+		local_origin: &Location,
+		bridge: BridgeId,
+		outbound_lane_backlog: MessageNonce,
+	) -> Result<(), Self::Error> {
+		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
+		// so `local_origin` is expected to represent only the local AH.
+		let queue_state = bp_xcm_bridge_hub_router::BridgeQueueState {
+			outbound_lane_backlog,
+			relayer_reward_per_message: ToWestendDeliveryConfirmationPayments::reward_per_message(),
+		};
+		send_xcm::<XcmpQueue>(
+			local_origin.clone(),
+			bp_asset_hub_rococo::build_queue_state_message(bridge.inner(), queue_state).into(),
+		)
+		.map(|_| ())
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -248,4 +248,47 @@ impl<T: frame_system::Config> pallet_bridge_messages::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(10))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `BridgePolkadotBulletinMessages::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgePolkadotBulletinMessages::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `BridgePolkadotBulletinGrandpa::ImportedHeaders` (r:1 w:0)
+	/// Proof: `BridgePolkadotBulletinGrandpa::ImportedHeaders` (`max_values`: Some(1024), `max_size`: Some(68), added: 1553, mode: `MaxEncodedLen`)
+	/// Storage: `BridgePolkadotBulletinMessages::InboundLanes` (r:1 w:1)
+	/// Proof: `BridgePolkadotBulletinMessages::InboundLanes` (`max_values`: None, `max_size`: Some(49180), added: 51655, mode: `MaxEncodedLen`)
+	/// Storage: `BridgePolkadotBulletinMessages::LanesCompression` (r:1 w:0)
+	/// Proof: `BridgePolkadotBulletinMessages::LanesCompression` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `XcmOverPolkadotBulletin::LaneToBridge` (r:1 w:0)
+	/// Proof: `XcmOverPolkadotBulletin::LaneToBridge` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `XcmOverPolkadotBulletin::Bridges` (r:1 w:0)
+	/// Proof: `XcmOverPolkadotBulletin::Bridges` (`max_values`: None, `max_size`: Some(1889), added: 4364, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16384]`.
+	fn receive_single_n_bytes_compressed_message_proof(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `581`
+		//  Estimated: `52645`
+		// Minimum execution time: 50_125_280 picoseconds.
+		Weight::from_parts(53_544_386, 0)
+			.saturating_add(Weight::from_parts(0, 52645))
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(2_970, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// The range of component `n` is `[1, 16384]`.
+	fn compress_single_n_bytes_message(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_612_000 picoseconds.
+		Weight::from_parts(10_043_229, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 17
+			.saturating_add(Weight::from_parts(6_278, 0).saturating_mul(n.into()))
+	}
+	fn set_lane_compression() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
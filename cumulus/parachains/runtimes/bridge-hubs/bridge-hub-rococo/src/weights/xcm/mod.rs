@@ -16,10 +16,13 @@
 mod pallet_xcm_benchmarks_fungible;
 mod pallet_xcm_benchmarks_generic;
 
-use crate::{xcm_config::MaxAssetsIntoHolding, Runtime};
+use crate::{
+	bridge_to_bulletin_config::RococoBulletinGlobalConsensusNetwork,
+	xcm_config::MaxAssetsIntoHolding, Runtime, XcmOverBridgeHubWestend, XcmOverPolkadotBulletin,
+};
 use alloc::vec::Vec;
 use codec::Encode;
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight};
 use pallet_xcm_benchmarks_fungible::WeightInfo as XcmFungibleWeight;
 use pallet_xcm_benchmarks_generic::WeightInfo as XcmGeneric;
 use sp_runtime::BoundedVec;
@@ -227,9 +230,14 @@ impl<Call> XcmWeightInfo<Call> for BridgeHubRococoXcmWeight<Call> {
 	fn universal_origin(_: &Junction) -> Weight {
 		Weight::MAX
 	}
-	fn export_message(_: &NetworkId, _: &Junctions, inner: &Xcm<()>) -> Weight {
+	fn export_message(network: &NetworkId, _: &Junctions, inner: &Xcm<()>) -> Weight {
 		let inner_encoded_len = inner.encode().len() as u32;
-		XcmGeneric::<Runtime>::export_message(inner_encoded_len)
+		let extra_weight = if *network == RococoBulletinGlobalConsensusNetwork::get() {
+			XcmOverPolkadotBulletin::export_message_extra_weight(inner_encoded_len)
+		} else {
+			XcmOverBridgeHubWestend::export_message_extra_weight(inner_encoded_len)
+		};
+		XcmGeneric::<Runtime>::export_message(inner_encoded_len).saturating_add(extra_weight)
 	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
 		Weight::MAX
@@ -195,6 +195,8 @@ impl<T: frame_system::Config> WeightInfo<T> {
 	}
 	// Storage: `PolkadotXcm::AssetTraps` (r:1 w:1)
 	// Proof: `PolkadotXcm::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `PolkadotXcm::AssetTrapsByOrigin` (r:0 w:1)
+	// Proof: `PolkadotXcm::AssetTrapsByOrigin` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	pub fn claim_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `24`
@@ -202,7 +204,7 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		// Minimum execution time: 9_836_000 picoseconds.
 		Weight::from_parts(10_243_000, 3489)
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	pub fn trap() -> Weight {
 		// Proof Size summary in bytes:
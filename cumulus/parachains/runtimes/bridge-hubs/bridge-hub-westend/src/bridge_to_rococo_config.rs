@@ -23,7 +23,7 @@ use crate::{
 };
 use bp_messages::{
 	source_chain::FromBridgedChainMessagesDeliveryProof,
-	target_chain::FromBridgedChainMessagesProof, LegacyLaneId,
+	target_chain::FromBridgedChainMessagesProof, LegacyLaneId, MessageNonce,
 };
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
 use bridge_hub_common::xcm_version::XcmVersionOfDestAndRemoteBridge;
@@ -129,6 +129,15 @@ impl pallet_bridge_parachains::Config<BridgeParachainRococoInstance> for Runtime
 	type OnNewHead = ();
 }
 
+/// Registers relayer rewards for messages, delivered to BridgeHubRococo.
+pub type ToRococoDeliveryConfirmationPayments =
+	pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
+		Runtime,
+		WithBridgeHubRococoMessagesInstance,
+		BridgeRelayersInstance,
+		DeliveryRewardInBalance,
+	>;
+
 /// Add XCM messages support for BridgeHubWestend to support Westend->Rococo XCM messages
 pub type WithBridgeHubRococoMessagesInstance = pallet_bridge_messages::Instance1;
 impl pallet_bridge_messages::Config<WithBridgeHubRococoMessagesInstance> for Runtime {
@@ -148,12 +157,7 @@ impl pallet_bridge_messages::Config<WithBridgeHubRococoMessagesInstance> for Run
 	type LaneId = LegacyLaneId;
 
 	type DeliveryPayments = ();
-	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
-		Runtime,
-		WithBridgeHubRococoMessagesInstance,
-		BridgeRelayersInstance,
-		DeliveryRewardInBalance,
-	>;
+	type DeliveryConfirmationPayments = ToRococoDeliveryConfirmationPayments;
 
 	type MessageDispatch = XcmOverBridgeHubRococo;
 	type OnMessagesDelivered = XcmOverBridgeHubRococo;
@@ -223,6 +227,23 @@ impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
 		)
 		.map(|_| ())
 	}
+	fn report_bridge_queue_state(
+		local_origin: &Location,
+		bridge: BridgeId,
+		outbound_lane_backlog: MessageNonce,
+	) -> Result<(), Self::Error> {
+		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
+		// so `local_origin` is expected to represent only the local AH.
+		let queue_state = bp_xcm_bridge_hub_router::BridgeQueueState {
+			outbound_lane_backlog,
+			relayer_reward_per_message: ToRococoDeliveryConfirmationPayments::reward_per_message(),
+		};
+		send_xcm::<XcmpQueue>(
+			local_origin.clone(),
+			bp_asset_hub_westend::build_queue_state_message(bridge.inner(), queue_state).into(),
+		)
+		.map(|_| ())
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -899,6 +899,44 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::topic_events::XcmTopicEventsApi<Block> for Runtime {
+		fn topic_events() -> Vec<xcm_runtime_apis::topic_events::XcmTopicEvent> {
+			use xcm_runtime_apis::topic_events::XcmTopicEventKind;
+
+			PolkadotXcm::topic_events(|event| match event {
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { message_id, .. }) => {
+					Some((Some(*message_id), XcmTopicEventKind::Sent))
+				},
+				RuntimeEvent::PolkadotXcm(
+					pallet_xcm::Event::SendFailed { message_id, .. } |
+					pallet_xcm::Event::ProcessXcmError { message_id, .. },
+				) => Some((Some(*message_id), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					id, success, ..
+				}) => Some((
+					Some(id.0),
+					if *success { XcmTopicEventKind::Processed } else { XcmTopicEventKind::Failed },
+				)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::ProcessingFailed {
+					id, ..
+				}) => Some((Some(id.0), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::OverweightEnqueued {
+					id, ..
+				}) => Some((Some(*id), XcmTopicEventKind::Queued)),
+				RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::XcmpMessageSent {
+					..
+				}) |
+				RuntimeEvent::ParachainSystem(
+					cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. },
+				) |
+				RuntimeEvent::BridgeRococoMessages(
+					pallet_bridge_messages::Event::MessageAccepted { .. },
+				) => Some((None, XcmTopicEventKind::Routed)),
+				_ => None,
+			})
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -17,7 +17,7 @@
 mod pallet_xcm_benchmarks_fungible;
 mod pallet_xcm_benchmarks_generic;
 
-use crate::{xcm_config::MaxAssetsIntoHolding, Runtime};
+use crate::{xcm_config::MaxAssetsIntoHolding, Runtime, XcmOverBridgeHubRococo};
 use alloc::vec::Vec;
 use codec::Encode;
 use frame_support::weights::Weight;
@@ -242,6 +242,7 @@ impl<Call> XcmWeightInfo<Call> for BridgeHubWestendXcmWeight<Call> {
 	fn export_message(_: &NetworkId, _: &Junctions, inner: &Xcm<()>) -> Weight {
 		let inner_encoded_len = inner.encode().len() as u32;
 		XcmGeneric::<Runtime>::export_message(inner_encoded_len)
+			.saturating_add(XcmOverBridgeHubRococo::export_message_extra_weight(inner_encoded_len))
 	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
 		Weight::MAX
@@ -186,6 +186,8 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	pallet_assets::migration::v2::MigrateV1ToV2<Runtime, AssetsInstance>,
+	pallet_assets::migration::v2::MigrateV1ToV2<Runtime, PoolAssetsInstance>,
 );
 
 /// Executive: handles dispatch to the various modules.
@@ -488,6 +490,7 @@ impl pallet_assets::Config<AssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -530,6 +533,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
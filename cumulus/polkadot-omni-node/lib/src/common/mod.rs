@@ -45,6 +45,7 @@ use sp_session::SessionKeys;
 use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
 use sp_transaction_storage_proof::runtime_api::TransactionStorageApi;
 use std::{fmt::Debug, path::PathBuf, str::FromStr};
+use xcm_runtime_apis::topic_events::XcmTopicEventsApi;
 
 pub trait NodeBlock:
 	BlockT<Extrinsic = OpaqueExtrinsic, Header = Self::BoundedHeader, Hash = DbHash>
@@ -81,6 +82,7 @@ pub trait NodeRuntimeApi<Block: BlockT>:
 	+ RelayParentOffsetApi<Block>
 	+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
 	+ SchedulingV3EnabledApi<Block>
+	+ XcmTopicEventsApi<Block>
 	+ Sized
 {
 }
@@ -98,6 +100,7 @@ impl<T, Block: BlockT> NodeRuntimeApi<Block> for T where
 		+ TransactionStorageApi<Block>
 		+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
 		+ SchedulingV3EnabledApi<Block>
+		+ XcmTopicEventsApi<Block>
 {
 }
 
@@ -23,6 +23,7 @@ use crate::common::{
 	ConstructNodeRuntimeApi,
 };
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+use polkadot_node_xcm_topic_indexer::{XcmTopics, XcmTopicsApiServer};
 use sc_hop::{HopApiServer, HopRpcServer};
 use sc_rpc::{
 	dev::{Dev, DevApiServer},
@@ -78,6 +79,7 @@ where
 			module.merge(System::new(client.clone(), pool).into_rpc())?;
 			module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 			module.merge(StateMigration::new(client.clone(), backend).into_rpc())?;
+			module.merge(XcmTopics::<_, Block>::new(client.clone()).into_rpc())?;
 			if let Some(statement_store) = statement_store {
 				module.merge(StatementStore::new(statement_store, spawn_handle).into_rpc())?;
 			}
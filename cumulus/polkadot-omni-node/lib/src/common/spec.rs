@@ -41,6 +41,7 @@ use cumulus_relay_chain_interface::{OverseerHandle, RelayChainInterface};
 use futures::FutureExt;
 use log::{debug, info};
 use parachains_common_types::Hash;
+use polkadot_node_xcm_topic_indexer::XcmTopicIndexer;
 use polkadot_primitives::CollatorPair;
 use prometheus_endpoint::Registry;
 use sc_client_api::Backend;
@@ -474,6 +475,14 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 				task_manager.spawn_handle().spawn("hop-maintenance", None, task.run());
 			}
 
+			if parachain_config.offchain_worker.indexing_enabled {
+				task_manager.spawn_handle().spawn_blocking(
+					"xcm-topic-indexer",
+					None,
+					XcmTopicIndexer::<Self::Block, _>::start(client.clone()),
+				);
+			}
+
 			if parachain_config.offchain_worker.enabled {
 				let custom_extensions = {
 					let statement_store = statement_store.clone();
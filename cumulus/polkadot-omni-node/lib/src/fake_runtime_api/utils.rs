@@ -274,6 +274,12 @@ macro_rules! impl_node_runtime_apis {
 					unimplemented!()
 				}
 			}
+
+			impl xcm_runtime_apis::topic_events::XcmTopicEventsApi<$block> for $runtime {
+				fn topic_events() -> Vec<xcm_runtime_apis::topic_events::XcmTopicEvent> {
+					unimplemented!()
+				}
+			}
 		}
 	};
 }
@@ -64,6 +64,135 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Sub-commands concerned with the parachains database.
+	#[command(subcommand)]
+	Db(DbCmd),
+
+	/// Sub-commands concerned with the evidence about disputes.
+	#[command(subcommand)]
+	DisputeEvidence(DisputeEvidenceCmd),
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, clap::Subcommand)]
+pub enum DbCmd {
+	/// Decode and list the records a parachain subsystem keeps in the parachains database.
+	///
+	/// The database is opened read-only and can be inspected while the node is running.
+	Inspect(InspectDbCmd),
+}
+
+/// A parachain subsystem with records in the parachains database.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum DbSubsystem {
+	AvStore,
+	ApprovalVoting,
+	DisputeCoordinator,
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct InspectDbCmd {
+	/// The subsystem whose records are listed.
+	#[arg(value_enum)]
+	pub subsystem: DbSubsystem,
+
+	/// Only list records related to the relay chain block with the given number or hash.
+	#[arg(long, value_name = "NUMBER_OR_HASH")]
+	pub block: Option<polkadot_service::parachains_db_inspect::BlockFilter>,
+
+	/// Only list records related to the candidate with the given hash.
+	#[arg(long, value_name = "HASH")]
+	pub candidate: Option<polkadot_service::Hash>,
+
+	/// Only list records related to the given session.
+	#[arg(long)]
+	pub session: Option<u32>,
+
+	/// The format of the listed records.
+	#[arg(long, value_name = "FORMAT", value_enum, ignore_case = true, default_value_t = sc_cli::OutputType::Text)]
+	pub output_type: sc_cli::OutputType,
+
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[clap(flatten)]
+	pub database_params: sc_cli::DatabaseParams,
+}
+
+impl sc_cli::CliConfiguration for InspectDbCmd {
+	fn shared_params(&self) -> &sc_cli::SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&sc_cli::DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, clap::Subcommand)]
+pub enum DisputeEvidenceCmd {
+	/// Export the evidence about the most recent dispute on a candidate to a file.
+	///
+	/// The evidence is signed by the node behind `--url`, which has to be a validator in the
+	/// session of the dispute and to allow unsafe RPC methods.
+	Export(ExportDisputeEvidenceCmd),
+
+	/// Check a file of evidence about a dispute.
+	///
+	/// The signatures in the evidence are checked against the validators of the session of the
+	/// dispute, as read from the finalized chain of the node behind `--url`.
+	Check(CheckDisputeEvidenceCmd),
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct ExportDisputeEvidenceCmd {
+	/// The hash of the disputed candidate.
+	#[arg(long, value_name = "HASH")]
+	pub candidate: polkadot_primitives::Hash,
+
+	/// The file the SCALE encoded evidence is written to.
+	#[arg(long, short, value_name = "PATH")]
+	pub output: PathBuf,
+
+	/// The RPC endpoint of the validator.
+	#[arg(long, value_name = "URL", default_value = "ws://127.0.0.1:9944")]
+	pub url: String,
+}
+
+#[allow(missing_docs)]
+#[derive(Debug, Parser)]
+pub struct CheckDisputeEvidenceCmd {
+	/// The file holding the SCALE encoded evidence.
+	#[arg(value_name = "PATH")]
+	pub input: PathBuf,
+
+	/// The RPC endpoint of a node trusted to follow the finalized chain.
+	#[arg(long, value_name = "URL", default_value = "ws://127.0.0.1:9944")]
+	pub url: String,
+}
+
+/// An erasure coding backend which can be selected on the command line.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ErasureCodingBackend {
+	Sequential,
+	Adaptive,
+}
+
+impl From<ErasureCodingBackend> for polkadot_service::ErasureCodingBackend {
+	fn from(backend: ErasureCodingBackend) -> Self {
+		match backend {
+			ErasureCodingBackend::Sequential => Self::Sequential,
+			ErasureCodingBackend::Adaptive => Self::Adaptive,
+		}
+	}
 }
 
 #[allow(missing_docs)]
@@ -150,6 +279,30 @@ pub struct RunCmd {
 	///  **Dangerous!** Do not touch unless explicitly advised to.
 	#[arg(long)]
 	pub prepare_workers_hard_max_num: Option<usize>,
+	/// Directory where failed candidate validations are dumped.
+	///
+	/// Each dump contains the validation code, PoV, persisted validation data and executor
+	/// parameters of the failed validation, and can be replayed with the `pvf-replay` tool.
+	/// Only the latest 64 dumps are kept, older ones are removed by the node.
+	#[arg(long, value_name = "PATH")]
+	pub pvf_dump_dir: Option<PathBuf>,
+
+	/// Prepare the artifacts of the current and upcoming parachain validation code in the
+	/// background, so that the first validations after a restart or a session change do not pay
+	/// the preparation cost.
+	#[arg(long)]
+	pub pvf_prewarm: bool,
+
+	/// Override the maximum number of pvf prepare workers which can be busy with pre-warming
+	/// artifacts at the same time. Defaults to 1.
+	#[arg(long)]
+	pub pvf_prewarm_workers_max_num: Option<usize>,
+
+	/// Override the size of the prepared artifacts on disk, in MiB, above which no more artifacts
+	/// are pre-warmed. Defaults to 2048.
+	#[arg(long, value_name = "MiB")]
+	pub pvf_prewarm_disk_limit: Option<u64>,
+
 	/// TESTING ONLY: disable the version check between nodes and workers.
 	#[arg(long, hide = true)]
 	pub disable_worker_version_check: bool,
@@ -160,6 +313,13 @@ pub struct RunCmd {
 	#[arg(long)]
 	pub keep_finalized_for: Option<u32>,
 
+	/// The erasure coding backend used by the availability store and recovery.
+	///
+	/// `adaptive` splits the encoding and reconstruction of large PoVs between several threads,
+	/// if the machine has more than one core.
+	#[arg(long, value_name = "BACKEND", value_enum, default_value_t = ErasureCodingBackend::Sequential)]
+	pub erasure_coding_backend: ErasureCodingBackend,
+
 	/// Overrides `HOLD_OFF_DURATION` in collator_protocol/validator_side. The value is in
 	/// milliseconds.
 	///
@@ -14,12 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::cli::{Cli, Subcommand, NODE_VERSION};
+use crate::cli::{Cli, DbCmd, DbSubsystem, Subcommand, NODE_VERSION};
 use frame_benchmarking_cli::{
 	BenchmarkCmd, ExtrinsicFactory, SubstrateRemarkBuilder, SUBSTRATE_REFERENCE_HARDWARE,
 };
 use futures::future::TryFutureExt;
 use log::{info, warn};
+use polkadot_primitives::CandidateHash;
 use polkadot_service::{
 	self,
 	benchmarking::{benchmark_inherent_data, TransferKeepAliveBuilder},
@@ -291,7 +292,15 @@ where
 				execute_workers_max_num: cli.run.execute_workers_max_num,
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
+				pvf_dump_dir: cli.run.pvf_dump_dir,
+				pvf_prewarm: cli.run.pvf_prewarm,
+				pvf_prewarm_workers_max_num: cli.run.pvf_prewarm_workers_max_num,
+				pvf_prewarm_disk_limit: cli
+					.run
+					.pvf_prewarm_disk_limit
+					.map(|mib| mib.saturating_mul(1024 * 1024)),
 				keep_finalized_for: cli.run.keep_finalized_for,
+				erasure_coding_backend: cli.run.erasure_coding_backend.into(),
 				invulnerable_ah_collators,
 				collator_protocol_hold_off,
 				experimental_collator_protocol,
@@ -532,6 +541,39 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			Ok(runner.sync_run(|config| cmd.run::<polkadot_service::Block>(&config))?)
 		},
+		Some(Subcommand::Db(DbCmd::Inspect(cmd))) => {
+			use polkadot_service::parachains_db_inspect::{Filter, Subsystem};
+
+			let runner = cli.create_runner(cmd)?;
+			let subsystem = match cmd.subsystem {
+				DbSubsystem::AvStore => Subsystem::AvailabilityStore,
+				DbSubsystem::ApprovalVoting => Subsystem::ApprovalVoting,
+				DbSubsystem::DisputeCoordinator => Subsystem::DisputeCoordinator,
+			};
+			let filter = Filter {
+				block: cmd.block,
+				candidate: cmd.candidate.map(CandidateHash),
+				session: cmd.session,
+			};
+
+			Ok(runner.sync_run(|config| {
+				let report =
+					polkadot_service::inspect_database(&config.database, subsystem, &filter)?;
+				match cmd.output_type {
+					sc_cli::OutputType::Json => println!(
+						"{}",
+						serde_json::to_string_pretty(&report)
+							.map_err(|e| Error::Other(e.to_string()))?
+					),
+					sc_cli::OutputType::Text => print!("{}", report),
+				}
+				Ok::<_, Error>(())
+			})?)
+		},
+		Some(Subcommand::DisputeEvidence(cmd)) => {
+			let runtime = sc_cli::build_runtime().map_err(|e| Error::Other(e.to_string()))?;
+			runtime.block_on(crate::dispute_evidence::run(cmd))
+		},
 	}?;
 
 	#[cfg(feature = "pyroscope")]
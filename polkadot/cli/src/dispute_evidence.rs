@@ -0,0 +1,138 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export and check the evidence about disputes, through the RPC of a node.
+//!
+//! The evidence is exported as the SCALE encoded `SignedDisputeEvidence`. It is checked against
+//! the validators of the session of the dispute, as read from the finalized chain of a node the
+//! user trusts, never against the session info carried by the evidence.
+
+use crate::{
+	cli::{CheckDisputeEvidenceCmd, DisputeEvidenceCmd, ExportDisputeEvidenceCmd},
+	error::Error,
+};
+use codec::{Decode, Encode};
+use jsonrpsee::{
+	core::{client::ClientT, ClientError},
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use polkadot_node_primitives::disputes::SignedDisputeEvidence;
+use polkadot_primitives::{Hash, SessionIndex, SessionInfo};
+use polkadot_rpc::ParachainsApiClient;
+use sp_core::Bytes;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Run a dispute evidence sub-command.
+pub async fn run(cmd: &DisputeEvidenceCmd) -> Result<()> {
+	match cmd {
+		DisputeEvidenceCmd::Export(cmd) => export(cmd).await,
+		DisputeEvidenceCmd::Check(cmd) => check(cmd).await,
+	}
+}
+
+async fn export(cmd: &ExportDisputeEvidenceCmd) -> Result<()> {
+	let client = connect(&cmd.url).await?;
+	let summary = client
+		.dispute_evidence(cmd.candidate)
+		.await
+		.map_err(|e| Error::DisputeEvidence(format!("Failed to export the evidence: {e}")))?
+		.ok_or_else(|| {
+			Error::DisputeEvidence(format!(
+				"No recent dispute on candidate {:?}, or the node is not a validator in its session",
+				cmd.candidate,
+			))
+		})?;
+
+	std::fs::write(&cmd.output, &summary.signed_evidence.0).map_err(|e| {
+		Error::DisputeEvidence(format!("Failed to write {}: {e}", cmd.output.display()))
+	})?;
+
+	println!(
+		"Exported the evidence about the dispute on candidate {:?} in session {}, signed by validator {}, to {}",
+		summary.candidate_hash,
+		summary.session_index,
+		summary.validator_index.0,
+		cmd.output.display(),
+	);
+	Ok(())
+}
+
+async fn check(cmd: &CheckDisputeEvidenceCmd) -> Result<()> {
+	let encoded = std::fs::read(&cmd.input).map_err(|e| {
+		Error::DisputeEvidence(format!("Failed to read {}: {e}", cmd.input.display()))
+	})?;
+	let signed = SignedDisputeEvidence::decode(&mut &encoded[..])
+		.map_err(|e| Error::DisputeEvidence(format!("Failed to decode the evidence: {e}")))?;
+	let evidence = &signed.evidence;
+
+	let client = connect(&cmd.url).await?;
+	let session_info = finalized_session_info(&client, evidence.session_index).await?;
+	signed.check(&session_info.validators.to_vec())?;
+
+	println!(
+		"The evidence about the dispute on candidate {:?} in session {} is valid.",
+		evidence.candidate_hash(),
+		evidence.session_index,
+	);
+	println!(
+		"Status: {:?}, valid votes: {}, invalid votes: {}, signed by validator {} ({:?}), available data: {}",
+		evidence.status,
+		evidence.valid_votes.len(),
+		evidence.invalid_votes.len(),
+		signed.validator_index.0,
+		evidence.local_participation,
+		if evidence.available_data.is_some() { "included" } else { "missing" },
+	);
+	Ok(())
+}
+
+async fn connect(url: &str) -> Result<WsClient> {
+	WsClientBuilder::default()
+		.build(url)
+		.await
+		.map_err(|e| Error::DisputeEvidence(format!("Failed to connect to {url}: {e}")))
+}
+
+/// The session info of `session_index`, as known to the finalized chain of the node.
+async fn finalized_session_info(
+	client: &WsClient,
+	session_index: SessionIndex,
+) -> Result<SessionInfo> {
+	let rpc_error =
+		|e: ClientError| Error::DisputeEvidence(format!("Failed to query the session info: {e}"));
+
+	let finalized: Hash = client
+		.request("chain_getFinalizedHead", rpc_params![])
+		.await
+		.map_err(rpc_error)?;
+	let result: Bytes = client
+		.request(
+			"state_call",
+			rpc_params!["ParachainHost_session_info", Bytes(session_index.encode()), finalized],
+		)
+		.await
+		.map_err(rpc_error)?;
+
+	Option::<SessionInfo>::decode(&mut &result[..])
+		.map_err(|e| Error::DisputeEvidence(format!("Failed to decode the session info: {e}")))?
+		.ok_or_else(|| {
+			Error::DisputeEvidence(format!(
+				"Session {session_index} is not known to the finalized chain of the node"
+			))
+		})
+}
@@ -51,6 +51,12 @@ pub enum Error {
 	#[error("Other: {0}")]
 	Other(String),
 
+	#[error("{0}")]
+	DisputeEvidence(String),
+
+	#[error("Invalid dispute evidence: {0}")]
+	InvalidDisputeEvidence(#[from] polkadot_node_primitives::disputes::DisputeEvidenceCheckError),
+
 	#[error("This subcommand is only available when compiled with `{feature}`")]
 	FeatureNotEnabled { feature: &'static str },
 }
@@ -77,6 +77,8 @@ pub type Hash = sp_core::H256;
 	Default,
 	PartialOrd,
 	Ord,
+	serde::Serialize,
+	serde::Deserialize,
 	TypeInfo,
 )]
 pub struct CandidateHash(pub Hash);
@@ -15,8 +15,9 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use polkadot_erasure_coding::Backend;
 use polkadot_primitives::Hash;
-use std::time::Duration;
+use std::{num::NonZeroUsize, thread, time::Duration};
 
 fn chunks(n_validators: usize, pov: &Vec<u8>) -> Vec<Vec<u8>> {
 	polkadot_erasure_coding::obtain_chunks(n_validators, pov).unwrap()
@@ -103,6 +104,51 @@ fn construct_and_reconstruct_5mb_pov(c: &mut Criterion) {
 	group.finish();
 }
 
+fn sequential_and_parallel_5mb_pov(c: &mut Criterion) {
+	const N_VALIDATORS: usize = 1000;
+	const MB: usize = 1024 * 1024;
+
+	let pov = vec![0xfe; 5 * MB];
+	let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+	let backends = [("sequential", Backend::Sequential), ("parallel", Backend::Parallel(threads))];
+
+	let mut group = c.benchmark_group("construct_backend");
+	group.throughput(Throughput::Bytes(pov.len() as u64));
+	for (name, backend) in backends {
+		group.bench_with_input(BenchmarkId::from_parameter(name), &backend, |b, &backend| {
+			b.iter(|| {
+				polkadot_erasure_coding::obtain_chunks_with_backend(backend, N_VALIDATORS, &pov)
+					.unwrap()
+			});
+		});
+	}
+	group.finish();
+
+	let all_chunks = chunks(N_VALIDATORS, &pov);
+	let chunks: Vec<_> = all_chunks
+		.iter()
+		.enumerate()
+		.take(polkadot_erasure_coding::recovery_threshold(N_VALIDATORS).unwrap())
+		.map(|(i, c)| (&c[..], i))
+		.collect();
+
+	let mut group = c.benchmark_group("reconstruct_regular_backend");
+	group.throughput(Throughput::Bytes(pov.len() as u64));
+	for (name, backend) in backends {
+		group.bench_with_input(BenchmarkId::from_parameter(name), &backend, |b, &backend| {
+			b.iter(|| {
+				let _pov: Vec<u8> = polkadot_erasure_coding::reconstruct_with_backend(
+					backend,
+					N_VALIDATORS,
+					chunks.clone(),
+				)
+				.unwrap();
+			});
+		});
+	}
+	group.finish();
+}
+
 fn criterion_config() -> Criterion {
 	Criterion::default()
 		.sample_size(15)
@@ -113,6 +159,6 @@ fn criterion_config() -> Criterion {
 criterion_group!(
 	name = re_construct;
 	config = criterion_config();
-	targets = construct_and_reconstruct_5mb_pov,
+	targets = construct_and_reconstruct_5mb_pov, sequential_and_parallel_5mb_pov,
 );
 criterion_main!(re_construct);
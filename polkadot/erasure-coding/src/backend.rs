@@ -0,0 +1,228 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Sequential and parallel implementations of the erasure coding.
+//!
+//! The payload is encoded in independent runs of `2 * k` bytes, each run yielding the symbol at
+//! the same position in every chunk. Likewise, every symbol position of the chunks is
+//! reconstructed independently. The parallel backend splits the symbol positions into
+//! contiguous ranges, one per thread, and concatenates the results, so it produces exactly the
+//! same output as the sequential backend.
+//!
+//! The parallel backend spawns its threads for every call, so it is opt-in: the process-wide
+//! default backend, used by the functions of this crate which don't take one, is the sequential
+//! one until [`set_default_backend`] is called.
+
+use std::{num::NonZeroUsize, ops::Range, sync::RwLock, thread};
+
+use novelpoly::{CodeParams, WrappedShard};
+
+/// Payloads smaller than this are always handled on the calling thread, as spawning threads
+/// would cost more than it saves.
+const PARALLEL_THRESHOLD_BYTES: usize = 256 * 1024;
+
+/// The minimum number of symbols of each chunk handled by one thread.
+const MIN_SYMBOLS_PER_THREAD: usize = 1024;
+
+/// The backend used by the functions which don't take one.
+static DEFAULT_BACKEND: RwLock<Backend> = RwLock::new(Backend::Sequential);
+
+/// Sets the backend used by the functions of this crate which don't take one.
+pub fn set_default_backend(backend: Backend) {
+	*DEFAULT_BACKEND.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = backend;
+}
+
+/// Returns the backend used by the functions of this crate which don't take one.
+pub fn default_backend() -> Backend {
+	*DEFAULT_BACKEND.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The implementation used to encode and reconstruct erasure-coded data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+	/// Use the parallel backend for large payloads, if the machine has more than one core, and
+	/// the sequential backend otherwise.
+	Adaptive,
+	/// Encode and reconstruct on the calling thread.
+	#[default]
+	Sequential,
+	/// Split the work between up to the given number of threads.
+	Parallel(NonZeroUsize),
+}
+
+impl Backend {
+	/// Resolve `Adaptive` into the backend to use for a payload of `payload_len` bytes.
+	fn resolve(self, payload_len: usize) -> Self {
+		match self {
+			Backend::Adaptive if payload_len < PARALLEL_THRESHOLD_BYTES => Backend::Sequential,
+			Backend::Adaptive => match thread::available_parallelism() {
+				Ok(threads) if threads.get() > 1 => Backend::Parallel(threads),
+				_ => Backend::Sequential,
+			},
+			backend => backend,
+		}
+	}
+
+	/// The ranges of symbols each thread handles, or `None` if the work should not be split.
+	fn split(self, payload_len: usize, n_symbols: usize) -> Option<Vec<Range<usize>>> {
+		let Backend::Parallel(threads) = self.resolve(payload_len) else { return None };
+
+		let n_threads = threads.get().min(n_symbols / MIN_SYMBOLS_PER_THREAD);
+		if n_threads <= 1 {
+			return None;
+		}
+
+		let per_thread = n_symbols.div_ceil(n_threads);
+		Some(
+			(0..n_symbols)
+				.step_by(per_thread)
+				.map(|start| start..(start + per_thread).min(n_symbols))
+				.collect(),
+		)
+	}
+
+	/// Encode `payload` into one chunk per validator.
+	pub(crate) fn encode(
+		self,
+		params: &CodeParams,
+		payload: &[u8],
+	) -> Result<Vec<Vec<u8>>, novelpoly::Error> {
+		let encoder = params.make_encoder();
+		// Each run of `2 * k` bytes of the payload yields one symbol of every chunk.
+		let run_len = params.k() * 2;
+		let Some(ranges) = self.split(payload.len(), payload.len().div_ceil(run_len)) else {
+			let shards = encoder.encode::<WrappedShard>(payload)?;
+			return Ok(shards.into_iter().map(WrappedShard::into_inner).collect());
+		};
+
+		let parts = run_in_parallel(ranges, |symbols| {
+			let end = (symbols.end * run_len).min(payload.len());
+			encoder.encode::<WrappedShard>(&payload[symbols.start * run_len..end])
+		})?;
+
+		Ok(concat_shards(parts))
+	}
+
+	/// Reconstruct the padded payload from the chunks present in `received`, indexed by chunk
+	/// index.
+	pub(crate) fn reconstruct(
+		self,
+		params: &CodeParams,
+		received: Vec<Option<&[u8]>>,
+	) -> Result<Vec<u8>, novelpoly::Error> {
+		let encoder = params.make_encoder();
+
+		// Leave it to the sequential backend to report inconsistent chunks.
+		let mut present = received.iter().flatten();
+		let ranges = present.next().map(|first| first.len()).and_then(|chunk_len| {
+			let n_present = 1 + present.clone().count();
+			let consistent = chunk_len > 0 && present.all(|chunk| chunk.len() == chunk_len);
+			(consistent && n_present >= params.k())
+				.then(|| self.split(chunk_len * params.k(), chunk_len / 2))
+				.flatten()
+		});
+		let Some(ranges) = ranges else {
+			return encoder.reconstruct(
+				received
+					.into_iter()
+					.map(|chunk| chunk.map(|chunk| WrappedShard::new(chunk.to_vec())))
+					.collect(),
+			);
+		};
+
+		let parts = run_in_parallel(ranges, |symbols| {
+			encoder.reconstruct(
+				received
+					.iter()
+					.map(|chunk| {
+						chunk.map(|chunk| {
+							WrappedShard::new(chunk[symbols.start * 2..symbols.end * 2].to_vec())
+						})
+					})
+					.collect(),
+			)
+		})?;
+
+		Ok(parts.concat())
+	}
+
+	/// Reconstruct the padded payload from the first `k` chunks.
+	pub(crate) fn reconstruct_from_systematic(
+		self,
+		params: &CodeParams,
+		chunks: Vec<Vec<u8>>,
+	) -> Result<Vec<u8>, novelpoly::Error> {
+		let encoder = params.make_encoder();
+
+		// Leave it to the sequential backend to report inconsistent chunks.
+		let ranges = chunks.first().map(|first| first.len()).and_then(|chunk_len| {
+			let consistent = chunk_len > 0 && chunks.iter().all(|chunk| chunk.len() == chunk_len);
+			(consistent && chunks.len() >= params.k())
+				.then(|| self.split(chunk_len * params.k(), chunk_len / 2))
+				.flatten()
+		});
+		let Some(ranges) = ranges else {
+			return encoder
+				.reconstruct_from_systematic(chunks.into_iter().map(WrappedShard::new).collect());
+		};
+
+		let parts = run_in_parallel(ranges, |symbols| {
+			encoder.reconstruct_from_systematic(
+				chunks
+					.iter()
+					.map(|chunk| {
+						WrappedShard::new(chunk[symbols.start * 2..symbols.end * 2].to_vec())
+					})
+					.collect(),
+			)
+		})?;
+
+		Ok(parts.concat())
+	}
+}
+
+/// Run `f` on each range on its own thread, returning the results in order.
+fn run_in_parallel<T, F>(ranges: Vec<Range<usize>>, f: F) -> Result<Vec<T>, novelpoly::Error>
+where
+	T: Send,
+	F: Fn(Range<usize>) -> Result<T, novelpoly::Error> + Sync,
+{
+	thread::scope(|scope| {
+		let f = &f;
+		let handles: Vec<_> =
+			ranges.into_iter().map(|range| scope.spawn(move || f(range))).collect();
+
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+			.collect()
+	})
+}
+
+/// Concatenate the chunks encoded from consecutive parts of the payload.
+fn concat_shards(parts: Vec<Vec<WrappedShard>>) -> Vec<Vec<u8>> {
+	let mut parts = parts.into_iter();
+	let Some(first) = parts.next() else { return Vec::new() };
+
+	let mut chunks: Vec<Vec<u8>> = first.into_iter().map(WrappedShard::into_inner).collect();
+	for part in parts {
+		for (chunk, shard) in chunks.iter_mut().zip(part) {
+			chunk.extend_from_slice(AsRef::<[u8]>::as_ref(&shard));
+		}
+	}
+
+	chunks
+}
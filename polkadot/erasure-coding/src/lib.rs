@@ -23,6 +23,8 @@
 //! Each of n validators stores their piece of data. We assume `n = 3f + k`, `0 < k ≤ 3`.
 //! f is the maximum number of faulty validators in the system.
 //! The data is coded so any f+1 chunks can be used to reconstruct the full data.
+//!
+//! Large payloads can be encoded and reconstructed on several threads, see [`Backend`].
 
 use codec::{Decode, Encode};
 use polkadot_node_primitives::{AvailableData, Proof};
@@ -34,7 +36,11 @@ use sp_trie::{
 };
 use thiserror::Error;
 
-use novelpoly::{CodeParams, WrappedShard};
+use novelpoly::CodeParams;
+
+mod backend;
+
+pub use backend::{default_backend, set_default_backend, Backend};
 
 // we are limited to the field order of GF(2^16), which is 65536
 const MAX_VALIDATORS: usize = novelpoly::f2e16::FIELD_SIZE;
@@ -156,6 +162,17 @@ pub fn reconstruct_from_systematic_v1(
 pub fn reconstruct_from_systematic<T: Decode>(
 	n_validators: usize,
 	chunks: Vec<Vec<u8>>,
+) -> Result<T, Error> {
+	reconstruct_from_systematic_with_backend(default_backend(), n_validators, chunks)
+}
+
+/// Reconstruct the available data from the set of systematic chunks, with the given backend.
+///
+/// See [`reconstruct_from_systematic`].
+pub fn reconstruct_from_systematic_with_backend<T: Decode>(
+	backend: Backend,
+	n_validators: usize,
+	mut chunks: Vec<Vec<u8>>,
 ) -> Result<T, Error> {
 	let code_params = code_params(n_validators)?;
 	let k = code_params.k();
@@ -166,9 +183,8 @@ pub fn reconstruct_from_systematic<T: Decode>(
 		}
 	}
 
-	let bytes = code_params.make_encoder().reconstruct_from_systematic(
-		chunks.into_iter().take(k).map(|data| WrappedShard::new(data)).collect(),
-	)?;
+	chunks.truncate(k);
+	let bytes = backend.reconstruct_from_systematic(&code_params, chunks)?;
 
 	Decode::decode(&mut &bytes[..]).map_err(|err| Error::Decode(err))
 }
@@ -184,6 +200,17 @@ pub fn obtain_chunks_v1(n_validators: usize, data: &AvailableData) -> Result<Vec
 ///
 /// Works only up to 65536 validators, and `n_validators` must be non-zero.
 pub fn obtain_chunks<T: Encode>(n_validators: usize, data: &T) -> Result<Vec<Vec<u8>>, Error> {
+	obtain_chunks_with_backend(default_backend(), n_validators, data)
+}
+
+/// Obtain erasure-coded chunks with the given backend, one for each validator.
+///
+/// See [`obtain_chunks`].
+pub fn obtain_chunks_with_backend<T: Encode>(
+	backend: Backend,
+	n_validators: usize,
+	data: &T,
+) -> Result<Vec<Vec<u8>>, Error> {
 	let params = code_params(n_validators)?;
 	let encoded = data.encode();
 
@@ -191,12 +218,11 @@ pub fn obtain_chunks<T: Encode>(n_validators: usize, data: &T) -> Result<Vec<Vec
 		return Err(Error::BadPayload);
 	}
 
-	let shards = params
-		.make_encoder()
-		.encode::<WrappedShard>(&encoded[..])
+	let chunks = backend
+		.encode(&params, &encoded[..])
 		.expect("Payload non-empty, shard sizes are uniform, and validator numbers checked; qed");
 
-	Ok(shards.into_iter().map(|w: WrappedShard| w.into_inner()).collect())
+	Ok(chunks)
 }
 
 /// Reconstruct the v1 available data from a set of chunks.
@@ -221,20 +247,34 @@ where
 ///
 /// Works only up to 65536 validators, and `n_validators` must be non-zero.
 pub fn reconstruct<'a, I: 'a, T: Decode>(n_validators: usize, chunks: I) -> Result<T, Error>
+where
+	I: IntoIterator<Item = (&'a [u8], usize)>,
+{
+	reconstruct_with_backend(default_backend(), n_validators, chunks)
+}
+
+/// Reconstruct decodable data from a set of chunks, with the given backend.
+///
+/// See [`reconstruct`].
+pub fn reconstruct_with_backend<'a, I: 'a, T: Decode>(
+	backend: Backend,
+	n_validators: usize,
+	chunks: I,
+) -> Result<T, Error>
 where
 	I: IntoIterator<Item = (&'a [u8], usize)>,
 {
 	let params = code_params(n_validators)?;
-	let mut received_shards: Vec<Option<WrappedShard>> = vec![None; n_validators];
+	let mut received_shards: Vec<Option<&[u8]>> = vec![None; n_validators];
 	for (chunk_data, chunk_idx) in chunks.into_iter().take(n_validators) {
 		if chunk_data.len() % 2 != 0 {
 			return Err(Error::UnevenLength);
 		}
 
-		received_shards[chunk_idx] = Some(WrappedShard::new(chunk_data.to_vec()));
+		received_shards[chunk_idx] = Some(chunk_data);
 	}
 
-	let payload_bytes = params.make_encoder().reconstruct(received_shards)?;
+	let payload_bytes = backend.reconstruct(&params, received_shards)?;
 
 	Decode::decode(&mut &payload_bytes[..]).map_err(|_| Error::BadPayload)
 }
@@ -331,7 +371,7 @@ pub fn branch_hash(root: &H256, branch_nodes: &Proof, index: usize) -> Result<H2
 
 #[cfg(test)]
 mod tests {
-	use std::sync::Arc;
+	use std::{num::NonZeroUsize, sync::Arc};
 
 	use super::*;
 	use polkadot_node_primitives::{AvailableData, BlockData, PoV};
@@ -411,6 +451,135 @@ mod tests {
 		QuickCheck::new().quickcheck(property as fn(ArbitraryAvailableData, u16))
 	}
 
+	#[test]
+	fn parallel_backend_matches_sequential() {
+		fn property(available_data: ArbitraryAvailableData, n_validators: u16, threads: u8) {
+			let n_validators = (n_validators % 1024).max(2) as usize;
+			let parallel = Backend::Parallel(NonZeroUsize::new(threads.max(2) as usize).unwrap());
+
+			let chunks =
+				obtain_chunks_with_backend(Backend::Sequential, n_validators, &available_data.0)
+					.unwrap();
+			assert_eq!(
+				obtain_chunks_with_backend(parallel, n_validators, &available_data.0).unwrap(),
+				chunks
+			);
+
+			// Reconstruct from the last chunks, so that the parity chunks are needed.
+			let threshold = recovery_threshold(n_validators).unwrap();
+			let present = chunks
+				.iter()
+				.enumerate()
+				.skip(n_validators - threshold)
+				.map(|(index, chunk)| (&chunk[..], index));
+			let reconstructed: AvailableData =
+				reconstruct_with_backend(parallel, n_validators, present).unwrap();
+			assert_eq!(reconstructed, available_data.0);
+
+			let systematic = chunks
+				.into_iter()
+				.take(systematic_recovery_threshold(n_validators).unwrap())
+				.collect();
+			let reconstructed: AvailableData =
+				reconstruct_from_systematic_with_backend(parallel, n_validators, systematic)
+					.unwrap();
+			assert_eq!(reconstructed, available_data.0);
+		}
+
+		QuickCheck::new()
+			.tests(20)
+			.quickcheck(property as fn(ArbitraryAvailableData, u16, u8))
+	}
+
+	#[test]
+	fn parallel_backend_matches_sequential_on_large_chunks() {
+		// Deterministic payloads long enough for every thread to handle at least
+		// `MIN_SYMBOLS_PER_THREAD` symbols of each chunk, with lengths which aren't a multiple of
+		// the run length.
+		let cases = [(10, 3 * 2048 + 7, 3), (100, 2048, 2), (1000, 4099, 4)];
+		let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+
+		for (n_validators, symbols_per_chunk, threads) in cases {
+			let k = code_params(n_validators).unwrap().k();
+			let pov = (0..symbols_per_chunk * 2 * k + 1)
+				.map(|_| {
+					state ^= state << 13;
+					state ^= state >> 7;
+					state ^= state << 17;
+					state as u8
+				})
+				.collect();
+			let available_data = AvailableData {
+				pov: PoV { block_data: BlockData(pov) }.into(),
+				validation_data: Default::default(),
+			};
+			let parallel = Backend::Parallel(NonZeroUsize::new(threads).unwrap());
+
+			let chunks =
+				obtain_chunks_with_backend(Backend::Sequential, n_validators, &available_data)
+					.unwrap();
+			assert!(chunks[0].len() / 2 >= symbols_per_chunk);
+			assert_eq!(
+				obtain_chunks_with_backend(parallel, n_validators, &available_data).unwrap(),
+				chunks
+			);
+
+			let threshold = recovery_threshold(n_validators).unwrap();
+			let present = || {
+				chunks
+					.iter()
+					.enumerate()
+					.skip(n_validators - threshold)
+					.map(|(index, chunk)| (&chunk[..], index))
+			};
+			let sequential: AvailableData =
+				reconstruct_with_backend(Backend::Sequential, n_validators, present()).unwrap();
+			let reconstructed: AvailableData =
+				reconstruct_with_backend(parallel, n_validators, present()).unwrap();
+			assert_eq!(reconstructed, sequential);
+			assert_eq!(reconstructed, available_data);
+
+			let systematic: Vec<_> = chunks
+				.iter()
+				.take(systematic_recovery_threshold(n_validators).unwrap())
+				.cloned()
+				.collect();
+			let reconstructed: AvailableData =
+				reconstruct_from_systematic_with_backend(parallel, n_validators, systematic)
+					.unwrap();
+			assert_eq!(reconstructed, available_data);
+		}
+	}
+
+	#[test]
+	fn parallel_backend_reports_the_same_errors() {
+		let parallel = Backend::Parallel(NonZeroUsize::new(4).unwrap());
+		let pov = PoV { block_data: BlockData(vec![0xfe; 1024 * 1024]) };
+		let available_data = AvailableData { pov: pov.into(), validation_data: Default::default() };
+		let chunks = obtain_chunks_with_backend(parallel, 10, &available_data).unwrap();
+
+		let not_enough = [(&chunks[0][..], 0), (&chunks[1][..], 1), (&chunks[2][..], 2)];
+		assert_eq!(
+			reconstruct_with_backend::<_, AvailableData>(parallel, 10, not_enough),
+			Err(Error::NotEnoughChunks)
+		);
+
+		let short = &chunks[3][..chunks[3].len() - 2];
+		let non_uniform =
+			[(&chunks[0][..], 0), (&chunks[1][..], 1), (&chunks[2][..], 2), (short, 3)];
+		assert_eq!(
+			reconstruct_with_backend::<_, AvailableData>(parallel, 10, non_uniform),
+			Err(Error::NonUniformChunks)
+		);
+
+		let mut systematic: Vec<_> = chunks.into_iter().take(4).collect();
+		systematic[1].truncate(2);
+		assert_eq!(
+			reconstruct_from_systematic_with_backend::<AvailableData>(parallel, 10, systematic),
+			Err(Error::NonUniformChunks)
+		);
+	}
+
 	#[test]
 	fn reconstruct_does_not_panic_on_low_validator_count() {
 		let reconstructed = reconstruct_v1(1, [].iter().cloned());
@@ -0,0 +1,115 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only access to the records of the availability store, for the offline inspection of a
+//! database. Nothing in here is used by the subsystem itself.
+
+use super::*;
+
+/// The pruning state of a candidate in the availability store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandidateState {
+	/// The candidate is not included in any block, the data was first observed at the given unix
+	/// time.
+	Unavailable(Duration),
+	/// The candidate was first observed at the given unix time and is included in the given
+	/// unfinalized blocks.
+	Unfinalized(Duration, Vec<(BlockNumber, Hash)>),
+	/// The candidate was included in a block finalized at the given unix time.
+	Finalized(Duration),
+}
+
+impl From<State> for CandidateState {
+	fn from(state: State) -> Self {
+		match state {
+			State::Unavailable(at) => Self::Unavailable(at.into()),
+			State::Unfinalized(at, blocks) => Self::Unfinalized(
+				at.into(),
+				blocks.into_iter().map(|(number, hash)| (number.0, hash)).collect(),
+			),
+			State::Finalized(at) => Self::Finalized(at.into()),
+		}
+	}
+}
+
+/// The meta record of a candidate in the availability store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateRecord {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The pruning state of the candidate.
+	pub state: CandidateState,
+	/// Whether the full available data of the candidate is stored.
+	pub data_available: bool,
+	/// The indices of the validators whose chunks are stored.
+	pub chunks_stored: Vec<ValidatorIndex>,
+}
+
+impl CandidateRecord {
+	fn new(candidate_hash: CandidateHash, meta: CandidateMeta) -> Self {
+		Self {
+			candidate_hash,
+			state: meta.state.into(),
+			data_available: meta.data_available,
+			chunks_stored: meta
+				.chunks_stored
+				.iter_ones()
+				.map(|index| ValidatorIndex(index as u32))
+				.collect(),
+		}
+	}
+}
+
+/// Load the meta record of the candidate with the given hash.
+pub fn load_candidate(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<CandidateRecord>, Error> {
+	Ok(load_meta(db, config, candidate_hash)?
+		.map(|meta| CandidateRecord::new(*candidate_hash, meta)))
+}
+
+/// Load the meta records of all candidates in the availability store, ordered by candidate hash.
+pub fn load_candidates(
+	db: &Arc<dyn Database>,
+	config: &Config,
+) -> Result<Vec<CandidateRecord>, Error> {
+	db.iter_with_prefix(config.col_meta, META_PREFIX)
+		.map(|r| {
+			let (key, value) = r?;
+			let candidate_hash = CandidateHash::decode(&mut &key[META_PREFIX.len()..])?;
+			let meta = CandidateMeta::decode(&mut &value[..])?;
+			Ok(CandidateRecord::new(candidate_hash, meta))
+		})
+		.collect()
+}
+
+/// Load the chunks stored for the candidate in `record`, along with the index of the validator
+/// each of them belongs to.
+pub fn load_chunks(
+	db: &Arc<dyn Database>,
+	config: &Config,
+	record: &CandidateRecord,
+) -> Result<Vec<(ValidatorIndex, ErasureChunk)>, Error> {
+	let mut chunks = Vec::with_capacity(record.chunks_stored.len());
+	for validator_index in &record.chunks_stored {
+		if let Some(chunk) = load_chunk(db, config, &record.candidate_hash, *validator_index)? {
+			chunks.push((*validator_index, chunk));
+		}
+	}
+	Ok(chunks)
+}
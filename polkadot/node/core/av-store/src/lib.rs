@@ -53,6 +53,7 @@ use polkadot_primitives::{
 };
 use util::availability_chunks::availability_chunk_indices;
 
+pub mod inspect;
 mod metrics;
 pub use self::metrics::*;
 
@@ -427,6 +428,8 @@ pub struct Config {
 	pub col_meta: u32,
 	/// How long finalized data should be kept (in hours).
 	pub keep_finalized_for: u32,
+	/// The erasure coding backend used to re-encode stored data.
+	pub erasure_coding_backend: polkadot_erasure_coding::Backend,
 }
 
 /// An implementation of the Availability Store subsystem.
@@ -1296,7 +1299,11 @@ fn store_available_data(
 
 	// Important note: This check below is critical for consensus and the `backing` subsystem relies
 	// on it to ensure candidate validity.
-	let chunks = polkadot_erasure_coding::obtain_chunks_v1(n_validators, &available_data)?;
+	let chunks = polkadot_erasure_coding::obtain_chunks_with_backend(
+		subsystem.config.erasure_coding_backend,
+		n_validators,
+		&available_data,
+	)?;
 	let branches = polkadot_erasure_coding::branches(chunks.as_ref());
 
 	if branches.root() != expected_erasure_root {
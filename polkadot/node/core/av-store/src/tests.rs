@@ -42,8 +42,12 @@ mod columns {
 	pub const NUM_COLUMNS: u32 = 2;
 }
 
-const TEST_CONFIG: Config =
-	Config { col_data: columns::DATA, col_meta: columns::META, keep_finalized_for: 1 };
+const TEST_CONFIG: Config = Config {
+	col_data: columns::DATA,
+	col_meta: columns::META,
+	keep_finalized_for: 1,
+	erasure_coding_backend: polkadot_erasure_coding::Backend::Sequential,
+};
 
 type VirtualOverseer =
 	polkadot_node_subsystem_test_helpers::TestSubsystemContextHandle<AvailabilityStoreMessage>;
@@ -102,6 +106,15 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 	state: TestState,
 	store: Arc<dyn Database>,
 	test: impl FnOnce(VirtualOverseer) -> T,
+) {
+	test_harness_with_config(TEST_CONFIG, state, store, test)
+}
+
+fn test_harness_with_config<T: Future<Output = VirtualOverseer>>(
+	config: Config,
+	state: TestState,
+	store: Arc<dyn Database>,
+	test: impl FnOnce(VirtualOverseer) -> T,
 ) {
 	sp_tracing::init_for_tests();
 
@@ -111,7 +124,7 @@ fn test_harness<T: Future<Output = VirtualOverseer>>(
 
 	let subsystem = AvailabilityStoreSubsystem::with_pruning_config_and_clock(
 		store,
-		TEST_CONFIG,
+		config,
 		state.pruning_config.clone(),
 		Arc::new(state.clock),
 		Box::new(NoSyncOracle),
@@ -442,6 +455,61 @@ fn store_available_data_erasure_mismatch() {
 	});
 }
 
+#[test]
+fn store_available_data_with_parallel_backend() {
+	let store = test_store();
+	let test_state = TestState::default();
+	let config = Config {
+		erasure_coding_backend: polkadot_erasure_coding::Backend::Parallel(
+			std::num::NonZeroUsize::new(2).unwrap(),
+		),
+		..TEST_CONFIG
+	};
+
+	test_harness_with_config(
+		config,
+		test_state.clone(),
+		store,
+		|mut virtual_overseer| async move {
+			let candidate_hash = CandidateHash(Hash::repeat_byte(1));
+			let n_validators = 10;
+
+			// Large enough for the work to be split between the threads.
+			let pov = PoV { block_data: BlockData(vec![42; 64 * 1024]) };
+			let available_data = AvailableData {
+				pov: Arc::new(pov),
+				validation_data: test_state.persisted_validation_data.clone(),
+			};
+
+			let chunks =
+				polkadot_erasure_coding::obtain_chunks_v1(n_validators as _, &available_data)
+					.unwrap();
+			let branches = polkadot_erasure_coding::branches(chunks.as_ref());
+
+			let (tx, rx) = oneshot::channel();
+			let block_msg = AvailabilityStoreMessage::StoreAvailableData {
+				candidate_hash,
+				n_validators,
+				available_data: available_data.clone(),
+				tx,
+				core_index: CoreIndex(0),
+				expected_erasure_root: branches.root(),
+				node_features: NodeFeatures::EMPTY,
+			};
+
+			virtual_overseer.send(FromOrchestra::Communication { msg: block_msg }).await;
+			assert_eq!(rx.await.unwrap(), Ok(()));
+
+			let chunk = query_chunk(&mut virtual_overseer, candidate_hash, ValidatorIndex(3))
+				.await
+				.unwrap();
+			assert_eq!(chunk.chunk, chunks[3]);
+
+			virtual_overseer
+		},
+	);
+}
+
 #[test]
 fn store_pov_and_queries_work() {
 	// If the AvailabilityChunkMapping feature is not enabled,
@@ -1364,3 +1432,61 @@ fn query_chunk_size_works() {
 		virtual_overseer
 	});
 }
+
+#[test]
+fn inspect_lists_stored_candidates_and_chunks() {
+	let store = test_store();
+	let candidate_hash = CandidateHash(Hash::repeat_byte(33));
+	let validator_index = ValidatorIndex(2);
+	let n_validators = 10;
+
+	let chunk = ErasureChunk {
+		chunk: vec![1, 2, 3],
+		index: ChunkIndex(5),
+		proof: Proof::try_from(vec![vec![3, 4, 5]]).unwrap(),
+	};
+
+	let mut chunks_stored = bitvec::bitvec![u8, BitOrderLsb0; 0; n_validators];
+	chunks_stored.set(validator_index.0 as usize, true);
+	with_tx(&store, |tx| {
+		super::write_meta(
+			tx,
+			&TEST_CONFIG,
+			&candidate_hash,
+			&CandidateMeta {
+				data_available: false,
+				chunks_stored,
+				state: State::Unfinalized(
+					BETimestamp(7),
+					vec![(BEBlockNumber(3), Hash::repeat_byte(1))],
+				),
+			},
+		);
+		super::write_chunk(tx, &TEST_CONFIG, &candidate_hash, validator_index, &chunk);
+	});
+
+	let expected = inspect::CandidateRecord {
+		candidate_hash,
+		state: inspect::CandidateState::Unfinalized(
+			Duration::from_secs(7),
+			vec![(3, Hash::repeat_byte(1))],
+		),
+		data_available: false,
+		chunks_stored: vec![validator_index],
+	};
+
+	assert_eq!(inspect::load_candidates(&store, &TEST_CONFIG).unwrap(), vec![expected.clone()]);
+	assert_eq!(
+		inspect::load_candidate(&store, &TEST_CONFIG, &candidate_hash).unwrap(),
+		Some(expected.clone()),
+	);
+	assert_eq!(
+		inspect::load_candidate(&store, &TEST_CONFIG, &CandidateHash(Hash::repeat_byte(1)))
+			.unwrap(),
+		None,
+	);
+	assert_eq!(
+		inspect::load_chunks(&store, &TEST_CONFIG, &expected).unwrap(),
+		vec![(validator_index, chunk)],
+	);
+}
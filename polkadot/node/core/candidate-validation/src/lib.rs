@@ -24,8 +24,9 @@
 #![warn(missing_docs)]
 
 use polkadot_node_core_pvf::{
-	InternalValidationError, InvalidCandidate as WasmInvalidCandidate, PossiblyInvalidError,
-	PrepareError, PrepareJobKind, PvfPrepData, ValidationError, ValidationHost,
+	prune_dumps, InternalValidationError, InvalidCandidate as WasmInvalidCandidate,
+	PossiblyInvalidError, PrepareError, PrepareJobKind, PvfPrepData, ValidationDump,
+	ValidationError, ValidationHost,
 };
 use polkadot_node_core_pvf_common::execute::ValidationContext;
 use polkadot_node_primitives::{InvalidCandidate, PoV, ValidationResult, DISPUTE_WINDOW};
@@ -54,21 +55,25 @@ use polkadot_primitives::{
 	CandidateDescriptorV2 as CandidateDescriptor, CandidateEvent,
 	CandidateReceiptV2 as CandidateReceipt,
 	CommittedCandidateReceiptV2 as CommittedCandidateReceipt, ExecutorParams, Hash,
-	PersistedValidationData, PvfExecKind as RuntimePvfExecKind, PvfPrepKind, SessionIndex,
-	ValidationCode, ValidationCodeHash, ValidatorId,
+	OccupiedCoreAssumption, PersistedValidationData, PvfExecKind as RuntimePvfExecKind,
+	PvfPrepKind, SessionIndex, ValidationCode, ValidationCodeHash, ValidatorId,
 };
 use sp_application_crypto::{AppCrypto, ByteArray};
 use sp_keystore::KeystorePtr;
 
 use codec::Encode;
 
-use futures::{channel::oneshot, prelude::*, stream::FuturesUnordered};
+use futures::{
+	channel::{mpsc, oneshot},
+	prelude::*,
+	stream::FuturesUnordered,
+};
 
 use schnellru::{ByLength, LruMap};
 
 use std::{
-	collections::HashSet,
-	path::PathBuf,
+	collections::{HashSet, VecDeque},
+	path::{Path, PathBuf},
 	pin::Pin,
 	sync::Arc,
 	time::{Duration, Instant},
@@ -96,6 +101,13 @@ const PVF_APPROVAL_EXECUTION_RETRY_DELAY: Duration = Duration::from_millis(200);
 // to allow exhaustive validation messages to fall through in case the tasks are clogged
 const TASK_LIMIT: usize = 30;
 
+/// The max number of failed validations waiting to be dumped. Failed validations are not dumped
+/// while the queue is full.
+const DUMP_QUEUE_SIZE: usize = 8;
+
+/// The max number of dumps kept in the dump directory, the oldest ones are removed first.
+const MAX_DUMPS: usize = 64;
+
 /// Configuration for the candidate validation subsystem
 #[derive(Clone, Default)]
 pub struct Config {
@@ -116,6 +128,17 @@ pub struct Config {
 	pub pvf_prepare_workers_soft_max_num: usize,
 	/// The absolute number of pvf workers that can be spawned in the pvf prepare pool.
 	pub pvf_prepare_workers_hard_max_num: usize,
+	/// An optional directory where failed validations are written as self-contained
+	/// [`ValidationDump`]s, to be replayed with the `pvf-replay` tool.
+	pub pvf_dump_dir: Option<PathBuf>,
+	/// Whether to prepare the artifacts of the current and upcoming validation code in the
+	/// background, ahead of the first validation.
+	pub pvf_prewarm: bool,
+	/// The maximum number of pvf prepare workers which can be busy with pre-warming artifacts.
+	pub pvf_prewarm_workers_max_num: usize,
+	/// The size of the prepared artifacts on disk, in bytes, above which no more artifacts are
+	/// pre-warmed.
+	pub pvf_prewarm_disk_limit: u64,
 }
 
 /// The candidate validation subsystem.
@@ -395,6 +418,7 @@ fn handle_validation_message<S, V>(
 	v3_ever_seen: bool,
 	msg: CandidateValidationMessage,
 	session_params: Option<SessionParams>,
+	dump_tx: Option<mpsc::Sender<ValidationDump>>,
 ) -> Pin<Box<dyn Future<Output = ()> + Send>>
 where
 	S: SubsystemSender<RuntimeApiMessage>,
@@ -449,6 +473,27 @@ where
 				},
 			};
 
+			// Keep a copy of the inputs in case the validation fails and has to be dumped.
+			let maybe_dump = dump_tx.map(|dump_tx| {
+				let executor_params = &session_params.executor_params;
+				let dump = ValidationDump {
+					validation_context: ValidationContext {
+						candidate_receipt: candidate_receipt.clone(),
+						pvd: Arc::new(validation_data.clone()),
+						pov: pov.clone(),
+						executor_params: executor_params.clone(),
+						exec_timeout: pvf_exec_timeout(executor_params, exec_kind.into()),
+						v3_seen: v3_ever_seen,
+					},
+					validation_code: validation_code.clone(),
+					validation_code_bomb_limit: pre.validation_code_bomb_limit,
+					prep_timeout: pvf_prep_timeout(executor_params, PvfPrepKind::Prepare),
+					exec_kind: exec_kind.into(),
+					error: String::new(),
+				};
+				(dump_tx, dump)
+			});
+
 			// Phase 2: PVF execution + output validation.
 			let res = validate_candidate(
 				validation_host,
@@ -464,6 +509,10 @@ where
 			)
 			.await;
 
+			if let Some((dump_tx, dump)) = maybe_dump {
+				dump_failed_validation(dump_tx, dump, &res);
+			}
+
 			metrics.on_validation_event(&res);
 			let _ = response_sender.send(res);
 		}
@@ -536,26 +585,40 @@ async fn run<Context>(
 		pvf_execute_workers_max_num,
 		pvf_prepare_workers_soft_max_num,
 		pvf_prepare_workers_hard_max_num,
+		pvf_dump_dir,
+		pvf_prewarm,
+		pvf_prewarm_workers_max_num,
+		pvf_prewarm_disk_limit,
 	}: Config,
 ) -> SubsystemResult<()> {
-	let (mut validation_host, task) = polkadot_node_core_pvf::start(
-		polkadot_node_core_pvf::Config::new(
-			artifacts_cache_path,
-			node_version,
-			secure_validator_mode,
-			prep_worker_path,
-			exec_worker_path,
-			pvf_execute_workers_max_num,
-			pvf_prepare_workers_soft_max_num,
-			pvf_prepare_workers_hard_max_num,
-		),
-		pvf_metrics,
-	)
-	.await?;
+	let mut pvf_config = polkadot_node_core_pvf::Config::new(
+		artifacts_cache_path,
+		node_version,
+		secure_validator_mode,
+		prep_worker_path,
+		exec_worker_path,
+		pvf_execute_workers_max_num,
+		pvf_prepare_workers_soft_max_num,
+		pvf_prepare_workers_hard_max_num,
+	);
+	pvf_config.prewarm_workers_max_num = pvf_prewarm_workers_max_num;
+	pvf_config.prewarm_disk_limit = pvf_prewarm_disk_limit;
+	let (mut validation_host, task) =
+		polkadot_node_core_pvf::start(pvf_config, pvf_metrics).await?;
 	ctx.spawn_blocking("pvf-validation-host", task.boxed())?;
 
+	let dump_tx = match pvf_dump_dir {
+		Some(dump_dir) => {
+			let (dump_tx, dump_rx) = mpsc::channel(DUMP_QUEUE_SIZE);
+			ctx.spawn_blocking("pvf-dump-writer", write_dumps(dump_dir, dump_rx).boxed())?;
+			Some(dump_tx)
+		},
+		None => None,
+	};
+
 	let mut tasks = FuturesUnordered::new();
 	let mut state = State::default();
+	state.pvf_prep.prewarm = pvf_prewarm;
 
 	loop {
 		loop {
@@ -613,6 +676,7 @@ async fn run<Context>(
 								state.v3_ever_seen,
 								msg,
 								session_params,
+								dump_tx.clone(),
 							);
 							tasks.push(task);
 							if tasks.len() >= TASK_LIMIT {
@@ -689,6 +753,15 @@ struct PvfPrepState {
 	already_prepared_code_hashes: HashSet<ValidationCodeHash>,
 	// How many PVFs per block we take to prepare themselves for the next session validation
 	per_block_limit: usize,
+	// Whether the artifacts of the current and upcoming validation code are pre-warmed
+	prewarm: bool,
+	// Code hashes sent for pre-warming in this session, kept apart from the ones above so that
+	// backed candidates still get their artifacts prepared at normal priority
+	prewarmed_code_hashes: HashSet<ValidationCodeHash>,
+	// Validation code hashes enumerated on the session change and not yet sent for pre-warming
+	prewarm_queue: VecDeque<ValidationCodeHash>,
+	// How many PVFs per block we send to be pre-warmed
+	prewarm_per_block_limit: usize,
 }
 
 impl Default for PvfPrepState {
@@ -697,6 +770,10 @@ impl Default for PvfPrepState {
 			is_next_session_authority: false,
 			already_prepared_code_hashes: HashSet::new(),
 			per_block_limit: 1,
+			prewarm: false,
+			prewarmed_code_hashes: HashSet::new(),
+			prewarm_queue: VecDeque::new(),
+			prewarm_per_block_limit: 4,
 		}
 	}
 }
@@ -781,8 +858,21 @@ async fn maybe_prepare_validation<Sender>(
 {
 	if let Some(new_session_index) = new_session {
 		pvf_prep.already_prepared_code_hashes.clear();
+		pvf_prep.prewarmed_code_hashes.clear();
 		pvf_prep.is_next_session_authority =
-			check_next_session_authority(sender, keystore, leaf.hash, new_session_index).await;
+			check_next_session_authority(sender, keystore.clone(), leaf.hash, new_session_index)
+				.await;
+
+		if pvf_prep.prewarm {
+			pvf_prep.prewarm_queue =
+				enumerate_code_to_prewarm(sender, keystore, leaf.hash, new_session_index).await;
+		}
+	}
+
+	// Before the backed candidates of the next session are known, pre-warm the artifacts of the
+	// validation code we are likely to need.
+	if !pvf_prep.prewarm_queue.is_empty() {
+		prewarm_pvfs(sender, validation_backend, leaf.hash, pvf_prep).await;
 	}
 
 	// On every active leaf check candidates and prepare PVFs our node doesn't have yet.
@@ -914,28 +1004,78 @@ where
 	let mut active_pvfs = vec![];
 	let mut processed_code_hashes = vec![];
 	for code_hash in code_hashes {
-		let Ok(Ok(Some(validation_code))) =
-			util::request_validation_code_by_hash(relay_parent, code_hash, sender)
-				.await
-				.await
+		let Some(pvf) = fetch_pvf_prep_data(
+			sender,
+			relay_parent,
+			code_hash,
+			&executor_params,
+			timeout,
+			PrepareJobKind::Prechecking,
+		)
+		.await
 		else {
-			gum::warn!(
-				target: LOG_TARGET,
-				?relay_parent,
-				?code_hash,
-				"cannot fetch validation code hash from runtime API",
-			);
 			continue;
 		};
 
-		let Some(session_index) = get_session_index(sender, relay_parent).await else { continue };
+		active_pvfs.push(pvf);
+		processed_code_hashes.push(code_hash);
+	}
 
-		let validation_code_bomb_limit = match util::runtime::fetch_validation_code_bomb_limit(
-			relay_parent,
-			session_index,
-			sender,
-		)
-		.await
+	if active_pvfs.is_empty() {
+		return None;
+	}
+
+	if let Err(err) = validation_backend.heads_up(active_pvfs).await {
+		gum::warn!(
+			target: LOG_TARGET,
+			?relay_parent,
+			?err,
+			"cannot prepare PVF for the next session",
+		);
+		return None;
+	};
+
+	gum::debug!(
+		target: LOG_TARGET,
+		?relay_parent,
+		?processed_code_hashes,
+		"Prepared PVF for the next session",
+	);
+
+	Some(processed_code_hashes)
+}
+
+// Fetches the validation code by its hash and builds the data for its preparation.
+async fn fetch_pvf_prep_data<Sender>(
+	sender: &mut Sender,
+	relay_parent: Hash,
+	code_hash: ValidationCodeHash,
+	executor_params: &ExecutorParams,
+	timeout: Duration,
+	prep_kind: PrepareJobKind,
+) -> Option<PvfPrepData>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	let Ok(Ok(Some(validation_code))) =
+		util::request_validation_code_by_hash(relay_parent, code_hash, sender)
+			.await
+			.await
+	else {
+		gum::warn!(
+			target: LOG_TARGET,
+			?relay_parent,
+			?code_hash,
+			"cannot fetch validation code hash from runtime API",
+		);
+		return None;
+	};
+
+	let session_index = get_session_index(sender, relay_parent).await?;
+
+	let validation_code_bomb_limit =
+		match util::runtime::fetch_validation_code_bomb_limit(relay_parent, session_index, sender)
+			.await
 		{
 			Ok(limit) => limit,
 			Err(err) => {
@@ -945,44 +1085,160 @@ where
 					?err,
 					"cannot fetch validation code bomb limit from runtime API",
 				);
-				continue;
+				return None;
 			},
 		};
 
-		let pvf = PvfPrepData::from_code(
-			validation_code.0,
-			executor_params.clone(),
-			timeout,
-			PrepareJobKind::Prechecking,
-			validation_code_bomb_limit,
-		);
+	Some(PvfPrepData::from_code(
+		validation_code.0,
+		executor_params.clone(),
+		timeout,
+		prep_kind,
+		validation_code_bomb_limit,
+	))
+}
 
-		active_pvfs.push(pvf);
-		processed_code_hashes.push(code_hash);
-	}
+// Returns the validation code hashes of the parachains of the session and of the code awaiting
+// pre-checking, if the node is an authority.
+async fn enumerate_code_to_prewarm<Sender>(
+	sender: &mut Sender,
+	keystore: KeystorePtr,
+	relay_parent: Hash,
+	session_index: SessionIndex,
+) -> VecDeque<ValidationCodeHash>
+where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	let Ok(Ok(authorities)) = util::request_authorities(relay_parent, sender).await.await else {
+		gum::warn!(
+			target: LOG_TARGET,
+			?relay_parent,
+			"cannot fetch authorities from runtime API",
+		);
+		return VecDeque::new();
+	};
 
-	if active_pvfs.is_empty() {
-		return None;
+	// Only authorities validate candidates, there is no point in spending resources otherwise.
+	if !authorities
+		.iter()
+		.any(|v| keystore.has_keys(&[(v.to_raw_vec(), AuthorityDiscoveryId::ID)]))
+	{
+		return VecDeque::new();
 	}
 
-	if let Err(err) = validation_backend.heads_up(active_pvfs).await {
+	let Ok(Ok(para_ids)) = util::request_para_ids(relay_parent, session_index, sender).await.await
+	else {
 		gum::warn!(
 			target: LOG_TARGET,
 			?relay_parent,
-			?err,
-			"cannot prepare PVF for the next session",
+			"cannot fetch parachain ids from runtime API",
 		);
-		return None;
+		return VecDeque::new();
 	};
 
+	let mut code_hashes = VecDeque::new();
+	for para_id in para_ids {
+		match util::request_validation_code_hash(
+			relay_parent,
+			para_id,
+			OccupiedCoreAssumption::Included,
+			sender,
+		)
+		.await
+		.await
+		{
+			Ok(Ok(Some(code_hash))) => code_hashes.push_back(code_hash),
+			Ok(Ok(None)) => {},
+			_ => gum::debug!(
+				target: LOG_TARGET,
+				?relay_parent,
+				?para_id,
+				"cannot fetch validation code hash from runtime API",
+			),
+		}
+	}
+
+	// The code of upcoming upgrades and onboarding parachains.
+	let (tx, rx) = oneshot::channel();
+	if let Ok(upcoming) =
+		runtime_api_request(sender, relay_parent, RuntimeApiRequest::PvfsRequirePrecheck(tx), rx)
+			.await
+	{
+		code_hashes.extend(upcoming);
+	}
+
+	let mut seen = HashSet::new();
+	code_hashes.retain(|code_hash| seen.insert(*code_hash));
+
 	gum::debug!(
 		target: LOG_TARGET,
 		?relay_parent,
-		?processed_code_hashes,
-		"Prepared PVF for the next session",
+		?session_index,
+		n_code_hashes = code_hashes.len(),
+		"Enumerated validation code to pre-warm",
 	);
 
-	Some(processed_code_hashes)
+	code_hashes
+}
+
+// Sends a limited number of PVFs from the pre-warm queue to the validation host.
+async fn prewarm_pvfs<Sender>(
+	sender: &mut Sender,
+	validation_backend: &mut impl ValidationBackend,
+	relay_parent: Hash,
+	pvf_prep: &mut PvfPrepState,
+) where
+	Sender: SubsystemSender<RuntimeApiMessage>,
+{
+	let Ok(executor_params) = util::executor_params_at_relay_parent(relay_parent, sender).await
+	else {
+		gum::warn!(
+			target: LOG_TARGET,
+			?relay_parent,
+			"cannot fetch executor params for the session",
+		);
+		return;
+	};
+	let timeout = pvf_prep_timeout(&executor_params, PvfPrepKind::Prepare);
+
+	let mut pvfs = vec![];
+	let mut processed_code_hashes = vec![];
+	while processed_code_hashes.len() < pvf_prep.prewarm_per_block_limit {
+		let Some(code_hash) = pvf_prep.prewarm_queue.pop_front() else { break };
+		if pvf_prep.already_prepared_code_hashes.contains(&code_hash) ||
+			pvf_prep.prewarmed_code_hashes.contains(&code_hash)
+		{
+			continue;
+		}
+
+		processed_code_hashes.push(code_hash);
+		if let Some(pvf) = fetch_pvf_prep_data(
+			sender,
+			relay_parent,
+			code_hash,
+			&executor_params,
+			timeout,
+			PrepareJobKind::Compilation,
+		)
+		.await
+		{
+			pvfs.push(pvf);
+		}
+	}
+
+	pvf_prep.prewarmed_code_hashes.extend(processed_code_hashes);
+	if pvfs.is_empty() {
+		return;
+	}
+
+	if let Err(err) = validation_backend.prewarm(pvfs).await {
+		gum::warn!(
+			target: LOG_TARGET,
+			?relay_parent,
+			?err,
+			"cannot send PVFs to be pre-warmed",
+		);
+	}
 }
 
 async fn update_active_leaves_validation_backend<Sender>(
@@ -1366,6 +1622,73 @@ async fn validate_candidate(
 	}
 }
 
+/// Queue `dump` to be written by [`write_dumps`] if `result` is not valid.
+///
+/// The dump is dropped if the queue is full, which is only logged.
+fn dump_failed_validation(
+	mut dump_tx: mpsc::Sender<ValidationDump>,
+	mut dump: ValidationDump,
+	result: &Result<ValidationResult, ValidationFailed>,
+) {
+	dump.error = match result {
+		Ok(ValidationResult::Valid(..)) => return,
+		Ok(ValidationResult::Invalid(e)) => format!("{e:?}"),
+		Err(ValidationFailed(e)) => e.clone(),
+	};
+
+	if let Err(err) = dump_tx.try_send(dump) {
+		let queue_full = err.is_full();
+		gum::warn!(
+			target: LOG_TARGET,
+			queue_full,
+			candidate_hash = ?err.into_inner().validation_context.candidate_receipt.hash(),
+			"Failed to queue the dump of a failed candidate validation",
+		);
+	}
+}
+
+/// Write the dumps received on `dumps` into `dump_dir`, keeping at most [`MAX_DUMPS`] of them.
+///
+/// Does blocking file system operations, so it must be spawned as a blocking task.
+async fn write_dumps(dump_dir: PathBuf, mut dumps: mpsc::Receiver<ValidationDump>) {
+	while let Some(dump) = dumps.next().await {
+		write_dump(&dump_dir, dump);
+	}
+}
+
+/// Write `dump` into `dump_dir` and remove the oldest dumps over [`MAX_DUMPS`].
+///
+/// Failing to write the dump is not fatal, it is only logged.
+fn write_dump(dump_dir: &Path, dump: ValidationDump) {
+	let candidate_hash = dump.validation_context.candidate_receipt.hash();
+	match dump.write(dump_dir) {
+		Ok(path) => gum::info!(
+			target: LOG_TARGET,
+			?candidate_hash,
+			?path,
+			"Dumped failed candidate validation",
+		),
+		Err(err) => gum::warn!(
+			target: LOG_TARGET,
+			?candidate_hash,
+			?dump_dir,
+			?err,
+			"Failed to dump failed candidate validation",
+		),
+	}
+
+	match prune_dumps(dump_dir, MAX_DUMPS) {
+		Ok(0) => {},
+		Ok(removed) => gum::debug!(target: LOG_TARGET, removed, "Removed the oldest dumps"),
+		Err(err) => gum::warn!(
+			target: LOG_TARGET,
+			?dump_dir,
+			?err,
+			"Failed to remove the oldest dumps",
+		),
+	}
+}
+
 #[async_trait]
 trait ValidationBackend {
 	/// Tries executing a PVF a single time (no retries).
@@ -1502,6 +1825,9 @@ trait ValidationBackend {
 
 	async fn heads_up(&mut self, active_pvfs: Vec<PvfPrepData>) -> Result<(), String>;
 
+	/// Prepare the artifacts of the given PVFs in the background, at the lowest priority.
+	async fn prewarm(&mut self, pvfs: Vec<PvfPrepData>) -> Result<(), String>;
+
 	/// Inform the backend about active leaf changes
 	///
 	/// Ancestors provided should match the still valid scheduling parents (implicit view) as of the
@@ -1556,6 +1882,10 @@ impl ValidationBackend for ValidationHost {
 		self.heads_up(active_pvfs).await
 	}
 
+	async fn prewarm(&mut self, pvfs: Vec<PvfPrepData>) -> Result<(), String> {
+		self.prewarm(pvfs).await
+	}
+
 	async fn update_active_leaves(
 		&mut self,
 		update: ActiveLeavesUpdate,
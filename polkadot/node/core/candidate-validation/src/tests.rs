@@ -476,6 +476,10 @@ impl ValidationBackend for MockValidateCandidateBackend {
 		unreachable!()
 	}
 
+	async fn prewarm(&mut self, _pvfs: Vec<PvfPrepData>) -> Result<(), String> {
+		unreachable!()
+	}
+
 	async fn update_active_leaves(
 		&mut self,
 		_update: ActiveLeavesUpdate,
@@ -1524,6 +1528,10 @@ impl ValidationBackend for MockPreCheckBackend {
 		unreachable!()
 	}
 
+	async fn prewarm(&mut self, _pvfs: Vec<PvfPrepData>) -> Result<(), String> {
+		unreachable!()
+	}
+
 	async fn update_active_leaves(
 		&mut self,
 		_update: ActiveLeavesUpdate,
@@ -1659,6 +1667,7 @@ fn precheck_properly_classifies_outcomes() {
 #[derive(Default, Clone)]
 struct MockHeadsUp {
 	heads_up_call_count: Arc<AtomicUsize>,
+	prewarm_call_count: Arc<AtomicUsize>,
 }
 
 #[async_trait]
@@ -1681,6 +1690,11 @@ impl ValidationBackend for MockHeadsUp {
 		Ok(())
 	}
 
+	async fn prewarm(&mut self, _pvfs: Vec<PvfPrepData>) -> Result<(), String> {
+		let _ = self.prewarm_call_count.fetch_add(1, Ordering::SeqCst);
+		Ok(())
+	}
+
 	async fn update_active_leaves(
 		&mut self,
 		_update: ActiveLeavesUpdate,
@@ -2294,6 +2308,7 @@ fn maybe_prepare_validation_does_not_prepare_already_prepared_pvfs() {
 				ValidationCode(vec![0; 16]).hash(),
 				ValidationCode(vec![1; 16]).hash(),
 			]),
+			..Default::default()
 		},
 		..Default::default()
 	};
@@ -2364,6 +2379,139 @@ fn maybe_prepare_validation_does_not_prepare_already_prepared_pvfs() {
 	assert_eq!(state.pvf_prep.already_prepared_code_hashes.len(), 3);
 }
 
+#[test]
+fn maybe_prepare_validation_prewarms_current_and_upcoming_pvfs() {
+	let pool = TaskExecutor::new();
+	let (mut ctx, mut ctx_handle) = make_subsystem_context::<AllMessages, _>(pool);
+
+	let keystore = alice_keystore();
+	let mut backend = MockHeadsUp::default();
+	let activated_hash = Hash::random();
+	let update = dummy_active_leaves_update(activated_hash);
+	let mut state = State {
+		pvf_prep: PvfPrepState { prewarm: true, prewarm_per_block_limit: 2, ..Default::default() },
+		..Default::default()
+	};
+
+	let code_hashes: Vec<ValidationCodeHash> =
+		(0..3).map(|i| ValidationCode(vec![i; 16]).hash()).collect();
+	let not_yet_prewarmed = code_hashes[2];
+
+	let check_fut =
+		handle_active_leaves_update(ctx.sender(), keystore, &mut backend, update, &mut state);
+
+	let test_fut = async move {
+		assert_new_active_leaf_messages(&mut ctx_handle, 1).await;
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::NodeFeatures(_, tx))) => {
+				let _ = tx.send(Ok(NodeFeatures::new()));
+			}
+		);
+
+		// Not an authority in the next session, so no PVFs of backed candidates are prepared.
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::Authorities(tx))) => {
+				let _ = tx.send(Ok(vec![Sr25519Keyring::Alice.public().into()]));
+			}
+		);
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionInfo(index, tx))) => {
+				assert_eq!(index, 1);
+				let _ = tx.send(Ok(Some(dummy_session_info(vec![Sr25519Keyring::Alice.public()]))));
+			}
+		);
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::Authorities(tx))) => {
+				let _ = tx.send(Ok(vec![Sr25519Keyring::Alice.public().into()]));
+			}
+		);
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ParaIds(index, tx))) => {
+				assert_eq!(index, 1);
+				let _ = tx.send(Ok(vec![ParaId::from(1_u32), ParaId::from(2_u32)]));
+			}
+		);
+
+		for code_hash in &code_hashes[..2] {
+			assert_matches!(
+				ctx_handle.recv().await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ValidationCodeHash(_, assumption, tx))) => {
+					assert_eq!(assumption, OccupiedCoreAssumption::Included);
+					let _ = tx.send(Ok(Some(*code_hash)));
+				}
+			);
+		}
+
+		// The code of an upgrade which is already known is not pre-warmed twice.
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::PvfsRequirePrecheck(tx))) => {
+				let _ = tx.send(Ok(vec![code_hashes[1], code_hashes[2]]));
+			}
+		);
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionIndexForChild(tx))) => {
+				let _ = tx.send(Ok(1));
+			}
+		);
+
+		assert_matches!(
+			ctx_handle.recv().await,
+			AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionExecutorParams(index, tx))) => {
+				assert_eq!(index, 1);
+				let _ = tx.send(Ok(Some(ExecutorParams::default())));
+			}
+		);
+
+		for code_hash in &code_hashes[..2] {
+			assert_matches!(
+				ctx_handle.recv().await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ValidationCodeByHash(hash, tx))) => {
+					assert_eq!(hash, *code_hash);
+					let _ = tx.send(Ok(Some(ValidationCode(Vec::new()))));
+				}
+			);
+
+			assert_matches!(
+				ctx_handle.recv().await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::SessionIndexForChild(tx))) => {
+					let _ = tx.send(Ok(1));
+				}
+			);
+
+			assert_matches!(
+				ctx_handle.recv().await,
+				AllMessages::RuntimeApi(RuntimeApiMessage::Request(_, RuntimeApiRequest::ValidationCodeBombLimit(session, tx))) => {
+					assert_eq!(session, 1);
+					let _ = tx.send(Ok(VALIDATION_CODE_BOMB_LIMIT));
+				}
+			);
+		}
+	};
+
+	let test_fut = future::join(test_fut, check_fut);
+	executor::block_on(test_fut);
+
+	assert_eq!(backend.heads_up_call_count.load(Ordering::SeqCst), 0);
+	assert_eq!(backend.prewarm_call_count.load(Ordering::SeqCst), 1);
+	assert!(!state.pvf_prep.is_next_session_authority);
+	// Pre-warmed code is still prepared at normal priority once backed candidates need it.
+	assert!(state.pvf_prep.already_prepared_code_hashes.is_empty());
+	assert_eq!(state.pvf_prep.prewarmed_code_hashes.len(), 2);
+	assert_eq!(state.pvf_prep.prewarm_queue, VecDeque::from([not_yet_prewarmed]));
+}
+
 /// Verify that a V3 descriptor is interpreted differently depending on `v3_ever_seen`.
 ///
 /// Before V3 activation: old rules apply — V3 descriptors appear as V1, so
@@ -2759,6 +2907,7 @@ fn pre_validation_scheduling_session_check() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -2792,6 +2941,97 @@ fn pre_validation_scheduling_session_check() {
 	}
 }
 
+/// Failed validations are written to the dump directory, successful ones are not.
+#[test]
+fn failed_validation_is_dumped() {
+	let validation_data = PersistedValidationData { max_pov_size: 1024, ..Default::default() };
+	let pov = PoV { block_data: BlockData(vec![1; 32]) };
+	let head_data = HeadData(vec![1, 1, 1]);
+	let validation_code = ValidationCode(vec![2; 16]);
+
+	let descriptor = make_valid_candidate_descriptor_v2(
+		ParaId::from(1_u32),
+		dummy_hash(),
+		CoreIndex(1),
+		1,
+		dummy_hash(),
+		pov.hash(),
+		validation_code.hash(),
+		head_data.hash(),
+		dummy_hash(),
+	);
+	let validation_result = WasmValidationResult {
+		head_data: head_data.clone(),
+		new_validation_code: None,
+		upward_messages: Default::default(),
+		horizontal_messages: Default::default(),
+		processed_downward_messages: 0,
+		hrmp_watermark: 0,
+	};
+	let commitments = CandidateCommitments {
+		head_data: validation_result.head_data.clone(),
+		upward_messages: validation_result.upward_messages.clone(),
+		horizontal_messages: validation_result.horizontal_messages.clone(),
+		new_validation_code: validation_result.new_validation_code.clone(),
+		processed_downward_messages: validation_result.processed_downward_messages,
+		hrmp_watermark: validation_result.hrmp_watermark,
+	};
+	let candidate_receipt = CandidateReceipt { descriptor, commitments_hash: commitments.hash() };
+	let tmp_dir = tempfile::tempdir().unwrap();
+	let dump_dir = tmp_dir.path().join("dumps");
+
+	let validate = |result: Result<WasmValidationResult, ValidationError>| {
+		let pool = TaskExecutor::new();
+		let (mut ctx, _ctx_handle) = make_subsystem_context::<AllMessages, _>(pool.clone());
+		let (response_tx, response_rx) = oneshot::channel();
+		let (dump_tx, dump_rx) = mpsc::channel(1);
+
+		let task = handle_validation_message(
+			ctx.sender().clone(),
+			MockValidateCandidateBackend::with_hardcoded_result(result),
+			Metrics::default(),
+			false,
+			CandidateValidationMessage::ValidateFromExhaustive {
+				validation_data: validation_data.clone(),
+				validation_code: validation_code.clone(),
+				candidate_receipt: candidate_receipt.clone(),
+				pov: Arc::new(pov.clone()),
+				scheduling_session_index: 1,
+				exec_kind: PvfExecKind::Approval,
+				response_sender: response_tx,
+			},
+			Some(default_session_params()),
+			Some(dump_tx),
+		);
+		executor::block_on(task);
+		// The task dropped its sender, so the writer stops after writing the queued dumps.
+		executor::block_on(write_dumps(dump_dir.clone(), dump_rx));
+		executor::block_on(response_rx).unwrap().unwrap()
+	};
+
+	assert_matches!(validate(Ok(validation_result)), ValidationResult::Valid(_, _));
+	assert!(!dump_dir.exists());
+
+	assert_matches!(
+		validate(Err(ValidationError::Invalid(WasmInvalidCandidate::HardTimeout))),
+		ValidationResult::Invalid(InvalidCandidate::Timeout)
+	);
+	let entries = std::fs::read_dir(&dump_dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.collect::<Vec<_>>();
+	assert_eq!(entries.len(), 1);
+
+	let dump = ValidationDump::read(&entries[0]).unwrap();
+	assert_eq!(dump.error, "Timeout");
+	assert_eq!(dump.validation_code, validation_code);
+	assert_eq!(dump.validation_context.candidate_receipt, candidate_receipt);
+	assert_eq!(*dump.validation_context.pov, pov);
+	assert_eq!(*dump.validation_context.pvd, validation_data);
+	assert_eq!(dump.validation_code_bomb_limit, VALIDATION_CODE_BOMB_LIMIT);
+	assert_eq!(dump.exec_kind, RuntimePvfExecKind::Approval);
+}
+
 /// V3 scheduling session offset mismatch: backing rejects when the computed scheduling session
 /// (session_index + offset) doesn't match the runtime. Uses `v3_ever_seen=true` — backing only
 /// sends V3 candidates after V3 is confirmed enabled.
@@ -2849,6 +3089,7 @@ fn pre_validation_v3_scheduling_offset_mismatch() {
 			response_sender: response_tx,
 		},
 		Some(default_session_params()),
+		None,
 	);
 
 	let test_fut = async move {
@@ -2973,6 +3214,7 @@ fn pre_validation_basic_checks() {
 					response_sender: response_tx,
 				},
 				Some(default_session_params()),
+				None,
 			);
 
 			// Basic checks fail before any runtime calls — no mock interaction needed.
@@ -3063,6 +3305,7 @@ fn pre_validation_relay_parent_session_check() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -3115,6 +3358,7 @@ fn pre_validation_relay_parent_session_check() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -3245,6 +3489,7 @@ fn pre_validation_relay_parent_session_check_v3_ancestor_query() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -3298,6 +3543,7 @@ fn pre_validation_relay_parent_session_check_v3_ancestor_query() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -3360,6 +3606,7 @@ fn pre_validation_relay_parent_session_check_v3_ancestor_query() {
 				response_sender: response_tx,
 			},
 			Some(default_session_params()),
+			None,
 		);
 
 		let test_fut = async move {
@@ -40,8 +40,8 @@ use crate::{
 	LOG_TARGET,
 };
 
-const RECENT_DISPUTES_KEY: &[u8; 15] = b"recent-disputes";
-const EARLIEST_SESSION_KEY: &[u8; 16] = b"earliest-session";
+pub(crate) const RECENT_DISPUTES_KEY: &[u8; 15] = b"recent-disputes";
+pub(crate) const EARLIEST_SESSION_KEY: &[u8; 16] = b"earliest-session";
 const CANDIDATE_VOTES_SUBKEY: &[u8; 15] = b"candidate-votes";
 /// Until what session have votes been cleaned up already?
 const CLEANED_VOTES_WATERMARK_KEY: &[u8; 23] = b"cleaned-votes-watermark";
@@ -187,7 +187,10 @@ impl Backend for DbBackend {
 	}
 }
 
-fn candidate_votes_key(session: SessionIndex, candidate_hash: &CandidateHash) -> [u8; 15 + 4 + 32] {
+pub(crate) fn candidate_votes_key(
+	session: SessionIndex,
+	candidate_hash: &CandidateHash,
+) -> [u8; 15 + 4 + 32] {
 	let mut buf = [0u8; 15 + 4 + 32];
 	buf[..15].copy_from_slice(CANDIDATE_VOTES_SUBKEY);
 
@@ -198,7 +201,7 @@ fn candidate_votes_key(session: SessionIndex, candidate_hash: &CandidateHash) ->
 	buf
 }
 
-fn candidate_votes_session_prefix(session: SessionIndex) -> [u8; 15 + 4] {
+pub(crate) fn candidate_votes_session_prefix(session: SessionIndex) -> [u8; 15 + 4] {
 	let mut buf = [0u8; 15 + 4];
 	buf[..15].copy_from_slice(CANDIDATE_VOTES_SUBKEY);
 
@@ -273,7 +276,7 @@ impl From<Error> for crate::error::Error {
 /// Result alias for DB errors.
 pub type Result<T> = std::result::Result<T, Error>;
 
-fn load_decode<D: Decode>(
+pub(crate) fn load_decode<D: Decode>(
 	db: &dyn Database,
 	col_dispute_data: u32,
 	key: &[u8],
@@ -375,6 +378,7 @@ fn load_cleaned_votes_watermark(
 mod tests {
 
 	use super::*;
+	use crate::inspect;
 	use polkadot_node_primitives::DISPUTE_WINDOW;
 	use polkadot_primitives::{Hash, Id as ParaId};
 	use polkadot_primitives_test_helpers::{
@@ -604,6 +608,62 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn inspect_reads_written_data() {
+		let mut backend = make_db();
+
+		let hash_a = CandidateHash(Hash::repeat_byte(0x0a));
+		let hash_b = CandidateHash(Hash::repeat_byte(0x0b));
+		let hash_c = CandidateHash(Hash::repeat_byte(0x0c));
+		let votes = |candidate_receipt| CandidateVotes {
+			candidate_receipt,
+			valid: Vec::new(),
+			invalid: Vec::new(),
+		};
+		let receipt_a = dummy_candidate_receipt_v2(Hash::repeat_byte(1));
+		let receipt_b = dummy_candidate_receipt_v2(Hash::repeat_byte(2));
+
+		let mut overlay_db = OverlayedBackend::new(&backend);
+		overlay_db.write_earliest_session(1);
+		overlay_db.write_recent_disputes(
+			vec![((2, hash_a), DisputeStatus::Active)].into_iter().collect(),
+		);
+		overlay_db.write_candidate_votes(2, hash_a, votes(receipt_a.clone()));
+		overlay_db.write_candidate_votes(2, hash_b, votes(receipt_b.clone()));
+		overlay_db.write_candidate_votes(
+			3,
+			hash_c,
+			votes(dummy_candidate_receipt_v2(dummy_hash())),
+		);
+		backend.write(overlay_db.into_write_ops()).unwrap();
+
+		let db = &*backend.inner;
+		let config = crate::Config { col_dispute_data: 0 };
+
+		assert_eq!(inspect::load_earliest_session(db, &config).unwrap(), Some(1));
+		assert_eq!(
+			inspect::load_recent_disputes(db, &config).unwrap(),
+			vec![((2, hash_a), DisputeStatus::Active)].into_iter().collect(),
+		);
+		assert_eq!(
+			inspect::load_candidate_votes(db, &config, 2, &hash_b)
+				.unwrap()
+				.unwrap()
+				.candidate_receipt,
+			receipt_b,
+		);
+		assert!(inspect::load_candidate_votes(db, &config, 3, &hash_b).unwrap().is_none());
+
+		let session_votes = inspect::load_session_votes(db, &config, 2).unwrap();
+		assert_eq!(
+			session_votes
+				.into_iter()
+				.map(|(hash, votes)| (hash, votes.candidate_receipt))
+				.collect::<Vec<_>>(),
+			vec![(hash_a, receipt_a), (hash_b, receipt_b)],
+		);
+	}
+
 	#[test]
 	fn note_earliest_session_prunes_old() {
 		let mut backend = make_db();
@@ -30,13 +30,15 @@ use sc_keystore::LocalKeystore;
 
 use polkadot_node_clock::Clock;
 use polkadot_node_primitives::{
-	disputes::ValidCandidateVotes, CandidateVotes, DisputeStatus, SignedDisputeStatement,
-	Timestamp, DISPUTE_WINDOW,
+	disputes::ValidCandidateVotes, CandidateVotes, DisputeEvidence, DisputeStatus,
+	InvalidDisputeVote, LocalParticipation, SignedDisputeEvidence, SignedDisputeStatement,
+	Timestamp, ValidDisputeVote, DISPUTE_WINDOW,
 };
 use polkadot_node_subsystem::{
 	messages::{
-		ApprovalVotingParallelMessage, BlockDescription, ChainSelectionMessage,
-		DisputeCoordinatorMessage, DisputeDistributionMessage, ImportStatementsResult,
+		ApprovalVotingParallelMessage, AvailabilityRecoveryMessage, AvailabilityStoreMessage,
+		BlockDescription, ChainSelectionMessage, DisputeCoordinatorMessage,
+		DisputeDistributionMessage, ImportStatementsResult,
 	},
 	overseer, ActivatedLeaf, ActiveLeavesUpdate, FromOrchestra, OverseerSignal, RuntimeApiError,
 };
@@ -973,6 +975,14 @@ impl Initialized {
 				}
 				let _ = tx.send(query_output);
 			},
+			DisputeCoordinatorMessage::QueryDisputeEvidence(candidate_hash, tx) => {
+				gum::trace!(
+					target: LOG_TARGET,
+					?candidate_hash,
+					"DisputeCoordinatorMessage::QueryDisputeEvidence"
+				);
+				self.query_dispute_evidence(ctx, overlay_db, candidate_hash, tx).await?;
+			},
 			DisputeCoordinatorMessage::IssueLocalStatement(
 				session,
 				candidate_hash,
@@ -1652,6 +1662,125 @@ impl Initialized {
 		Ok(())
 	}
 
+	/// Gather the evidence about the most recent dispute on `candidate_hash`.
+	///
+	/// The available data of the candidate is fetched in the background, `tx` is answered once
+	/// the evidence is complete and signed.
+	async fn query_dispute_evidence<Context>(
+		&mut self,
+		ctx: &mut Context,
+		overlay_db: &mut OverlayedBackend<'_, impl Backend>,
+		candidate_hash: CandidateHash,
+		tx: oneshot::Sender<Option<SignedDisputeEvidence>>,
+	) -> Result<()> {
+		let recent_disputes = overlay_db.load_recent_disputes()?.unwrap_or_default();
+		let Some((session, status)) = recent_disputes
+			.iter()
+			.rev()
+			.find(|((_, hash), _)| *hash == candidate_hash)
+			.map(|((session, _), status)| (*session, *status))
+		else {
+			gum::debug!(target: LOG_TARGET, ?candidate_hash, "No recent dispute for candidate");
+			let _ = tx.send(None);
+			return Ok(())
+		};
+
+		let Some(votes) =
+			overlay_db.load_candidate_votes(session, &candidate_hash)?.map(CandidateVotes::from)
+		else {
+			gum::debug!(target: LOG_TARGET, ?candidate_hash, session, "No votes found for dispute");
+			let _ = tx.send(None);
+			return Ok(())
+		};
+
+		let env = match CandidateEnvironment::new(
+			ctx,
+			&mut self.runtime_info,
+			session,
+			votes
+				.candidate_receipt
+				.descriptor
+				.scheduling_parent_for_candidate_validation(self.v3_ever_seen),
+			self.offchain_disabled_validators.iter(session),
+			&mut self.controlled_validator_indices,
+		)
+		.await
+		{
+			None => {
+				gum::warn!(
+					target: LOG_TARGET,
+					session,
+					"Missing info for session of a dispute, can't export evidence",
+				);
+				let _ = tx.send(None);
+				return Ok(())
+			},
+			Some(env) => env,
+		};
+
+		let Some(validator_index) = env.controlled_indices().iter().next().copied() else {
+			gum::debug!(
+				target: LOG_TARGET,
+				?candidate_hash,
+				session,
+				"Not a validator in the session of the dispute, can't sign evidence",
+			);
+			let _ = tx.send(None);
+			return Ok(())
+		};
+
+		let valid_votes = votes.valid.raw();
+		let local_participation = if valid_votes.contains_key(&validator_index) {
+			LocalParticipation::Valid
+		} else if votes.invalid.contains_key(&validator_index) {
+			LocalParticipation::Invalid
+		} else {
+			LocalParticipation::NoVote
+		};
+
+		let evidence = DisputeEvidence {
+			session_index: session,
+			candidate_receipt: votes.candidate_receipt.clone(),
+			session_info: env.session_info().clone(),
+			status,
+			valid_votes: valid_votes
+				.iter()
+				.map(|(validator_index, (kind, signature))| ValidDisputeVote {
+					validator_index: *validator_index,
+					signature: signature.clone(),
+					kind: kind.clone(),
+				})
+				.collect(),
+			invalid_votes: votes
+				.invalid
+				.iter()
+				.map(|(validator_index, (kind, signature))| InvalidDisputeVote {
+					validator_index: *validator_index,
+					signature: signature.clone(),
+					kind: *kind,
+				})
+				.collect(),
+			local_participation,
+			available_data: None,
+		};
+
+		let sender = ctx.sender().clone();
+		ctx.spawn(
+			"dispute-evidence-export",
+			export_dispute_evidence(
+				sender,
+				self.keystore.clone(),
+				evidence,
+				validator_index,
+				tx,
+			)
+			.boxed(),
+		)
+		.map_err(FatalError::SpawnFailed)?;
+
+		Ok(())
+	}
+
 	fn session_is_ancient(&self, session_idx: SessionIndex) -> bool {
 		return session_idx < self.highest_session_seen.saturating_sub(DISPUTE_WINDOW.get() - 1);
 	}
@@ -1755,6 +1884,67 @@ impl MaybeCandidateReceipt {
 	}
 }
 
+/// Complete `evidence` with the available data of the candidate, sign it and send it over `tx`.
+///
+/// The available data is taken from the availability store if present, otherwise it is recovered
+/// from the network. Evidence is still exported if the recovery fails.
+async fn export_dispute_evidence(
+	mut sender: impl overseer::DisputeCoordinatorSenderTrait,
+	keystore: Arc<LocalKeystore>,
+	mut evidence: DisputeEvidence,
+	validator_index: ValidatorIndex,
+	tx: oneshot::Sender<Option<SignedDisputeEvidence>>,
+) {
+	let candidate_hash = evidence.candidate_hash();
+
+	let (av_store_tx, av_store_rx) = oneshot::channel();
+	sender
+		.send_message(AvailabilityStoreMessage::QueryAvailableData(candidate_hash, av_store_tx))
+		.await;
+	evidence.available_data = match av_store_rx.await {
+		Ok(Some(available_data)) => Some(available_data),
+		Ok(None) | Err(oneshot::Canceled) => {
+			let (recovery_tx, recovery_rx) = oneshot::channel();
+			sender
+				.send_message(AvailabilityRecoveryMessage::RecoverAvailableData(
+					evidence.candidate_receipt.clone(),
+					evidence.session_index,
+					None,
+					None,
+					recovery_tx,
+				))
+				.await;
+			match recovery_rx.await {
+				Ok(Ok(available_data)) => Some(available_data),
+				Ok(Err(err)) => {
+					gum::debug!(
+						target: LOG_TARGET,
+						?candidate_hash,
+						?err,
+						"Recovering available data for dispute evidence failed",
+					);
+					None
+				},
+				Err(oneshot::Canceled) => None,
+			}
+		},
+	};
+
+	let keystore = keystore as Arc<_>;
+	let signed_evidence = match SignedDisputeEvidence::sign(&keystore, evidence, validator_index) {
+		Ok(signed_evidence) => signed_evidence,
+		Err(err) => {
+			gum::error!(
+				target: LOG_TARGET,
+				?err,
+				"Encountered keystore error while signing dispute evidence",
+			);
+			None
+		},
+	};
+	let _ = tx.send(signed_evidence);
+}
+
 /// Determine the best block and its block number.
 /// Assumes `block_descriptions` are sorted from the one
 /// with the lowest `BlockNumber` to the highest.
@@ -0,0 +1,76 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only access to the data of the dispute coordinator, for the offline inspection of a
+//! database. Nothing in here is used by the subsystem itself.
+
+use codec::Decode;
+
+use polkadot_node_primitives::CandidateVotes;
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{CandidateHash, SessionIndex};
+
+use crate::{
+	db::v1::{
+		self, candidate_votes_key, candidate_votes_session_prefix, load_decode,
+		EARLIEST_SESSION_KEY, RECENT_DISPUTES_KEY,
+	},
+	Config,
+};
+
+pub use crate::db::v1::{Error, RecentDisputes, Result};
+
+/// Load the earliest session the dispute coordinator keeps votes for.
+pub fn load_earliest_session(db: &dyn Database, config: &Config) -> Result<Option<SessionIndex>> {
+	load_decode(db, config.col_dispute_data, EARLIEST_SESSION_KEY)
+}
+
+/// Load all disputes which have not been pruned yet, along with their status.
+pub fn load_recent_disputes(db: &dyn Database, config: &Config) -> Result<RecentDisputes> {
+	Ok(load_decode(db, config.col_dispute_data, RECENT_DISPUTES_KEY)?.unwrap_or_default())
+}
+
+/// Load the votes on a candidate in the given session.
+pub fn load_candidate_votes(
+	db: &dyn Database,
+	config: &Config,
+	session: SessionIndex,
+	candidate_hash: &CandidateHash,
+) -> Result<Option<CandidateVotes>> {
+	Ok(load_decode::<v1::CandidateVotes>(
+		db,
+		config.col_dispute_data,
+		&candidate_votes_key(session, candidate_hash),
+	)?
+	.map(Into::into))
+}
+
+/// Load the votes on all candidates in the given session, ordered by candidate hash.
+pub fn load_session_votes(
+	db: &dyn Database,
+	config: &Config,
+	session: SessionIndex,
+) -> Result<Vec<(CandidateHash, CandidateVotes)>> {
+	let prefix = candidate_votes_session_prefix(session);
+	db.iter_with_prefix(config.col_dispute_data, &prefix)
+		.map(|r| {
+			let (key, value) = r?;
+			let candidate_hash = CandidateHash::decode(&mut &key[prefix.len()..])?;
+			let votes = v1::CandidateVotes::decode(&mut &value[..])?;
+			Ok((candidate_hash, votes.into()))
+		})
+		.collect()
+}
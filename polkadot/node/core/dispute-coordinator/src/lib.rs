@@ -69,6 +69,9 @@ pub(crate) mod backend;
 pub(crate) mod db;
 pub(crate) mod error;
 
+/// Read-only access to the database, for offline inspection.
+pub mod inspect;
+
 /// Subsystem after receiving the first active leaf.
 mod initialized;
 use initialized::{InitialData, Initialized};
@@ -29,13 +29,15 @@ use futures::{
 use polkadot_node_subsystem_util::database::Database;
 
 use polkadot_node_primitives::{
-	DisputeMessage, DisputeStatus, SignedDisputeStatement, SignedFullStatement, Statement,
-	DISPUTE_WINDOW,
+	DisputeEvidenceCheckError, DisputeMessage, DisputeStatus, LocalParticipation,
+	SignedDisputeStatement, SignedFullStatement, Statement, DISPUTE_WINDOW,
 };
 use polkadot_node_subsystem::{
+	errors::RecoveryError,
 	messages::{
-		ApprovalVotingParallelMessage, ChainApiMessage, ChainSelectionMessage,
-		DisputeCoordinatorMessage, DisputeDistributionMessage, ImportStatementsResult,
+		ApprovalVotingParallelMessage, AvailabilityRecoveryMessage, AvailabilityStoreMessage,
+		ChainApiMessage, ChainSelectionMessage, DisputeCoordinatorMessage,
+		DisputeDistributionMessage, ImportStatementsResult,
 	},
 	overseer::FromOrchestra,
 	OverseerSignal,
@@ -2671,6 +2673,138 @@ fn issue_local_statement_does_cause_distribution_but_not_duplicate_participation
 	});
 }
 
+#[test]
+fn dispute_evidence_can_be_exported() {
+	test_harness(|mut test_state, mut virtual_overseer| {
+		Box::pin(async move {
+			let session = 1;
+
+			test_state.handle_resume_sync(&mut virtual_overseer, session).await;
+
+			let candidate_receipt = make_valid_candidate_receipt();
+			let candidate_hash = candidate_receipt.hash();
+
+			test_state
+				.activate_leaf_at_session(&mut virtual_overseer, session, 1, Vec::new())
+				.await;
+
+			// No dispute yet:
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::QueryDisputeEvidence(candidate_hash, tx),
+				})
+				.await;
+			assert!(rx.await.unwrap().is_none());
+
+			let invalid_vote = test_state.issue_explicit_statement_with_index(
+				ValidatorIndex(1),
+				candidate_hash,
+				session,
+				false,
+			);
+
+			let (pending_confirmation, confirmation_rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::ImportStatements {
+						candidate_receipt: candidate_receipt.clone(),
+						session,
+						statements: vec![(invalid_vote, ValidatorIndex(1))],
+						pending_confirmation: Some(pending_confirmation),
+					},
+				})
+				.await;
+
+			handle_disabled_validators_queries(&mut virtual_overseer, Vec::new()).await;
+			assert_eq!(confirmation_rx.await, Ok(ImportStatementsResult::ValidImport));
+
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::IssueLocalStatement(
+						session,
+						candidate_hash,
+						candidate_receipt.clone(),
+						true,
+					),
+				})
+				.await;
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::DisputeDistribution(DisputeDistributionMessage::SendDispute(_))
+			);
+			handle_approval_vote_request(&mut virtual_overseer, &candidate_hash, HashMap::new())
+				.await;
+
+			let (tx, rx) = oneshot::channel();
+			virtual_overseer
+				.send(FromOrchestra::Communication {
+					msg: DisputeCoordinatorMessage::QueryDisputeEvidence(candidate_hash, tx),
+				})
+				.await;
+
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::AvailabilityStore(AvailabilityStoreMessage::QueryAvailableData(
+					hash,
+					tx,
+				)) => {
+					assert_eq!(hash, candidate_hash);
+					tx.send(None).unwrap();
+				}
+			);
+			assert_matches!(
+				overseer_recv(&mut virtual_overseer).await,
+				AllMessages::AvailabilityRecovery(AvailabilityRecoveryMessage::RecoverAvailableData(
+					receipt,
+					recovery_session,
+					_,
+					_,
+					tx,
+				)) => {
+					assert_eq!(receipt, candidate_receipt);
+					assert_eq!(recovery_session, session);
+					tx.send(Err(RecoveryError::Unavailable)).unwrap();
+				}
+			);
+
+			let mut signed_evidence = rx.await.unwrap().expect("We are a validator in the session");
+			let validators = test_state.validator_public.to_vec();
+			assert_eq!(signed_evidence.check(&validators), Ok(()));
+
+			let evidence = &signed_evidence.evidence;
+			assert_eq!(evidence.candidate_hash(), candidate_hash);
+			assert_eq!(evidence.session_index, session);
+			assert_eq!(evidence.local_participation, LocalParticipation::Valid);
+			assert_eq!(evidence.valid_votes.len(), 1);
+			assert_eq!(evidence.invalid_votes.len(), 1);
+			assert_eq!(evidence.invalid_votes[0].validator_index, ValidatorIndex(1));
+			assert!(evidence.available_data.is_none());
+
+			// Tampering with the evidence is detected:
+			signed_evidence.evidence.local_participation = LocalParticipation::Invalid;
+			assert_eq!(
+				signed_evidence.check(&validators),
+				Err(DisputeEvidenceCheckError::InvalidSignature)
+			);
+
+			// A forged validator set is rejected:
+			let mut forged_validators = validators.clone();
+			forged_validators.swap(0, 1);
+			assert_eq!(
+				signed_evidence.check(&forged_validators),
+				Err(DisputeEvidenceCheckError::SessionMismatch)
+			);
+
+			virtual_overseer.send(FromOrchestra::Signal(OverseerSignal::Conclude)).await;
+			assert!(virtual_overseer.try_recv().await.is_none());
+
+			test_state
+		})
+	});
+}
+
 #[test]
 fn participation_with_onchain_disabling_unconfirmed() {
 	test_harness(|mut test_state, mut virtual_overseer| {
@@ -130,14 +130,18 @@ use std::{
 };
 
 use super::LOG_TARGET;
+use polkadot_node_primitives::{
+	ExclusionReason, FragmentChainIntrospection, IntrospectedCandidate,
+};
 use polkadot_node_subsystem::messages::{Ancestors, BackableCandidateRef};
 use polkadot_node_subsystem_util::inclusion_emulator::{
 	self, validate_commitments, ConstraintModifications, Constraints, Fragment,
 	ProspectiveCandidate, RelayChainBlockInfo as RelayParentInfo,
 };
 use polkadot_primitives::{
-	BlockNumber, CandidateHash, CommittedCandidateReceiptV2 as CommittedCandidateReceipt, Hash,
-	HeadData, Id as ParaId, PersistedValidationData,
+	async_backing::Constraints as PrimitiveConstraints, BlockNumber, CandidateHash,
+	CommittedCandidateReceiptV2 as CommittedCandidateReceipt, Hash, HeadData, Id as ParaId,
+	PersistedValidationData,
 };
 use thiserror::Error;
 
@@ -174,6 +178,8 @@ pub(crate) enum Error {
 	RelayParentNotInScope(Hash),
 	#[error("Scheduling parent {0:?} not in scope")]
 	SchedulingParentNotInScope(Hash),
+	#[error("An ancestor of the candidate was dropped")]
+	AncestorDropped,
 }
 
 impl Error {
@@ -182,6 +188,9 @@ impl Error {
 	}
 }
 
+/// The maximum number of dropped candidates a [`FragmentChain`] remembers, for introspection.
+const MAX_REJECTED_CANDIDATES: usize = 32;
+
 /// The rule for selecting between two backed candidate forks, when adding to the chain.
 /// All validators should adhere to this rule, in order to not lose out on rewards in case of
 /// forking parachains.
@@ -690,6 +699,10 @@ pub(crate) struct FragmentChain {
 	// but may become in the future. These can form any tree shape as well as contain any
 	// unconnected candidates for which we don't know the parent.
 	unconnected: CandidateStorage,
+
+	// The most recently dropped candidates and the reason they were dropped, oldest first. Only
+	// kept for introspection.
+	rejected: VecDeque<(CandidateHash, Error)>,
 }
 
 impl FragmentChain {
@@ -700,8 +713,12 @@ impl FragmentChain {
 		scope: Scope,
 		mut candidates_pending_availability: CandidateStorage,
 	) -> Self {
-		let mut fragment_chain =
-			Self { scope, chain: BackedChain::default(), unconnected: CandidateStorage::default() };
+		let mut fragment_chain = Self {
+			scope,
+			chain: BackedChain::default(),
+			unconnected: CandidateStorage::default(),
+			rejected: VecDeque::new(),
+		};
 
 		// We only need to populate the backable chain. Candidates pending availability must
 		// form a chain with the latest included head.
@@ -718,6 +735,7 @@ impl FragmentChain {
 		prev_fragment_chain: &FragmentChain,
 	) {
 		let mut prev_storage = prev_fragment_chain.unconnected.clone();
+		self.rejected = prev_fragment_chain.rejected.clone();
 
 		for candidate in prev_fragment_chain.chain.chain.iter() {
 			// If they used to be pending availability, don't add them. This is fine
@@ -885,7 +903,14 @@ impl FragmentChain {
 			return Err(Error::IntroduceBackedCandidate);
 		}
 
-		self.can_add_candidate_as_potential(scheduling_scope, candidate)?;
+		match self.can_add_candidate_as_potential(scheduling_scope, candidate) {
+			Ok(()) => {},
+			Err(Error::CandidateAlreadyKnown) => return Err(Error::CandidateAlreadyKnown),
+			Err(e) => {
+				self.note_rejected(candidate.candidate_hash, e.clone());
+				return Err(e);
+			},
+		}
 
 		// This clone is cheap, as it uses an Arc for the expensive stuff.
 		// We can't consume the candidate because other fragment chains may use it also.
@@ -970,6 +995,99 @@ impl FragmentChain {
 		res
 	}
 
+	/// Remember that a candidate was dropped from this chain, for introspection. Only the most
+	/// recent [`MAX_REJECTED_CANDIDATES`] are kept.
+	pub fn note_rejected(&mut self, candidate_hash: CandidateHash, reason: Error) {
+		self.rejected.retain(|(hash, _)| hash != &candidate_hash);
+		if self.rejected.len() >= MAX_REJECTED_CANDIDATES {
+			self.rejected.pop_front();
+		}
+		self.rejected.push_back((candidate_hash, reason));
+	}
+
+	/// Take a snapshot of this chain, explaining why each known candidate is not part of the
+	/// backable chain.
+	pub fn introspect(&self, leaf: Hash, para_id: ParaId) -> FragmentChainIntrospection {
+		let chain = self
+			.chain
+			.chain
+			.iter()
+			.map(|node| IntrospectedCandidate {
+				candidate_hash: node.candidate_hash,
+				relay_parent: node.relay_parent(),
+				scheduling_parent: node.scheduling_parent,
+				parent_head_data_hash: node.parent_head_data_hash,
+				output_head_data_hash: node.output_head_data_hash,
+				backed: true,
+				pending_availability: self
+					.scope
+					.get_pending_availability(&node.candidate_hash)
+					.is_some(),
+			})
+			.collect();
+
+		let unconnected = self
+			.unconnected
+			.candidates()
+			.map(|entry| {
+				let candidate = IntrospectedCandidate {
+					candidate_hash: entry.candidate_hash,
+					relay_parent: entry.relay_parent,
+					scheduling_parent: entry.scheduling_parent,
+					parent_head_data_hash: entry.parent_head_data_hash,
+					output_head_data_hash: entry.output_head_data_hash,
+					backed: entry.state == CandidateState::Backed,
+					pending_availability: false,
+				};
+				(candidate, self.exclusion_reason(entry))
+			})
+			.collect();
+
+		// A candidate dropped at some point may have been introduced again since.
+		let rejected = self
+			.rejected
+			.iter()
+			.filter(|(hash, _)| !self.chain.contains(hash) && !self.unconnected.contains(hash))
+			.map(|(hash, reason)| (*hash, ExclusionReason::Rejected(reason.to_string())))
+			.collect();
+
+		FragmentChainIntrospection {
+			leaf,
+			para_id,
+			constraints: PrimitiveConstraints::from(&self.scope.base_constraints),
+			chain,
+			unconnected,
+			rejected,
+		}
+	}
+
+	// Why an unconnected candidate is not part of the backable chain.
+	fn exclusion_reason(&self, candidate: &CandidateEntry) -> ExclusionReason {
+		if candidate.state != CandidateState::Backed {
+			return ExclusionReason::NotBacked;
+		}
+
+		let chain_head = self
+			.chain
+			.chain
+			.last()
+			.map(|node| node.output_head_data_hash)
+			.unwrap_or_else(|| self.scope.base_constraints.required_parent.hash());
+
+		if candidate.parent_head_data_hash == chain_head {
+			if self.chain.chain.len() >= self.scope.max_backable_len {
+				ExclusionReason::ChainFull
+			} else {
+				ExclusionReason::NotSelected
+			}
+		} else if let Some(other) = self.chain.by_parent_head.get(&candidate.parent_head_data_hash)
+		{
+			ExclusionReason::Fork(*other)
+		} else {
+			ExclusionReason::UnknownParent
+		}
+	}
+
 	// Tries to orders the ancestors into a viable path from root to the last one.
 	// Stops when the ancestors are all used or when a node in the chain is not present in the
 	// ancestor set. Returns the index in the chain were the search stopped.
@@ -1038,6 +1156,7 @@ impl FragmentChain {
 					} else {
 						gum::trace!(target: LOG_TARGET, msg);
 					};
+					self.note_rejected(candidate.candidate_hash, e);
 				},
 			};
 		}
@@ -1225,7 +1344,7 @@ impl FragmentChain {
 	// If `starting_point` is `Some()`, start the search from the candidates having this parent head
 	// hash.
 	fn trim_uneligible_forks(
-		&self,
+		&mut self,
 		scheduling_scope: &SchedulingScope,
 		storage: &mut CandidateStorage,
 		starting_point: Option<Hash>,
@@ -1264,11 +1383,11 @@ impl FragmentChain {
 
 				// Only keep a candidate if its full ancestry was already kept as potential and this
 				// candidate itself has potential.
-				let mut keep = false;
+				let mut rejection = Some(Error::AncestorDropped);
 				if parent_has_potential {
 					match self.check_potential(scheduling_scope, child) {
 						Ok(()) => {
-							keep = true;
+							rejection = None;
 						},
 						Err(e) => {
 							gum::debug!(
@@ -1279,23 +1398,25 @@ impl FragmentChain {
 								err = ?e,
 								"check_potential failed for candidate"
 							);
+							rejection = Some(e);
 						},
 					}
 				}
 
-				if keep {
-					queue.push_back((child.output_head_data_hash, true));
-				} else {
+				if let Some(rejection) = rejection {
 					// Otherwise, remove this candidate and continue looping for its children, but
 					// mark the parent's potential as `false`. We only want to remove its
 					// children.
-					to_remove.push(*child_hash);
+					to_remove.push((*child_hash, rejection));
 					queue.push_back((child.output_head_data_hash, false));
+				} else {
+					queue.push_back((child.output_head_data_hash, true));
 				}
 			}
 
-			for hash in to_remove {
+			for (hash, rejection) in to_remove {
 				storage.remove_candidate(&hash);
+				self.note_rejected(hash, rejection);
 			}
 		}
 	}
@@ -1813,3 +1813,82 @@ fn test_relay_parent_below_min_relay_parent_number_rejected() {
 		Err(Error::RelayParentNotInScope(hash)) if hash == old_relay_parent
 	);
 }
+
+#[test]
+fn test_introspect_explains_exclusions() {
+	let para_id = ParaId::from(5u32);
+	let relay_parent = Hash::repeat_byte(1);
+	let required_parent: HeadData = vec![0xff].into();
+
+	let relay_parent_info =
+		RelayChainBlockInfo { number: 1, hash: relay_parent, storage_root: Hash::zero() };
+	let base_constraints = make_constraints(1, vec![1], required_parent.clone());
+	let (scheduling_scope, scope) =
+		make_scope(relay_parent_info, base_constraints.clone(), vec![], 2, vec![]);
+	let mut chain = FragmentChain::init(&scheduling_scope, scope, CandidateStorage::default());
+
+	let make_entry = |relay_parent_number, parent_head: HeadData, para_head: HeadData| {
+		let (pvd, candidate) = CandidateBuilder::new(para_id, relay_parent)
+			.relay_parent_number(relay_parent_number)
+			.parent_head(parent_head)
+			.para_head(para_head)
+			.hrmp_watermark(relay_parent_number)
+			.build();
+		CandidateEntry::new_seconded(candidate.hash(), candidate, pvd).unwrap()
+	};
+
+	// A <- B <- C build on the latest included head, D has an unknown parent and E is only
+	// seconded.
+	let candidate_a = make_entry(1, required_parent.clone(), vec![0xa].into());
+	let candidate_b = make_entry(1, vec![0xa].into(), vec![0xb].into());
+	let candidate_c = make_entry(1, vec![0xb].into(), vec![0xc].into());
+	let candidate_d = make_entry(1, vec![0xd].into(), vec![0xe].into());
+	let candidate_e = make_entry(1, vec![0xc].into(), vec![0xf].into());
+	for candidate in [&candidate_a, &candidate_b, &candidate_c, &candidate_d, &candidate_e] {
+		chain.try_adding_seconded_candidate(&scheduling_scope, candidate).unwrap();
+	}
+	for candidate in [&candidate_a, &candidate_b, &candidate_c, &candidate_d] {
+		chain.candidate_backed(&scheduling_scope, &candidate.hash());
+	}
+
+	// The relay parent of this one is out of scope.
+	let candidate_old = make_entry(0, vec![0xc].into(), vec![0x1].into());
+	assert_matches!(
+		chain.try_adding_seconded_candidate(&scheduling_scope, &candidate_old),
+		Err(Error::RelayParentNotInScope(_))
+	);
+
+	let introspection = chain.introspect(relay_parent, para_id);
+	assert_eq!(introspection.leaf, relay_parent);
+	assert_eq!(introspection.para_id, para_id);
+	assert_eq!(Constraints::from(introspection.constraints), base_constraints);
+	assert_eq!(
+		introspection
+			.chain
+			.iter()
+			.map(|candidate| (candidate.candidate_hash, candidate.backed))
+			.collect::<Vec<_>>(),
+		vec![(candidate_a.hash(), true), (candidate_b.hash(), true)]
+	);
+
+	let unconnected = introspection
+		.unconnected
+		.into_iter()
+		.map(|(candidate, reason)| (candidate.candidate_hash, reason))
+		.collect::<HashMap<_, _>>();
+	assert_eq!(
+		unconnected,
+		[
+			(candidate_c.hash(), ExclusionReason::ChainFull),
+			(candidate_d.hash(), ExclusionReason::UnknownParent),
+			(candidate_e.hash(), ExclusionReason::NotBacked),
+		]
+		.into_iter()
+		.collect()
+	);
+
+	assert_matches!(
+		introspection.rejected.as_slice(),
+		[(hash, ExclusionReason::Rejected(_))] if *hash == candidate_old.hash()
+	);
+}
@@ -40,6 +40,7 @@ use std::collections::{BTreeSet, HashMap, HashSet};
 use fragment_chain::CandidateStorage;
 use futures::{channel::oneshot, prelude::*};
 
+use polkadot_node_primitives::FragmentChainIntrospection;
 use polkadot_node_subsystem::{
 	messages::{
 		Ancestors, BackableCandidateRef, ChainApiMessage, HypotheticalCandidate,
@@ -211,6 +212,9 @@ async fn run_iteration<Context>(
 				ProspectiveParachainsMessage::GetProspectiveValidationData(request, tx) => {
 					answer_prospective_validation_data_request(ctx, view, request, tx).await
 				},
+				ProspectiveParachainsMessage::IntrospectFragmentChains(para, tx) => {
+					answer_introspect_fragment_chains(&view, para, tx)
+				},
 			},
 		}
 	}
@@ -677,6 +681,10 @@ async fn handle_introduce_seconded_candidate<Context>(
 				"Cannot introduce seconded candidate: {}",
 				err
 			);
+			chain.note_rejected(
+				candidate_hash,
+				FragmentChainError::RelayParentNotInScope(relay_parent),
+			);
 			continue;
 		}
 
@@ -896,6 +904,25 @@ fn answer_get_backable_candidates(
 	let _ = tx.send(backable_candidates);
 }
 
+fn answer_introspect_fragment_chains(
+	view: &View,
+	para: ParaId,
+	tx: oneshot::Sender<Vec<FragmentChainIntrospection>>,
+) {
+	let introspections = view
+		.active_leaves
+		.iter()
+		.filter_map(|leaf| {
+			view.per_scheduling_parent
+				.get(leaf)
+				.and_then(|data| data.fragment_chains.get(&para))
+				.map(|chain| chain.introspect(*leaf, para))
+		})
+		.collect();
+
+	let _ = tx.send(introspections);
+}
+
 #[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
 async fn answer_hypothetical_membership_request<Context>(
 	ctx: &mut Context,
@@ -17,6 +17,7 @@
 use super::*;
 use assert_matches::assert_matches;
 use futures::FutureExt;
+use polkadot_node_primitives::ExclusionReason;
 use polkadot_node_subsystem::{
 	messages::{
 		AllMessages, HypotheticalMembershipRequest, ParentHeadData, ProspectiveParachainsMessage,
@@ -1742,6 +1743,95 @@ fn unconnected_candidates_become_connected() {
 	assert_eq!(view.active_leaves.len(), 1);
 }
 
+#[test]
+fn introspect_fragment_chains() {
+	let test_state = TestState::default();
+
+	let view = test_harness(|mut virtual_overseer| async move {
+		let leaf_a = TestLeaf {
+			number: 100,
+			hash: Hash::from_low_u64_be(130),
+			para_data: vec![
+				(1.into(), PerParaData::new(HeadData(vec![1, 2, 3]))),
+				(2.into(), PerParaData::new(HeadData(vec![2, 3, 4]))),
+			],
+		};
+		activate_leaf(&mut virtual_overseer, &leaf_a, &test_state).await;
+
+		// Candidate A builds on the included head, candidate B on a head which is not known yet.
+		let (candidate_a, pvd_a) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![1, 2, 3]),
+			HeadData(vec![1]),
+			test_state.validation_code_hash,
+		);
+		let (candidate_b, pvd_b) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![2]),
+			HeadData(vec![3]),
+			test_state.validation_code_hash,
+		);
+		introduce_seconded_candidate(
+			&mut virtual_overseer,
+			&test_state,
+			candidate_a.clone(),
+			pvd_a,
+		)
+		.await;
+		introduce_seconded_candidate(
+			&mut virtual_overseer,
+			&test_state,
+			candidate_b.clone(),
+			pvd_b,
+		)
+		.await;
+		back_candidate(&mut virtual_overseer, &candidate_a, candidate_a.hash()).await;
+
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(overseer::FromOrchestra::Communication {
+				msg: ProspectiveParachainsMessage::IntrospectFragmentChains(1.into(), tx),
+			})
+			.await;
+		let introspections = rx.await.unwrap();
+		assert_eq!(introspections.len(), 1);
+
+		let introspection = &introspections[0];
+		assert_eq!(introspection.leaf, leaf_a.hash);
+		assert_eq!(introspection.constraints.required_parent, HeadData(vec![1, 2, 3]));
+		assert_eq!(
+			introspection
+				.chain
+				.iter()
+				.map(|candidate| candidate.candidate_hash)
+				.collect::<Vec<_>>(),
+			vec![candidate_a.hash()]
+		);
+		assert_matches!(
+			introspection.unconnected.as_slice(),
+			[(candidate, ExclusionReason::NotBacked)]
+				if candidate.candidate_hash == candidate_b.hash()
+		);
+
+		// Para 3 is not scheduled.
+		let (tx, rx) = oneshot::channel();
+		virtual_overseer
+			.send(overseer::FromOrchestra::Communication {
+				msg: ProspectiveParachainsMessage::IntrospectFragmentChains(3.into(), tx),
+			})
+			.await;
+		assert!(rx.await.unwrap().is_empty());
+
+		virtual_overseer
+	});
+
+	assert_eq!(view.active_leaves.len(), 1);
+}
+
 // Backs some candidates and tests `GetBackableCandidates` when requesting a single candidate.
 #[test]
 fn check_backable_query_single_candidate() {
@@ -205,6 +205,17 @@ impl Artifacts {
 		Self { inner: HashMap::new() }
 	}
 
+	/// Returns the total size in bytes of the prepared artifacts.
+	pub(crate) fn prepared_size(&self) -> u64 {
+		self.inner
+			.values()
+			.map(|state| match state {
+				ArtifactState::Prepared { size, .. } => *size,
+				_ => 0,
+			})
+			.sum()
+	}
+
 	#[cfg(test)]
 	fn len(&self) -> usize {
 		self.inner.len()
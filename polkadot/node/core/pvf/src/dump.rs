@@ -0,0 +1,194 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Self-contained bundles of failed candidate validations.
+//!
+//! A [`ValidationDump`] contains everything that is needed to re-run a validation through the
+//! prepare and execute workers: the validation code, the PoV, the persisted validation data, the
+//! executor parameters and the timeouts that were used by the node. Dumps are written by the
+//! candidate validation subsystem if a dump directory is configured, and can be replayed with the
+//! `pvf-replay` tool.
+
+use codec::{Decode, Encode};
+use polkadot_node_core_pvf_common::execute::ValidationContext;
+use polkadot_primitives::{PvfExecKind, ValidationCode};
+use std::{
+	io,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+/// The version of the dump format, written as the first byte of every dump file.
+const DUMP_VERSION: u8 = 1;
+
+/// The extension of dump files.
+pub const DUMP_EXTENSION: &str = "pvfdump";
+
+/// A failed candidate validation, with all the inputs needed to reproduce it.
+#[derive(Clone, Debug, Encode, Decode)]
+pub struct ValidationDump {
+	/// The candidate, PoV, persisted validation data and executor parameters of the validation,
+	/// along with the execution timeout that was used.
+	pub validation_context: ValidationContext,
+	/// The validation code, as found on chain (i.e. possibly compressed).
+	pub validation_code: ValidationCode,
+	/// The validation code bomb limit used to decompress `validation_code`.
+	pub validation_code_bomb_limit: u32,
+	/// The preparation timeout that was used.
+	pub prep_timeout: Duration,
+	/// Whether the validation was done for backing or for approval/dispute.
+	pub exec_kind: PvfExecKind,
+	/// The error, as reported by the node.
+	pub error: String,
+}
+
+impl ValidationDump {
+	/// The file name of the dump, derived from the candidate hash.
+	pub fn file_name(&self) -> String {
+		format!(
+			"{:?}-{:?}.{}",
+			self.validation_context.candidate_receipt.hash().0,
+			self.exec_kind,
+			DUMP_EXTENSION
+		)
+	}
+
+	/// Write the dump into `dir`, which is created if it doesn't exist.
+	///
+	/// The dump is first written to a temporary file and then moved into place, so that partially
+	/// written dumps are never observed. An existing dump of the same candidate and execution kind
+	/// is kept as is. Returns the path of the dump.
+	pub fn write(&self, dir: &Path) -> io::Result<PathBuf> {
+		std::fs::create_dir_all(dir)?;
+		let path = dir.join(self.file_name());
+		if path.exists() {
+			return Ok(path);
+		}
+
+		let tmp_path = path.with_extension("tmp");
+		std::fs::write(&tmp_path, (DUMP_VERSION, self).encode())?;
+		std::fs::rename(&tmp_path, &path)?;
+		Ok(path)
+	}
+
+	/// Read a dump previously written with [`ValidationDump::write`].
+	pub fn read(path: &Path) -> io::Result<Self> {
+		let bytes = std::fs::read(path)?;
+		let (version, dump) = <(u8, Self)>::decode(&mut &bytes[..])
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+		if version != DUMP_VERSION {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("unsupported dump version {version}, expected {DUMP_VERSION}"),
+			));
+		}
+		Ok(dump)
+	}
+}
+
+/// Remove the oldest dumps in `dir`, so that at most `max_dumps` of them are left.
+///
+/// Files that are not dumps are left alone. Returns the number of removed dumps.
+pub fn prune_dumps(dir: &Path, max_dumps: usize) -> io::Result<usize> {
+	let mut dumps = std::fs::read_dir(dir)?
+		.filter_map(|entry| entry.ok())
+		.filter(|entry| entry.path().extension().is_some_and(|ext| ext == DUMP_EXTENSION))
+		.map(|entry| Ok((entry.metadata()?.modified()?, entry.path())))
+		.collect::<io::Result<Vec<_>>>()?;
+	let excess = dumps.len().saturating_sub(max_dumps);
+	dumps.sort();
+	for (_, path) in &dumps[..excess] {
+		std::fs::remove_file(path)?;
+	}
+	Ok(excess)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_primitives::{BlockData, PoV};
+	use polkadot_primitives::{ExecutorParams, PersistedValidationData};
+	use polkadot_primitives_test_helpers::dummy_candidate_receipt_v2;
+	use sp_core::H256;
+	use std::sync::Arc;
+
+	fn dump() -> ValidationDump {
+		ValidationDump {
+			validation_context: ValidationContext {
+				candidate_receipt: dummy_candidate_receipt_v2(H256::default()),
+				pvd: Arc::new(PersistedValidationData::default()),
+				pov: Arc::new(PoV { block_data: BlockData(vec![1, 2, 3]) }),
+				executor_params: ExecutorParams::default(),
+				exec_timeout: Duration::from_secs(2),
+				v3_seen: false,
+			},
+			validation_code: ValidationCode(vec![4, 5, 6]),
+			validation_code_bomb_limit: 1024,
+			prep_timeout: Duration::from_secs(60),
+			exec_kind: PvfExecKind::Approval,
+			error: "worker reported: panic".into(),
+		}
+	}
+
+	#[test]
+	fn write_and_read_roundtrip() {
+		let dir = tempfile::tempdir().unwrap();
+		let dump = dump();
+
+		let path = dump.write(&dir.path().join("dumps")).unwrap();
+		assert!(path.to_string_lossy().ends_with(".pvfdump"));
+
+		let read = ValidationDump::read(&path).unwrap();
+		assert_eq!(read.encode(), dump.encode());
+
+		// an existing dump is not overwritten.
+		let mut other = dump.clone();
+		other.error = "hard timeout".into();
+		assert_eq!(other.write(&dir.path().join("dumps")).unwrap(), path);
+		assert_eq!(ValidationDump::read(&path).unwrap().error, dump.error);
+	}
+
+	#[test]
+	fn prune_removes_oldest_dumps() {
+		let dir = tempfile::tempdir().unwrap();
+		let now = std::time::SystemTime::now();
+		for (i, name) in ["b", "a", "c"].into_iter().enumerate() {
+			let file = std::fs::File::create(dir.path().join(name).with_extension(DUMP_EXTENSION))
+				.unwrap();
+			file.set_modified(now - Duration::from_secs(10 - i as u64)).unwrap();
+		}
+		std::fs::write(dir.path().join("other"), b"").unwrap();
+
+		assert_eq!(prune_dumps(dir.path(), 1).unwrap(), 2);
+		let mut left = std::fs::read_dir(dir.path())
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name().into_string().unwrap())
+			.collect::<Vec<_>>();
+		left.sort();
+		assert_eq!(left, vec!["c.pvfdump".to_string(), "other".to_string()]);
+
+		assert_eq!(prune_dumps(dir.path(), 1).unwrap(), 0);
+	}
+
+	#[test]
+	fn read_rejects_unknown_version() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("dump.pvfdump");
+		std::fs::write(&path, (DUMP_VERSION + 1, dump()).encode()).unwrap();
+
+		assert_eq!(ValidationDump::read(&path).unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+}
@@ -44,7 +44,7 @@ use polkadot_node_subsystem::{
 use polkadot_parachain_primitives::primitives::ValidationResult;
 use polkadot_primitives::Hash;
 use std::{
-	collections::HashMap,
+	collections::{HashMap, HashSet, VecDeque},
 	path::PathBuf,
 	time::{Duration, SystemTime},
 };
@@ -59,6 +59,13 @@ pub const PREPARE_FAILURE_COOLDOWN: Duration = Duration::from_millis(200);
 /// The amount of times we will retry failed prepare jobs.
 pub const NUM_PREPARE_RETRIES: u32 = 5;
 
+/// The default maximum number of prepare workers busy with pre-warming artifacts.
+pub const DEFAULT_PREWARM_WORKERS_MAX_NUM: usize = 1;
+
+/// The default size of the prepared artifacts on disk, in bytes, above which no more artifacts
+/// are pre-warmed.
+pub const DEFAULT_PREWARM_DISK_LIMIT: u64 = 2 * 1024 * 1024 * 1024;
+
 /// The name of binary spawned to prepare a PVF artifact
 pub const PREPARE_BINARY_NAME: &str = "polkadot-prepare-worker";
 
@@ -142,6 +149,20 @@ impl ValidationHost {
 			.map_err(|_| "the inner loop hung up".to_string())
 	}
 
+	/// Sends a signal to the validation host requesting to prepare the given PVFs in the
+	/// background, because they are likely to be needed soon.
+	///
+	/// Unlike [`Self::heads_up`], the preparation uses only a limited number of workers and stops
+	/// once the prepared artifacts take up too much space on disk.
+	///
+	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
+	pub async fn prewarm(&mut self, pvfs: Vec<PvfPrepData>) -> Result<(), String> {
+		self.to_host_tx
+			.send(ToHost::Prewarm { pvfs })
+			.await
+			.map_err(|_| "the inner loop hung up".to_string())
+	}
+
 	/// Sends a signal to the validation host requesting to update best block.
 	///
 	/// Returns an error if the request cannot be sent to the validation host, i.e. if it shut down.
@@ -181,6 +202,9 @@ enum ToHost {
 	HeadsUp {
 		active_pvfs: Vec<PvfPrepData>,
 	},
+	Prewarm {
+		pvfs: Vec<PvfPrepData>,
+	},
 	UpdateActiveLeaves {
 		update: ActiveLeavesUpdate,
 		ancestors: Vec<Hash>,
@@ -219,6 +243,11 @@ pub struct Config {
 	pub prepare_workers_soft_max_num: usize,
 	/// The absolute number of workers that can be spawned in the prepare pool.
 	pub prepare_workers_hard_max_num: usize,
+	/// The maximum number of prepare workers which can be busy with pre-warming artifacts.
+	pub prewarm_workers_max_num: usize,
+	/// The size of the prepared artifacts on disk, in bytes, above which no more artifacts are
+	/// pre-warmed.
+	pub prewarm_disk_limit: u64,
 
 	/// The path to the program that can be used to spawn the execute workers.
 	pub execute_worker_program_path: PathBuf,
@@ -249,6 +278,8 @@ impl Config {
 			prepare_worker_spawn_timeout: Duration::from_secs(3),
 			prepare_workers_soft_max_num,
 			prepare_workers_hard_max_num,
+			prewarm_workers_max_num: DEFAULT_PREWARM_WORKERS_MAX_NUM,
+			prewarm_disk_limit: DEFAULT_PREWARM_DISK_LIMIT,
 
 			execute_worker_program_path,
 			execute_worker_spawn_timeout: Duration::from_secs(3),
@@ -320,13 +351,14 @@ pub async fn start(
 		metrics.clone(),
 		config.prepare_workers_soft_max_num,
 		config.prepare_workers_hard_max_num,
+		config.prewarm_workers_max_num,
 		config.cache_path.clone(),
 		to_prepare_pool,
 		from_prepare_pool,
 	);
 
 	let (to_execute_queue_tx, from_execute_queue_rx, run_execute_queue) = execute::start(
-		metrics,
+		metrics.clone(),
 		config.execute_worker_program_path.to_owned(),
 		config.cache_path.clone(),
 		config.execute_workers_max_num,
@@ -350,6 +382,8 @@ pub async fn start(
 			from_execute_queue_rx,
 			to_sweeper_tx,
 			awaiting_prepare: AwaitingPrepare::default(),
+			prewarm: Prewarm::new(config.prewarm_workers_max_num, config.prewarm_disk_limit),
+			metrics,
 		})
 		.await
 	};
@@ -383,6 +417,102 @@ impl AwaitingPrepare {
 	}
 }
 
+/// Bookkeeping of the pre-warming of artifacts.
+///
+/// The PVFs to pre-warm wait here and are only handed to the prepare queue a few at a time. The
+/// size of the artifacts on disk is unknown before they are prepared, so the disk limit is checked
+/// again each time a PVF leaves the pending queue, which bounds the overshoot to the number of
+/// jobs in flight.
+struct Prewarm {
+	/// The maximum number of pre-warm jobs handed to the prepare queue at any time.
+	max_in_flight: usize,
+	/// No more artifacts are pre-warmed while the prepared ones take up more space on disk.
+	disk_limit: u64,
+	/// The PVFs waiting to be handed to the prepare queue.
+	pending: VecDeque<PvfPrepData>,
+	/// The artifacts which are enqueued for preparation in the background.
+	background: HashSet<ArtifactId>,
+	/// The artifacts which were prepared in the background, pruned together with the artifacts.
+	prewarmed: HashSet<ArtifactId>,
+	/// The artifacts which were needed for execution, pruned together with the artifacts.
+	executed: HashSet<ArtifactId>,
+}
+
+impl Prewarm {
+	fn new(max_in_flight: usize, disk_limit: u64) -> Self {
+		Self {
+			max_in_flight,
+			disk_limit,
+			pending: VecDeque::new(),
+			background: HashSet::new(),
+			prewarmed: HashSet::new(),
+			executed: HashSet::new(),
+		}
+	}
+
+	/// Hands pending PVFs to the prepare queue, as long as there is room for more jobs in flight
+	/// and the prepared artifacts don't exceed the disk limit.
+	async fn dispatch(
+		&mut self,
+		artifacts: &mut Artifacts,
+		prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
+		metrics: &Metrics,
+	) -> Result<(), Fatal> {
+		while self.background.len() < self.max_in_flight {
+			let Some(pvf) = self.pending.pop_front() else { break };
+			let artifact_id = ArtifactId::from_pvf_prep_data(&pvf);
+			// The artifact could have been requested in the meantime.
+			if artifacts.artifact_state_mut(&artifact_id).is_some() {
+				metrics.on_prewarm_request("known");
+				continue;
+			}
+
+			if artifacts.prepared_size() >= self.disk_limit {
+				gum::debug!(
+					target: LOG_TARGET,
+					disk_limit = self.disk_limit,
+					n_dropped = self.pending.len() + 1,
+					"Not pre-warming PVFs, the disk limit is reached",
+				);
+				for _ in 0..=self.pending.len() {
+					metrics.on_prewarm_request("disk_limit");
+				}
+				self.pending.clear();
+				break;
+			}
+
+			metrics.on_prewarm_request("enqueued");
+			artifacts.insert_preparing(artifact_id.clone(), Vec::new());
+			self.background.insert(artifact_id);
+			send_prepare(
+				prepare_queue,
+				prepare::ToQueue::Enqueue { priority: Priority::Background, pvf },
+			)
+			.await?;
+		}
+
+		Ok(())
+	}
+
+	/// Raises the priority of the preparation of the artifact, if it is pre-warmed in the
+	/// background, because it is needed now.
+	async fn amend(
+		&self,
+		prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
+		priority: Priority,
+		artifact_id: &ArtifactId,
+	) -> Result<(), Fatal> {
+		if !self.background.contains(artifact_id) {
+			return Ok(());
+		}
+		send_prepare(
+			prepare_queue,
+			prepare::ToQueue::Amend { priority, artifact_id: artifact_id.clone() },
+		)
+		.await
+	}
+}
+
 struct Inner {
 	cleanup_pulse_interval: Duration,
 	cleanup_config: ArtifactsCleanupConfig,
@@ -399,6 +529,8 @@ struct Inner {
 	to_sweeper_tx: mpsc::Sender<PathBuf>,
 
 	awaiting_prepare: AwaitingPrepare,
+	prewarm: Prewarm,
+	metrics: Metrics,
 }
 
 #[derive(Debug)]
@@ -416,6 +548,8 @@ async fn run(
 		mut to_execute_queue_tx,
 		mut to_sweeper_tx,
 		mut awaiting_prepare,
+		mut prewarm,
+		metrics,
 	}: Inner,
 ) {
 	macro_rules! break_if_fatal {
@@ -465,6 +599,8 @@ async fn run(
 					&mut artifacts,
 					&cleanup_config,
 				).await);
+				prewarm.prewarmed.retain(|artifact_id| artifacts.artifact_state_mut(artifact_id).is_some());
+				prewarm.executed.retain(|artifact_id| artifacts.artifact_state_mut(artifact_id).is_some());
 			},
 			to_host = to_host_rx.next() => {
 				let to_host = match to_host {
@@ -483,12 +619,18 @@ async fn run(
 					&mut to_prepare_queue_tx,
 					&mut to_execute_queue_tx,
 					&mut awaiting_prepare,
+					&mut prewarm,
+					&metrics,
 					to_host,
 				)
 				.await);
 			},
 			from_prepare_queue = from_prepare_queue_rx.next() => {
 				let from_queue = break_if_fatal!(from_prepare_queue.ok_or(Fatal));
+				let prewarmed = prewarm.background.remove(&from_queue.artifact_id);
+				if prewarmed {
+					prewarm.prewarmed.insert(from_queue.artifact_id.clone());
+				}
 
 				// Note that the preparation outcome is always reported as concluded.
 				//
@@ -505,6 +647,14 @@ async fn run(
 					&mut awaiting_prepare,
 					from_queue,
 				).await);
+
+				if prewarmed {
+					break_if_fatal!(prewarm.dispatch(
+						&mut artifacts,
+						&mut to_prepare_queue_tx,
+						&metrics,
+					).await);
+				}
 			},
 		}
 	}
@@ -515,18 +665,31 @@ async fn handle_to_host(
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
 	execute_queue: &mut mpsc::Sender<execute::ToQueue>,
 	awaiting_prepare: &mut AwaitingPrepare,
+	prewarm: &mut Prewarm,
+	metrics: &Metrics,
 	to_host: ToHost,
 ) -> Result<(), Fatal> {
 	match to_host {
 		ToHost::PrecheckPvf { pvf, result_tx } => {
-			handle_precheck_pvf(artifacts, prepare_queue, pvf, result_tx).await?;
+			handle_precheck_pvf(artifacts, prepare_queue, prewarm, pvf, result_tx).await?;
 		},
 		ToHost::ExecutePvf(inputs) => {
-			handle_execute_pvf(artifacts, prepare_queue, execute_queue, awaiting_prepare, inputs)
-				.await?;
+			note_execution(artifacts, prewarm, metrics, &inputs.pvf);
+			handle_execute_pvf(
+				artifacts,
+				prepare_queue,
+				execute_queue,
+				awaiting_prepare,
+				prewarm,
+				inputs,
+			)
+			.await?;
 		},
 		ToHost::HeadsUp { active_pvfs } => {
-			handle_heads_up(artifacts, prepare_queue, active_pvfs).await?
+			handle_heads_up(artifacts, prepare_queue, prewarm, active_pvfs).await?
+		},
+		ToHost::Prewarm { pvfs } => {
+			handle_prewarm(artifacts, prepare_queue, prewarm, metrics, pvfs).await?
 		},
 		ToHost::UpdateActiveLeaves { update, ancestors } => {
 			handle_update_active_leaves(execute_queue, update, ancestors).await?
@@ -550,6 +713,7 @@ async fn handle_to_host(
 async fn handle_precheck_pvf(
 	artifacts: &mut Artifacts,
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
+	prewarm: &Prewarm,
 	pvf: PvfPrepData,
 	result_sender: PrecheckResultSender,
 ) -> Result<(), Fatal> {
@@ -562,7 +726,8 @@ async fn handle_precheck_pvf(
 				let _ = result_sender.send(Ok(()));
 			},
 			ArtifactState::Preparing { waiting_for_response, num_failures: _ } => {
-				waiting_for_response.push(result_sender)
+				waiting_for_response.push(result_sender);
+				prewarm.amend(prepare_queue, Priority::Normal, &artifact_id).await?;
 			},
 			ArtifactState::FailedToProcess { error, .. } => {
 				// Do not retry an artifact that previously failed preparation.
@@ -594,6 +759,7 @@ async fn handle_execute_pvf(
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
 	execute_queue: &mut mpsc::Sender<execute::ToQueue>,
 	awaiting_prepare: &mut AwaitingPrepare,
+	prewarm: &Prewarm,
 	inputs: ExecutePvfInputs,
 ) -> Result<(), Fatal> {
 	let ExecutePvfInputs { pvf, validation_context, priority, exec_kind, result_tx } = inputs;
@@ -653,6 +819,8 @@ async fn handle_execute_pvf(
 				}
 			},
 			ArtifactState::Preparing { .. } => {
+				// The artifact might be pre-warmed in the background, but now it is needed.
+				prewarm.amend(prepare_queue, priority, &artifact_id).await?;
 				awaiting_prepare.add(
 					artifact_id,
 					PendingExecutionRequest {
@@ -721,6 +889,7 @@ async fn handle_execute_pvf(
 async fn handle_heads_up(
 	artifacts: &mut Artifacts,
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
+	prewarm: &Prewarm,
 	active_pvfs: Vec<PvfPrepData>,
 ) -> Result<(), Fatal> {
 	let now = SystemTime::now();
@@ -733,7 +902,8 @@ async fn handle_heads_up(
 					*last_time_needed = now;
 				},
 				ArtifactState::Preparing { .. } => {
-					// The artifact is already being prepared, so we don't need to do anything.
+					// The artifact is already being prepared, possibly in the background.
+					prewarm.amend(prepare_queue, Priority::Normal, &artifact_id).await?;
 				},
 				ArtifactState::FailedToProcess { last_time_failed, num_failures, error } => {
 					if can_retry_prepare_after_failure(*last_time_failed, *num_failures, error) {
@@ -779,6 +949,58 @@ async fn handle_heads_up(
 	Ok(())
 }
 
+/// Handles a request to pre-warm artifacts.
+///
+/// Only unknown artifacts are enqueued, with [`Priority::Background`], and only as long as the
+/// prepared artifacts take up less space on disk than the pre-warm limit. Artifacts which failed
+/// before are left to the retry logic of the regular requests.
+async fn handle_prewarm(
+	artifacts: &mut Artifacts,
+	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
+	prewarm: &mut Prewarm,
+	metrics: &Metrics,
+	pvfs: Vec<PvfPrepData>,
+) -> Result<(), Fatal> {
+	for pvf in pvfs {
+		let artifact_id = ArtifactId::from_pvf_prep_data(&pvf);
+		if artifacts.artifact_state_mut(&artifact_id).is_some() ||
+			prewarm.pending.iter().any(|p| ArtifactId::from_pvf_prep_data(p) == artifact_id)
+		{
+			metrics.on_prewarm_request("known");
+			continue;
+		}
+
+		prewarm.pending.push_back(pvf);
+	}
+
+	prewarm.dispatch(artifacts, prepare_queue, metrics).await
+}
+
+/// Records whether an artifact was already prepared when it was first needed for execution,
+/// which tells how well the pre-warming covers the artifacts which are actually needed.
+fn note_execution(
+	artifacts: &mut Artifacts,
+	prewarm: &mut Prewarm,
+	metrics: &Metrics,
+	pvf: &PvfPrepData,
+) {
+	let artifact_id = ArtifactId::from_pvf_prep_data(pvf);
+	if prewarm.executed.contains(&artifact_id) {
+		return;
+	}
+
+	let prepared =
+		matches!(artifacts.artifact_state_mut(&artifact_id), Some(ArtifactState::Prepared { .. }));
+	metrics.on_first_execution(prepared);
+	// An artifact prepared by other means, e.g. by pre-checking, is neither a hit nor a miss.
+	if !prepared {
+		metrics.on_prewarm_miss();
+	} else if prewarm.prewarmed.contains(&artifact_id) {
+		metrics.on_prewarm_hit();
+	}
+	prewarm.executed.insert(artifact_id);
+}
+
 async fn handle_prepare_done(
 	artifacts: &mut Artifacts,
 	execute_queue: &mut mpsc::Sender<execute::ToQueue>,
@@ -1063,6 +1285,7 @@ pub(crate) mod tests {
 		cleanup_pulse_interval: Duration,
 		cleanup_config: ArtifactsCleanupConfig,
 		artifacts: Artifacts,
+		prewarm_disk_limit: u64,
 	}
 
 	impl Builder {
@@ -1072,6 +1295,7 @@ pub(crate) mod tests {
 				cleanup_pulse_interval: Duration::from_secs(3600),
 				cleanup_config: ArtifactsCleanupConfig::default(),
 				artifacts: Artifacts::empty(),
+				prewarm_disk_limit: DEFAULT_PREWARM_DISK_LIMIT,
 			}
 		}
 
@@ -1094,7 +1318,9 @@ pub(crate) mod tests {
 	}
 
 	impl Test {
-		fn new(Builder { cleanup_pulse_interval, artifacts, cleanup_config }: Builder) -> Self {
+		fn new(
+			Builder { cleanup_pulse_interval, artifacts, cleanup_config, prewarm_disk_limit }: Builder,
+		) -> Self {
 			let (to_host_tx, to_host_rx) = mpsc::channel(10);
 			let (to_prepare_queue_tx, to_prepare_queue_rx) = mpsc::channel(10);
 			let (from_prepare_queue_tx, from_prepare_queue_rx) = mpsc::unbounded();
@@ -1113,6 +1339,8 @@ pub(crate) mod tests {
 				from_execute_queue_rx,
 				to_sweeper_tx,
 				awaiting_prepare: AwaitingPrepare::default(),
+				prewarm: Prewarm::new(DEFAULT_PREWARM_WORKERS_MAX_NUM, prewarm_disk_limit),
+				metrics: Metrics::default(),
 			})
 			.boxed();
 
@@ -1879,6 +2107,155 @@ pub(crate) mod tests {
 		);
 	}
 
+	#[tokio::test]
+	async fn prewarm_prepares_in_background() {
+		let mut test = Builder::default().build();
+		let mut host = test.host_handle();
+
+		host.prewarm(vec![PvfPrepData::from_discriminator(1)]).await.unwrap();
+
+		// The queue received the prepare request at the lowest priority.
+		assert_matches!(
+			test.poll_and_recv_to_prepare_queue().await,
+			prepare::ToQueue::Enqueue { priority: Priority::Background, .. }
+		);
+
+		// A known artifact is not pre-warmed again.
+		host.prewarm(vec![PvfPrepData::from_discriminator(1)]).await.unwrap();
+		test.poll_ensure_to_prepare_queue_is_empty().await;
+
+		// The artifact is needed for execution before it is prepared, so its preparation is
+		// bumped.
+		let pvd = Arc::new(PersistedValidationData {
+			parent_head: Default::default(),
+			relay_parent_number: 1u32,
+			relay_parent_storage_root: H256::default(),
+			max_pov_size: 4096 * 1024,
+		});
+		let pov = Arc::new(PoV { block_data: BlockData(b"pov".to_vec()) });
+		let (result_tx, _result_rx) = oneshot::channel();
+		host.execute_pvf(
+			PvfPrepData::from_discriminator(1),
+			test_validation_context(pvd, pov),
+			Priority::Normal,
+			PvfExecKind::Backing(H256::default()),
+			result_tx,
+		)
+		.await
+		.unwrap();
+
+		assert_matches!(
+			test.poll_and_recv_to_prepare_queue().await,
+			prepare::ToQueue::Amend { priority: Priority::Normal, artifact_id: id } => {
+				assert_eq!(id, artifact_id(1));
+			}
+		);
+	}
+
+	#[test]
+	fn prewarm_hits_and_misses_are_counted_on_first_execution() {
+		use polkadot_node_metrics::metrics::{prometheus, Metrics as _};
+
+		let registry = prometheus::Registry::new();
+		let metrics = Metrics::try_register(&registry).unwrap();
+		let count = |outcome: &str| {
+			registry
+				.gather()
+				.iter()
+				.filter(|family| family.get_name() == "polkadot_pvf_prewarm_execution")
+				.flat_map(|family| family.get_metric())
+				.filter(|metric| {
+					metric.get_label().iter().any(|label| label.get_value() == outcome)
+				})
+				.map(|metric| metric.get_counter().get_value() as u64)
+				.sum::<u64>()
+		};
+
+		let mut artifacts = Artifacts::empty();
+		let mut prewarm = Prewarm::new(1, u64::MAX);
+		let tempdir = tempfile::tempdir().unwrap();
+		for i in 1..=2 {
+			artifacts.insert_prepared(
+				artifact_id(i),
+				generate_artifact_path(tempdir.path()),
+				Default::default(),
+				SystemTime::now(),
+				1024,
+			);
+		}
+		prewarm.prewarmed.insert(artifact_id(1));
+		artifacts.insert_preparing(artifact_id(4), Vec::new());
+
+		// Pre-warmed and prepared: a hit, counted only once.
+		note_execution(&mut artifacts, &mut prewarm, &metrics, &PvfPrepData::from_discriminator(1));
+		note_execution(&mut artifacts, &mut prewarm, &metrics, &PvfPrepData::from_discriminator(1));
+		// Prepared by other means: neither a hit nor a miss.
+		note_execution(&mut artifacts, &mut prewarm, &metrics, &PvfPrepData::from_discriminator(2));
+		// Not prepared yet, or not known at all: a miss.
+		note_execution(&mut artifacts, &mut prewarm, &metrics, &PvfPrepData::from_discriminator(4));
+		note_execution(&mut artifacts, &mut prewarm, &metrics, &PvfPrepData::from_discriminator(5));
+
+		assert_eq!(count("hit"), 1);
+		assert_eq!(count("miss"), 2);
+	}
+
+	#[tokio::test]
+	async fn prewarm_respects_disk_limit() {
+		let mut builder = Builder::default();
+		builder.prewarm_disk_limit = 0;
+		let mut test = builder.build();
+		let mut host = test.host_handle();
+
+		host.prewarm(vec![PvfPrepData::from_discriminator(1)]).await.unwrap();
+		test.poll_ensure_to_prepare_queue_is_empty().await;
+	}
+
+	#[tokio::test]
+	async fn prewarm_rechecks_disk_limit_on_dispatch() {
+		let mut builder = Builder::default();
+		builder.prewarm_disk_limit = 100;
+		let mut test = builder.build();
+		let mut host = test.host_handle();
+
+		host.prewarm((1..=3).map(PvfPrepData::from_discriminator).collect())
+			.await
+			.unwrap();
+
+		// Only one job is in flight at a time.
+		assert_matches!(
+			test.poll_and_recv_to_prepare_queue().await,
+			prepare::ToQueue::Enqueue { priority: Priority::Background, pvf } => {
+				assert_eq!(ArtifactId::from_pvf_prep_data(&pvf), artifact_id(1));
+			}
+		);
+		test.poll_ensure_to_prepare_queue_is_empty().await;
+
+		// The next one is dispatched once the first one is prepared, below the disk limit.
+		test.from_prepare_queue_tx
+			.send(prepare::FromQueue {
+				artifact_id: artifact_id(1),
+				result: Ok(PrepareSuccess { size: 50, ..Default::default() }),
+			})
+			.await
+			.unwrap();
+		assert_matches!(
+			test.poll_and_recv_to_prepare_queue().await,
+			prepare::ToQueue::Enqueue { priority: Priority::Background, pvf } => {
+				assert_eq!(ArtifactId::from_pvf_prep_data(&pvf), artifact_id(2));
+			}
+		);
+
+		// The second one exceeds the disk limit, the last one is dropped.
+		test.from_prepare_queue_tx
+			.send(prepare::FromQueue {
+				artifact_id: artifact_id(2),
+				result: Ok(PrepareSuccess { size: 60, ..Default::default() }),
+			})
+			.await
+			.unwrap();
+		test.poll_ensure_to_prepare_queue_is_empty().await;
+	}
+
 	#[tokio::test]
 	async fn cancellation() {
 		let mut test = Builder::default().build();
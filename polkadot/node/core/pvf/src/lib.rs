@@ -91,6 +91,7 @@
 //! [`params`][`polkadot_parachain_primitives::primitives::ValidationParams`].
 
 mod artifacts;
+mod dump;
 mod error;
 mod execute;
 mod host;
@@ -104,10 +105,11 @@ mod worker_interface;
 #[cfg(feature = "test-utils")]
 pub mod testing;
 
+pub use dump::{prune_dumps, ValidationDump, DUMP_EXTENSION};
 pub use error::{InvalidCandidate, PossiblyInvalidError, ValidationError};
 pub use host::{
-	start, Config, ValidationHost, EXECUTE_BINARY_NAME, HOST_MESSAGE_QUEUE_SIZE,
-	PREPARE_BINARY_NAME,
+	start, Config, ValidationHost, DEFAULT_PREWARM_DISK_LIMIT, DEFAULT_PREWARM_WORKERS_MAX_NUM,
+	EXECUTE_BINARY_NAME, HOST_MESSAGE_QUEUE_SIZE, PREPARE_BINARY_NAME,
 };
 pub use metrics::Metrics;
 pub use priority::Priority;
@@ -128,6 +128,37 @@ impl Metrics {
 			metrics.exec_kind_selected.with_label_values(&[kind.as_str()]).inc();
 		}
 	}
+
+	/// When a PVF was requested to be pre-warmed, with the outcome of the request.
+	pub(crate) fn on_prewarm_request(&self, outcome: &str) {
+		if let Some(metrics) = &self.0 {
+			metrics.prewarm_requests.with_label_values(&[outcome]).inc();
+		}
+	}
+
+	/// When a PVF is executed for the first time, whether its artifact was already prepared.
+	pub(crate) fn on_first_execution(&self, prepared: bool) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.first_execution
+				.with_label_values(&[if prepared { "true" } else { "false" }])
+				.inc();
+		}
+	}
+
+	/// When a PVF is executed for the first time and its artifact was prepared by pre-warming.
+	pub(crate) fn on_prewarm_hit(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.prewarm_execution.with_label_values(&["hit"]).inc();
+		}
+	}
+
+	/// When a PVF is executed for the first time and its artifact has yet to be prepared.
+	pub(crate) fn on_prewarm_miss(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.prewarm_execution.with_label_values(&["miss"]).inc();
+		}
+	}
 }
 
 #[derive(Clone)]
@@ -155,6 +186,9 @@ struct MetricsInner {
 	pov_size: prometheus::HistogramVec,
 	code_size: prometheus::Histogram,
 	exec_kind_selected: prometheus::CounterVec<prometheus::U64>,
+	prewarm_requests: prometheus::CounterVec<prometheus::U64>,
+	first_execution: prometheus::CounterVec<prometheus::U64>,
+	prewarm_execution: prometheus::CounterVec<prometheus::U64>,
 }
 
 impl metrics::Metrics for Metrics {
@@ -388,6 +422,36 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			prewarm_requests: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_prewarm_requests",
+						"The total number of PVFs requested to be pre-warmed, by outcome",
+					),
+					&["outcome"],
+				)?,
+				registry,
+			)?,
+			first_execution: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_first_execution",
+						"The number of PVFs executed for the first time, by whether their artifact was already prepared",
+					),
+					&["prepared"],
+				)?,
+				registry,
+			)?,
+			prewarm_execution: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_prewarm_execution",
+						"The number of PVFs executed for the first time, by whether their artifact was prepared by pre-warming (hit) or had to be prepared for the execution (miss)",
+					),
+					&["outcome"],
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(inner)))
 	}
@@ -37,6 +37,9 @@ pub enum ToQueue {
 	/// Note that it is incorrect to enqueue the same PVF again without first receiving the
 	/// [`FromQueue`] response.
 	Enqueue { priority: Priority, pvf: PvfPrepData },
+	/// Raises the priority of the preparation of an already enqueued artifact, if it is not being
+	/// prepared yet. Does nothing for unknown artifacts or a priority which is not higher.
+	Amend { priority: Priority, artifact_id: ArtifactId },
 }
 
 /// A response from queue.
@@ -60,6 +63,9 @@ struct Limits {
 	/// at `soft_capacity`, we are allowed to grow up to `hard_capacity`. Thus this should be equal
 	/// or smaller than `hard_capacity`.
 	soft_capacity: usize,
+
+	/// The maximum number of workers which can be busy with background jobs at the same time.
+	background_capacity: usize,
 }
 
 impl Limits {
@@ -101,6 +107,7 @@ impl WorkerData {
 /// background starve.
 #[derive(Default)]
 struct Unscheduled {
+	background: VecDeque<Job>,
 	normal: VecDeque<Job>,
 	critical: VecDeque<Job>,
 }
@@ -108,6 +115,7 @@ struct Unscheduled {
 impl Unscheduled {
 	fn queue_mut(&mut self, prio: Priority) -> &mut VecDeque<Job> {
 		match prio {
+			Priority::Background => &mut self.background,
 			Priority::Normal => &mut self.normal,
 			Priority::Critical => &mut self.critical,
 		}
@@ -121,13 +129,28 @@ impl Unscheduled {
 		self.queue_mut(prio).push_front(job);
 	}
 
+	/// Moves a job to the queue of a higher priority. Returns `false` if the job is not found.
+	fn bump(&mut self, from: Priority, to: Priority, job: Job) -> bool {
+		let queue = self.queue_mut(from);
+		let Some(position) = queue.iter().position(|j| *j == job) else { return false };
+		queue.remove(position);
+		self.add(to, job);
+		true
+	}
+
 	fn is_empty(&self) -> bool {
-		self.normal.is_empty() && self.critical.is_empty()
+		self.background.is_empty() && self.normal.is_empty() && self.critical.is_empty()
 	}
 
-	fn next(&mut self) -> Option<Job> {
+	fn next(&mut self, allow_background: bool) -> Option<Job> {
 		let mut check = |prio: Priority| self.queue_mut(prio).pop_front();
-		check(Priority::Critical).or_else(|| check(Priority::Normal))
+		check(Priority::Critical).or_else(|| check(Priority::Normal)).or_else(|| {
+			if allow_background {
+				check(Priority::Background)
+			} else {
+				None
+			}
+		})
 	}
 }
 
@@ -165,6 +188,7 @@ impl Queue {
 		metrics: Metrics,
 		soft_capacity: usize,
 		hard_capacity: usize,
+		background_capacity: usize,
 		cache_path: PathBuf,
 		to_queue_rx: mpsc::Receiver<ToQueue>,
 		from_queue_tx: mpsc::UnboundedSender<FromQueue>,
@@ -179,7 +203,7 @@ impl Queue {
 			from_pool_rx,
 			cache_path,
 			spawn_inflight: 0,
-			limits: Limits { hard_capacity, soft_capacity },
+			limits: Limits { hard_capacity, soft_capacity, background_capacity },
 			jobs: slotmap::SlotMap::with_key(),
 			unscheduled: Unscheduled::default(),
 			artifact_id_to_job: HashMap::new(),
@@ -213,6 +237,9 @@ async fn handle_to_queue(queue: &mut Queue, to_queue: ToQueue) -> Result<(), Fat
 		ToQueue::Enqueue { priority, pvf } => {
 			handle_enqueue(queue, priority, pvf).await?;
 		},
+		ToQueue::Amend { priority, artifact_id } => {
+			handle_amend(queue, priority, artifact_id).await?;
+		},
 	}
 	Ok(())
 }
@@ -251,7 +278,9 @@ async fn handle_enqueue(
 	let job = queue.jobs.insert(JobData { priority, pvf, worker: None });
 	queue.artifact_id_to_job.insert(artifact_id, job);
 
-	if let Some(available) = find_idle_worker(queue) {
+	if priority.is_background() && !can_assign_background(queue) {
+		queue.unscheduled.add(priority, job);
+	} else if let Some(available) = find_idle_worker(queue) {
 		// This may seem not fair (w.r.t priority) on the first glance, but it should be. This is
 		// because as soon as a worker finishes with the job it's immediately given the next one.
 		assign(queue, available, job).await?;
@@ -263,6 +292,57 @@ async fn handle_enqueue(
 	Ok(())
 }
 
+async fn handle_amend(
+	queue: &mut Queue,
+	priority: Priority,
+	artifact_id: ArtifactId,
+) -> Result<(), Fatal> {
+	let Some(&job) = queue.artifact_id_to_job.get(&artifact_id) else { return Ok(()) };
+	let job_data = &mut queue.jobs[job];
+	if job_data.worker.is_some() || job_data.priority >= priority {
+		return Ok(());
+	}
+
+	gum::debug!(
+		target: LOG_TARGET,
+		validation_code_hash = ?artifact_id.code_hash,
+		from = ?job_data.priority,
+		to = ?priority,
+		"Bumping the priority of a PVF preparation.",
+	);
+	if !queue.unscheduled.bump(job_data.priority, priority, job) {
+		return Ok(());
+	}
+	job_data.priority = priority;
+
+	// A background job may have been held back while there are idle workers.
+	if let Some(available) = find_idle_worker(queue) {
+		if let Some(job) = next_unscheduled(queue) {
+			assign(queue, available, job).await?;
+		}
+	} else {
+		spawn_extra_worker(queue, priority.is_critical()).await?;
+	}
+
+	Ok(())
+}
+
+/// Returns `true` if one more worker may take up a background job.
+fn can_assign_background(queue: &Queue) -> bool {
+	let busy = queue
+		.jobs
+		.values()
+		.filter(|data| data.worker.is_some() && data.priority.is_background())
+		.count();
+	busy < queue.limits.background_capacity
+}
+
+/// Takes the next unscheduled job, respecting the limit of workers busy with background jobs.
+fn next_unscheduled(queue: &mut Queue) -> Option<Job> {
+	let allow_background = can_assign_background(queue);
+	queue.unscheduled.next(allow_background)
+}
+
 fn find_idle_worker(queue: &mut Queue) -> Option<Worker> {
 	queue.workers.iter().filter(|(_, data)| data.is_idle()).map(|(k, _)| k).next()
 }
@@ -283,7 +363,7 @@ async fn handle_worker_spawned(queue: &mut Queue, worker: Worker) -> Result<(),
 	queue.workers.insert(worker, WorkerData::default());
 	queue.spawn_inflight -= 1;
 
-	if let Some(job) = queue.unscheduled.next() {
+	if let Some(job) = next_unscheduled(queue) {
 		assign(queue, worker, job).await?;
 	}
 
@@ -372,7 +452,7 @@ async fn handle_worker_concluded(
 		send_pool(&mut queue.to_pool_tx, pool::ToPool::Kill(worker)).await?;
 	} else {
 		// see if there are more work available and schedule it.
-		if let Some(job) = queue.unscheduled.next() {
+		if let Some(job) = next_unscheduled(queue) {
 			assign(queue, worker, job).await?;
 		}
 	}
@@ -464,6 +544,7 @@ pub fn start(
 	metrics: Metrics,
 	soft_capacity: usize,
 	hard_capacity: usize,
+	background_capacity: usize,
 	cache_path: PathBuf,
 	to_pool_tx: mpsc::Sender<pool::ToPool>,
 	from_pool_rx: mpsc::UnboundedReceiver<pool::FromPool>,
@@ -475,6 +556,7 @@ pub fn start(
 		metrics,
 		soft_capacity,
 		hard_capacity,
+		background_capacity,
 		cache_path,
 		to_queue_rx,
 		from_queue_tx,
@@ -536,6 +618,14 @@ mod tests {
 
 	impl Test {
 		fn new(soft_capacity: usize, hard_capacity: usize) -> Self {
+			Self::with_background_capacity(soft_capacity, hard_capacity, hard_capacity)
+		}
+
+		fn with_background_capacity(
+			soft_capacity: usize,
+			hard_capacity: usize,
+			background_capacity: usize,
+		) -> Self {
 			let tempdir = tempfile::tempdir().unwrap();
 
 			let (to_pool_tx, to_pool_rx) = mpsc::channel(10);
@@ -547,6 +637,7 @@ mod tests {
 				Metrics::default(),
 				soft_capacity,
 				hard_capacity,
+				background_capacity,
 				tempdir.path().to_owned().into(),
 				to_pool_tx,
 				from_pool_rx,
@@ -668,6 +759,40 @@ mod tests {
 		assert_eq!(test.poll_and_recv_to_pool().await, pool::ToPool::Spawn);
 	}
 
+	#[tokio::test]
+	async fn background_jobs_are_limited_until_amended() {
+		let mut test = Test::with_background_capacity(2, 2, 1);
+
+		test.send_queue(ToQueue::Enqueue { priority: Priority::Background, pvf: pvf(1) });
+		test.send_queue(ToQueue::Enqueue { priority: Priority::Background, pvf: pvf(2) });
+
+		assert_eq!(test.poll_and_recv_to_pool().await, pool::ToPool::Spawn);
+		assert_eq!(test.poll_and_recv_to_pool().await, pool::ToPool::Spawn);
+
+		let w1 = test.workers.insert(());
+		let w2 = test.workers.insert(());
+
+		test.send_from_pool(pool::FromPool::Spawned(w1));
+		assert_matches!(
+			test.poll_and_recv_to_pool().await,
+			pool::ToPool::StartWork { worker, .. } if worker == w1
+		);
+
+		// Only one worker may be busy with a background job.
+		test.send_from_pool(pool::FromPool::Spawned(w2));
+		test.poll_ensure_to_pool_is_empty().await;
+
+		// Once the second job is needed it is started right away.
+		test.send_queue(ToQueue::Amend {
+			priority: Priority::Normal,
+			artifact_id: ArtifactId::from_pvf_prep_data(&pvf(2)),
+		});
+		assert_matches!(
+			test.poll_and_recv_to_pool().await,
+			pool::ToPool::StartWork { worker, .. } if worker == w2
+		);
+	}
+
 	#[tokio::test]
 	async fn cull_unwanted() {
 		let mut test = Test::new(1, 2);
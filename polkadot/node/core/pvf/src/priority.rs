@@ -19,6 +19,12 @@ use polkadot_node_subsystem::messages::PvfExecKind;
 /// A priority assigned to preparation of a PVF.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Priority {
+	/// Lowest priority for artifacts which are not needed yet, but likely will be, like the ones
+	/// of parachains active in the current or upcoming session.
+	///
+	/// Only a limited number of workers prepare background jobs at any time, so that they don't
+	/// compete with the jobs which are needed now.
+	Background,
 	/// Normal priority for things that do not require immediate response, but still need to be
 	/// done pretty quick.
 	///
@@ -36,6 +42,11 @@ impl Priority {
 	pub fn is_critical(self) -> bool {
 		self == Priority::Critical
 	}
+
+	/// Returns `true` if `self` is `Background`
+	pub fn is_background(self) -> bool {
+		self == Priority::Background
+	}
 }
 
 impl From<PvfExecKind> for Priority {
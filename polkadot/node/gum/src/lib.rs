@@ -102,6 +102,11 @@
 //!
 //! On the other hand if you want all `parachain` logs, specify `parachain=trace`, which will also
 //! include logs from `parachain::pvf` and other subtargets.
+//!
+//! When the logs are consumed by a log pipeline, run the node with `--log-format json`. Every
+//! field, including the `traceID`, is then a separate key of the JSON object of a log line, and
+//! an event without a `traceID` inherits the one of the innermost enclosing span that has it.
+//! Filtering on the `traceID` yields the lifecycle of a candidate across all subsystems.
 
 pub use tracing::{enabled, event, Level};
 
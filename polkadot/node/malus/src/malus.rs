@@ -42,6 +42,10 @@ enum NemesisVariant {
 	DisputeFinalizedCandidates(DisputeFinalizedCandidatesOptions),
 	/// Spam many request statements instead of sending a single one.
 	SpamStatementRequests(SpamStatementRequestsOptions),
+	/// Back candidates and refuse to serve their chunks.
+	WithholdChunks(WithholdChunksOptions),
+	/// Send conflicting approval assignments to different peers.
+	EquivocateAssignments(EquivocateAssignmentsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -105,6 +109,16 @@ impl MalusCli {
 
 				polkadot_cli::run_node(cli, SpamStatementRequests { spam_factor }, finality_delay)?
 			},
+			NemesisVariant::WithholdChunks(opts) => {
+				let WithholdChunksOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(cli, WithholdChunks { percentage }, finality_delay)?
+			},
+			NemesisVariant::EquivocateAssignments(opts) => {
+				let EquivocateAssignmentsOptions { percentage, cli } = opts;
+
+				polkadot_cli::run_node(cli, EquivocateAssignments { percentage }, finality_delay)?
+			},
 		}
 		Ok(())
 	}
@@ -244,4 +258,40 @@ mod tests {
 			assert!(opts.cli.run.base.bob);
 		});
 	}
+
+	#[test]
+	fn percentage_works_withhold_chunks() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"withhold-chunks",
+			"--percentage",
+			"50",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::WithholdChunks(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 50);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
+
+	#[test]
+	fn equivocate_assignments_works() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"equivocate-assignments",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::EquivocateAssignments(opts),
+			..
+		} => {
+			assert_eq!(opts.percentage, 100);
+			assert!(opts.cli.run.base.bob);
+		});
+	}
 }
@@ -0,0 +1,353 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that equivocates on its approval assignments.
+//!
+//! This malus variant behaves honestly in everything except when gossiping approval assignments
+//! through the network bridge subsystem. The peers an assignment is sent to are split in two
+//! halves: the first half receives the original assignment, the second half receives a different
+//! assignment for the same block, signed with the node's assignment key. Both assignments pass the
+//! VRF checks of honest validators, which end up with conflicting views of when the malus node is
+//! supposed to check a candidate, while the candidates still get approved thanks to the remaining
+//! checkers.
+//!
+//! The conflicting assignment is a `RelayVRFDelay` certificate. Modulo assignments get one for
+//! their first claimed candidate, delay assignments get one for the candidate following the claimed
+//! one. The candidates and the VRF story of a block are taken from the `NewBlocks` messages
+//! received by approval voting.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_network_protocol::{
+	v3::{ApprovalDistributionMessage, ValidationProtocol},
+	PeerId, ValidationProtocols, VersionedValidationProtocol,
+};
+use polkadot_node_primitives::approval::{
+	v1::{RelayVRFStory, RELAY_VRF_DELAY_CONTEXT},
+	v2::{
+		AssignmentCertKindV2, AssignmentCertV2, CandidateBitfield, IndirectAssignmentCertV2,
+		VrfPreOutput, VrfProof, VrfSignature,
+	},
+};
+use polkadot_node_subsystem::{messages::NetworkBridgeTxMessage, OverseerSignal, SpawnGlue};
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::{
+	AssignmentId, AssignmentPair, BlockNumber, CandidateIndex, CoreIndex, Hash,
+	ASSIGNMENT_KEY_TYPE_ID,
+};
+use sc_keystore::LocalKeystore;
+use sp_core::traits::SpawnNamed;
+use sp_keystore::Keystore;
+
+use codec::Encode;
+use merlin::Transcript;
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+
+/// What is needed to sign assignments for the candidates of a block.
+struct BlockInfo {
+	number: BlockNumber,
+	vrf_story: RelayVRFStory,
+	/// The cores of the candidates, indexed by `CandidateIndex`.
+	cores: Vec<CoreIndex>,
+}
+
+type Blocks = Arc<Mutex<HashMap<Hash, BlockInfo>>>;
+
+/// Wraps around approval voting and records the blocks it imports.
+#[derive(Clone)]
+struct BlockTracker {
+	blocks: Blocks,
+}
+
+impl<Sender> MessageInterceptor<Sender> for BlockTracker
+where
+	Sender: overseer::ApprovalVotingParallelSenderTrait + Clone + Send + 'static,
+{
+	type Message = ApprovalVotingParallelMessage;
+
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: ApprovalVotingParallelMessage::NewBlocks(ref metas),
+			} => {
+				let mut blocks = self.blocks.lock().expect("Lock is never poisoned; qed");
+				for meta in metas {
+					blocks.insert(
+						meta.hash,
+						BlockInfo {
+							number: meta.number,
+							vrf_story: meta.vrf_story.clone(),
+							cores: meta.candidates.iter().map(|(_, core, _)| *core).collect(),
+						},
+					);
+				}
+			},
+			FromOrchestra::Signal(OverseerSignal::BlockFinalized(_, finalized)) => {
+				self.blocks
+					.lock()
+					.expect("Lock is never poisoned; qed")
+					.retain(|_, block| block.number > finalized);
+			},
+			_ => {},
+		}
+		Some(msg)
+	}
+}
+
+/// Wraps around network bridge and replaces it.
+#[derive(Clone)]
+struct AssignmentEquivocator {
+	percentage: f64,
+	blocks: Blocks,
+	keystore: Arc<LocalKeystore>,
+}
+
+impl AssignmentEquivocator {
+	/// Split the recipients of an assignment message and send conflicting assignments to the
+	/// second half. Any other message, or assignments we can't sign a conflicting one for, are
+	/// sent unchanged.
+	fn equivocate(
+		&self,
+		peers: Vec<PeerId>,
+		message: VersionedValidationProtocol,
+	) -> Vec<(Vec<PeerId>, VersionedValidationProtocol)> {
+		let ValidationProtocols::V3(ValidationProtocol::ApprovalDistribution(
+			ApprovalDistributionMessage::Assignments(ref assignments),
+		)) = message
+		else {
+			return vec![(peers, message)];
+		};
+
+		let distribution = Bernoulli::new(self.percentage / 100.0)
+			.expect("Invalid probability! Percentage must be in range [0..=100].");
+		if peers.len() < 2 || !distribution.sample(&mut rand::thread_rng()) {
+			return vec![(peers, message)];
+		}
+
+		let Some(assignment_key) = self.assignment_key() else {
+			gum::debug!(target: MALUS, "No assignment key, can't equivocate.");
+			return vec![(peers, message)];
+		};
+
+		let conflicting = {
+			let blocks = self.blocks.lock().expect("Lock is never poisoned; qed");
+			assignments
+				.iter()
+				.filter_map(|(cert, claimed_candidates)| {
+					let block = blocks.get(&cert.block_hash)?;
+					let conflicting =
+						conflicting_assignment(&assignment_key, block, cert, claimed_candidates)?;
+					gum::info!(
+						target: MALUS,
+						block_hash = ?cert.block_hash,
+						validator = ?cert.validator,
+						"😈 Equivocating approval assignment.",
+					);
+					Some(conflicting)
+				})
+				.collect::<Vec<_>>()
+		};
+		if conflicting.is_empty() {
+			return vec![(peers, message)];
+		}
+		let conflicting = ValidationProtocols::V3(ValidationProtocol::ApprovalDistribution(
+			ApprovalDistributionMessage::Assignments(conflicting),
+		));
+
+		let mut honest_peers = peers;
+		let equivocation_peers = honest_peers.split_off(honest_peers.len() / 2);
+		vec![(honest_peers, message), (equivocation_peers, conflicting)]
+	}
+
+	/// The assignment key of this node, the first one found in the keystore.
+	fn assignment_key(&self) -> Option<schnorrkel::Keypair> {
+		let public_keys = Keystore::sr25519_public_keys(&*self.keystore, ASSIGNMENT_KEY_TYPE_ID);
+		public_keys.into_iter().find_map(|public| {
+			let pair = self
+				.keystore
+				.key_pair::<AssignmentPair>(&AssignmentId::from(public))
+				.ok()
+				.flatten()?;
+			let pair: &sp_core::sr25519::Pair = pair.as_ref();
+			let keypair: &schnorrkel::Keypair = pair.as_ref();
+			Some(keypair.clone())
+		})
+	}
+}
+
+/// Sign a `RelayVRFDelay` assignment conflicting with the given one.
+fn conflicting_assignment(
+	assignment_key: &schnorrkel::Keypair,
+	block: &BlockInfo,
+	assignment: &IndirectAssignmentCertV2,
+	claimed_candidates: &CandidateBitfield,
+) -> Option<(IndirectAssignmentCertV2, CandidateBitfield)> {
+	let first_claimed = claimed_candidates.first_one()?;
+	let candidate_index = match assignment.cert.kind {
+		// A block without cores has nothing to equivocate on.
+		AssignmentCertKindV2::RelayVRFDelay { .. } => {
+			(first_claimed + 1).checked_rem(block.cores.len())?
+		},
+		_ => first_claimed,
+	};
+	let core_index = *block.cores.get(candidate_index)?;
+
+	let (vrf_in_out, vrf_proof, _) =
+		assignment_key.vrf_sign(relay_vrf_delay_transcript(&block.vrf_story, core_index));
+	let cert = AssignmentCertV2 {
+		kind: AssignmentCertKindV2::RelayVRFDelay { core_index },
+		vrf: VrfSignature {
+			pre_output: VrfPreOutput(vrf_in_out.to_preout()),
+			proof: VrfProof(vrf_proof),
+		},
+	};
+
+	Some((
+		IndirectAssignmentCertV2 {
+			block_hash: assignment.block_hash,
+			validator: assignment.validator,
+			cert,
+		},
+		CandidateBitfield::from(candidate_index as CandidateIndex),
+	))
+}
+
+/// Same transcript approval voting signs delay assignments with.
+fn relay_vrf_delay_transcript(
+	relay_vrf_story: &RelayVRFStory,
+	core_index: CoreIndex,
+) -> Transcript {
+	let mut t = Transcript::new(RELAY_VRF_DELAY_CONTEXT);
+	t.append_message(b"RC-VRF", &relay_vrf_story.0);
+	core_index.0.using_encoded(|s| t.append_message(b"core", s));
+	t
+}
+
+impl<Sender> MessageInterceptor<Sender> for AssignmentEquivocator
+where
+	Sender: overseer::NetworkBridgeTxSenderTrait + Clone + Send + 'static,
+{
+	type Message = NetworkBridgeTxMessage;
+
+	/// Intercept `NetworkBridgeTxMessage::SendValidationMessage(s)` carrying approval
+	/// assignments and equivocate on them.
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessage(peers, message),
+			} => Some(FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessages(
+					self.equivocate(peers, message),
+				),
+			}),
+			FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessages(messages),
+			} => Some(FromOrchestra::Communication {
+				msg: NetworkBridgeTxMessage::SendValidationMessages(
+					messages
+						.into_iter()
+						.flat_map(|(peers, message)| self.equivocate(peers, message))
+						.collect(),
+				),
+			}),
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct EquivocateAssignmentsOptions {
+	/// Determines the percentage of assignment messages that are equivocated.
+	/// Defaults to equivocating on all assignments.
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// EquivocateAssignments implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct EquivocateAssignments {
+	/// The probability of equivocating on an assignment message.
+	pub percentage: u8,
+}
+
+impl OverseerGen for EquivocateAssignments {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that equivocates on {}% of its approval assignments.",
+			&self.percentage,
+		);
+
+		let ext_args =
+			ext_args.expect("Extended arguments required to build validator overseer are provided");
+		let blocks = Blocks::default();
+		let block_tracker = BlockTracker { blocks: blocks.clone() };
+		let equivocator = AssignmentEquivocator {
+			percentage: f64::from(self.percentage),
+			blocks,
+			keystore: ext_args.keystore.clone(),
+		};
+
+		validator_overseer_builder(args, ext_args)?
+			.replace_approval_voting_parallel(move |cb| {
+				InterceptedSubsystem::new(cb, block_tracker)
+			})
+			.replace_network_bridge_tx(move |cb| InterceptedSubsystem::new(cb, equivocator))
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,201 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node variant that backs candidates and then refuses to serve their data.
+//!
+//! This malus variant behaves honestly in backing, it stores the erasure chunks of the candidates
+//! it backs and signs availability bitfields for them. It never answers chunk or available data
+//! requests from other validators for a configurable percentage of candidates though, so the
+//! other validators can only fetch their chunks from the rest of the backing group and have to
+//! recover the candidates from the chunks held by honest validators.
+//!
+//! Available data is served by two subsystems: availability distribution answers the requests
+//! made while fetching chunks and availability recovery answers the full data requests made by
+//! recovering validators. Both are intercepted.
+//!
+//! Withheld candidates are picked by their hash rather than at random, so all requests for a
+//! candidate are treated the same and several malus nodes withhold the same candidates.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use polkadot_cli::{
+	service::{
+		AuxStore, Error, ExtendedOverseerGenArgs, Overseer, OverseerConnector, OverseerGen,
+		OverseerGenArgs, OverseerHandle,
+	},
+	validator_overseer_builder, Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::{ChainApiBackend, RuntimeApiSubsystemClient};
+use polkadot_primitives::CandidateHash;
+use sp_core::traits::SpawnNamed;
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::sync::Arc;
+
+/// Wraps around availability distribution and drops the availability store queries it makes to
+/// answer requests.
+#[derive(Clone)]
+struct ChunkWithholder {
+	percentage: u8,
+}
+
+impl ChunkWithholder {
+	fn withholds(&self, candidate_hash: &CandidateHash) -> bool {
+		let bytes = candidate_hash.0.to_fixed_bytes();
+		u16::from_le_bytes([bytes[0], bytes[1]]) % 100 < u16::from(self.percentage)
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ChunkWithholder
+where
+	Sender: overseer::AvailabilityDistributionSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityDistributionMessage;
+
+	/// `QueryChunk` and `QueryAvailableData` are only sent by the responders answering requests
+	/// of other validators. Dropping them drops the response channel, the request fails on the
+	/// requesting side.
+	fn need_intercept_outgoing(
+		&self,
+		msg: &overseer::AvailabilityDistributionOutgoingMessages,
+	) -> bool {
+		let candidate_hash = match msg {
+			overseer::AvailabilityDistributionOutgoingMessages::AvailabilityStoreMessage(
+				AvailabilityStoreMessage::QueryChunk(candidate_hash, _, _) |
+				AvailabilityStoreMessage::QueryAvailableData(candidate_hash, _),
+			) => candidate_hash,
+			_ => return false,
+		};
+
+		if self.withholds(candidate_hash) {
+			gum::info!(target: MALUS, ?candidate_hash, "😈 Withholding candidate data.");
+			true
+		} else {
+			false
+		}
+	}
+
+	fn intercept_outgoing(
+		&self,
+		_msg: &overseer::AvailabilityDistributionOutgoingMessages,
+	) -> Option<overseer::AvailabilityDistributionOutgoingMessages> {
+		None
+	}
+}
+
+/// Wraps around availability recovery and drops the full data queries it makes to the
+/// availability store.
+///
+/// Availability recovery uses the same query to answer `AvailableDataFetchingV1` requests and to
+/// look up the data locally before recovering a candidate itself, the two can't be told apart.
+/// The malus node doesn't find withheld candidates locally and recovers them from the network
+/// like any other validator.
+#[derive(Clone)]
+struct AvailableDataWithholder(ChunkWithholder);
+
+impl<Sender> MessageInterceptor<Sender> for AvailableDataWithholder
+where
+	Sender: overseer::AvailabilityRecoverySenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityRecoveryMessage;
+
+	fn need_intercept_outgoing(
+		&self,
+		msg: &overseer::AvailabilityRecoveryOutgoingMessages,
+	) -> bool {
+		let candidate_hash = match msg {
+			overseer::AvailabilityRecoveryOutgoingMessages::AvailabilityStoreMessage(
+				AvailabilityStoreMessage::QueryAvailableData(candidate_hash, _),
+			) => candidate_hash,
+			_ => return false,
+		};
+
+		if self.0.withholds(candidate_hash) {
+			gum::info!(target: MALUS, ?candidate_hash, "😈 Withholding candidate data.");
+			true
+		} else {
+			false
+		}
+	}
+
+	fn intercept_outgoing(
+		&self,
+		_msg: &overseer::AvailabilityRecoveryOutgoingMessages,
+	) -> Option<overseer::AvailabilityRecoveryOutgoingMessages> {
+		None
+	}
+}
+
+//----------------------------------------------------------------------------------
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct WithholdChunksOptions {
+	/// Determines the percentage of candidates whose chunks and available data are withheld.
+	/// Defaults to withholding the data of all candidates.
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// WithholdChunks implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct WithholdChunks {
+	/// The percentage of candidates whose data is withheld.
+	pub percentage: u8,
+}
+
+impl OverseerGen for WithholdChunks {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+		ext_args: Option<ExtendedOverseerGenArgs>,
+	) -> Result<(Overseer<SpawnGlue<Spawner>, Arc<RuntimeClient>>, OverseerHandle), Error>
+	where
+		RuntimeClient: RuntimeApiSubsystemClient + ChainApiBackend + AuxStore + 'static,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			"😈 Started Malus node that withholds the data of {}% of the candidates.",
+			&self.percentage,
+		);
+
+		let chunk_withholder = ChunkWithholder { percentage: self.percentage };
+		let data_withholder = AvailableDataWithholder(chunk_withholder.clone());
+
+		validator_overseer_builder(
+			args,
+			ext_args.expect("Extended arguments required to build validator overseer are provided"),
+		)?
+		.replace_availability_distribution(move |ad_subsystem| {
+			InterceptedSubsystem::new(ad_subsystem, chunk_withholder)
+		})
+		.replace_availability_recovery(move |ar_subsystem| {
+			InterceptedSubsystem::new(ar_subsystem, data_withholder)
+		})
+		.build_with_connector(connector)
+		.map_err(|e| e.into())
+	}
+}
@@ -20,7 +20,12 @@
 //!
 //! Subsystems involved:
 //! - availability-recovery
+//!
+//! Recovery from backers is checked against fixed baselines. Recovery from chunks is run with the
+//! sequential and the parallel erasure-coding backends, and the parallel one is checked against
+//! the sequential one, so the objectives hold on any machine.
 
+use polkadot_erasure_coding::{set_default_backend, Backend};
 use polkadot_subsystem_bench::{
 	availability::{
 		benchmark_availability_read, prepare_test, DataAvailabilityReadOptions, Strategy,
@@ -30,33 +35,41 @@ use polkadot_subsystem_bench::{
 	usage::BenchmarkUsage,
 	utils::save_to_file,
 };
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize, thread};
 
 const BENCH_COUNT: usize = 10;
 
-fn main() -> Result<(), String> {
-	let mut messages = vec![];
-
-	let options = DataAvailabilityReadOptions { strategy: Strategy::FullFromBackers };
-	let mut config = TestConfiguration::default();
-	config.num_blocks = 3;
-	config.generate_pov_sizes();
+/// The maximum relative difference between the CPU usage of availability-recovery with the
+/// parallel and the sequential erasure-coding backends.
+const PARALLEL_CPU_USAGE_PRECISION: f64 = 0.1;
 
-	let state = TestState::new(&config);
-
-	println!("Benchmarking...");
+fn bench(state: &TestState, strategy: Strategy) -> BenchmarkUsage {
+	let options = DataAvailabilityReadOptions { strategy };
+	println!("Benchmarking {:?}...", options.strategy);
 	let usages: Vec<BenchmarkUsage> = (0..BENCH_COUNT)
 		.map(|n| {
 			print!("\r[{}{}]", "#".repeat(n), "_".repeat(BENCH_COUNT - n));
 			std::io::stdout().flush().unwrap();
 			let (mut env, _cfgs) =
-				prepare_test(&state, TestDataAvailability::Read(options.clone()), false);
-			env.runtime().block_on(benchmark_availability_read(&mut env, &state))
+				prepare_test(state, TestDataAvailability::Read(options.clone()), false);
+			env.runtime().block_on(benchmark_availability_read(&mut env, state))
 		})
 		.collect();
 	println!("\rDone!{}", " ".repeat(BENCH_COUNT));
 
-	let average_usage = BenchmarkUsage::average(&usages);
+	BenchmarkUsage::average(&usages)
+}
+
+fn main() -> Result<(), String> {
+	let mut messages = vec![];
+
+	let mut config = TestConfiguration::default();
+	config.num_blocks = 3;
+	config.generate_pov_sizes();
+
+	let state = TestState::new(&config);
+
+	let average_usage = bench(&state, Strategy::FullFromBackers);
 	save_to_file(
 		"charts/availability-recovery-regression-bench.json",
 		average_usage.to_chart_json().map_err(|e| e.to_string())?,
@@ -72,6 +85,35 @@ fn main() -> Result<(), String> {
 	]));
 	messages.extend(average_usage.check_cpu_usage(&[("availability-recovery", 11.1600, 0.1)]));
 
+	set_default_backend(Backend::Sequential);
+	let sequential_usage = bench(&state, Strategy::Chunks);
+	println!("{}", sequential_usage);
+
+	let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+	set_default_backend(Backend::Parallel(threads));
+	let parallel_usage = bench(&state, Strategy::Chunks);
+	set_default_backend(Backend::Sequential);
+	println!("{}", parallel_usage);
+
+	// The backend doesn't change what is fetched from the network.
+	let network_checks: Vec<_> = sequential_usage
+		.network_usage
+		.iter()
+		.filter(|usage| usage.per_block > 0.0)
+		.map(|usage| (usage.resource_name.as_str(), usage.per_block, 0.001))
+		.collect();
+	messages.extend(parallel_usage.check_network_usage(&network_checks));
+
+	match parallel_usage.cpu_usage_diff(&sequential_usage, "availability-recovery") {
+		Some(diff) if diff < PARALLEL_CPU_USAGE_PRECISION => {},
+		Some(diff) => messages.push(format!(
+			"The CPU usage of `availability-recovery` with the parallel backend is expected to \
+			 be within {} of the sequential one, but the difference is {}",
+			PARALLEL_CPU_USAGE_PRECISION, diff
+		)),
+		None => messages.push("The resource `availability-recovery` is not found".to_string()),
+	}
+
 	if messages.is_empty() {
 		Ok(())
 	} else {
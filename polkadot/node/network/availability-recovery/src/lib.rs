@@ -42,8 +42,8 @@ use task::{
 };
 
 use polkadot_erasure_coding::{
-	branches, obtain_chunks_v1, recovery_threshold, systematic_recovery_threshold,
-	Error as ErasureEncodingError,
+	branches, obtain_chunks_with_backend, recovery_threshold, systematic_recovery_threshold,
+	Backend as ErasureCodingBackend, Error as ErasureEncodingError,
 };
 use task::{RecoveryParams, RecoveryStrategy, RecoveryTask};
 
@@ -138,6 +138,8 @@ pub struct AvailabilityRecoverySubsystem {
 	req_v1_protocol_name: ProtocolName,
 	/// Full protocol name for ChunkFetchingV2.
 	req_v2_protocol_name: ProtocolName,
+	/// The erasure coding backend used to reconstruct and re-encode the available data.
+	erasure_coding_backend: ErasureCodingBackend,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -178,6 +180,7 @@ enum ErasureTask {
 /// invalid data as well, because the root won't match. In the latter case the situation is the
 /// same for anyone anyways.
 fn reconstructed_data_matches_root(
+	erasure_coding_backend: ErasureCodingBackend,
 	n_validators: usize,
 	expected_root: &Hash,
 	data: &AvailableData,
@@ -185,7 +188,7 @@ fn reconstructed_data_matches_root(
 ) -> bool {
 	let _timer = metrics.time_reencode_chunks();
 
-	let chunks = match obtain_chunks_v1(n_validators, data) {
+	let chunks = match obtain_chunks_with_backend(erasure_coding_backend, n_validators, data) {
 		Ok(chunks) => chunks,
 		Err(e) => {
 			gum::debug!(
@@ -383,6 +386,7 @@ async fn handle_recover<Context>(
 	maybe_core_index: Option<CoreIndex>,
 	req_v1_protocol_name: ProtocolName,
 	req_v2_protocol_name: ProtocolName,
+	erasure_coding_backend: ErasureCodingBackend,
 ) -> Result<()> {
 	let candidate_hash = receipt.hash();
 
@@ -549,6 +553,7 @@ async fn handle_recover<Context>(
 					req_v1_protocol_name,
 					req_v2_protocol_name,
 					chunk_mapping_enabled,
+					erasure_coding_backend,
 					erasure_task_tx,
 				},
 			)
@@ -613,6 +618,7 @@ impl AvailabilityRecoverySubsystem {
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
 			req_v2_protocol_name: req_protocol_names
 				.get_name(request_v2::ChunkFetchingRequest::PROTOCOL),
+			erasure_coding_backend: ErasureCodingBackend::Sequential,
 		}
 	}
 
@@ -643,9 +649,20 @@ impl AvailabilityRecoverySubsystem {
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
 			req_v2_protocol_name: req_protocol_names
 				.get_name(request_v2::ChunkFetchingRequest::PROTOCOL),
+			erasure_coding_backend: ErasureCodingBackend::Sequential,
 		}
 	}
 
+	/// Use the given erasure coding backend to reconstruct and re-encode the available data,
+	/// instead of the sequential one.
+	pub fn with_erasure_coding_backend(
+		mut self,
+		erasure_coding_backend: ErasureCodingBackend,
+	) -> Self {
+		self.erasure_coding_backend = erasure_coding_backend;
+		self
+	}
+
 	/// Customise the recovery strategy kind
 	/// Currently only useful for tests.
 	#[cfg(any(test, feature = "subsystem-benchmarks"))]
@@ -665,6 +682,7 @@ impl AvailabilityRecoverySubsystem {
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
 			req_v2_protocol_name: req_protocol_names
 				.get_name(request_v2::ChunkFetchingRequest::PROTOCOL),
+			erasure_coding_backend: ErasureCodingBackend::Sequential,
 		}
 	}
 
@@ -679,6 +697,7 @@ impl AvailabilityRecoverySubsystem {
 			post_recovery_check,
 			req_v1_protocol_name,
 			req_v2_protocol_name,
+			erasure_coding_backend,
 		} = self;
 
 		let (erasure_task_tx, erasure_task_rx) = futures::channel::mpsc::channel(16);
@@ -705,6 +724,7 @@ impl AvailabilityRecoverySubsystem {
 		let mut to_pool = ThreadPoolBuilder::build(
 			// Pool is guaranteed to have at least 1 worker thread.
 			NonZeroUsize::new(2).expect("There are 2 threads; qed"),
+			erasure_coding_backend,
 			metrics.clone(),
 			&mut ctx,
 		)
@@ -766,6 +786,7 @@ impl AvailabilityRecoverySubsystem {
 										maybe_core_index,
 										req_v1_protocol_name.clone(),
 										req_v2_protocol_name.clone(),
+										erasure_coding_backend,
 									).await
 							}
 						},
@@ -848,6 +869,7 @@ impl ThreadPoolBuilder {
 	#[overseer::contextbounds(AvailabilityRecovery, prefix = self::overseer)]
 	pub fn build<Context>(
 		size: NonZeroUsize,
+		erasure_coding_backend: ErasureCodingBackend,
 		metrics: Metrics,
 		ctx: &mut Context,
 	) -> Vec<futures::channel::mpsc::Sender<ErasureTask>> {
@@ -859,9 +881,10 @@ impl ThreadPoolBuilder {
 			let (tx, rx) = futures::channel::mpsc::channel(8);
 			senders.push(tx);
 
-			if let Err(e) = ctx
-				.spawn_blocking("erasure-task", Box::pin(erasure_task_thread(metrics.clone(), rx)))
-			{
+			if let Err(e) = ctx.spawn_blocking(
+				"erasure-task",
+				Box::pin(erasure_task_thread(erasure_coding_backend, metrics.clone(), rx)),
+			) {
 				gum::warn!(
 					target: LOG_TARGET,
 					err = ?e,
@@ -876,13 +899,15 @@ impl ThreadPoolBuilder {
 
 // Handles CPU intensive operation on a dedicated blocking thread.
 async fn erasure_task_thread(
+	erasure_coding_backend: ErasureCodingBackend,
 	metrics: Metrics,
 	mut ingress: futures::channel::mpsc::Receiver<ErasureTask>,
 ) {
 	loop {
 		match ingress.next().await {
 			Some(ErasureTask::Reconstruct(n_validators, chunks, sender)) => {
-				let _ = sender.send(polkadot_erasure_coding::reconstruct_v1(
+				let _ = sender.send(polkadot_erasure_coding::reconstruct_with_backend(
+					erasure_coding_backend,
 					n_validators,
 					chunks.iter().map(|(c_index, chunk)| {
 						(
@@ -897,6 +922,7 @@ async fn erasure_task_thread(
 				let metrics = metrics.clone();
 
 				let maybe_data = if reconstructed_data_matches_root(
+					erasure_coding_backend,
 					n_validators,
 					&root,
 					&available_data,
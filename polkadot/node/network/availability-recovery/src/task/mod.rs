@@ -31,6 +31,7 @@ pub use self::strategy::{REGULAR_CHUNKS_REQ_RETRY_LIMIT, SYSTEMATIC_CHUNKS_REQ_R
 use crate::{metrics::Metrics, ErasureTask, PostRecoveryCheck, LOG_TARGET};
 
 use codec::Encode;
+use polkadot_erasure_coding::Backend as ErasureCodingBackend;
 use polkadot_node_primitives::AvailableData;
 use polkadot_node_subsystem::{messages::AvailabilityStoreMessage, overseer, RecoveryError};
 use polkadot_primitives::{AuthorityDiscoveryId, CandidateHash, Hash};
@@ -81,6 +82,9 @@ pub struct RecoveryParams {
 	/// Whether or not chunk mapping is enabled.
 	pub chunk_mapping_enabled: bool,
 
+	/// The erasure coding backend used for systematic reconstruction.
+	pub erasure_coding_backend: ErasureCodingBackend,
+
 	/// Channel to the erasure task handler.
 	pub erasure_task_tx: mpsc::Sender<ErasureTask>,
 }
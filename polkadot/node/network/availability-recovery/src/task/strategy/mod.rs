@@ -648,7 +648,9 @@ mod tests {
 		channel::mpsc::{self, UnboundedReceiver},
 		executor, future, Future, FutureExt, StreamExt,
 	};
-	use polkadot_erasure_coding::{recovery_threshold, systematic_recovery_threshold};
+	use polkadot_erasure_coding::{
+		recovery_threshold, systematic_recovery_threshold, Backend as ErasureCodingBackend,
+	};
 	use polkadot_node_network_protocol::request_response::Protocol;
 	use polkadot_node_primitives::{BlockData, PoV};
 	use polkadot_node_subsystem::{AllMessages, TimeoutExt};
@@ -689,6 +691,7 @@ mod tests {
 				req_v1_protocol_name: "/req_chunk/1".into(),
 				req_v2_protocol_name: "/req_chunk/2".into(),
 				chunk_mapping_enabled: true,
+				erasure_coding_backend: ErasureCodingBackend::Sequential,
 				erasure_task_tx,
 			}
 		}
@@ -113,7 +113,8 @@ impl FetchSystematicChunks {
 			.map(|(_, chunk)| chunk.chunk.clone())
 			.collect::<Vec<_>>();
 
-		let available_data = polkadot_erasure_coding::reconstruct_from_systematic_v1(
+		let available_data = polkadot_erasure_coding::reconstruct_from_systematic_with_backend(
+			common_params.erasure_coding_backend,
 			common_params.n_validators,
 			chunks,
 		);
@@ -724,7 +724,14 @@ fn map_chunks(
 #[rstest]
 #[case(true)]
 #[case(false)]
-fn availability_is_recovered_from_chunks_if_no_group_provided(#[case] systematic_recovery: bool) {
+fn availability_is_recovered_from_chunks_if_no_group_provided(
+	#[case] systematic_recovery: bool,
+	#[values(
+		ErasureCodingBackend::Sequential,
+		ErasureCodingBackend::Parallel(NonZeroUsize::new(2).unwrap())
+	)]
+	erasure_coding_backend: ErasureCodingBackend,
+) {
 	let test_state = TestState::default();
 	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
 	let (subsystem, threshold) = match systematic_recovery {
@@ -745,6 +752,7 @@ fn availability_is_recovered_from_chunks_if_no_group_provided(#[case] systematic
 			test_state.threshold(),
 		),
 	};
+	let subsystem = subsystem.with_erasure_coding_backend(erasure_coding_backend);
 
 	test_harness(subsystem, |mut virtual_overseer| async move {
 		overseer_signal(
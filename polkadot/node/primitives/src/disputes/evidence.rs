@@ -0,0 +1,230 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! `DisputeEvidence` and associated types.
+//!
+//! A `DisputeEvidence` bundles everything a node knows about a dispute: the candidate, all votes
+//! with their signatures, the session the votes have to be checked against and, if it could be
+//! recovered, the available data of the candidate. The exporting validator signs the bundle.
+//!
+//! The bundle carries the session info, but it is not trusted: the resulting
+//! `SignedDisputeEvidence` is checked against the validators of the session, which the verifier
+//! has to obtain on its own, e.g. from the `session_info` runtime API of a finalized block.
+
+use thiserror::Error;
+
+use codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_application_crypto::{AppCrypto, RuntimeAppPublic};
+use sp_keystore::{Error as KeystoreError, KeystorePtr};
+
+use super::{DisputeStatus, InvalidDisputeVote, ValidDisputeVote};
+use crate::AvailableData;
+use polkadot_primitives::{
+	CandidateHash, CandidateReceiptV2 as CandidateReceipt, DisputeStatement, SessionIndex,
+	SessionInfo, ValidatorId, ValidatorIndex, ValidatorSignature,
+};
+
+/// Prefix of the payload signed by the exporting validator, so the signature can't be mistaken
+/// for any other statement.
+const EVIDENCE_SIGNING_CONTEXT: &[u8] = b"DISPUTE_EVIDENCE";
+
+/// How the exporting node took part in the dispute.
+#[derive(Clone, Copy, Encode, Decode, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum LocalParticipation {
+	/// The node has no recorded vote on the candidate.
+	#[codec(index = 0)]
+	NoVote,
+	/// The node voted for the validity of the candidate.
+	#[codec(index = 1)]
+	Valid,
+	/// The node voted against the validity of the candidate.
+	#[codec(index = 2)]
+	Invalid,
+}
+
+/// Everything the dispute coordinator knows about a dispute.
+#[derive(Clone, Encode, Decode, Debug)]
+pub struct DisputeEvidence {
+	/// The session the candidate appears in.
+	pub session_index: SessionIndex,
+
+	/// The candidate being disputed.
+	pub candidate_receipt: CandidateReceipt,
+
+	/// The session info of the dispute session, as known to the exporting node.
+	///
+	/// Only informational, votes are checked against the validators supplied by the verifier.
+	pub session_info: SessionInfo,
+
+	/// The status of the dispute at the time of the export.
+	pub status: DisputeStatus,
+
+	/// All recorded votes for the validity of the candidate.
+	pub valid_votes: Vec<ValidDisputeVote>,
+
+	/// All recorded votes against the validity of the candidate.
+	pub invalid_votes: Vec<InvalidDisputeVote>,
+
+	/// The vote of the exporting node.
+	pub local_participation: LocalParticipation,
+
+	/// The available data of the candidate, if it could be recovered.
+	pub available_data: Option<AvailableData>,
+}
+
+impl DisputeEvidence {
+	/// The hash of the disputed candidate.
+	pub fn candidate_hash(&self) -> CandidateHash {
+		self.candidate_receipt.hash()
+	}
+
+	fn signing_payload(&self) -> Vec<u8> {
+		(EVIDENCE_SIGNING_CONTEXT, self).encode()
+	}
+
+	fn validator_public(&self, index: ValidatorIndex) -> Result<&ValidatorId, Error> {
+		self.session_info.validators.get(index).ok_or(Error::UnknownValidator(index))
+	}
+}
+
+fn validator_public(
+	validators: &[ValidatorId],
+	index: ValidatorIndex,
+) -> Result<&ValidatorId, Error> {
+	validators.get(index.0 as usize).ok_or(Error::UnknownValidator(index))
+}
+
+/// A `DisputeEvidence`, signed by the validator that exported it.
+///
+/// NOTE: This is written to disk and handed over to other parties, any changes need to be
+/// versioned.
+#[derive(Clone, Encode, Decode, Debug)]
+pub struct SignedDisputeEvidence {
+	/// The signed evidence.
+	pub evidence: DisputeEvidence,
+
+	/// The index of the exporting validator in the session of the dispute.
+	pub validator_index: ValidatorIndex,
+
+	/// The signature of the exporting validator over the evidence.
+	pub signature: ValidatorSignature,
+}
+
+/// Things that can be wrong with a `SignedDisputeEvidence`.
+#[derive(Error, Debug, PartialEq)]
+pub enum Error {
+	/// The validators carried by the evidence differ from the validators of the session.
+	#[error("Validators of the evidence do not match the validators of the session")]
+	SessionMismatch,
+
+	/// A validator index is not part of the session of the dispute.
+	#[error("Validator {0:?} is not part of the session")]
+	UnknownValidator(ValidatorIndex),
+
+	/// The signature of the exporting validator did not check out.
+	#[error("Signature of the exporting validator is invalid")]
+	InvalidSignature,
+
+	/// The signature of a vote did not check out.
+	#[error("Vote of validator {0:?} has an invalid signature")]
+	InvalidVote(ValidatorIndex),
+
+	/// The available data does not match the `pov_hash` of the candidate.
+	#[error("PoV of the available data does not match the candidate")]
+	PoVHashMismatch,
+
+	/// The available data does not match the validation data hash of the candidate.
+	#[error("Validation data of the available data does not match the candidate")]
+	ValidationDataHashMismatch,
+}
+
+impl SignedDisputeEvidence {
+	/// Sign `evidence` with the key of the validator at `validator_index` in the session of the
+	/// dispute.
+	///
+	/// Returns `Ok(None)` if the key is not in the keystore.
+	pub fn sign(
+		keystore: &KeystorePtr,
+		evidence: DisputeEvidence,
+		validator_index: ValidatorIndex,
+	) -> Result<Option<Self>, KeystoreError> {
+		let Ok(validator_public) = evidence.validator_public(validator_index) else {
+			return Ok(None);
+		};
+
+		let signature = keystore.sr25519_sign(
+			<ValidatorId as AppCrypto>::ID,
+			validator_public.as_ref(),
+			&evidence.signing_payload(),
+		)?;
+
+		Ok(signature.map(|signature| Self {
+			evidence,
+			validator_index,
+			signature: signature.into(),
+		}))
+	}
+
+	/// Check the signature of the exporting validator, the signatures of all votes and the
+	/// available data against the candidate.
+	///
+	/// `validators` are the validators of the session `self.evidence.session_index`. They must be
+	/// obtained independently of the evidence, since a forged evidence may carry a forged session.
+	pub fn check(&self, validators: &[ValidatorId]) -> Result<(), Error> {
+		let evidence = &self.evidence;
+
+		if evidence.session_info.validators.to_vec() != validators {
+			return Err(Error::SessionMismatch);
+		}
+
+		let exporter = validator_public(validators, self.validator_index)?;
+		if !exporter.verify(&evidence.signing_payload(), &self.signature) {
+			return Err(Error::InvalidSignature);
+		}
+
+		let candidate_hash = evidence.candidate_hash();
+		let valid_votes = evidence.valid_votes.iter().map(|vote| {
+			(vote.validator_index, DisputeStatement::Valid(vote.kind.clone()), &vote.signature)
+		});
+		let invalid_votes = evidence.invalid_votes.iter().map(|vote| {
+			(vote.validator_index, DisputeStatement::Invalid(vote.kind), &vote.signature)
+		});
+		for (validator_index, statement, signature) in valid_votes.chain(invalid_votes) {
+			statement
+				.check_signature(
+					validator_public(validators, validator_index)?,
+					candidate_hash,
+					evidence.session_index,
+					signature,
+				)
+				.map_err(|()| Error::InvalidVote(validator_index))?;
+		}
+
+		if let Some(available_data) = &evidence.available_data {
+			let descriptor = &evidence.candidate_receipt.descriptor;
+			if available_data.pov.hash() != descriptor.pov_hash() {
+				return Err(Error::PoVHashMismatch);
+			}
+			if available_data.validation_data.hash() != descriptor.persisted_validation_data_hash()
+			{
+				return Err(Error::ValidationDataHashMismatch);
+			}
+		}
+
+		Ok(())
+	}
+}
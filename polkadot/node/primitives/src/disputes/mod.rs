@@ -30,6 +30,11 @@ use polkadot_primitives::{
 	ValidDisputeStatementKind, ValidatorId, ValidatorIndex, ValidatorSignature,
 };
 
+/// `DisputeEvidence` and related types.
+mod evidence;
+pub use evidence::{
+	DisputeEvidence, Error as DisputeEvidenceCheckError, LocalParticipation, SignedDisputeEvidence,
+};
 /// `DisputeMessage` and related types.
 mod message;
 pub use message::{DisputeMessage, Error as DisputeMessageCheckError, UncheckedDisputeMessage};
@@ -50,9 +50,16 @@ pub mod approval;
 /// Disputes related types.
 pub mod disputes;
 pub use disputes::{
-	dispute_is_inactive, CandidateVotes, DisputeMessage, DisputeMessageCheckError, DisputeStatus,
-	InvalidDisputeVote, SignedDisputeStatement, Timestamp, UncheckedDisputeMessage,
-	ValidDisputeVote, ACTIVE_DURATION_SECS,
+	dispute_is_inactive, CandidateVotes, DisputeEvidence, DisputeEvidenceCheckError,
+	DisputeMessage, DisputeMessageCheckError, DisputeStatus, InvalidDisputeVote,
+	LocalParticipation, SignedDisputeEvidence, SignedDisputeStatement, Timestamp,
+	UncheckedDisputeMessage, ValidDisputeVote, ACTIVE_DURATION_SECS,
+};
+
+/// Prospective parachains introspection types.
+pub mod prospective_parachains;
+pub use prospective_parachains::{
+	ExclusionReason, FragmentChainIntrospection, IntrospectedCandidate,
 };
 
 /// The current node version, which takes the basic SemVer form `<major>.<minor>.<patch>`.
@@ -0,0 +1,86 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Introspection of the fragment chains kept by the prospective parachains subsystem.
+//!
+//! A `FragmentChainIntrospection` is a snapshot of the fragment chain of one para under one
+//! active leaf: the backable chain, the candidates which are known but not part of it, and why.
+
+use codec::{Decode, Encode};
+use polkadot_primitives::{async_backing::Constraints, CandidateHash, Hash, Id as ParaId};
+use serde::{Deserialize, Serialize};
+
+/// A candidate known to a fragment chain.
+#[derive(Clone, Encode, Decode, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct IntrospectedCandidate {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The relay parent of the candidate.
+	pub relay_parent: Hash,
+	/// The scheduling parent of the candidate.
+	pub scheduling_parent: Hash,
+	/// The hash of the head data the candidate builds on.
+	pub parent_head_data_hash: Hash,
+	/// The hash of the head data the candidate outputs.
+	pub output_head_data_hash: Hash,
+	/// Whether the candidate was backed by its group.
+	pub backed: bool,
+	/// Whether the candidate is pending availability on chain.
+	pub pending_availability: bool,
+}
+
+/// Why a candidate is not part of the backable chain.
+#[derive(Clone, Encode, Decode, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum ExclusionReason {
+	/// The candidate was seconded but it is not backed yet.
+	#[codec(index = 0)]
+	NotBacked,
+	/// The head data the candidate builds on is not output by the chain or by the latest included
+	/// candidate.
+	#[codec(index = 1)]
+	UnknownParent,
+	/// The candidate forks off the chain, where the fork selection rule favours the given
+	/// candidate.
+	#[codec(index = 2)]
+	Fork(CandidateHash),
+	/// The candidate builds on the chain, which already has the maximum backable length.
+	#[codec(index = 3)]
+	ChainFull,
+	/// The candidate builds on the chain, but did not pass the checks to be appended to it.
+	#[codec(index = 4)]
+	NotSelected,
+	/// The candidate was dropped from the fragment chain, for the given reason.
+	#[codec(index = 5)]
+	Rejected(String),
+}
+
+/// A snapshot of the fragment chain of a para under an active leaf.
+#[derive(Clone, Encode, Decode, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FragmentChainIntrospection {
+	/// The active leaf the fragment chain is built under.
+	pub leaf: Hash,
+	/// The para the fragment chain belongs to.
+	pub para_id: ParaId,
+	/// The constraints the chain builds on, as taken from the backing state of the para at the
+	/// leaf.
+	pub constraints: Constraints,
+	/// The backable chain, in order. Starts with the candidates pending availability.
+	pub chain: Vec<IntrospectedCandidate>,
+	/// The candidates which may become part of the chain in the future.
+	pub unconnected: Vec<(IntrospectedCandidate, ExclusionReason)>,
+	/// The candidates recently dropped from the fragment chain.
+	pub rejected: Vec<(CandidateHash, ExclusionReason)>,
+}
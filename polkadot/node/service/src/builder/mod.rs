@@ -47,6 +47,7 @@ use polkadot_node_network_protocol::{
 	request_response::{IncomingRequest, ReqProtocolNames},
 };
 use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use polkadot_node_xcm_topic_indexer::XcmTopicIndexer;
 use polkadot_overseer::{Handle, OverseerConnector};
 use polkadot_primitives::Block;
 use sc_client_api::Backend;
@@ -88,8 +89,19 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_soft_max_num: Option<usize>,
 	/// An optional absolute number of pvf workers that can be spawned in the pvf prepare pool.
 	pub prepare_workers_hard_max_num: Option<usize>,
+	/// An optional directory where failed candidate validations are dumped.
+	pub pvf_dump_dir: Option<std::path::PathBuf>,
+	/// Whether to pre-warm the artifacts of the current and upcoming validation code.
+	pub pvf_prewarm: bool,
+	/// An optional maximum number of pvf prepare workers busy with pre-warming artifacts.
+	pub pvf_prewarm_workers_max_num: Option<usize>,
+	/// An optional size of the prepared artifacts on disk, in bytes, above which no more
+	/// artifacts are pre-warmed.
+	pub pvf_prewarm_disk_limit: Option<u64>,
 	/// How long finalized data should be kept in the availability store (in hours)
 	pub keep_finalized_for: Option<u32>,
+	/// The erasure coding backend used by the availability store and recovery.
+	pub erasure_coding_backend: crate::ErasureCodingBackend,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -160,8 +172,14 @@ where
 			SelectRelayChain::new_longest_chain(basics.backend.clone())
 		};
 
-		let partial_components =
-			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+		// Only validators run the subsystems queried by the parachain RPCs.
+		let rpc_overseer_handle = config.role.is_authority().then(|| overseer_handle.clone());
+		let partial_components = new_partial::<SelectRelayChain<_>>(
+			&mut config,
+			basics,
+			select_chain,
+			rpc_overseer_handle,
+		)?;
 
 		let net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 			&config.network,
@@ -211,7 +229,12 @@ where
 					execute_workers_max_num,
 					prepare_workers_soft_max_num,
 					prepare_workers_hard_max_num,
+					pvf_dump_dir,
+					pvf_prewarm,
+					pvf_prewarm_workers_max_num,
+					pvf_prewarm_disk_limit,
 					keep_finalized_for,
+					erasure_coding_backend,
 					invulnerable_ah_collators,
 					collator_protocol_hold_off,
 					experimental_collator_protocol,
@@ -406,6 +429,12 @@ where
 					pvf_execute_workers_max_num: execute_workers_max_num.unwrap_or(4),
 					pvf_prepare_workers_soft_max_num: prepare_workers_soft_max_num.unwrap_or(1),
 					pvf_prepare_workers_hard_max_num: prepare_workers_hard_max_num.unwrap_or(2),
+					pvf_dump_dir,
+					pvf_prewarm,
+					pvf_prewarm_workers_max_num: pvf_prewarm_workers_max_num
+						.unwrap_or(polkadot_node_core_pvf::DEFAULT_PREWARM_WORKERS_MAX_NUM),
+					pvf_prewarm_disk_limit: pvf_prewarm_disk_limit
+						.unwrap_or(polkadot_node_core_pvf::DEFAULT_PREWARM_DISK_LIMIT),
 				})
 			} else {
 				None
@@ -445,6 +474,7 @@ where
 				} else {
 					KEEP_FINALIZED_FOR_LIVE_NETWORKS
 				},
+				erasure_coding_backend,
 			};
 
 			Some(ExtendedOverseerGenArgs {
@@ -792,7 +822,7 @@ where
 				.spawn_essential_handle()
 				.spawn_blocking("beefy-gadget", None, gadget);
 		}
-		// When offchain indexing is enabled, MMR gadget should also run.
+		// When offchain indexing is enabled, MMR gadget and XCM topic indexer should also run.
 		if is_offchain_indexing_enabled {
 			task_manager.spawn_essential_handle().spawn_blocking(
 				"mmr-gadget",
@@ -803,6 +833,11 @@ where
 					sp_mmr_primitives::INDEXING_PREFIX.to_vec(),
 				),
 			);
+			task_manager.spawn_handle().spawn_blocking(
+				"xcm-topic-indexer",
+				None,
+				XcmTopicIndexer::<Block, _>::start(client.clone()),
+			);
 		}
 
 		let config = sc_consensus_grandpa::Config {
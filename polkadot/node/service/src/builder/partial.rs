@@ -150,6 +150,7 @@ pub(crate) fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	overseer_handle: Option<polkadot_overseer::Handle>,
 ) -> Result<PolkadotPartialComponents<ChainSelection>, Error>
 where
 	ChainSelection: 'static + SelectChain<Block>,
@@ -262,6 +263,9 @@ where
 					beefy_best_block_stream: beefy_rpc_links.from_voter_best_beefy_stream.clone(),
 					subscription_executor,
 				},
+				parachains: overseer_handle
+					.clone()
+					.map(|overseer_handle| polkadot_rpc::ParachainsDeps { overseer_handle }),
 				backend: backend.clone(),
 			};
 
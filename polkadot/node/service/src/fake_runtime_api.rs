@@ -454,4 +454,10 @@ sp_api::impl_runtime_apis! {
 			unimplemented!()
 		}
 	}
+
+	impl xcm_runtime_apis::topic_events::XcmTopicEventsApi<Block> for Runtime {
+		fn topic_events() -> Vec<xcm_runtime_apis::topic_events::XcmTopicEvent> {
+			unimplemented!()
+		}
+	}
 }
@@ -74,6 +74,7 @@ use prometheus_endpoint::Registry;
 use sc_service::SpawnTaskHandle;
 
 pub use chain_spec::{GenericChainSpec, RococoChainSpec, WestendChainSpec};
+pub use polkadot_erasure_coding::Backend as ErasureCodingBackend;
 pub use polkadot_primitives::{Block, BlockId, BlockNumber, CollatorPair, Hash, Id as ParaId};
 pub use sc_client_api::{Backend, CallExecutor};
 pub use sc_consensus::{BlockImport, LongestChain};
@@ -98,6 +99,9 @@ pub use {westend_runtime, westend_runtime_constants};
 
 pub use fake_runtime_api::{GetLastTimestamp, RuntimeApi};
 
+#[cfg(feature = "full-node")]
+pub use parachains_db::inspect as parachains_db_inspect;
+
 #[cfg(feature = "full-node")]
 pub type FullBackend = sc_service::TFullBackend<Block>;
 
@@ -220,6 +224,10 @@ pub enum Error {
 	#[error("Expected at least one of polkadot, kusama, westend or rococo runtime feature")]
 	NoRuntime,
 
+	#[cfg(feature = "full-node")]
+	#[error(transparent)]
+	DatabaseInspection(#[from] parachains_db_inspect::Error),
+
 	#[cfg(feature = "full-node")]
 	#[error("Worker binaries not executable, prepare binary: {prep_worker_path:?}, execute binary: {exec_worker_path:?}")]
 	InvalidWorkerBinaries { prep_worker_path: PathBuf, exec_worker_path: PathBuf },
@@ -345,6 +353,45 @@ pub fn open_database(db_source: &DatabaseSource) -> Result<Arc<dyn Database>, Er
 	Ok(parachains_db)
 }
 
+/// Inspect the records `subsystem` keeps in the parachains DB, without modifying the database.
+///
+/// The database has to be at the current version, it is never upgraded.
+#[cfg(feature = "full-node")]
+pub fn inspect_database(
+	db_source: &DatabaseSource,
+	subsystem: parachains_db_inspect::Subsystem,
+	filter: &parachains_db_inspect::Filter,
+) -> Result<parachains_db_inspect::Report, Error> {
+	// RocksDB is opened as a secondary instance, which needs a directory of its own.
+	let secondary_path =
+		std::env::temp_dir().join(format!("polkadot-db-inspect-{}", std::process::id()));
+
+	let parachains_db = match db_source {
+		DatabaseSource::RocksDb { path, .. } => {
+			parachains_db::open_read_only_rocksdb(path.clone(), secondary_path.clone())?
+		},
+		DatabaseSource::ParityDb { path, .. } => parachains_db::open_read_only_paritydb(
+			path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+		)?,
+		DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } => {
+			if paritydb_path.is_dir() && paritydb_path.exists() {
+				parachains_db::open_read_only_paritydb(
+					paritydb_path.parent().ok_or(Error::DatabasePathRequired)?.into(),
+				)?
+			} else {
+				parachains_db::open_read_only_rocksdb(rocksdb_path.clone(), secondary_path.clone())?
+			}
+		},
+		DatabaseSource::Custom { .. } => {
+			unimplemented!("No polkadot subsystem db for custom source.");
+		},
+	};
+
+	let report = parachains_db_inspect::inspect(parachains_db, subsystem, filter);
+	let _ = std::fs::remove_dir_all(&secondary_path);
+	Ok(report?)
+}
+
 /// Is this node running as in-process node for a parachain node?
 #[cfg(feature = "full-node")]
 #[derive(Clone)]
@@ -400,7 +447,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let sc_service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
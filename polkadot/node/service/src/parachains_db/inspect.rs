@@ -0,0 +1,557 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline inspection of the records the parachain subsystems keep in the parachains DB.
+//!
+//! The records are decoded with the accessors of the subsystems themselves and converted into
+//! plain report structures, which can be serialized to JSON or printed as text.
+
+use super::REAL_COLUMNS;
+use polkadot_node_core_approval_voting::approval_db::{
+	common as approval_db, v3 as approval_db_v3,
+};
+use polkadot_node_core_av_store::inspect as av_store;
+use polkadot_node_core_dispute_coordinator::inspect as dispute_coordinator;
+use polkadot_node_primitives::{CandidateVotes, DisputeStatus};
+use polkadot_node_subsystem_util::database::Database;
+use polkadot_primitives::{BlockNumber, CandidateHash, Hash, SessionIndex};
+use serde::Serialize;
+use std::{collections::BTreeSet, fmt, sync::Arc};
+
+/// The subsystem whose records should be inspected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+	/// The availability store.
+	AvailabilityStore,
+	/// Approval voting.
+	ApprovalVoting,
+	/// The dispute coordinator.
+	DisputeCoordinator,
+}
+
+impl fmt::Display for Subsystem {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::AvailabilityStore => write!(f, "av-store"),
+			Self::ApprovalVoting => write!(f, "approval-voting"),
+			Self::DisputeCoordinator => write!(f, "dispute-coordinator"),
+		}
+	}
+}
+
+/// A relay chain block, by number or by hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockFilter {
+	/// The blocks at the given height.
+	Number(BlockNumber),
+	/// The block with the given hash.
+	Hash(Hash),
+}
+
+impl BlockFilter {
+	fn matches(&self, number: BlockNumber, hash: &Hash) -> bool {
+		match self {
+			Self::Number(n) => *n == number,
+			Self::Hash(h) => h == hash,
+		}
+	}
+}
+
+impl std::str::FromStr for BlockFilter {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.starts_with("0x") {
+			s.parse()
+				.map(Self::Hash)
+				.map_err(|e| format!("Invalid block hash {}: {:?}", s, e))
+		} else {
+			s.parse()
+				.map(Self::Number)
+				.map_err(|e| format!("Invalid block number {}: {}", s, e))
+		}
+	}
+}
+
+/// Restricts the records which are reported. Unset filters match all records.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+	/// Only report records related to this relay chain block.
+	pub block: Option<BlockFilter>,
+	/// Only report records related to this candidate.
+	pub candidate: Option<CandidateHash>,
+	/// Only report records related to this session.
+	pub session: Option<SessionIndex>,
+}
+
+/// Errors while inspecting the parachains DB.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Failed to read the availability store: {0}")]
+	AvailabilityStore(#[from] polkadot_node_core_av_store::Error),
+
+	#[error("Failed to read the approval voting records: {0}")]
+	ApprovalVoting(#[from] polkadot_overseer::SubsystemError),
+
+	#[error("Failed to read the dispute coordinator records: {0}")]
+	DisputeCoordinator(#[from] dispute_coordinator::Error),
+
+	#[error("The {subsystem} records can't be filtered by {filter}")]
+	UnsupportedFilter { subsystem: Subsystem, filter: &'static str },
+}
+
+/// The inspected records of one subsystem.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all_fields = "camelCase", tag = "subsystem")]
+pub enum Report {
+	/// Records of the availability store.
+	#[serde(rename = "av-store")]
+	AvailabilityStore { candidates: Vec<AvailabilityCandidate> },
+	/// Records of approval voting.
+	#[serde(rename = "approval-voting")]
+	ApprovalVoting { blocks: Vec<ApprovalBlock>, candidates: Vec<ApprovalCandidate> },
+	/// Records of the dispute coordinator.
+	#[serde(rename = "dispute-coordinator")]
+	DisputeCoordinator {
+		earliest_session: Option<SessionIndex>,
+		disputes: Vec<Dispute>,
+		votes: Vec<DisputeVotes>,
+	},
+}
+
+/// The meta record of a candidate in the availability store, along with its stored chunks.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailabilityCandidate {
+	pub candidate_hash: Hash,
+	/// One of `unavailable`, `unfinalized` or `finalized`.
+	pub state: &'static str,
+	/// Unix time in seconds at which the candidate was first seen or finalized.
+	pub since: u64,
+	/// The unfinalized blocks the candidate is included in.
+	pub included_in: Vec<(BlockNumber, Hash)>,
+	pub data_available: bool,
+	pub chunks: Vec<AvailabilityChunk>,
+}
+
+/// A chunk stored in the availability store.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailabilityChunk {
+	pub validator_index: u32,
+	pub chunk_index: u32,
+	pub size: usize,
+}
+
+/// A block entry of approval voting.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalBlock {
+	pub block_hash: Hash,
+	pub block_number: BlockNumber,
+	pub parent_hash: Hash,
+	pub session: SessionIndex,
+	pub slot: u64,
+	/// The candidates included in the block, with the core they occupied and whether they
+	/// are approved in the context of the block.
+	pub candidates: Vec<ApprovalBlockCandidate>,
+	pub children: Vec<Hash>,
+}
+
+/// A candidate included in an [`ApprovalBlock`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalBlockCandidate {
+	pub candidate_hash: Hash,
+	pub core_index: u32,
+	pub approved: bool,
+}
+
+/// A candidate entry of approval voting.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalCandidate {
+	pub candidate_hash: Hash,
+	pub para_id: u32,
+	pub session: SessionIndex,
+	/// The validators which approved the candidate.
+	pub approvals: Vec<u32>,
+	/// The assignments of the candidate, per block it is included in.
+	pub assignments: Vec<ApprovalAssignments>,
+}
+
+/// The assignments to a candidate in the context of one block.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApprovalAssignments {
+	pub block_hash: Hash,
+	pub backing_group: u32,
+	pub approved: bool,
+	/// The tranche of our own assignment and whether it was triggered.
+	pub our_assignment: Option<(u32, bool)>,
+	/// The assigned validators per tranche.
+	pub tranches: Vec<(u32, Vec<u32>)>,
+}
+
+/// A dispute known to the dispute coordinator.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dispute {
+	pub session: SessionIndex,
+	pub candidate_hash: Hash,
+	/// One of `active`, `confirmed`, `concludedFor` or `concludedAgainst`.
+	pub status: &'static str,
+	/// Unix time in seconds at which the dispute concluded.
+	pub concluded_at: Option<u64>,
+}
+
+/// The votes on a candidate known to the dispute coordinator.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeVotes {
+	pub session: SessionIndex,
+	pub candidate_hash: Hash,
+	pub para_id: u32,
+	pub valid: Vec<u32>,
+	pub invalid: Vec<u32>,
+}
+
+/// Inspect the records of `subsystem` in `db`, restricted to the ones matching `filter`.
+pub fn inspect(
+	db: Arc<dyn Database>,
+	subsystem: Subsystem,
+	filter: &Filter,
+) -> Result<Report, Error> {
+	match subsystem {
+		Subsystem::AvailabilityStore => inspect_av_store(&db, filter),
+		Subsystem::ApprovalVoting => inspect_approval_voting(&*db, filter),
+		Subsystem::DisputeCoordinator => inspect_dispute_coordinator(&*db, filter),
+	}
+}
+
+fn inspect_av_store(db: &Arc<dyn Database>, filter: &Filter) -> Result<Report, Error> {
+	if filter.session.is_some() {
+		return Err(Error::UnsupportedFilter {
+			subsystem: Subsystem::AvailabilityStore,
+			filter: "session",
+		});
+	}
+
+	let config = polkadot_node_core_av_store::Config {
+		col_data: REAL_COLUMNS.col_availability_data,
+		col_meta: REAL_COLUMNS.col_availability_meta,
+		keep_finalized_for: 0,
+		erasure_coding_backend: Default::default(),
+	};
+
+	let records = match filter.candidate {
+		Some(candidate_hash) => {
+			av_store::load_candidate(db, &config, &candidate_hash)?.into_iter().collect()
+		},
+		None => av_store::load_candidates(db, &config)?,
+	};
+
+	let mut candidates = Vec::new();
+	for record in records {
+		let (state, since, included_in) = match &record.state {
+			av_store::CandidateState::Unavailable(at) => ("unavailable", at, Vec::new()),
+			av_store::CandidateState::Unfinalized(at, blocks) => {
+				("unfinalized", at, blocks.clone())
+			},
+			av_store::CandidateState::Finalized(at) => ("finalized", at, Vec::new()),
+		};
+
+		// Finalized candidates are no longer tracked per block.
+		if let Some(block) = filter.block {
+			if !included_in.iter().any(|(number, hash)| block.matches(*number, hash)) {
+				continue;
+			}
+		}
+
+		let chunks = av_store::load_chunks(db, &config, &record)?
+			.into_iter()
+			.map(|(validator_index, chunk)| AvailabilityChunk {
+				validator_index: validator_index.0,
+				chunk_index: chunk.index.0,
+				size: chunk.chunk.len(),
+			})
+			.collect();
+
+		candidates.push(AvailabilityCandidate {
+			candidate_hash: record.candidate_hash.0,
+			state,
+			since: since.as_secs(),
+			included_in,
+			data_available: record.data_available,
+			chunks,
+		});
+	}
+
+	Ok(Report::AvailabilityStore { candidates })
+}
+
+fn inspect_approval_voting(db: &dyn Database, filter: &Filter) -> Result<Report, Error> {
+	let config = approval_db::Config { col_approval_data: REAL_COLUMNS.col_approval_data };
+
+	let block_hashes = match filter.block {
+		Some(BlockFilter::Hash(hash)) => vec![hash],
+		Some(BlockFilter::Number(number)) => {
+			approval_db::load_blocks_at_height(db, &config, &number)?
+		},
+		None => approval_db::load_all_blocks(db, &config)?,
+	};
+
+	let mut blocks = Vec::new();
+	let mut candidate_hashes = BTreeSet::new();
+	for block_hash in block_hashes {
+		let Some(entry) = approval_db::load_block_entry(db, &config, &block_hash)? else {
+			continue;
+		};
+		if filter.session.map_or(false, |session| session != entry.session) {
+			continue;
+		}
+		if filter
+			.candidate
+			.map_or(false, |c| !entry.candidates.iter().any(|(_, hash)| *hash == c))
+		{
+			continue;
+		}
+
+		candidate_hashes.extend(entry.candidates.iter().map(|(_, hash)| *hash));
+		blocks.push(approval_block(entry));
+	}
+
+	// A candidate can still be looked up when none of the blocks it is included in are known.
+	if let Some(candidate_hash) = filter.candidate {
+		candidate_hashes = [candidate_hash].into();
+	}
+
+	let mut candidates = Vec::new();
+	for candidate_hash in candidate_hashes {
+		let Some(entry) = approval_db::load_candidate_entry(db, &config, &candidate_hash)? else {
+			continue;
+		};
+		if filter.session.map_or(false, |session| session != entry.session) {
+			continue;
+		}
+		candidates.push(approval_candidate(candidate_hash, entry));
+	}
+
+	Ok(Report::ApprovalVoting { blocks, candidates })
+}
+
+fn approval_block(entry: approval_db_v3::BlockEntry) -> ApprovalBlock {
+	ApprovalBlock {
+		block_hash: entry.block_hash,
+		block_number: entry.block_number,
+		parent_hash: entry.parent_hash,
+		session: entry.session,
+		slot: entry.slot.into(),
+		candidates: entry
+			.candidates
+			.iter()
+			.enumerate()
+			.map(|(i, (core_index, candidate_hash))| ApprovalBlockCandidate {
+				candidate_hash: candidate_hash.0,
+				core_index: core_index.0,
+				approved: entry.approved_bitfield.get(i).map_or(false, |b| *b),
+			})
+			.collect(),
+		children: entry.children,
+	}
+}
+
+fn approval_candidate(
+	candidate_hash: CandidateHash,
+	entry: approval_db_v3::CandidateEntry,
+) -> ApprovalCandidate {
+	ApprovalCandidate {
+		candidate_hash: candidate_hash.0,
+		para_id: entry.candidate.descriptor.para_id().into(),
+		session: entry.session,
+		approvals: entry.approvals.iter_ones().map(|i| i as u32).collect(),
+		assignments: entry
+			.block_assignments
+			.into_iter()
+			.map(|(block_hash, approval_entry)| ApprovalAssignments {
+				block_hash,
+				backing_group: approval_entry.backing_group.0,
+				approved: approval_entry.approved,
+				our_assignment: approval_entry
+					.our_assignment
+					.map(|assignment| (assignment.tranche, assignment.triggered)),
+				tranches: approval_entry
+					.tranches
+					.into_iter()
+					.map(|tranche| {
+						(
+							tranche.tranche,
+							tranche.assignments.into_iter().map(|(v, _)| v.0).collect(),
+						)
+					})
+					.collect(),
+			})
+			.collect(),
+	}
+}
+
+fn inspect_dispute_coordinator(db: &dyn Database, filter: &Filter) -> Result<Report, Error> {
+	if filter.block.is_some() {
+		return Err(Error::UnsupportedFilter {
+			subsystem: Subsystem::DisputeCoordinator,
+			filter: "block",
+		});
+	}
+
+	let config = polkadot_node_core_dispute_coordinator::Config {
+		col_dispute_data: REAL_COLUMNS.col_dispute_coordinator_data,
+	};
+
+	let earliest_session = dispute_coordinator::load_earliest_session(db, &config)?;
+	let matches = |session: SessionIndex, candidate_hash: &CandidateHash| {
+		filter.session.map_or(true, |s| s == session) &&
+			filter.candidate.map_or(true, |c| c == *candidate_hash)
+	};
+
+	let recent_disputes = dispute_coordinator::load_recent_disputes(db, &config)?;
+	let disputes = recent_disputes
+		.iter()
+		.filter(|((session, candidate_hash), _)| matches(*session, candidate_hash))
+		.map(|((session, candidate_hash), status)| {
+			let (status, concluded_at) = match status {
+				DisputeStatus::Active => ("active", None),
+				DisputeStatus::Confirmed => ("confirmed", None),
+				DisputeStatus::ConcludedFor(at) => ("concludedFor", Some(*at)),
+				DisputeStatus::ConcludedAgainst(at) => ("concludedAgainst", Some(*at)),
+			};
+			Dispute { session: *session, candidate_hash: candidate_hash.0, status, concluded_at }
+		})
+		.collect();
+
+	// Votes are keyed by session, so without a session filter only the votes of recent
+	// disputes are reported.
+	let votes = match filter.session {
+		Some(session) => dispute_coordinator::load_session_votes(db, &config, session)?
+			.into_iter()
+			.filter(|(candidate_hash, _)| matches(session, candidate_hash))
+			.map(|(candidate_hash, votes)| dispute_votes(session, candidate_hash, votes))
+			.collect(),
+		None => {
+			let mut votes = Vec::new();
+			for (session, candidate_hash) in recent_disputes.keys() {
+				if !matches(*session, candidate_hash) {
+					continue;
+				}
+				if let Some(v) = dispute_coordinator::load_candidate_votes(
+					db,
+					&config,
+					*session,
+					candidate_hash,
+				)? {
+					votes.push(dispute_votes(*session, *candidate_hash, v));
+				}
+			}
+			votes
+		},
+	};
+
+	Ok(Report::DisputeCoordinator { earliest_session, disputes, votes })
+}
+
+fn dispute_votes(
+	session: SessionIndex,
+	candidate_hash: CandidateHash,
+	votes: CandidateVotes,
+) -> DisputeVotes {
+	DisputeVotes {
+		session,
+		candidate_hash: candidate_hash.0,
+		para_id: votes.candidate_receipt.descriptor.para_id().into(),
+		valid: votes.valid.keys().map(|v| v.0).collect(),
+		invalid: votes.invalid.keys().map(|v| v.0).collect(),
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::AvailabilityStore { candidates } => {
+				for c in candidates {
+					writeln!(
+						f,
+						"candidate {:?}: {} since {}, data available: {}, included in: {:?}",
+						c.candidate_hash, c.state, c.since, c.data_available, c.included_in,
+					)?;
+					for chunk in &c.chunks {
+						writeln!(
+							f,
+							"  chunk {} of validator {}: {} bytes",
+							chunk.chunk_index, chunk.validator_index, chunk.size,
+						)?;
+					}
+				}
+			},
+			Self::ApprovalVoting { blocks, candidates } => {
+				for b in blocks {
+					writeln!(
+						f,
+						"block #{} {:?}: session {}, slot {}, parent {:?}",
+						b.block_number, b.block_hash, b.session, b.slot, b.parent_hash,
+					)?;
+					for c in &b.candidates {
+						writeln!(
+							f,
+							"  core {}: candidate {:?}, approved: {}",
+							c.core_index, c.candidate_hash, c.approved,
+						)?;
+					}
+				}
+				for c in candidates {
+					writeln!(
+						f,
+						"candidate {:?}: para {}, session {}, approvals: {:?}",
+						c.candidate_hash, c.para_id, c.session, c.approvals,
+					)?;
+					for a in &c.assignments {
+						writeln!(
+							f,
+							"  in block {:?}: backing group {}, approved: {}, our assignment: {:?}, tranches: {:?}",
+							a.block_hash, a.backing_group, a.approved, a.our_assignment, a.tranches,
+						)?;
+					}
+				}
+			},
+			Self::DisputeCoordinator { earliest_session, disputes, votes } => {
+				writeln!(f, "earliest session: {:?}", earliest_session)?;
+				for d in disputes {
+					writeln!(
+						f,
+						"dispute in session {} on candidate {:?}: {}",
+						d.session, d.candidate_hash, d.status,
+					)?;
+				}
+				for v in votes {
+					writeln!(
+						f,
+						"votes in session {} on candidate {:?} of para {}: valid {:?}, invalid {:?}",
+						v.session, v.candidate_hash, v.para_id, v.valid, v.invalid,
+					)?;
+				}
+			},
+		}
+		Ok(())
+	}
+}
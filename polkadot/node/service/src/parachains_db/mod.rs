@@ -18,6 +18,8 @@ use {
 	polkadot_node_subsystem_util::database::Database, std::io, std::path::PathBuf, std::sync::Arc,
 };
 
+#[cfg(feature = "full-node")]
+pub mod inspect;
 #[cfg(feature = "full-node")]
 mod upgrade;
 
@@ -190,3 +192,45 @@ pub fn open_creating_paritydb(
 	);
 	Ok(Arc::new(db))
 }
+
+/// Open an existing database for reading only.
+///
+/// RocksDB only allows a single process to open a database for writing, so the database is
+/// opened as a secondary instance, which keeps its own info logs in `secondary_path`.
+#[cfg(feature = "full-node")]
+pub fn open_read_only_rocksdb(
+	root: PathBuf,
+	secondary_path: PathBuf,
+) -> io::Result<Arc<dyn Database>> {
+	use kvdb_rocksdb::{Database, DatabaseConfig};
+
+	let path = root.join("parachains").join("db");
+	upgrade::ensure_current_version(&path, DatabaseKind::RocksDB)?;
+
+	let mut db_config = DatabaseConfig::with_columns(columns::v6::NUM_COLUMNS);
+	db_config.secondary = Some(secondary_path);
+
+	let db = Database::open(&db_config, &path)?;
+	let db = polkadot_node_subsystem_util::database::kvdb_impl::DbAdapter::new(
+		db,
+		columns::v6::ORDERED_COL,
+	);
+
+	Ok(Arc::new(db))
+}
+
+/// Open an existing parity db database for reading only.
+#[cfg(feature = "full-node")]
+pub fn open_read_only_paritydb(root: PathBuf) -> io::Result<Arc<dyn Database>> {
+	let path = root.join("parachains");
+	upgrade::ensure_current_version(&path, DatabaseKind::ParityDB)?;
+
+	let db = parity_db::Db::open_read_only(&upgrade::paritydb_version_6_config(&path))
+		.map_err(|err| io::Error::new(io::ErrorKind::Other, format!("{:?}", err)))?;
+
+	let db = polkadot_node_subsystem_util::database::paritydb_impl::DbAdapter::new(
+		db,
+		columns::v6::ORDERED_COL,
+	);
+	Ok(Arc::new(db))
+}
@@ -55,6 +55,8 @@ pub enum Error {
 	MigrationFailed,
 	#[error("Parachain DB migration would take forever")]
 	MigrationLoop,
+	#[error("Parachains DB has an outdated version (expected {current:?}, found {got:?})")]
+	OutdatedVersion { current: Version, got: Option<Version> },
 }
 
 impl From<Error> for io::Error {
@@ -93,6 +95,19 @@ pub(crate) fn try_upgrade_db(
 	Err(Error::MigrationLoop)
 }
 
+/// Check that the database has the current version, without upgrading it.
+pub(crate) fn ensure_current_version(db_path: &Path, db_kind: DatabaseKind) -> Result<(), Error> {
+	match get_db_version(db_path)? {
+		Some(CURRENT_VERSION) => Ok(()),
+		// RocksDB databases created before versioning was introduced have no version file.
+		None if db_kind == DatabaseKind::RocksDB => Ok(()),
+		Some(v) if v > CURRENT_VERSION => {
+			Err(Error::FutureVersion { current: CURRENT_VERSION, got: v })
+		},
+		got => Err(Error::OutdatedVersion { current: CURRENT_VERSION, got }),
+	}
+}
+
 /// Try upgrading parachain's database to the next version.
 /// If successful, it returns the current version.
 pub(crate) fn try_upgrade_db_to_next_version(
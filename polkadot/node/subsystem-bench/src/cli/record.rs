@@ -0,0 +1,112 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Records a network trace from the Prometheus endpoint of a running node.
+
+use color_eyre::eyre;
+use colored::Colorize;
+use http_body_util::BodyExt;
+use hyper::Uri;
+use hyper_util::{client::legacy::Client, rt::TokioExecutor};
+use polkadot_subsystem_bench::network_trace::TraceRecorder;
+use std::{path::PathBuf, time::Duration};
+
+const LOG_TARGET: &str = "subsystem-bench::record";
+
+#[derive(Debug, clap::Args)]
+pub struct RecordNetworkTraceOptions {
+	/// URL of the Prometheus endpoint of the node to record
+	#[clap(long, default_value_t = String::from("http://localhost:9615/metrics"))]
+	pub metrics_url: String,
+
+	/// Label identifying the peer in the recorded metrics
+	#[clap(long, default_value_t = String::from("peer"))]
+	pub peer_label: String,
+
+	/// Counter of the bytes exchanged with each peer
+	#[clap(long)]
+	pub bandwidth_metric: String,
+
+	/// Gauge or histogram of the round trip time to each peer, in seconds
+	#[clap(long)]
+	pub latency_metric: String,
+
+	/// Time between two samples, in milliseconds
+	#[clap(long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(1..))]
+	pub interval_ms: u64,
+
+	/// For how long to record, in seconds
+	#[clap(long, default_value_t = 600)]
+	pub duration_secs: u64,
+
+	/// Path of the trace file to write
+	#[clap(long)]
+	pub output: PathBuf,
+}
+
+impl RecordNetworkTraceOptions {
+	pub fn run(self) -> eyre::Result<()> {
+		let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+		runtime.block_on(self.record())
+	}
+
+	async fn record(self) -> eyre::Result<()> {
+		let uri = Uri::try_from(self.metrics_url.as_str())?;
+		let client = Client::builder(TokioExecutor::new())
+			.build_http::<http_body_util::Full<hyper::body::Bytes>>();
+		let interval = Duration::from_millis(self.interval_ms);
+		let mut recorder = TraceRecorder::new(
+			interval,
+			self.peer_label,
+			self.bandwidth_metric,
+			self.latency_metric,
+		);
+
+		gum::info!(
+			target: LOG_TARGET,
+			"{}",
+			format!("Recording network trace from {uri} for {}s", self.duration_secs).bright_blue()
+		);
+
+		// One more scrape than samples, the first one is the baseline of the counters.
+		let n_scrapes = self.duration_secs * 1000 / self.interval_ms + 1;
+		let mut ticks = tokio::time::interval(interval);
+		for _ in 0..n_scrapes {
+			ticks.tick().await;
+			let body = client.get(uri.clone()).await?.into_body().collect().await?.to_bytes();
+			let body = String::from_utf8(body.to_vec())?;
+			let scrape =
+				prometheus_parse::Scrape::parse(body.lines().map(|line| Ok(line.to_owned())))?;
+			recorder.observe(&scrape.samples);
+		}
+
+		let trace = recorder.finish();
+		trace.to_file(&self.output)?;
+		gum::info!(
+			target: LOG_TARGET,
+			"{}",
+			format!(
+				"Recorded {} samples of {} peers to {}",
+				trace.len(),
+				trace.peers.len(),
+				self.output.display()
+			)
+			.bright_blue()
+		);
+
+		Ok(())
+	}
+}
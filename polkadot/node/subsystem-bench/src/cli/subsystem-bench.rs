@@ -20,12 +20,15 @@
 use clap::Parser;
 use color_eyre::eyre;
 use colored::Colorize;
-use polkadot_subsystem_bench::{approval, availability, configuration, disputes, statement};
+use polkadot_subsystem_bench::{
+	approval, availability, collator_protocol, configuration, disputes, statement,
+};
 use pyroscope::PyroscopeAgent;
 use pyroscope_pprofrs::{pprof_backend, PprofConfig};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+mod record;
 mod valgrind;
 
 const LOG_TARGET: &str = "subsystem-bench::cli";
@@ -44,6 +47,8 @@ pub enum TestObjective {
 	StatementDistribution,
 	/// Benchmark the dispute-coordinator subsystem
 	DisputeCoordinator(disputes::DisputesOptions),
+	/// Benchmark the validator side of the collator-protocol subsystem
+	CollatorProtocol(collator_protocol::CollatorProtocolOptions),
 }
 
 impl std::fmt::Display for TestObjective {
@@ -57,6 +62,7 @@ impl std::fmt::Display for TestObjective {
 				Self::ApprovalVoting(_) => "ApprovalVoting",
 				Self::StatementDistribution => "StatementDistribution",
 				Self::DisputeCoordinator(_) => "DisputeCoordinator",
+				Self::CollatorProtocol(_) => "CollatorProtocol",
 			}
 		)
 	}
@@ -85,9 +91,20 @@ impl TestSequence {
 	}
 }
 
+/// Commands other than running a test sequence
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+	/// Record a network trace from a running node, to be replayed by the tests
+	RecordNetworkTrace(record::RecordNetworkTraceOptions),
+}
+
 #[derive(Debug, Parser)]
 #[allow(missing_docs)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct BenchCli {
+	#[command(subcommand)]
+	pub command: Option<Command>,
+
 	#[clap(long, default_value_t = false)]
 	/// Enable CPU Profiling with Pyroscope
 	pub profile: bool,
@@ -106,11 +123,16 @@ struct BenchCli {
 
 	#[arg(required = true)]
 	/// Path to the test sequence configuration file
-	pub path: String,
+	pub path: Option<String>,
 }
 
 impl BenchCli {
 	fn launch(self) -> eyre::Result<()> {
+		if let Some(Command::RecordNetworkTrace(options)) = self.command {
+			return options.run();
+		}
+		let path = self.path.expect("required unless a command is given");
+
 		let is_valgrind_running = valgrind::is_valgrind_running();
 		if !is_valgrind_running && self.cache_misses {
 			return valgrind::relaunch_in_valgrind_mode();
@@ -126,7 +148,7 @@ impl BenchCli {
 			None
 		};
 
-		let test_sequence = TestSequence::new_from_file(Path::new(&self.path))
+		let test_sequence = TestSequence::new_from_file(Path::new(&path))
 			.expect("File exists")
 			.test_configurations;
 		let num_steps = test_sequence.len();
@@ -135,7 +157,7 @@ impl BenchCli {
 		for (index, CliTestConfiguration { objective, mut test_config }) in
 			test_sequence.into_iter().enumerate()
 		{
-			let benchmark_name = format!("{} #{} {}", &self.path, index + 1, objective);
+			let benchmark_name = format!("{} #{} {}", &path, index + 1, objective);
 			gum::info!(target: LOG_TARGET, "{}", format!("Step {}/{}", index + 1, num_steps).bright_purple(),);
 			gum::info!(target: LOG_TARGET, "[{}] {}", format!("objective = {objective:?}").green(), test_config);
 			test_config.generate_pov_sizes();
@@ -178,6 +200,12 @@ impl BenchCli {
 					env.runtime()
 						.block_on(disputes::benchmark_dispute_coordinator(&mut env, &state))
 				},
+				TestObjective::CollatorProtocol(ref options) => {
+					let state = collator_protocol::TestState::new(&test_config, options);
+					let mut env = collator_protocol::prepare_test(&state, true);
+					env.runtime()
+						.block_on(collator_protocol::benchmark_collator_protocol(&mut env, &state))
+				},
 			};
 			println!("\n{}\n{}", benchmark_name.purple(), usage);
 		}
@@ -662,11 +662,12 @@ impl PeerMessageProducer {
 					message.clone().split_by_peer_id(&self.state.test_authorities)
 				{
 					for message in messages {
-						self.state
-							.total_sent_messages_to_node
-							.as_ref()
-							.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-						self.queue_message_from_peer(message, peer.0)
+						if self.queue_message_from_peer(message, peer.0) {
+							self.state
+								.total_sent_messages_to_node
+								.as_ref()
+								.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+						}
 					}
 				}
 			}
@@ -714,7 +715,13 @@ impl PeerMessageProducer {
 	}
 
 	// Queue message to be sent by validator `sent_by`
-	fn queue_message_from_peer(&mut self, message: TestMessageInfo, sent_by: ValidatorIndex) {
+	//
+	// Returns `false` if the peer is not connected and the message was dropped.
+	fn queue_message_from_peer(
+		&mut self,
+		message: TestMessageInfo,
+		sent_by: ValidatorIndex,
+	) -> bool {
 		let peer_authority_id = self
 			.state
 			.test_authorities
@@ -728,7 +735,9 @@ impl PeerMessageProducer {
 			protocol_v3::ValidationProtocol::ApprovalDistribution(message.msg).into(),
 		) {
 			gum::warn!(target: LOG_TARGET, ?sent_by, ?err, "Validator can not send message");
+			return false;
 		}
+		true
 	}
 
 	// Queues a message to be sent by the peer identified by the `sent_by` value.
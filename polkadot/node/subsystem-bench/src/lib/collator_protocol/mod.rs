@@ -0,0 +1,267 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmark of the validator side of the collator protocol.
+//!
+//! The node under test is assigned to the first core. Emulated collators of the parachain
+//! scheduled on that core advertise a collation on every relay chain block, which the node
+//! fetches over the emulated network and hands over to the mocked candidate backing.
+
+use crate::{
+	dummy_builder,
+	environment::{TestEnvironment, TestEnvironmentDependencies},
+	mock::{
+		candidate_backing::MockCandidateBacking,
+		chain_api::{ChainApiState, MockChainApi},
+		network_bridge::{MockNetworkBridgeRx, MockNetworkBridgeTx},
+		prospective_parachains::MockProspectiveParachains,
+		runtime_api::{MockRuntimeApi, MockRuntimeApiCoreState},
+		AlwaysSupportsParachains,
+	},
+	network::{
+		new_network_with_collators, NetworkEmulatorHandle, NetworkInterface,
+		NetworkInterfaceReceiver,
+	},
+	usage::BenchmarkUsage,
+	NODE_UNDER_TEST,
+};
+use colored::Colorize;
+use polkadot_collator_protocol::{CollatorProtocolSubsystem, ProtocolSide};
+use polkadot_node_metrics::metrics::Metrics;
+use polkadot_node_network_protocol::{
+	peer_set::CollationVersion, v2 as protocol_v2, CollationProtocols, ObservedRole, OurView, View,
+};
+use polkadot_node_subsystem::messages::{AllMessages, CollatorProtocolMessage, NetworkBridgeEvent};
+use polkadot_overseer::{
+	Handle as OverseerHandle, Overseer, OverseerConnector, OverseerMetrics, SpawnGlue,
+};
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use sp_core::Pair;
+use std::{collections::HashSet, sync::Arc, time::Instant};
+pub use test_state::TestState;
+
+mod test_state;
+
+const LOG_TARGET: &str = "subsystem-bench::collator-protocol";
+
+/// Parameters specific to the collator protocol benchmark
+#[derive(Debug, Clone, Serialize, Deserialize, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct CollatorProtocolOptions {
+	#[clap(short, long, default_value_t = 10)]
+	/// The number of collators advertising a collation on every relay chain block.
+	pub n_collators: u32,
+	#[clap(short = 'q', long, default_value_t = 3)]
+	/// The number of claims of each core in the claim queue.
+	pub claim_queue_depth: usize,
+	#[clap(short = 's', long, default_value_t = 1)]
+	/// The number of cores assigned to each parachain, more than one enables elastic scaling.
+	pub cores_per_para: usize,
+}
+
+fn build_overseer(
+	state: &TestState,
+	network: NetworkEmulatorHandle,
+	network_interface: NetworkInterface,
+	network_receiver: NetworkInterfaceReceiver,
+	dependencies: &TestEnvironmentDependencies,
+) -> (Overseer<SpawnGlue<SpawnTaskHandle>, AlwaysSupportsParachains>, OverseerHandle) {
+	let overseer_connector = OverseerConnector::with_event_capacity(64000);
+	let overseer_metrics = OverseerMetrics::try_register(&dependencies.registry).unwrap();
+	let spawn_task_handle = dependencies.task_manager.spawn_handle();
+	let mock_runtime_api = MockRuntimeApi::new(
+		state.config.clone(),
+		state.test_authorities.clone(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		0,
+		MockRuntimeApiCoreState::Scheduled,
+	)
+	.with_claim_queue(state.claim_queue.clone());
+	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
+	let mock_chain_api = MockChainApi::new(chain_api_state);
+	let mock_prospective_parachains = MockProspectiveParachains::new(state.pvd.clone());
+	let mock_candidate_backing = MockCandidateBacking::new(
+		state.config.clone(),
+		state
+			.test_authorities
+			.validator_pairs
+			.get(NODE_UNDER_TEST as usize)
+			.unwrap()
+			.clone(),
+		state.pvd.clone(),
+		Default::default(),
+	);
+	let subsystem = CollatorProtocolSubsystem::new(ProtocolSide::Validator {
+		keystore: crate::statement::make_keystore(),
+		eviction_policy: Default::default(),
+		metrics: Metrics::try_register(&dependencies.registry).unwrap(),
+		invulnerables: HashSet::new(),
+		collator_protocol_hold_off: None,
+		clock: polkadot_node_clock::system_clock(),
+	});
+	let network_bridge_tx = MockNetworkBridgeTx::new(
+		network,
+		network_interface.subsystem_sender(),
+		state.test_authorities.clone(),
+	);
+	let network_bridge_rx = MockNetworkBridgeRx::new(network_receiver, None);
+
+	let dummy = dummy_builder!(spawn_task_handle, overseer_metrics)
+		.replace_runtime_api(|_| mock_runtime_api)
+		.replace_chain_api(|_| mock_chain_api)
+		.replace_prospective_parachains(|_| mock_prospective_parachains)
+		.replace_candidate_backing(|_| mock_candidate_backing)
+		.replace_collator_protocol(|_| subsystem)
+		.replace_network_bridge_tx(|_| network_bridge_tx)
+		.replace_network_bridge_rx(|_| network_bridge_rx);
+	let (overseer, raw_handle) = dummy.build_with_connector(overseer_connector).unwrap();
+	let overseer_handle = OverseerHandle::new(raw_handle);
+
+	(overseer, overseer_handle)
+}
+
+pub fn prepare_test(state: &TestState, with_prometheus_endpoint: bool) -> TestEnvironment {
+	let dependencies = TestEnvironmentDependencies::default();
+	let collators = state
+		.collators
+		.iter()
+		.map(|collator| (collator.peer_id, collator.authority_id.clone()))
+		.collect::<Vec<_>>();
+	let (network, network_interface, network_receiver) = new_network_with_collators(
+		&state.config,
+		&dependencies,
+		&state.test_authorities,
+		&collators,
+		vec![Arc::new(state.clone())],
+	);
+	let (overseer, overseer_handle) =
+		build_overseer(state, network.clone(), network_interface, network_receiver, &dependencies);
+
+	TestEnvironment::new(
+		dependencies,
+		state.config.clone(),
+		network,
+		overseer,
+		overseer_handle,
+		state.test_authorities.clone(),
+		with_prometheus_endpoint,
+	)
+}
+
+fn collator_protocol_message(
+	event: NetworkBridgeEvent<polkadot_node_network_protocol::CollatorProtocolMessage>,
+) -> AllMessages {
+	AllMessages::CollatorProtocol(CollatorProtocolMessage::NetworkBridgeUpdate(event))
+}
+
+pub async fn benchmark_collator_protocol(
+	env: &mut TestEnvironment,
+	state: &TestState,
+) -> BenchmarkUsage {
+	let config = env.config().clone();
+
+	env.metrics().set_n_validators(config.n_validators);
+	env.metrics().set_n_cores(config.n_cores);
+
+	gum::info!(target: LOG_TARGET, "Connecting {} collators of {}", state.collators.len(), state.para_id);
+	for collator in state.collators.iter() {
+		env.send_message(collator_protocol_message(NetworkBridgeEvent::PeerConnected(
+			collator.peer_id,
+			ObservedRole::Full,
+			CollationVersion::V2.into(),
+			None,
+		)))
+		.await;
+		let signature =
+			collator.pair.sign(&protocol_v2::declare_signature_payload(&collator.peer_id));
+		env.send_message(collator_protocol_message(NetworkBridgeEvent::PeerMessage(
+			collator.peer_id,
+			CollationProtocols::V2(protocol_v2::CollatorProtocolMessage::Declare(
+				collator.pair.public(),
+				state.para_id,
+				signature,
+			)),
+		)))
+		.await;
+	}
+
+	// The node under test fetches one collation for every core assigned to its parachain.
+	let collations_per_block = state.options.cores_per_para.min(state.collators.len());
+
+	let test_start = Instant::now();
+	for block_info in state.block_infos.iter() {
+		let block_num = block_info.number as usize;
+		gum::info!(target: LOG_TARGET, "Current block {}/{} {:?}", block_num, config.num_blocks, block_info.hash);
+		env.metrics().set_current_block(block_num);
+		env.import_block(block_info.clone()).await;
+
+		env.send_message(AllMessages::CollatorProtocol(
+			CollatorProtocolMessage::NetworkBridgeUpdate(NetworkBridgeEvent::OurViewChange(
+				OurView::new([block_info.hash], 0),
+			)),
+		))
+		.await;
+
+		let collations = state.collations.get(&block_info.hash).expect("Generated at test start");
+		for (collator, collation) in state.collators.iter().zip(collations) {
+			env.send_message(collator_protocol_message(NetworkBridgeEvent::PeerViewChange(
+				collator.peer_id,
+				View::new([block_info.hash], 0),
+			)))
+			.await;
+			env.send_message(collator_protocol_message(NetworkBridgeEvent::PeerMessage(
+				collator.peer_id,
+				CollationProtocols::V2(protocol_v2::CollatorProtocolMessage::AdvertiseCollation {
+					scheduling_parent: block_info.hash,
+					candidate_hash: collation.receipt.hash(),
+					parent_head_data_hash: state.pvd.parent_head.hash(),
+				}),
+			)))
+			.await;
+		}
+
+		env.wait_until_metric(
+			"polkadot_parachain_collation_requests_total",
+			Some(("success", "succeeded")),
+			|value| value >= (block_num * collations_per_block) as f64,
+		)
+		.await;
+	}
+
+	let duration: u128 = test_start.elapsed().as_millis();
+	gum::info!(target: LOG_TARGET, "All blocks processed in {}", format!("{duration:?}ms").cyan());
+	gum::info!(target: LOG_TARGET,
+		"Avg block time: {}",
+		format!("{} ms", duration / config.num_blocks as u128).red()
+	);
+
+	let test_metrics = crate::display::parse_metrics(env.registry());
+	let fetch_count = test_metrics
+		.sum_by("polkadot_parachain_collator_protocol_validator_collation_request_duration_count");
+	let fetch_time = test_metrics
+		.sum_by("polkadot_parachain_collator_protocol_validator_collation_request_duration_sum");
+	gum::info!(target: LOG_TARGET,
+		"Avg collation fetch time: {}",
+		format!("{:.0} ms", 1000.0 * fetch_time / fetch_count.max(1.0)).red()
+	);
+
+	env.stop().await;
+	env.collect_resource_usage(&["collator-protocol"], false)
+}
@@ -0,0 +1,237 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	collator_protocol::CollatorProtocolOptions,
+	configuration::{TestAuthorities, TestConfiguration},
+	network::{HandleNetworkMessage, NetworkMessage},
+};
+use codec::Encode;
+use polkadot_node_network_protocol::request_response::{v2::CollationFetchingResponse, Requests};
+use polkadot_node_primitives::{BlockData, PoV};
+use polkadot_node_subsystem_test_helpers::mock::new_block_import_info;
+use polkadot_overseer::BlockInfo;
+use polkadot_primitives::{
+	AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
+	CandidateReceiptV2 as CandidateReceipt, CollatorPair, CoreIndex, Hash, Header, Id,
+	MutateDescriptorV2, PersistedValidationData,
+};
+use polkadot_primitives_test_helpers::{
+	dummy_committed_candidate_receipt_v2, dummy_hash, dummy_head_data, dummy_pvd,
+};
+use sc_network::ProtocolName;
+use sc_network_types::PeerId;
+use sp_core::{Pair, H256};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+const SESSION_INDEX: u32 = 0;
+
+/// The id of the first emulated parachain. Low para ids are avoided on purpose, the validator side
+/// treats some system parachains differently.
+const FIRST_PARA_ID: u32 = 2000;
+
+/// An emulated collator of the parachain assigned to the node under test.
+#[derive(Clone)]
+pub struct Collator {
+	pub pair: CollatorPair,
+	pub peer_id: PeerId,
+	pub authority_id: AuthorityDiscoveryId,
+}
+
+/// A collation advertised by an emulated collator.
+#[derive(Clone)]
+pub struct Collation {
+	pub receipt: CandidateReceipt,
+	// The PoV is regenerated when requested to keep the memory usage low.
+	pub pov_size: usize,
+	pub pov_seed: (BlockNumber, u32),
+}
+
+#[derive(Clone)]
+pub struct TestState {
+	// Full test config
+	pub config: TestConfiguration,
+	// Benchmark specific options
+	pub options: CollatorProtocolOptions,
+	// Authority keys for the network emulation.
+	pub test_authorities: TestAuthorities,
+	// Relay chain block infos
+	pub block_infos: Vec<BlockInfo>,
+	// Relay chain block headers
+	pub block_headers: HashMap<H256, Header>,
+	// PersistedValidationData, we use one for all candidates
+	pub pvd: PersistedValidationData,
+	// The claim queue served by the runtime for every relay chain block
+	pub claim_queue: BTreeMap<CoreIndex, VecDeque<Id>>,
+	// The parachain assigned to the core of the node under test
+	pub para_id: Id,
+	// Emulated collators of `para_id`
+	pub collators: Vec<Collator>,
+	// Collations advertised by each collator, by relay chain block
+	pub collations: HashMap<H256, Vec<Collation>>,
+}
+
+impl TestState {
+	pub fn new(config: &TestConfiguration, options: &CollatorProtocolOptions) -> Self {
+		assert!(options.cores_per_para > 0, "cores_per_para must be at least 1");
+		assert!(options.claim_queue_depth > 0, "claim_queue_depth must be at least 1");
+
+		let mut test_authorities = config.generate_authorities();
+		let claim_queue = generate_claim_queue(config.n_cores, options);
+		// The node under test is in the first backing group, so it is assigned to the first core.
+		let para_id = *claim_queue
+			.get(&CoreIndex(0))
+			.and_then(|claims| claims.front())
+			.expect("There is at least one core; qed");
+
+		let collators = (0..options.n_collators)
+			.map(|index| {
+				let authority_id = test_authorities
+					.keyring
+					.sr25519_new(format!("//Collator{index}").as_str())
+					.into();
+				Collator {
+					pair: CollatorPair::generate().0,
+					peer_id: PeerId::random(),
+					authority_id,
+				}
+			})
+			.collect::<Vec<_>>();
+		// Requests to collators are routed through the same mapping as requests to validators.
+		test_authorities.peer_id_to_authority.extend(
+			collators
+				.iter()
+				.map(|collator| (collator.peer_id, collator.authority_id.clone())),
+		);
+
+		let mut state = Self {
+			config: config.clone(),
+			options: options.clone(),
+			test_authorities,
+			block_infos: (1..=config.num_blocks).map(generate_block_info).collect(),
+			block_headers: Default::default(),
+			pvd: dummy_pvd(dummy_head_data(), 0),
+			claim_queue,
+			para_id,
+			collators,
+			collations: Default::default(),
+		};
+
+		state.block_headers = state.block_infos.iter().map(generate_block_header).collect();
+
+		let pov_size = config.pov_sizes().first().copied().unwrap_or(config.max_pov_size * 1024);
+		for block_info in state.block_infos.iter() {
+			let collations = (0..options.n_collators)
+				.map(|index| {
+					let pov_seed = (block_info.number, index);
+					let pov = make_pov(pov_seed, pov_size);
+					let commitments = CandidateCommitments::default();
+					let mut receipt = dummy_committed_candidate_receipt_v2(dummy_hash());
+					receipt.descriptor.set_para_id(para_id);
+					receipt.descriptor.set_relay_parent(block_info.hash);
+					receipt.descriptor.set_core_index(CoreIndex(0));
+					receipt.descriptor.set_session_index(SESSION_INDEX);
+					receipt.descriptor.set_persisted_validation_data_hash(state.pvd.hash());
+					receipt.descriptor.set_pov_hash(pov.hash());
+					receipt.descriptor.set_para_head(commitments.head_data.hash());
+
+					Collation {
+						receipt: CandidateReceipt {
+							descriptor: receipt.descriptor,
+							commitments_hash: commitments.hash(),
+						},
+						pov_size,
+						pov_seed,
+					}
+				})
+				.collect();
+			state.collations.insert(block_info.hash, collations);
+		}
+
+		state
+	}
+
+	fn collation(&self, candidate_hash: &CandidateHash) -> Option<&Collation> {
+		self.collations
+			.values()
+			.flatten()
+			.find(|collation| collation.receipt.hash() == *candidate_hash)
+	}
+}
+
+/// Assigns `cores_per_para` consecutive cores to every parachain, each core claimed
+/// `claim_queue_depth` times ahead.
+fn generate_claim_queue(
+	n_cores: usize,
+	options: &CollatorProtocolOptions,
+) -> BTreeMap<CoreIndex, VecDeque<Id>> {
+	(0..n_cores)
+		.map(|core| {
+			let para_id = Id::new(FIRST_PARA_ID + (core / options.cores_per_para) as u32);
+			(CoreIndex(core as u32), vec![para_id; options.claim_queue_depth].into())
+		})
+		.collect()
+}
+
+fn make_pov((block_number, collator_index): (BlockNumber, u32), pov_size: usize) -> PoV {
+	let mut block_data = (block_number, collator_index).encode();
+	block_data.resize(pov_size.max(block_data.len()), 0);
+	PoV { block_data: BlockData(block_data) }
+}
+
+fn generate_block_info(block_num: usize) -> BlockInfo {
+	new_block_import_info(Hash::repeat_byte(block_num as u8), block_num as BlockNumber)
+}
+
+fn generate_block_header(info: &BlockInfo) -> (H256, Header) {
+	(
+		info.hash,
+		Header {
+			digest: Default::default(),
+			number: info.number,
+			parent_hash: info.parent_hash,
+			extrinsics_root: Default::default(),
+			state_root: Default::default(),
+		},
+	)
+}
+
+#[async_trait::async_trait]
+impl HandleNetworkMessage for TestState {
+	async fn handle(
+		&self,
+		message: NetworkMessage,
+		_node_sender: &mut futures::channel::mpsc::UnboundedSender<NetworkMessage>,
+	) -> Option<NetworkMessage> {
+		match message {
+			NetworkMessage::RequestFromNode(authority_id, requests) => {
+				let Requests::CollationFetchingV2(req) = *requests else {
+					return Some(NetworkMessage::RequestFromNode(authority_id, requests));
+				};
+				let collation = self
+					.collation(&req.payload.candidate_hash)
+					.expect("Only advertised collations are fetched");
+				let response = CollationFetchingResponse::Collation(
+					collation.receipt.clone(),
+					make_pov(collation.pov_seed, collation.pov_size),
+				);
+				let _ = req.pending_response.send(Ok((response.encode(), ProtocolName::from(""))));
+				None
+			},
+			_ => Some(message),
+		}
+	}
+}
@@ -27,7 +27,10 @@ use sc_network_types::PeerId;
 use serde::{Deserialize, Serialize};
 use sp_consensus_babe::AuthorityId;
 use sp_core::Pair;
-use std::collections::{HashMap, HashSet};
+use std::{
+	collections::{HashMap, HashSet},
+	path::PathBuf,
+};
 
 /// Peer networking latency configuration.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -147,6 +150,10 @@ pub struct TestConfiguration {
 	/// topology.
 	#[serde(default = "default_connectivity")]
 	pub connectivity: usize,
+	/// Optional network trace to replay, overriding the latency and bandwidth of the peers
+	/// with recorded time series.
+	#[serde(default)]
+	pub network_trace: Option<PathBuf>,
 	/// Number of blocks to run the test for
 	pub num_blocks: usize,
 	/// Number of minimum backing votes
@@ -178,6 +185,7 @@ impl Default for TestConfiguration {
 			bandwidth: default_bandwidth(),
 			latency: default_peer_latency(),
 			connectivity: default_connectivity(),
+			network_trace: None,
 			num_blocks: Default::default(),
 			minimum_backing_votes: default_minimum_backing_votes(),
 			max_candidate_depth: default_max_candidate_depth(),
@@ -18,13 +18,18 @@
 
 use crate::{configuration::TestConfiguration, NODE_UNDER_TEST};
 use futures::FutureExt;
-use polkadot_node_primitives::{SignedFullStatementWithPVD, Statement, StatementWithPVD};
+use polkadot_node_primitives::{
+	SignedFullStatement, SignedFullStatementWithPVD, Statement, StatementWithPVD,
+};
 use polkadot_node_subsystem::{
-	messages::CandidateBackingMessage, overseer, SpawnedSubsystem, SubsystemError,
+	messages::{CandidateBackingMessage, CollatorProtocolMessage},
+	overseer, SpawnedSubsystem, SubsystemError,
 };
 use polkadot_node_subsystem_types::OverseerSignal;
 use polkadot_primitives::{
-	CandidateHash, Hash, PersistedValidationData, SigningContext, ValidatorIndex, ValidatorPair,
+	CandidateCommitments, CandidateHash, CandidateReceiptV2 as CandidateReceipt,
+	CommittedCandidateReceiptV2 as CommittedCandidateReceipt, Hash, PersistedValidationData,
+	SigningContext, ValidatorIndex, ValidatorPair,
 };
 use sp_core::Pair;
 use std::collections::HashMap;
@@ -123,6 +128,31 @@ impl MockCandidateBacking {
 
 		messages
 	}
+
+	/// Seconds a collation fetched by the collator protocol.
+	///
+	/// Collations are not validated and are expected to have default commitments.
+	fn second_collation(
+		&self,
+		relay_parent: Hash,
+		candidate: CandidateReceipt,
+	) -> SignedFullStatement {
+		let statement = Statement::Seconded(CommittedCandidateReceipt {
+			descriptor: candidate.descriptor,
+			commitments: CandidateCommitments::default(),
+		});
+		let context = SigningContext { parent_hash: relay_parent, session_index: 0 };
+		let payload = statement.to_compact().signing_payload(&context);
+
+		SignedFullStatement::new(
+			statement,
+			ValidatorIndex(NODE_UNDER_TEST),
+			self.state.pair.sign(&payload[..]),
+			&context,
+			&self.state.pair.public(),
+		)
+		.unwrap()
+	}
 }
 
 #[overseer::subsystem(CandidateBacking, error=SubsystemError, prefix=self::overseer)]
@@ -164,6 +194,19 @@ impl MockCandidateBacking {
 								ctx.send_message(message).await;
 							}
 						},
+						CandidateBackingMessage::CanSecond(_request, tx) => {
+							let _ = tx.send(true);
+						},
+						CandidateBackingMessage::Second {
+							scheduling_parent, candidate, ..
+						} => {
+							let statement = self.second_collation(scheduling_parent, candidate);
+							ctx.send_message(CollatorProtocolMessage::Seconded(
+								scheduling_parent,
+								statement,
+							))
+							.await;
+						},
 						_ => {
 							unimplemented!("Unexpected candidate-backing message")
 						},
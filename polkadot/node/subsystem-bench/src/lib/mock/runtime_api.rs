@@ -87,20 +87,26 @@ impl MockRuntimeApi {
 			session_info_for_peers(&config, &authorities).validator_groups.len();
 
 		// Each para gets one core assigned and there is only one candidate per
-		// parachain per relay chain block (no elastic scaling).
+		// parachain per relay chain block (no elastic scaling). Tests that don't generate
+		// candidates upfront are expected to provide their own claim queue.
 		let claim_queue = candidate_hashes
-			.iter()
+			.values()
 			.next()
-			.expect("Candidates are generated at test start")
-			.1
-			.iter()
-			.enumerate()
-			.map(|(index, candidate_receipt)| {
-				// Ensure test breaks if badly configured.
-				assert!(index < validator_group_count);
-				(CoreIndex(index as u32), vec![candidate_receipt.descriptor.para_id()].into())
+			.map(|candidate_receipts| {
+				candidate_receipts
+					.iter()
+					.enumerate()
+					.map(|(index, candidate_receipt)| {
+						// Ensure test breaks if badly configured.
+						assert!(index < validator_group_count);
+						(
+							CoreIndex(index as u32),
+							vec![candidate_receipt.descriptor.para_id()].into(),
+						)
+					})
+					.collect()
 			})
-			.collect();
+			.unwrap_or_default();
 
 		Self {
 			state: RuntimeApiState {
@@ -117,6 +123,12 @@ impl MockRuntimeApi {
 		}
 	}
 
+	/// Replaces the claim queue derived from the candidates with `claim_queue`.
+	pub fn with_claim_queue(mut self, claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>) -> Self {
+		self.state.claim_queue = claim_queue;
+		self
+	}
+
 	fn session_info(&self) -> SessionInfo {
 		session_info_for_peers(&self.config, &self.state.authorities)
 	}
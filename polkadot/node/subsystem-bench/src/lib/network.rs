@@ -36,6 +36,7 @@
 use crate::{
 	configuration::{random_latency, TestAuthorities, TestConfiguration},
 	environment::TestEnvironmentDependencies,
+	network_trace::{self, NetworkTrace, PeerConditions},
 	NODE_UNDER_TEST,
 };
 use codec::Encode;
@@ -101,8 +102,8 @@ impl RateLimit {
 	// Create a new `RateLimit` from a `cps` (credits per second) budget and
 	// `tick_rate`.
 	pub fn new(tick_rate: usize, cps: usize) -> Self {
-		// Compute how much refill for each tick
-		let max_refill = cps / tick_rate;
+		// Compute how much refill for each tick, a budget below the tick rate still refills.
+		let max_refill = (cps / tick_rate).max(1);
 		RateLimit {
 			tick_rate,
 			total_ticks: 0,
@@ -130,6 +131,11 @@ impl RateLimit {
 		self.last_refill = Instant::now();
 	}
 
+	// Change the `cps` budget, effective from the next refill.
+	pub fn set_cps(&mut self, cps: usize) {
+		self.max_refill = (cps / self.tick_rate).max(1);
+	}
+
 	// Reap credits from the bucket.
 	// Blocks if credits budged goes negative during call.
 	pub async fn reap(&mut self, amount: usize) {
@@ -404,6 +410,12 @@ impl NetworkInterface {
 		// Modify the request response sender so we can intercept the answer
 		let sender = request.swap_response_sender(proxy_sender);
 
+		// The peer might have gone offline while the request was queued.
+		if !tx_network.is_peer_connected(&peer) {
+			let _ = sender.send(Err(RequestFailure::NotConnected));
+			return;
+		}
+
 		// Send the modified request to the peer.
 		tx_network.send_request_to_peer(&peer, request);
 
@@ -438,6 +450,8 @@ pub struct EmulatedPeerHandle {
 	actions_tx: UnboundedSender<NetworkMessage>,
 	peer_id: PeerId,
 	authority_id: AuthorityDiscoveryId,
+	/// The current network conditions of the peer.
+	conditions: Arc<PeerConditions>,
 }
 
 impl EmulatedPeerHandle {
@@ -467,19 +481,21 @@ struct EmulatedPeer {
 	to_node: UnboundedSender<NetworkMessage>,
 	tx_limiter: RateLimit,
 	rx_limiter: RateLimit,
-	latency_ms: usize,
+	conditions: Arc<PeerConditions>,
 }
 
 impl EmulatedPeer {
 	/// Send a message to the node.
 	pub async fn send_message(&mut self, message: NetworkMessage) {
+		self.tx_limiter.set_cps(self.conditions.bandwidth());
 		self.tx_limiter.reap(message.size()).await;
 
-		if self.latency_ms == 0 {
+		let latency_ms = self.conditions.latency_ms();
+		if latency_ms == 0 {
 			self.to_node.unbounded_send(message).expect("Sending to the node never fails");
 		} else {
 			let to_node = self.to_node.clone();
-			let latency_ms = std::time::Duration::from_millis(self.latency_ms as u64);
+			let latency_ms = std::time::Duration::from_millis(latency_ms as u64);
 
 			// Emulate RTT latency
 			self.spawn_handle
@@ -492,6 +508,7 @@ impl EmulatedPeer {
 
 	/// Returns the rx bandwidth limiter.
 	pub fn rx_limiter(&mut self) -> &mut RateLimit {
+		self.rx_limiter.set_cps(self.conditions.bandwidth());
 		&mut self.rx_limiter
 	}
 }
@@ -616,28 +633,28 @@ async fn emulated_peer_loop(
 }
 
 /// Creates a new peer emulator task and returns a handle to it.
-#[allow(clippy::too_many_arguments)]
+///
+/// The bandwidth and latency of the peer follow its `conditions`.
 pub fn new_peer(
-	bandwidth: usize,
 	spawn_task_handle: SpawnTaskHandle,
 	handlers: Vec<Arc<dyn HandleNetworkMessage + Sync + Send>>,
 	stats: Arc<PeerEmulatorStats>,
 	to_network_interface: UnboundedSender<NetworkMessage>,
-	latency_ms: usize,
+	conditions: Arc<PeerConditions>,
 	peer_id: PeerId,
 	authority_id: AuthorityDiscoveryId,
 ) -> EmulatedPeerHandle {
 	let (messages_tx, messages_rx) = mpsc::unbounded::<NetworkMessage>();
 	let (actions_tx, actions_rx) = mpsc::unbounded::<NetworkMessage>();
 
-	let rx_limiter = RateLimit::new(10, bandwidth);
-	let tx_limiter = RateLimit::new(10, bandwidth);
+	let rx_limiter = RateLimit::new(10, conditions.bandwidth());
+	let tx_limiter = RateLimit::new(10, conditions.bandwidth());
 	let emulated_peer = EmulatedPeer {
 		spawn_handle: spawn_task_handle.clone(),
 		rx_limiter,
 		tx_limiter,
 		to_node: to_network_interface.clone(),
-		latency_ms,
+		conditions: conditions.clone(),
 	};
 
 	spawn_task_handle.clone().spawn(
@@ -654,7 +671,7 @@ pub fn new_peer(
 		.boxed(),
 	);
 
-	EmulatedPeerHandle { messages_tx, actions_tx, peer_id, authority_id }
+	EmulatedPeerHandle { messages_tx, actions_tx, peer_id, authority_id, conditions }
 }
 
 /// Book keeping of sent and received bytes.
@@ -713,6 +730,14 @@ impl Peer {
 		matches!(self, Peer::Connected(_))
 	}
 
+	/// Returns true if the peer is connected and, when replaying a network trace, online.
+	pub fn is_online(&self) -> bool {
+		match self {
+			Peer::Connected(handle) => handle.conditions.is_connected(),
+			Peer::Disconnected(_) => false,
+		}
+	}
+
 	pub fn handle(&self) -> &EmulatedPeerHandle {
 		match self {
 			Peer::Connected(ref emulator) => emulator,
@@ -740,7 +765,7 @@ pub struct NetworkEmulatorHandle {
 	peers: Vec<Peer>,
 	/// Per peer stats.
 	stats: Vec<Arc<PeerEmulatorStats>>,
-	/// Each emulated peer is a validator.
+	/// Each emulated peer is a validator or, in collator benchmarks, a collator.
 	validator_authority_ids: HashMap<AuthorityDiscoveryId, usize>,
 }
 
@@ -786,6 +811,20 @@ pub fn new_network(
 	dependencies: &TestEnvironmentDependencies,
 	authorities: &TestAuthorities,
 	handlers: Vec<Arc<dyn HandleNetworkMessage + Sync + Send>>,
+) -> (NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver) {
+	new_network_with_collators(config, dependencies, authorities, &[], handlers)
+}
+
+/// Create a new emulated network based on `config`, with additional peers for `collators`.
+///
+/// Collators are addressed by the `AuthorityDiscoveryId` they are paired with and are always
+/// connected to the node under test, regardless of the configured connectivity.
+pub fn new_network_with_collators(
+	config: &TestConfiguration,
+	dependencies: &TestEnvironmentDependencies,
+	authorities: &TestAuthorities,
+	collators: &[(PeerId, AuthorityDiscoveryId)],
+	handlers: Vec<Arc<dyn HandleNetworkMessage + Sync + Send>>,
 ) -> (NetworkEmulatorHandle, NetworkInterface, NetworkInterfaceReceiver) {
 	let n_peers = config.n_validators;
 	gum::info!(target: LOG_TARGET, "{}",format!("Initializing emulation for a {n_peers} peer network.").bright_blue());
@@ -799,21 +838,32 @@ pub fn new_network(
 	let (to_network_interface, from_network) = mpsc::unbounded();
 
 	// Create a `PeerEmulator` for each peer.
-	let (stats, mut peers): (_, Vec<_>) = (0..n_peers)
+	let mut conditions = Vec::new();
+	let (stats, mut peers): (_, Vec<_>) = authorities
+		.peer_ids
+		.iter()
+		.copied()
 		.zip(authorities.validator_authority_id.clone())
-		.map(|(peer_index, authority_id)| {
+		.take(n_peers)
+		.chain(collators.iter().cloned())
+		.enumerate()
+		.map(|(peer_index, (peer_id, authority_id))| {
 			validator_authority_id_mapping.insert(authority_id.clone(), peer_index);
 			let stats = Arc::new(PeerEmulatorStats::new(peer_index, metrics.clone()));
+			let peer_conditions = Arc::new(PeerConditions::new(
+				random_latency(config.latency.as_ref()),
+				config.peer_bandwidth,
+			));
+			conditions.push(peer_conditions.clone());
 			(
 				stats.clone(),
 				Peer::Connected(new_peer(
-					config.peer_bandwidth,
 					dependencies.task_manager.spawn_handle(),
 					handlers.clone(),
 					stats,
 					to_network_interface.clone(),
-					random_latency(config.latency.as_ref()),
-					*authorities.peer_ids.get(peer_index).unwrap(),
+					peer_conditions,
+					peer_id,
 					authority_id,
 				)),
 			)
@@ -834,6 +884,26 @@ pub fn new_network(
 
 	gum::info!(target: LOG_TARGET, "{}",format!("Network created, connected validator count {connected_count}").bright_black());
 
+	if let Some(path) = config.network_trace.as_ref() {
+		let trace = NetworkTrace::from_file(path).expect("Network trace file is valid");
+		gum::info!(target: LOG_TARGET, "{}",format!("Replaying network trace {}", path.display()).bright_black());
+
+		// Validators replay the recorded peers in turn, collators keep their static parameters.
+		let n_recorded = trace.peers.len();
+		let replayed = conditions
+			.into_iter()
+			.take(n_peers)
+			.enumerate()
+			.skip(NODE_UNDER_TEST as usize + 1)
+			.map(|(peer_index, conditions)| (conditions, (peer_index - 1) % n_recorded))
+			.collect();
+		dependencies.task_manager.spawn_handle().spawn(
+			"network-trace-replay",
+			"test-environment",
+			network_trace::replay(trace, replayed),
+		);
+	}
+
 	let handle = NetworkEmulatorHandle {
 		peers,
 		stats,
@@ -859,12 +929,14 @@ pub enum EmulatedPeerError {
 
 impl NetworkEmulatorHandle {
 	/// Returns true if the emulated peer is connected to the node under test.
+	///
+	/// When a network trace is replayed, peers which are offline are not connected.
 	pub fn is_peer_connected(&self, peer: &AuthorityDiscoveryId) -> bool {
-		self.peer(peer).is_connected()
+		self.peer(peer).is_online()
 	}
 
 	/// Forward notification `message` to an emulated `peer`.
-	/// Panics if peer is not connected.
+	/// Panics if peer is not connected. Messages to a peer which went offline are dropped.
 	pub fn send_message_to_peer(
 		&self,
 		peer_id: &AuthorityDiscoveryId,
@@ -872,6 +944,10 @@ impl NetworkEmulatorHandle {
 	) {
 		let peer = self.peer(peer_id);
 		assert!(peer.is_connected(), "forward message only for connected peers.");
+		if !peer.is_online() {
+			gum::trace!(target: LOG_TARGET, ?peer_id, "Dropping message to an offline peer");
+			return;
+		}
 		peer.handle().receive(NetworkMessage::MessageFromNode(peer_id.clone(), message));
 	}
 
@@ -892,7 +968,7 @@ impl NetworkEmulatorHandle {
 	) -> Result<(), EmulatedPeerError> {
 		let dst_peer = self.peer(from_peer);
 
-		if !dst_peer.is_connected() {
+		if !dst_peer.is_online() {
 			gum::warn!(target: LOG_TARGET, "Attempted to send message from a peer not connected to our node, operation ignored");
 			return Err(EmulatedPeerError::NotConnected);
 		}
@@ -909,7 +985,7 @@ impl NetworkEmulatorHandle {
 	) -> Result<(), EmulatedPeerError> {
 		let dst_peer = self.peer(from_peer);
 
-		if !dst_peer.is_connected() {
+		if !dst_peer.is_online() {
 			gum::warn!(target: LOG_TARGET, "Attempted to send request from a peer not connected to our node, operation ignored");
 			return Err(EmulatedPeerError::NotConnected);
 		}
@@ -1031,7 +1107,7 @@ impl RequestExt for Requests {
 				}
 			},
 			// Requested by PeerId
-			Requests::AttestedCandidateV2(_) => None,
+			Requests::AttestedCandidateV2(_) | Requests::CollationFetchingV2(_) => None,
 			Requests::DisputeSendingV1(request) => {
 				if let Recipient::Authority(authority_id) = &request.peer {
 					Some(authority_id)
@@ -1051,6 +1127,10 @@ impl RequestExt for Requests {
 				Recipient::Authority(_) => None,
 				Recipient::Peer(peer_id) => Some(peer_id),
 			},
+			Requests::CollationFetchingV2(request) => match &request.peer {
+				Recipient::Authority(_) => None,
+				Recipient::Peer(peer_id) => Some(peer_id),
+			},
 			request => {
 				unimplemented!("peer_id() is not implemented for {:?}", request)
 			},
@@ -1064,6 +1144,7 @@ impl RequestExt for Requests {
 				outgoing_request.pending_response
 			},
 			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.pending_response,
+			Requests::CollationFetchingV2(outgoing_request) => outgoing_request.pending_response,
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1083,6 +1164,9 @@ impl RequestExt for Requests {
 			Requests::DisputeSendingV1(outgoing_request) => {
 				std::mem::replace(&mut outgoing_request.pending_response, new_sender)
 			},
+			Requests::CollationFetchingV2(outgoing_request) => {
+				std::mem::replace(&mut outgoing_request.pending_response, new_sender)
+			},
 			_ => unimplemented!("unsupported request type"),
 		}
 	}
@@ -1098,6 +1182,9 @@ impl RequestExt for Requests {
 				outgoing_request.payload.encoded_size()
 			},
 			Requests::DisputeSendingV1(outgoing_request) => outgoing_request.payload.encoded_size(),
+			Requests::CollationFetchingV2(outgoing_request) => {
+				outgoing_request.payload.encoded_size()
+			},
 			_ => unimplemented!("received an unexpected request"),
 		}
 	}
@@ -0,0 +1,451 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Recording and replay of per-peer network conditions.
+//!
+//! A [`NetworkTrace`] is a time series of the latency, bandwidth and connectivity of a set of
+//! peers, sampled at a fixed interval. It is recorded from the Prometheus metrics of a running
+//! node with a [`TraceRecorder`] and replayed by the network emulation, so that the benchmarks
+//! see the bursts and the churn of a production network instead of static peer parameters.
+
+use prometheus_parse::{Sample, Value};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::Path,
+	sync::{
+		atomic::{AtomicBool, AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+const LOG_TARGET: &str = "subsystem-bench::network-trace";
+
+/// The share of its bandwidth, in percent, a peer keeps when the recorded traffic alone saturates
+/// it.
+const MIN_AVAILABLE_BANDWIDTH_PERCENT: usize = 10;
+
+/// A recorded time series of the network conditions of a set of peers.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkTrace {
+	/// The time between two consecutive samples.
+	pub interval_ms: u64,
+	/// The recorded peers, all with the same number of samples.
+	pub peers: Vec<PeerTrace>,
+}
+
+/// The recorded network conditions of a single peer.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PeerTrace {
+	/// The peer as identified by the recorded metrics.
+	pub peer: String,
+	/// The samples of the peer, one per interval.
+	pub samples: Vec<PeerSample>,
+}
+
+/// The network conditions of a peer during one interval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PeerSample {
+	/// Whether the peer was connected.
+	pub connected: bool,
+	/// The observed round trip latency in milliseconds, if any.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub latency_ms: Option<usize>,
+	/// The observed throughput in bytes per second, if there was any traffic.
+	///
+	/// This is how much of the link was used by the peer, not its capacity.
+	#[serde(default, alias = "bandwidth", skip_serializing_if = "Option::is_none")]
+	pub throughput: Option<usize>,
+}
+
+impl NetworkTrace {
+	/// Reads a trace from a JSON file.
+	pub fn from_file(path: &Path) -> std::io::Result<Self> {
+		let trace: Self = serde_json::from_slice(&std::fs::read(path)?)?;
+		if trace.interval_ms == 0 || trace.is_empty() {
+			return Err(std::io::Error::new(
+				std::io::ErrorKind::InvalidData,
+				"network trace must have samples taken at a non-zero interval",
+			));
+		}
+		Ok(trace)
+	}
+
+	/// Writes the trace to a JSON file.
+	pub fn to_file(&self, path: &Path) -> std::io::Result<()> {
+		std::fs::write(path, serde_json::to_vec_pretty(self)?)
+	}
+
+	/// Returns the number of samples per peer.
+	pub fn len(&self) -> usize {
+		self.peers.iter().map(|peer| peer.samples.len()).max().unwrap_or_default()
+	}
+
+	/// Returns `true` if the trace has no samples.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// The current network conditions of an emulated peer.
+///
+/// Peers start with the static parameters of the test configuration, which are updated while a
+/// trace is replayed. The recorded throughput is treated as background traffic: the bandwidth of
+/// the peer stays the configured one, minus what the recorded traffic uses.
+#[derive(Debug)]
+pub struct PeerConditions {
+	latency_ms: AtomicUsize,
+	bandwidth: AtomicUsize,
+	connected: AtomicBool,
+	// The static parameters, used for intervals without observations.
+	default_latency_ms: usize,
+	default_bandwidth: usize,
+}
+
+impl PeerConditions {
+	/// Creates the conditions of a connected peer with static parameters.
+	pub fn new(latency_ms: usize, bandwidth: usize) -> Self {
+		Self {
+			latency_ms: AtomicUsize::new(latency_ms),
+			bandwidth: AtomicUsize::new(bandwidth),
+			connected: AtomicBool::new(true),
+			default_latency_ms: latency_ms,
+			default_bandwidth: bandwidth,
+		}
+	}
+
+	/// The current round trip latency in milliseconds.
+	pub fn latency_ms(&self) -> usize {
+		self.latency_ms.load(Ordering::Relaxed)
+	}
+
+	/// The bandwidth currently available, in bytes per second.
+	pub fn bandwidth(&self) -> usize {
+		self.bandwidth.load(Ordering::Relaxed)
+	}
+
+	/// Whether the peer is currently online.
+	pub fn is_connected(&self) -> bool {
+		self.connected.load(Ordering::Relaxed)
+	}
+
+	fn apply(&self, sample: &PeerSample) {
+		self.latency_ms
+			.store(sample.latency_ms.unwrap_or(self.default_latency_ms), Ordering::Relaxed);
+		let min_bandwidth = self.default_bandwidth * MIN_AVAILABLE_BANDWIDTH_PERCENT / 100;
+		let bandwidth = self
+			.default_bandwidth
+			.saturating_sub(sample.throughput.unwrap_or_default())
+			.max(min_bandwidth);
+		self.bandwidth.store(bandwidth, Ordering::Relaxed);
+		self.connected.store(sample.connected, Ordering::Relaxed);
+	}
+}
+
+/// Replays `trace` on the conditions of the emulated peers.
+///
+/// Each entry of `peers` is paired with the index of the recorded peer it replays. The trace is
+/// looped until the test ends.
+pub async fn replay(trace: NetworkTrace, peers: Vec<(Arc<PeerConditions>, usize)>) {
+	let len = trace.len();
+	if len == 0 || peers.is_empty() {
+		return;
+	}
+
+	gum::info!(
+		target: LOG_TARGET,
+		n_peers = trace.peers.len(),
+		n_samples = len,
+		interval_ms = trace.interval_ms,
+		"Replaying network trace",
+	);
+
+	let mut interval = tokio::time::interval(Duration::from_millis(trace.interval_ms));
+	for step in (0..len).cycle() {
+		interval.tick().await;
+		for (conditions, index) in peers.iter() {
+			let samples = &trace.peers[*index].samples;
+			// Peers recorded for a shorter time are offline for the rest of the trace.
+			let sample = samples.get(step).copied().unwrap_or_default();
+			conditions.apply(&sample);
+		}
+	}
+}
+
+/// The per-peer values of a single scrape.
+#[derive(Clone, Copy, Debug, Default)]
+struct PeerCounters {
+	bytes: f64,
+	latency_seconds: Option<f64>,
+	latency_sum: f64,
+	latency_count: f64,
+}
+
+/// Records a [`NetworkTrace`] from consecutive scrapes of Prometheus metrics.
+///
+/// Peers are identified by the `peer_label` of the metrics:
+/// - `bandwidth_metric` is a counter of the bytes exchanged with a peer, possibly split over other
+///   labels, which are summed up;
+/// - `latency_metric` is either a gauge or a histogram of the round trip time to a peer, in
+///   seconds.
+///
+/// A peer which is missing from a scrape is recorded as disconnected.
+pub struct TraceRecorder {
+	interval: Duration,
+	peer_label: String,
+	bandwidth_metric: String,
+	latency_metric: String,
+	last: HashMap<String, PeerCounters>,
+	peers: BTreeMap<String, Vec<PeerSample>>,
+	n_scrapes: usize,
+}
+
+impl TraceRecorder {
+	/// Creates a recorder for scrapes taken every `interval`.
+	pub fn new(
+		interval: Duration,
+		peer_label: impl Into<String>,
+		bandwidth_metric: impl Into<String>,
+		latency_metric: impl Into<String>,
+	) -> Self {
+		Self {
+			interval,
+			peer_label: peer_label.into(),
+			bandwidth_metric: bandwidth_metric.into(),
+			latency_metric: latency_metric.into(),
+			last: HashMap::new(),
+			peers: BTreeMap::new(),
+			n_scrapes: 0,
+		}
+	}
+
+	/// Records the samples of a scrape taken one interval after the previous one.
+	///
+	/// The first scrape only sets the baseline of the counters.
+	pub fn observe(&mut self, samples: &[Sample]) {
+		let current = self.peer_counters(samples);
+		self.n_scrapes += 1;
+		if self.n_scrapes == 1 {
+			self.last = current;
+			return;
+		}
+
+		let n_samples = self.n_scrapes - 2;
+		let interval = self.interval.as_secs_f64();
+		for (peer, counters) in current.iter() {
+			let sample = match self.last.get(peer) {
+				Some(last) => {
+					let bytes = counters.bytes - last.bytes;
+					let latency_count = counters.latency_count - last.latency_count;
+					let latency_seconds = counters.latency_seconds.or_else(|| {
+						(latency_count > 0.0)
+							.then(|| (counters.latency_sum - last.latency_sum) / latency_count)
+					});
+					PeerSample {
+						connected: true,
+						latency_ms: latency_seconds.filter(|seconds| *seconds >= 0.0).map(millis),
+						// A negative delta is a counter reset, nothing can be said about it.
+						throughput: (bytes > 0.0).then(|| (bytes / interval) as usize),
+					}
+				},
+				// A peer which just connected, there is no baseline for the counters yet.
+				None => PeerSample {
+					connected: true,
+					latency_ms: counters.latency_seconds.map(millis),
+					throughput: None,
+				},
+			};
+
+			self.peers
+				.entry(peer.clone())
+				.or_insert_with(|| vec![PeerSample::default(); n_samples])
+				.push(sample);
+		}
+
+		// Peers missing from the scrape are disconnected.
+		for (peer, samples) in self.peers.iter_mut() {
+			if !current.contains_key(peer) {
+				samples.push(PeerSample::default());
+			}
+		}
+
+		self.last = current;
+	}
+
+	/// Returns the recorded trace.
+	pub fn finish(self) -> NetworkTrace {
+		NetworkTrace {
+			interval_ms: self.interval.as_millis() as u64,
+			peers: self
+				.peers
+				.into_iter()
+				.map(|(peer, samples)| PeerTrace { peer, samples })
+				.collect(),
+		}
+	}
+
+	fn peer_counters(&self, samples: &[Sample]) -> HashMap<String, PeerCounters> {
+		let latency_sum = format!("{}_sum", self.latency_metric);
+		let latency_count = format!("{}_count", self.latency_metric);
+
+		let mut peers = HashMap::<String, PeerCounters>::new();
+		for sample in samples {
+			let Some(peer) = sample.labels.get(&self.peer_label) else { continue };
+			let value = match sample.value {
+				Value::Counter(value) | Value::Gauge(value) | Value::Untyped(value) => value,
+				// The buckets are not needed, the `_sum` and `_count` samples are enough.
+				Value::Histogram(_) | Value::Summary(_) => continue,
+			};
+
+			let metric = sample.metric.as_str();
+			if metric == self.bandwidth_metric {
+				peers.entry(peer.to_string()).or_default().bytes += value;
+			} else if metric == self.latency_metric {
+				peers.entry(peer.to_string()).or_default().latency_seconds = Some(value);
+			} else if metric == latency_sum {
+				peers.entry(peer.to_string()).or_default().latency_sum += value;
+			} else if metric == latency_count {
+				peers.entry(peer.to_string()).or_default().latency_count += value;
+			}
+		}
+
+		peers
+	}
+}
+
+fn millis(seconds: f64) -> usize {
+	(seconds * 1000.0).round() as usize
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prometheus_parse::Scrape;
+
+	fn scrape(text: &str) -> Vec<Sample> {
+		Scrape::parse(text.lines().map(|line| Ok(line.to_owned()))).unwrap().samples
+	}
+
+	#[test]
+	fn records_rates_latencies_and_churn() {
+		let mut recorder =
+			TraceRecorder::new(Duration::from_secs(2), "peer", "peer_bytes", "peer_rtt_seconds");
+
+		recorder.observe(&scrape(
+			"# TYPE peer_bytes counter
+peer_bytes{peer=\"a\",direction=\"in\"} 1000
+peer_bytes{peer=\"a\",direction=\"out\"} 1000
+peer_bytes{peer=\"b\",direction=\"in\"} 500
+# TYPE peer_rtt_seconds histogram
+peer_rtt_seconds_bucket{peer=\"a\",le=\"+Inf\"} 2
+peer_rtt_seconds_sum{peer=\"a\"} 0.1
+peer_rtt_seconds_count{peer=\"a\"} 2",
+		));
+		recorder.observe(&scrape(
+			"# TYPE peer_bytes counter
+peer_bytes{peer=\"a\",direction=\"in\"} 3000
+peer_bytes{peer=\"a\",direction=\"out\"} 3000
+peer_bytes{peer=\"b\",direction=\"in\"} 500
+# TYPE peer_rtt_seconds histogram
+peer_rtt_seconds_bucket{peer=\"a\",le=\"+Inf\"} 4
+peer_rtt_seconds_sum{peer=\"a\"} 0.3
+peer_rtt_seconds_count{peer=\"a\"} 4",
+		));
+		// `b` goes away and `c` shows up.
+		recorder.observe(&scrape(
+			"# TYPE peer_bytes counter
+peer_bytes{peer=\"a\",direction=\"in\"} 3000
+peer_bytes{peer=\"a\",direction=\"out\"} 3000
+peer_bytes{peer=\"c\",direction=\"in\"} 100",
+		));
+
+		let trace = recorder.finish();
+		assert_eq!(trace.interval_ms, 2000);
+		assert_eq!(trace.len(), 2);
+		assert_eq!(
+			trace.peers,
+			vec![
+				PeerTrace {
+					peer: "a".into(),
+					samples: vec![
+						PeerSample {
+							connected: true,
+							latency_ms: Some(100),
+							throughput: Some(2000)
+						},
+						PeerSample { connected: true, latency_ms: None, throughput: None },
+					],
+				},
+				PeerTrace {
+					peer: "b".into(),
+					samples: vec![
+						PeerSample { connected: true, latency_ms: None, throughput: None },
+						PeerSample::default(),
+					],
+				},
+				PeerTrace {
+					peer: "c".into(),
+					samples: vec![
+						PeerSample::default(),
+						PeerSample { connected: true, latency_ms: None, throughput: None },
+					],
+				},
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn replays_trace_in_a_loop() {
+		let trace = NetworkTrace {
+			interval_ms: 200,
+			peers: vec![PeerTrace {
+				peer: "a".into(),
+				samples: vec![
+					PeerSample { connected: true, latency_ms: Some(5), throughput: Some(4000) },
+					PeerSample { connected: false, latency_ms: None, throughput: None },
+					PeerSample { connected: true, latency_ms: None, throughput: Some(20_000) },
+				],
+			}],
+		};
+		let conditions = Arc::new(PeerConditions::new(30, 10_000));
+		let replay = tokio::spawn(replay(trace, vec![(conditions.clone(), 0)]));
+
+		// The first sample applies right away, the recorded traffic uses part of the bandwidth.
+		tokio::time::sleep(Duration::from_millis(100)).await;
+		assert_eq!(
+			(conditions.latency_ms(), conditions.bandwidth(), conditions.is_connected()),
+			(5, 6000, true)
+		);
+
+		// Intervals without observations fall back to the static parameters.
+		tokio::time::sleep(Duration::from_millis(200)).await;
+		assert_eq!(
+			(conditions.latency_ms(), conditions.bandwidth(), conditions.is_connected()),
+			(30, 10_000, false)
+		);
+
+		// Recorded traffic above the bandwidth leaves the peer a minimal share of it.
+		tokio::time::sleep(Duration::from_millis(200)).await;
+		assert_eq!(conditions.bandwidth(), 1000);
+
+		// And the trace starts over.
+		tokio::time::sleep(Duration::from_millis(200)).await;
+		assert!(conditions.is_connected());
+
+		replay.abort();
+	}
+}
@@ -96,7 +96,7 @@ fn build_overseer(
 	);
 	let chain_api_state = ChainApiState { block_headers: state.block_headers.clone() };
 	let mock_chain_api = MockChainApi::new(chain_api_state);
-	let mock_prospective_parachains = MockProspectiveParachains::new();
+	let mock_prospective_parachains = MockProspectiveParachains::new(state.pvd.clone());
 	let mock_candidate_backing = MockCandidateBacking::new(
 		state.config.clone(),
 		state
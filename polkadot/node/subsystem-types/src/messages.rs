@@ -37,9 +37,9 @@ use polkadot_node_primitives::{
 		v2::{CandidateBitfield, IndirectAssignmentCertV2, IndirectSignedApprovalVoteV2},
 	},
 	AvailableData, BabeEpoch, BlockWeight, CandidateVotes, CollationGenerationConfig,
-	CollationSecondedSignal, DisputeMessage, DisputeStatus, ErasureChunk, PoV,
-	SignedDisputeStatement, SignedFullStatement, SignedFullStatementWithPVD, SubmitCollationParams,
-	ValidationResult,
+	CollationSecondedSignal, DisputeMessage, DisputeStatus, ErasureChunk,
+	FragmentChainIntrospection, PoV, SignedDisputeEvidence, SignedDisputeStatement,
+	SignedFullStatement, SignedFullStatementWithPVD, SubmitCollationParams, ValidationResult,
 };
 use polkadot_primitives::{
 	self,
@@ -377,6 +377,12 @@ pub enum DisputeCoordinatorMessage {
 		Vec<(SessionIndex, CandidateHash)>,
 		oneshot::Sender<Vec<(SessionIndex, CandidateHash, CandidateVotes)>>,
 	),
+	/// Export all evidence about the most recent dispute on a candidate, signed with the key this
+	/// node holds in the session of the dispute.
+	///
+	/// Responds with `None` if there is no recent dispute on the candidate or this node is not a
+	/// validator in its session.
+	QueryDisputeEvidence(CandidateHash, oneshot::Sender<Option<SignedDisputeEvidence>>),
 	/// Sign and issue local dispute votes. A value of `true` indicates validity, and `false`
 	/// invalidity.
 	IssueLocalStatement(SessionIndex, CandidateHash, CandidateReceipt, bool),
@@ -1554,4 +1560,9 @@ pub enum ProspectiveParachainsMessage {
 		ProspectiveValidationDataRequest,
 		oneshot::Sender<Option<PersistedValidationData>>,
 	),
+	/// Get a snapshot of the fragment chain of the given para under each active leaf, for
+	/// diagnostics.
+	///
+	/// The response is empty if the para is not scheduled under any active leaf.
+	IntrospectFragmentChains(ParaId, oneshot::Sender<Vec<FragmentChainIntrospection>>),
 }
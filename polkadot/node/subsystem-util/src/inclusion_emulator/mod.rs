@@ -81,9 +81,13 @@
 /// That means a few blocks of execution time lost, which is not a big deal for code upgrades
 /// in practice at most once every few weeks.
 use polkadot_primitives::{
-	async_backing::Constraints as PrimitiveConstraints, skip_ump_signals, BlockNumber,
-	CandidateCommitments, Hash, HeadData, Id as ParaId, PersistedValidationData,
-	UpgradeRestriction, ValidationCodeHash,
+	async_backing::{
+		Constraints as PrimitiveConstraints,
+		InboundHrmpLimitations as PrimitiveInboundHrmpLimitations,
+		OutboundHrmpChannelLimitations as PrimitiveOutboundHrmpChannelLimitations,
+	},
+	skip_ump_signals, BlockNumber, CandidateCommitments, Hash, HeadData, Id as ParaId,
+	PersistedValidationData, UpgradeRestriction, ValidationCodeHash,
 };
 use std::{collections::HashMap, sync::Arc};
 
@@ -177,6 +181,45 @@ impl From<PrimitiveConstraints> for Constraints {
 	}
 }
 
+impl From<&Constraints> for PrimitiveConstraints {
+	fn from(c: &Constraints) -> Self {
+		let mut hrmp_channels_out: Vec<_> = c
+			.hrmp_channels_out
+			.iter()
+			.map(|(para_id, limits)| {
+				(
+					*para_id,
+					PrimitiveOutboundHrmpChannelLimitations {
+						bytes_remaining: limits.bytes_remaining as _,
+						messages_remaining: limits.messages_remaining as _,
+					},
+				)
+			})
+			.collect();
+		hrmp_channels_out.sort_by_key(|(para_id, _)| *para_id);
+
+		PrimitiveConstraints {
+			min_relay_parent_number: c.min_relay_parent_number,
+			max_pov_size: c.max_pov_size as _,
+			max_code_size: c.max_code_size as _,
+			max_head_data_size: c.max_head_data_size as _,
+			ump_remaining: c.ump_remaining as _,
+			ump_remaining_bytes: c.ump_remaining_bytes as _,
+			max_ump_num_per_candidate: c.max_ump_num_per_candidate as _,
+			dmp_remaining_messages: c.dmp_remaining_messages.clone(),
+			hrmp_inbound: PrimitiveInboundHrmpLimitations {
+				valid_watermarks: c.hrmp_inbound.valid_watermarks.clone(),
+			},
+			hrmp_channels_out,
+			max_hrmp_num_per_candidate: c.max_hrmp_num_per_candidate as _,
+			required_parent: c.required_parent.clone(),
+			validation_code_hash: c.validation_code_hash,
+			upgrade_restriction: c.upgrade_restriction,
+			future_validation_code: c.future_validation_code,
+		}
+	}
+}
+
 /// Kinds of errors that can occur when modifying constraints.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModificationError {
@@ -950,6 +993,22 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn constraints_roundtrip_through_primitive() {
+		let constraints = make_constraints();
+		let primitive = PrimitiveConstraints::from(&constraints);
+
+		assert_eq!(
+			primitive
+				.hrmp_channels_out
+				.iter()
+				.map(|(para_id, _)| *para_id)
+				.collect::<Vec<_>>(),
+			vec![ParaId::from(1u32), ParaId::from(2u32), ParaId::from(3u32)],
+		);
+		assert_eq!(Constraints::from(primitive), constraints);
+	}
+
 	#[test]
 	fn constraints_check_trunk_watermark() {
 		let constraints = make_constraints();
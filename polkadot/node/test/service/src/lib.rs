@@ -99,7 +99,12 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		execute_workers_max_num: None,
 		prepare_workers_hard_max_num: None,
 		prepare_workers_soft_max_num: None,
+		pvf_dump_dir: None,
+		pvf_prewarm: false,
+		pvf_prewarm_workers_max_num: None,
+		pvf_prewarm_disk_limit: None,
 		keep_finalized_for: None,
+		erasure_coding_backend: polkadot_service::ErasureCodingBackend::Sequential,
 		invulnerable_ah_collators: HashSet::new(),
 		collator_protocol_hold_off: None,
 		experimental_collator_protocol: false,
@@ -0,0 +1,306 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Schema for the XCM topic indexer state persisted in the aux-db.
+
+use crate::TopicEventRecord;
+use codec::{Decode, Encode};
+use sc_client_api::backend::AuxStore;
+use sp_blockchain::{Error as ClientError, Result as ClientResult};
+use sp_runtime::traits::{Block, CheckedSub, NumberFor};
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+const VERSION_KEY: &[u8] = b"xcm_topic_indexer_version";
+const INDEXER_STATE: &[u8] = b"xcm_topic_indexer_state";
+const TOPIC_EVENTS_PREFIX: &[u8] = b"xcm_topic_events";
+const BLOCK_TOPICS_PREFIX: &[u8] = b"xcm_topic_block_topics";
+
+const CURRENT_VERSION: u32 = 1;
+
+/// The maximal number of events indexed for a single topic.
+///
+/// Topics are chosen by the message senders, so someone may reuse the same topic for any number
+/// of messages. Only the latest events are kept, so the later hops of a message are always
+/// indexed.
+pub(crate) const MAX_EVENTS_PER_TOPIC: usize = 64;
+
+/// The number of blocks for which the indexed events are kept.
+///
+/// Events of older blocks are pruned. It is a week of blocks, if a block is produced every six
+/// seconds.
+pub(crate) const EVENTS_HISTORY_DEPTH: u32 = 7 * 14_400;
+
+/// The number of the last indexed block.
+pub(crate) type PersistedState<B> = NumberFor<B>;
+
+/// An indexed event together with its topic.
+pub(crate) type BlockTopicEvent<B> = ([u8; 32], TopicEventRecord<<B as Block>::Hash, NumberFor<B>>);
+
+fn topic_events_key(topic: &[u8; 32]) -> Vec<u8> {
+	(TOPIC_EVENTS_PREFIX, topic).encode()
+}
+
+fn block_topics_key<N: Encode>(number: N) -> Vec<u8> {
+	(BLOCK_TOPICS_PREFIX, number).encode()
+}
+
+fn load_decode<B: AuxStore, T: Decode>(backend: &B, key: &[u8]) -> ClientResult<Option<T>> {
+	match backend.get_aux(key)? {
+		None => Ok(None),
+		Some(t) => T::decode(&mut &t[..])
+			.map_err(|e| {
+				ClientError::Backend(format!("XCM topic indexer aux DB is corrupted: {}", e))
+			})
+			.map(Some),
+	}
+}
+
+/// Load the indexer state from backend.
+pub(crate) fn load_state<B, BE>(backend: &BE) -> ClientResult<Option<PersistedState<B>>>
+where
+	B: Block,
+	BE: AuxStore,
+{
+	let version: Option<u32> = load_decode(backend, VERSION_KEY)?;
+
+	match version {
+		None => Ok(None),
+		Some(1) => load_decode::<_, PersistedState<B>>(backend, INDEXER_STATE),
+		other => Err(ClientError::Backend(format!(
+			"Unsupported XCM topic indexer aux DB version: {:?}",
+			other
+		))),
+	}
+}
+
+/// Load the events indexed for `topic`.
+pub(crate) fn load_topic_events<B, BE>(
+	backend: &BE,
+	topic: &[u8; 32],
+) -> ClientResult<Vec<TopicEventRecord<B::Hash, NumberFor<B>>>>
+where
+	B: Block,
+	BE: AuxStore,
+{
+	Ok(load_decode(backend, &topic_events_key(topic))?.unwrap_or_default())
+}
+
+/// Get the events of `topic` from the `topics` cache, loading them from the backend if needed.
+fn load_topic<'a, B, BE>(
+	backend: &BE,
+	topics: &'a mut BTreeMap<[u8; 32], Vec<TopicEventRecord<B::Hash, NumberFor<B>>>>,
+	topic: [u8; 32],
+) -> ClientResult<&'a mut Vec<TopicEventRecord<B::Hash, NumberFor<B>>>>
+where
+	B: Block,
+	BE: AuxStore,
+{
+	Ok(match topics.entry(topic) {
+		Entry::Occupied(entry) => entry.into_mut(),
+		Entry::Vacant(entry) => entry.insert(load_topic_events::<B, _>(backend, &topic)?),
+	})
+}
+
+/// Index `events` of the block `number` and persist it as the last indexed block.
+///
+/// Events of the block that has left the [`EVENTS_HISTORY_DEPTH`] window are pruned. Everything
+/// is written in a single transaction, so the indexer never indexes a block twice.
+pub(crate) fn write_block_events<B, BE>(
+	backend: &BE,
+	number: NumberFor<B>,
+	events: Vec<BlockTopicEvent<B>>,
+) -> ClientResult<()>
+where
+	B: Block,
+	BE: AuxStore,
+{
+	let mut topics = BTreeMap::new();
+
+	let mut delete = Vec::new();
+	if let Some(pruned_number) = number.checked_sub(&EVENTS_HISTORY_DEPTH.into()) {
+		let pruned_topics_key = block_topics_key(pruned_number);
+		let pruned_topics: Vec<[u8; 32]> =
+			load_decode(backend, &pruned_topics_key)?.unwrap_or_default();
+		for topic in pruned_topics {
+			load_topic::<B, _>(backend, &mut topics, topic)?
+				.retain(|event| event.block_number > pruned_number);
+		}
+		delete.push(pruned_topics_key);
+	}
+
+	let mut block_topics = BTreeSet::new();
+	for (topic, event) in events {
+		let topic_events = load_topic::<B, _>(backend, &mut topics, topic)?;
+		topic_events.push(event);
+		if topic_events.len() > MAX_EVENTS_PER_TOPIC {
+			topic_events.drain(..topic_events.len() - MAX_EVENTS_PER_TOPIC);
+		}
+		block_topics.insert(topic);
+	}
+
+	let mut insert = Vec::new();
+	for (topic, topic_events) in topics {
+		if topic_events.is_empty() {
+			delete.push(topic_events_key(&topic));
+		} else {
+			insert.push((topic_events_key(&topic), topic_events.encode()));
+		}
+	}
+	if !block_topics.is_empty() {
+		insert.push((
+			block_topics_key(number),
+			block_topics.into_iter().collect::<Vec<_>>().encode(),
+		));
+	}
+	insert.push((VERSION_KEY.to_vec(), CURRENT_VERSION.encode()));
+	insert.push((INDEXER_STATE.to_vec(), number.encode()));
+
+	let insert = insert.iter().map(|(key, value)| (&key[..], &value[..])).collect::<Vec<_>>();
+	let delete = delete.iter().map(|key| &key[..]).collect::<Vec<_>>();
+	backend.insert_aux(&insert, &delete)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use crate::TopicEventKind;
+	use parking_lot::Mutex;
+	use sp_runtime::testing::{Block as RawBlock, MockCallU64, TestXt};
+	use std::collections::HashMap;
+
+	pub(crate) type Block = RawBlock<TestXt<MockCallU64, ()>>;
+
+	#[derive(Default)]
+	pub(crate) struct TestAuxStore(Mutex<HashMap<Vec<u8>, Vec<u8>>>);
+
+	impl AuxStore for TestAuxStore {
+		fn insert_aux<
+			'a,
+			'b: 'a,
+			'c: 'a,
+			I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+			D: IntoIterator<Item = &'a &'b [u8]>,
+		>(
+			&self,
+			insert: I,
+			delete: D,
+		) -> ClientResult<()> {
+			let mut storage = self.0.lock();
+			for (key, value) in insert {
+				storage.insert(key.to_vec(), value.to_vec());
+			}
+			for key in delete {
+				storage.remove(*key);
+			}
+			Ok(())
+		}
+
+		fn get_aux(&self, key: &[u8]) -> ClientResult<Option<Vec<u8>>> {
+			Ok(self.0.lock().get(key).cloned())
+		}
+	}
+
+	pub(crate) fn event(
+		topic: u8,
+		block_number: u64,
+		kind: TopicEventKind,
+		event_index: u32,
+	) -> BlockTopicEvent<Block> {
+		let block_hash = [block_number as u8; 32].into();
+		([topic; 32], TopicEventRecord { block_number, block_hash, event_index, kind })
+	}
+
+	#[test]
+	fn block_events_are_indexed_by_topic() {
+		let backend = TestAuxStore::default();
+		assert_eq!(load_state::<Block, _>(&backend).unwrap(), None);
+
+		write_block_events::<Block, _>(
+			&backend,
+			1,
+			vec![event(1, 1, TopicEventKind::Sent, 0), event(2, 1, TopicEventKind::Processed, 3)],
+		)
+		.unwrap();
+		write_block_events::<Block, _>(&backend, 2, vec![event(1, 2, TopicEventKind::Failed, 1)])
+			.unwrap();
+
+		assert_eq!(load_state::<Block, _>(&backend).unwrap(), Some(2));
+		assert_eq!(
+			load_topic_events::<Block, _>(&backend, &[1; 32]).unwrap(),
+			vec![
+				TopicEventRecord {
+					block_number: 1,
+					block_hash: [1; 32].into(),
+					event_index: 0,
+					kind: TopicEventKind::Sent,
+				},
+				TopicEventRecord {
+					block_number: 2,
+					block_hash: [2; 32].into(),
+					event_index: 1,
+					kind: TopicEventKind::Failed,
+				},
+			],
+		);
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[2; 32]).unwrap().len(), 1);
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[3; 32]).unwrap(), vec![]);
+	}
+
+	#[test]
+	fn only_latest_events_are_kept_above_the_limit() {
+		let backend = TestAuxStore::default();
+		let events = (0..MAX_EVENTS_PER_TOPIC as u32)
+			.map(|event_index| event(1, 1, TopicEventKind::Sent, event_index))
+			.collect();
+		write_block_events::<Block, _>(&backend, 1, events).unwrap();
+		write_block_events::<Block, _>(&backend, 2, vec![event(1, 2, TopicEventKind::Failed, 0)])
+			.unwrap();
+
+		let indexed = load_topic_events::<Block, _>(&backend, &[1; 32]).unwrap();
+		assert_eq!(indexed.len(), MAX_EVENTS_PER_TOPIC);
+		assert_eq!(indexed.first().unwrap().event_index, 1);
+		assert_eq!(indexed.last().unwrap().kind, TopicEventKind::Failed);
+	}
+
+	#[test]
+	fn events_of_old_blocks_are_pruned() {
+		let backend = TestAuxStore::default();
+		write_block_events::<Block, _>(&backend, 1, vec![event(1, 1, TopicEventKind::Sent, 0)])
+			.unwrap();
+		write_block_events::<Block, _>(
+			&backend,
+			2,
+			vec![event(1, 2, TopicEventKind::Failed, 0), event(2, 2, TopicEventKind::Sent, 0)],
+		)
+		.unwrap();
+
+		let depth = EVENTS_HISTORY_DEPTH as u64;
+		write_block_events::<Block, _>(&backend, depth, vec![]).unwrap();
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[1; 32]).unwrap().len(), 2);
+
+		write_block_events::<Block, _>(&backend, depth + 1, vec![]).unwrap();
+		assert_eq!(
+			load_topic_events::<Block, _>(&backend, &[1; 32]).unwrap(),
+			vec![event(1, 2, TopicEventKind::Failed, 0).1],
+		);
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[2; 32]).unwrap().len(), 1);
+
+		write_block_events::<Block, _>(&backend, depth + 2, vec![]).unwrap();
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[1; 32]).unwrap(), vec![]);
+		assert_eq!(load_topic_events::<Block, _>(&backend, &[2; 32]).unwrap(), vec![]);
+		assert_eq!(backend.0.lock().len(), 2);
+	}
+}
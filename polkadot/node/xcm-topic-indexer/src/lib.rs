@@ -0,0 +1,512 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM topic indexer
+//!
+//! Messages sent with `pallet-xcm` carry a topic, set by the `SetTopic` instruction, which is
+//! kept when the message is forwarded to other chains. The indexer follows the finalized blocks
+//! of the chain and stores every event tied to a topic (sent, queued, processed, failed,
+//! forwarded or routed) in the aux-db of the node, together with the block it has been
+//! deposited at.
+//!
+//! The events are queried by topic with the `xcm_topicEvents` RPC method. Running the indexer
+//! at nodes of all chains on the path of a message allows to reconstruct the full path of the
+//! message.
+//!
+//! Events are read using the `XcmTopicEventsApi` runtime API. Blocks of runtimes that don't
+//! implement the API and blocks which state is no longer available are skipped. The indexer
+//! starts with the block that is finalized when it is first started, i.e. it doesn't index the
+//! history of the chain.
+//!
+//! Only the latest events of every topic are kept and events of old blocks are pruned, so the
+//! size of the index is bounded.
+//!
+//! Nodes start the indexer only if offchain indexing is enabled, i.e. with
+//! `--enable-offchain-indexing true`. Otherwise the RPC method returns an error.
+
+#![warn(missing_docs)]
+
+mod aux_schema;
+mod rpc;
+
+pub use rpc::{XcmTopics, XcmTopicsApiServer};
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use log::{debug, error, info, warn};
+use sc_client_api::{AuxStore, BlockchainEvents};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	traits::{Block, Header, NumberFor, One},
+	Saturating,
+};
+use std::sync::Arc;
+use xcm_runtime_apis::topic_events::{XcmTopicEventKind, XcmTopicEventsApi};
+
+/// Logging target for the XCM topic indexer.
+pub const LOG_TARGET: &str = "xcm-topic-indexer";
+
+/// What happened to an XCM message at the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TopicEventKind {
+	/// The message has been sent from the chain.
+	Sent,
+	/// The message has been received, but it is waiting in a queue of the chain.
+	Queued,
+	/// The message has been received and processed successfully.
+	Processed,
+	/// The message has failed to be processed or sent.
+	Failed,
+	/// The message has been sent further while processing a received message with the same topic.
+	Forwarded,
+	/// The message has been handed over to the transport, e.g. an XCMP or UMP queue or a bridge
+	/// outbound lane.
+	Routed,
+}
+
+impl From<XcmTopicEventKind> for TopicEventKind {
+	fn from(kind: XcmTopicEventKind) -> Self {
+		match kind {
+			XcmTopicEventKind::Sent => TopicEventKind::Sent,
+			XcmTopicEventKind::Queued => TopicEventKind::Queued,
+			XcmTopicEventKind::Processed => TopicEventKind::Processed,
+			XcmTopicEventKind::Failed => TopicEventKind::Failed,
+			XcmTopicEventKind::Forwarded => TopicEventKind::Forwarded,
+			XcmTopicEventKind::Routed => TopicEventKind::Routed,
+		}
+	}
+}
+
+/// An indexed event tied to an XCM topic.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicEventRecord<Hash, Number> {
+	/// The number of the block the event has been deposited at.
+	pub block_number: Number,
+	/// The hash of the block the event has been deposited at.
+	pub block_hash: Hash,
+	/// The index of the event in the block.
+	pub event_index: u32,
+	/// What happened to the message.
+	pub kind: TopicEventKind,
+}
+
+/// The XCM topic indexer.
+pub struct XcmTopicIndexer<B: Block, C> {
+	client: Arc<C>,
+	last_indexed: NumberFor<B>,
+}
+
+impl<B, C> XcmTopicIndexer<B, C>
+where
+	B: Block,
+	C: BlockchainEvents<B> + HeaderBackend<B> + ProvideRuntimeApi<B> + AuxStore,
+	C::Api: XcmTopicEventsApi<B>,
+{
+	/// Create and run the XCM topic indexer.
+	pub async fn start(client: Arc<C>) {
+		let mut finality_notifications = client.finality_notification_stream();
+
+		let last_indexed = match aux_schema::load_state::<B, _>(&*client) {
+			Ok(Some(last_indexed)) => last_indexed,
+			Ok(None) => {
+				let finalized_number = client.info().finalized_number;
+				info!(
+					target: LOG_TARGET,
+					"Starting to index XCM topics at block {:?}", finalized_number
+				);
+				finalized_number
+			},
+			Err(e) => {
+				error!(target: LOG_TARGET, "Failed to load the XCM topic indexer state: {}", e);
+				return;
+			},
+		};
+
+		let mut indexer = XcmTopicIndexer { client, last_indexed };
+		while let Some(notification) = finality_notifications.next().await {
+			indexer.index_finalized(*notification.header.number());
+		}
+
+		error!(target: LOG_TARGET, "Finality notifications stream closed unexpectedly");
+	}
+}
+
+impl<B, C> XcmTopicIndexer<B, C>
+where
+	B: Block,
+	C: HeaderBackend<B> + ProvideRuntimeApi<B> + AuxStore,
+	C::Api: XcmTopicEventsApi<B>,
+{
+	/// Index all blocks up to the finalized block `finalized_number`.
+	///
+	/// Blocks which events can't be read, e.g. because their state has been pruned, are skipped.
+	/// If the indexed events can't be written to the aux-db, the indexing stops and is retried
+	/// with the next finalized block.
+	fn index_finalized(&mut self, finalized_number: NumberFor<B>) {
+		let mut skipped = 0u32;
+		while self.last_indexed < finalized_number {
+			let number = self.last_indexed.saturating_add(One::one());
+			let events = self.block_events(number).unwrap_or_else(|e| {
+				debug!(target: LOG_TARGET, "Skipping XCM topics at block {:?}: {}", number, e);
+				skipped += 1;
+				Vec::new()
+			});
+			if let Err(e) = aux_schema::write_block_events::<B, _>(&*self.client, number, events) {
+				error!(target: LOG_TARGET, "Failed to index XCM topics at block {:?}: {}", number, e);
+				break;
+			}
+			self.last_indexed = number;
+		}
+
+		if skipped != 0 {
+			warn!(
+				target: LOG_TARGET,
+				"Skipped {} blocks up to {:?}, because their XCM topic events can't be read",
+				skipped,
+				self.last_indexed,
+			);
+		}
+	}
+
+	fn block_events(
+		&self,
+		number: NumberFor<B>,
+	) -> sp_blockchain::Result<Vec<aux_schema::BlockTopicEvent<B>>> {
+		let hash = self
+			.client
+			.hash(number)?
+			.ok_or_else(|| sp_blockchain::Error::UnknownBlock(format!("{:?}", number)))?;
+
+		let api = self.client.runtime_api();
+		let events = if api.has_api::<dyn XcmTopicEventsApi<B>>(hash)? {
+			api.topic_events(hash)?
+		} else {
+			debug!(target: LOG_TARGET, "XcmTopicEventsApi is not available at block {:?}", hash);
+			Vec::new()
+		};
+
+		Ok(events
+			.into_iter()
+			.map(|event| {
+				let record = TopicEventRecord {
+					block_number: number,
+					block_hash: hash,
+					event_index: event.event_index,
+					kind: event.kind.into(),
+				};
+				(event.topic, record)
+			})
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::aux_schema::{
+		load_topic_events,
+		tests::{Block, TestAuxStore},
+	};
+	use parking_lot::Mutex;
+	use sp_api::{
+		ApiError, ApiRef, CallContext, Core, ProofRecorder, RuntimeApiInfo, StorageChanges,
+		StorageProof, TransactionOutcome,
+	};
+	use sp_blockchain::{BlockStatus, Info};
+	use sp_core::H256;
+	use sp_externalities::Extension;
+	use sp_runtime::traits::HashingFor;
+	use sp_state_machine::{Backend as StateBackend, OverlayedChanges};
+	use sp_version::RuntimeVersion;
+	use std::collections::HashMap;
+	use xcm_runtime_apis::topic_events::XcmTopicEvent;
+
+	/// What the runtime API returns at a block.
+	#[derive(Clone)]
+	enum TestBlock {
+		/// The events of the block.
+		Events(Vec<XcmTopicEvent>),
+		/// The runtime of the block doesn't implement `XcmTopicEventsApi`.
+		NoApi,
+		/// The state of the block has been pruned.
+		Pruned,
+	}
+
+	#[derive(Default)]
+	struct TestClient {
+		aux: TestAuxStore,
+		blocks: Mutex<HashMap<H256, TestBlock>>,
+		fail_writes: Mutex<bool>,
+	}
+
+	impl TestClient {
+		fn with_blocks(blocks: Vec<(u64, TestBlock)>) -> Arc<Self> {
+			let blocks = blocks.into_iter().map(|(number, block)| (block_hash(number), block));
+			Arc::new(TestClient { blocks: Mutex::new(blocks.collect()), ..Default::default() })
+		}
+	}
+
+	struct TestApi {
+		blocks: HashMap<H256, TestBlock>,
+	}
+
+	fn block_hash(number: u64) -> H256 {
+		H256::from_low_u64_be(number)
+	}
+
+	fn event(topic: u8, kind: XcmTopicEventKind, event_index: u32) -> XcmTopicEvent {
+		XcmTopicEvent { topic: [topic; 32], kind, event_index }
+	}
+
+	fn indexer(client: Arc<TestClient>) -> XcmTopicIndexer<Block, TestClient> {
+		XcmTopicIndexer { client, last_indexed: 0 }
+	}
+
+	fn indexed(client: &TestClient, topic: u8) -> Vec<(u64, TopicEventKind)> {
+		load_topic_events::<Block, _>(client, &[topic; 32])
+			.unwrap()
+			.into_iter()
+			.map(|record| (record.block_number, record.kind))
+			.collect()
+	}
+
+	impl HeaderBackend<Block> for TestClient {
+		fn header(&self, _: H256) -> sp_blockchain::Result<Option<sp_runtime::testing::Header>> {
+			unimplemented!()
+		}
+
+		fn info(&self) -> Info<Block> {
+			unimplemented!()
+		}
+
+		fn status(&self, _: H256) -> sp_blockchain::Result<BlockStatus> {
+			unimplemented!()
+		}
+
+		fn number(&self, _: H256) -> sp_blockchain::Result<Option<u64>> {
+			unimplemented!()
+		}
+
+		fn hash(&self, number: u64) -> sp_blockchain::Result<Option<H256>> {
+			let hash = block_hash(number);
+			Ok(self.blocks.lock().contains_key(&hash).then_some(hash))
+		}
+	}
+
+	impl ProvideRuntimeApi<Block> for TestClient {
+		type Api = TestApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			TestApi { blocks: self.blocks.lock().clone() }.into()
+		}
+	}
+
+	impl AuxStore for TestClient {
+		fn insert_aux<
+			'a,
+			'b: 'a,
+			'c: 'a,
+			I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+			D: IntoIterator<Item = &'a &'b [u8]>,
+		>(
+			&self,
+			insert: I,
+			delete: D,
+		) -> sp_blockchain::Result<()> {
+			if *self.fail_writes.lock() {
+				return Err(sp_blockchain::Error::Backend("aux-db is not writable".into()));
+			}
+			self.aux.insert_aux(insert, delete)
+		}
+
+		fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+			self.aux.get_aux(key)
+		}
+	}
+
+	impl TestApi {
+		fn block(&self, at: H256) -> Result<&TestBlock, ApiError> {
+			match self.blocks.get(&at) {
+				Some(TestBlock::Pruned) => Err(ApiError::UnknownBlock("state is pruned".into())),
+				Some(block) => Ok(block),
+				None => Err(ApiError::UnknownBlock(format!("{:?}", at))),
+			}
+		}
+	}
+
+	impl ApiExt<Block> for TestApi {
+		fn execute_in_transaction<F: FnOnce(&Self) -> TransactionOutcome<R>, R>(
+			&self,
+			call: F,
+		) -> R {
+			call(self).into_inner()
+		}
+
+		fn has_api<A: RuntimeApiInfo + ?Sized>(&self, at: H256) -> Result<bool, ApiError> {
+			assert_eq!(A::ID, <dyn XcmTopicEventsApi<Block>>::ID);
+			Ok(!matches!(self.block(at)?, TestBlock::NoApi))
+		}
+
+		fn has_api_with<A: RuntimeApiInfo + ?Sized, P: Fn(u32) -> bool>(
+			&self,
+			_: H256,
+			_: P,
+		) -> Result<bool, ApiError> {
+			unimplemented!()
+		}
+
+		fn api_version<A: RuntimeApiInfo + ?Sized>(
+			&self,
+			_: H256,
+		) -> Result<Option<u32>, ApiError> {
+			unimplemented!()
+		}
+
+		fn record_proof(&mut self) {
+			unimplemented!()
+		}
+
+		fn record_proof_with_recorder(&mut self, _: ProofRecorder<Block>) {
+			unimplemented!()
+		}
+
+		fn extract_proof(&mut self) -> Option<StorageProof> {
+			unimplemented!()
+		}
+
+		fn proof_recorder(&self) -> Option<ProofRecorder<Block>> {
+			unimplemented!()
+		}
+
+		fn into_storage_changes<S: StateBackend<HashingFor<Block>>>(
+			&self,
+			_: &S,
+			_: H256,
+		) -> Result<StorageChanges<Block>, String> {
+			unimplemented!()
+		}
+
+		fn set_call_context(&mut self, _: CallContext) {
+			unimplemented!()
+		}
+
+		fn register_extension<E: Extension>(&mut self, _: E) {
+			unimplemented!()
+		}
+
+		fn set_overlayed_changes(&mut self, _: OverlayedChanges<HashingFor<Block>>) {
+			unimplemented!()
+		}
+	}
+
+	impl Core<Block> for TestApi {
+		fn __runtime_api_internal_call_api_at(
+			&self,
+			_: H256,
+			_: Vec<u8>,
+			_: &dyn Fn(RuntimeVersion) -> &'static str,
+		) -> Result<Vec<u8>, ApiError> {
+			unimplemented!()
+		}
+	}
+
+	impl XcmTopicEventsApi<Block> for TestApi {
+		fn __runtime_api_internal_call_api_at(
+			&self,
+			at: H256,
+			_: Vec<u8>,
+			_: &dyn Fn(RuntimeVersion) -> &'static str,
+		) -> Result<Vec<u8>, ApiError> {
+			match self.block(at)? {
+				TestBlock::Events(events) => Ok(events.encode()),
+				_ => unreachable!("`topic_events` is only called if the API is available"),
+			}
+		}
+	}
+
+	#[test]
+	fn catches_up_with_finalized_blocks() {
+		let client = TestClient::with_blocks(vec![
+			(1, TestBlock::Events(vec![event(1, XcmTopicEventKind::Sent, 0)])),
+			(2, TestBlock::Events(vec![])),
+			(
+				3,
+				TestBlock::Events(vec![
+					event(2, XcmTopicEventKind::Processed, 1),
+					event(1, XcmTopicEventKind::Forwarded, 3),
+				]),
+			),
+		]);
+		let mut indexer = indexer(client.clone());
+
+		indexer.index_finalized(3);
+
+		assert_eq!(indexer.last_indexed, 3);
+		assert_eq!(aux_schema::load_state::<Block, _>(&*client).unwrap(), Some(3));
+		assert_eq!(
+			indexed(&client, 1),
+			vec![(1, TopicEventKind::Sent), (3, TopicEventKind::Forwarded)]
+		);
+		assert_eq!(indexed(&client, 2), vec![(3, TopicEventKind::Processed)]);
+	}
+
+	#[test]
+	fn blocks_without_readable_events_are_skipped() {
+		let client = TestClient::with_blocks(vec![
+			(1, TestBlock::Events(vec![event(1, XcmTopicEventKind::Sent, 0)])),
+			(2, TestBlock::NoApi),
+			(3, TestBlock::Pruned),
+			// The hash of block 4 is unknown.
+			(5, TestBlock::Events(vec![event(1, XcmTopicEventKind::Routed, 2)])),
+		]);
+		let mut indexer = indexer(client.clone());
+
+		indexer.index_finalized(5);
+
+		assert_eq!(indexer.last_indexed, 5);
+		assert_eq!(
+			indexed(&client, 1),
+			vec![(1, TopicEventKind::Sent), (5, TopicEventKind::Routed)]
+		);
+	}
+
+	#[test]
+	fn failed_writes_are_retried_with_the_next_finalized_block() {
+		let client = TestClient::with_blocks(vec![
+			(1, TestBlock::Events(vec![event(1, XcmTopicEventKind::Sent, 0)])),
+			(2, TestBlock::Events(vec![event(1, XcmTopicEventKind::Failed, 1)])),
+		]);
+		let mut indexer = indexer(client.clone());
+
+		*client.fail_writes.lock() = true;
+		indexer.index_finalized(1);
+		assert_eq!(indexer.last_indexed, 0);
+		assert_eq!(aux_schema::load_state::<Block, _>(&*client).unwrap(), None);
+		assert!(indexed(&client, 1).is_empty());
+
+		*client.fail_writes.lock() = false;
+		indexer.index_finalized(2);
+		assert_eq!(indexer.last_indexed, 2);
+		assert_eq!(
+			indexed(&client, 1),
+			vec![(1, TopicEventKind::Sent), (2, TopicEventKind::Failed)]
+		);
+	}
+}
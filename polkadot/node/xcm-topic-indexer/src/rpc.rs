@@ -0,0 +1,131 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC querying the events indexed by the XCM topic indexer.
+
+use crate::{aux_schema, TopicEventRecord};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use sc_client_api::AuxStore;
+use sp_core::H256;
+use sp_runtime::traits::{Block, NumberFor};
+use std::{marker::PhantomData, sync::Arc};
+
+/// The indexer doesn't run at the node.
+const INDEXER_NOT_RUNNING: i32 = 9000;
+
+/// XCM topics RPC methods.
+#[rpc(client, server)]
+pub trait XcmTopicsApi<BlockHash, BlockNumber> {
+	/// Returns the events indexed for the XCM `topic`, in the order they have been deposited.
+	///
+	/// Only finalized blocks are indexed. Returns an empty list if nothing has been indexed for
+	/// the topic. The indexer runs only at nodes started with `--enable-offchain-indexing true`,
+	/// an error is returned at other nodes.
+	#[method(name = "xcm_topicEvents")]
+	fn topic_events(&self, topic: H256)
+		-> RpcResult<Vec<TopicEventRecord<BlockHash, BlockNumber>>>;
+}
+
+/// An implementation of the XCM topics RPC methods.
+pub struct XcmTopics<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> XcmTopics<C, B> {
+	/// Create new XCM topics RPC methods, reading the events indexed in the aux-db of `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, B> XcmTopicsApiServer<B::Hash, NumberFor<B>> for XcmTopics<C, B>
+where
+	B: Block,
+	C: AuxStore + Send + Sync + 'static,
+{
+	fn topic_events(&self, topic: H256) -> RpcResult<Vec<TopicEventRecord<B::Hash, NumberFor<B>>>> {
+		let internal_error = |e: sp_blockchain::Error| {
+			ErrorObject::owned(ErrorCode::InternalError.code(), e.to_string(), None::<()>)
+		};
+
+		if aux_schema::load_state::<B, _>(&*self.client).map_err(internal_error)?.is_none() {
+			return Err(ErrorObject::owned(
+				INDEXER_NOT_RUNNING,
+				"The XCM topic indexer hasn't indexed any block at this node. It runs only if the \
+				 node is started with `--enable-offchain-indexing true`.",
+				None::<()>,
+			));
+		}
+
+		aux_schema::load_topic_events::<B, _>(&*self.client, topic.as_fixed_bytes())
+			.map_err(internal_error)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		aux_schema::{
+			tests::{event, Block, TestAuxStore},
+			write_block_events,
+		},
+		TopicEventKind,
+	};
+
+	#[tokio::test]
+	async fn topic_events_are_returned() {
+		let client = Arc::new(TestAuxStore::default());
+		write_block_events::<Block, _>(
+			&*client,
+			42,
+			vec![event(7, 42, TopicEventKind::Forwarded, 2)],
+		)
+		.unwrap();
+		let api = XcmTopics::<_, Block>::new(client).into_rpc();
+
+		let request = format!(
+			r#"{{"jsonrpc":"2.0","method":"xcm_topicEvents","params":["{:?}"],"id":1}}"#,
+			H256::repeat_byte(7),
+		);
+		let (response, _) = api.raw_json_request(&request, 1).await.unwrap();
+
+		let expected = format!(
+			r#"{{"jsonrpc":"2.0","id":1,"result":[{{"blockNumber":42,"blockHash":"{:?}","eventIndex":2,"kind":"forwarded"}}]}}"#,
+			H256::repeat_byte(42),
+		);
+		assert_eq!(response, expected);
+	}
+
+	#[tokio::test]
+	async fn error_is_returned_if_indexer_does_not_run() {
+		let client = Arc::new(TestAuxStore::default());
+		let api = XcmTopics::<_, Block>::new(client).into_rpc();
+
+		let request = format!(
+			r#"{{"jsonrpc":"2.0","method":"xcm_topicEvents","params":["{:?}"],"id":1}}"#,
+			H256::repeat_byte(7),
+		);
+		let (response, _) = api.raw_json_request(&request, 1).await.unwrap();
+
+		assert!(response.contains(&format!(r#""code":{}"#, INDEXER_NOT_RUNNING)));
+	}
+}
@@ -111,6 +111,8 @@ impl ValidationCode {
 	PartialEq,
 	PartialOrd,
 	Ord,
+	serde::Serialize,
+	serde::Deserialize,
 	TypeInfo,
 )]
 pub struct ValidationCodeHash(Hash);
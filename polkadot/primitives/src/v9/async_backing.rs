@@ -54,7 +54,9 @@ pub struct AsyncBackingParams {
 }
 
 /// Constraints on inbound HRMP channels.
-#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo)]
+#[derive(
+	Debug, Clone, PartialEq, Encode, Decode, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
 pub struct InboundHrmpLimitations<N = BlockNumber> {
 	/// An exhaustive set of all valid watermarks, sorted ascending.
 	///
@@ -64,7 +66,9 @@ pub struct InboundHrmpLimitations<N = BlockNumber> {
 }
 
 /// Constraints on outbound HRMP channels.
-#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo)]
+#[derive(
+	Debug, Clone, PartialEq, Encode, Decode, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
 pub struct OutboundHrmpChannelLimitations {
 	/// The maximum bytes that can be written to the channel.
 	pub bytes_remaining: u32,
@@ -75,7 +79,9 @@ pub struct OutboundHrmpChannelLimitations {
 /// Constraints on the actions that can be taken by a new parachain
 /// block. These limitations are implicitly associated with some particular
 /// parachain, which should be apparent from usage.
-#[derive(Debug, Clone, PartialEq, Encode, Decode, TypeInfo)]
+#[derive(
+	Debug, Clone, PartialEq, Encode, Decode, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
 pub struct Constraints<N = BlockNumber> {
 	/// The minimum relay-parent number accepted under these constraints.
 	pub min_relay_parent_number: N,
@@ -1005,7 +1005,9 @@ pub struct AbridgedHrmpChannel {
 }
 
 /// A possible upgrade restriction that prevents a parachain from performing an upgrade.
-#[derive(Copy, Clone, Encode, Decode, PartialEq, Debug, TypeInfo)]
+#[derive(
+	Copy, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
 pub enum UpgradeRestriction {
 	/// There is an upgrade restriction and there are no details about its specifics nor how long
 	/// it could last.
@@ -38,6 +38,12 @@ use sp_consensus_babe::BabeApi;
 use sp_consensus_beefy::AuthorityIdBound;
 use sp_keystore::KeystorePtr;
 
+mod parachains;
+
+pub use parachains::{
+	DisputeEvidenceSummary, Parachains, ParachainsApiClient, ParachainsApiServer,
+};
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = RpcModule<()>;
 
@@ -73,6 +79,12 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
 	pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
 
+/// Dependencies for the parachain subsystems RPCs.
+pub struct ParachainsDeps {
+	/// A handle to the overseer running the parachain subsystems.
+	pub overseer_handle: polkadot_overseer::Handle,
+}
+
 /// Full client dependencies
 pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	/// The client instance to use.
@@ -89,13 +101,25 @@ pub struct FullDeps<C, P, SC, B, AuthorityId: AuthorityIdBound> {
 	pub grandpa: GrandpaDeps<B>,
 	/// BEEFY specific dependencies.
 	pub beefy: BeefyDeps<AuthorityId>,
+	/// Parachain subsystems dependencies, only present if the node runs them.
+	pub parachains: Option<ParachainsDeps>,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
 }
 
 /// Instantiate all RPC extensions.
 pub fn create_full<C, P, SC, B, AuthorityId>(
-	FullDeps { client, pool, select_chain, chain_spec, babe, grandpa, beefy, backend }: FullDeps<
+	FullDeps {
+		client,
+		pool,
+		select_chain,
+		chain_spec,
+		babe,
+		grandpa,
+		beefy,
+		parachains,
+		backend,
+	}: FullDeps<
 		C,
 		P,
 		SC,
@@ -125,6 +149,7 @@ where
 {
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use polkadot_node_xcm_topic_indexer::{XcmTopics, XcmTopicsApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_beefy_rpc::{Beefy, BeefyApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
@@ -167,6 +192,7 @@ where
 		)
 		.into_rpc(),
 	)?;
+	io.merge(XcmTopics::<_, Block>::new(client.clone()).into_rpc())?;
 	io.merge(
 		SyncState::new(chain_spec, client, shared_authority_set, babe_worker_handle)?.into_rpc(),
 	)?;
@@ -180,5 +206,9 @@ where
 		.into_rpc(),
 	)?;
 
+	if let Some(ParachainsDeps { overseer_handle }) = parachains {
+		io.merge(Parachains::new(overseer_handle).into_rpc())?;
+	}
+
 	Ok(io)
 }
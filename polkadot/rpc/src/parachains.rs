@@ -0,0 +1,167 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPCs querying the parachain subsystems of the node.
+
+use codec::Encode;
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+	Extensions,
+};
+use polkadot_node_primitives::{
+	disputes::{DisputeStatus, LocalParticipation, SignedDisputeEvidence},
+	prospective_parachains::FragmentChainIntrospection,
+};
+use polkadot_node_subsystem_types::messages::{
+	DisputeCoordinatorMessage, ProspectiveParachainsMessage,
+};
+use polkadot_overseer::Handle;
+use polkadot_primitives::{CandidateHash, Hash, Id as ParaId, SessionIndex, ValidatorIndex};
+use sc_rpc_api::check_if_safe;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// The evidence about a dispute, as returned by `parachains_disputeEvidence`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DisputeEvidenceSummary {
+	/// The hash of the disputed candidate.
+	pub candidate_hash: CandidateHash,
+	/// The session the candidate appears in.
+	pub session_index: SessionIndex,
+	/// The status of the dispute at the time of the export.
+	pub status: DisputeStatus,
+	/// The validators which voted for the validity of the candidate.
+	pub valid_votes: Vec<ValidatorIndex>,
+	/// The validators which voted against the validity of the candidate.
+	pub invalid_votes: Vec<ValidatorIndex>,
+	/// The vote of this node.
+	pub local_participation: LocalParticipation,
+	/// Whether the available data of the candidate could be recovered.
+	pub available_data: bool,
+	/// The index of this node in the session of the dispute.
+	pub validator_index: ValidatorIndex,
+	/// The SCALE encoded `SignedDisputeEvidence`, to be checked with
+	/// `SignedDisputeEvidence::check`.
+	pub signed_evidence: Bytes,
+}
+
+impl From<SignedDisputeEvidence> for DisputeEvidenceSummary {
+	fn from(signed: SignedDisputeEvidence) -> Self {
+		let evidence = &signed.evidence;
+		Self {
+			candidate_hash: evidence.candidate_hash(),
+			session_index: evidence.session_index,
+			status: evidence.status,
+			valid_votes: evidence.valid_votes.iter().map(|vote| vote.validator_index).collect(),
+			invalid_votes: evidence.invalid_votes.iter().map(|vote| vote.validator_index).collect(),
+			local_participation: evidence.local_participation,
+			available_data: evidence.available_data.is_some(),
+			validator_index: signed.validator_index,
+			signed_evidence: signed.encode().into(),
+		}
+	}
+}
+
+/// Parachain subsystems RPC methods.
+#[rpc(client, server)]
+pub trait ParachainsApi {
+	/// Export the evidence about the most recent dispute on a candidate.
+	///
+	/// Returns a summary of the votes, along with the SCALE encoded `SignedDisputeEvidence`, signed
+	/// with the validator key of this node, or `None` if there is no recent dispute on the
+	/// candidate or this node is not a validator in the session of the dispute. May take a while,
+	/// as the available data of the candidate is recovered from the network if it is not stored
+	/// locally.
+	#[method(name = "parachains_disputeEvidence", with_extensions)]
+	async fn dispute_evidence(
+		&self,
+		candidate_hash: Hash,
+	) -> RpcResult<Option<DisputeEvidenceSummary>>;
+
+	/// Inspect the fragment chains of a para.
+	///
+	/// Returns one entry for each active leaf the para is scheduled under. Each entry holds the
+	/// backable chain, the constraints it builds on and the reason each other known candidate is
+	/// not part of it.
+	#[method(name = "parachains_fragmentChains", with_extensions)]
+	async fn fragment_chains(&self, para_id: u32) -> RpcResult<Vec<FragmentChainIntrospection>>;
+}
+
+/// An implementation of the parachain subsystems RPC methods.
+pub struct Parachains {
+	overseer_handle: Handle,
+}
+
+impl Parachains {
+	/// Create new parachain subsystems RPC methods, querying the overseer behind `overseer_handle`.
+	pub fn new(overseer_handle: Handle) -> Self {
+		Self { overseer_handle }
+	}
+}
+
+#[async_trait]
+impl ParachainsApiServer for Parachains {
+	async fn dispute_evidence(
+		&self,
+		ext: &Extensions,
+		candidate_hash: Hash,
+	) -> RpcResult<Option<DisputeEvidenceSummary>> {
+		check_if_safe(ext)?;
+
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle
+			.clone()
+			.send_msg(
+				DisputeCoordinatorMessage::QueryDisputeEvidence(CandidateHash(candidate_hash), tx),
+				"parachains-rpc",
+			)
+			.await;
+
+		let evidence = rx.await.map_err(|_| request_dropped("Dispute coordinator"))?;
+
+		Ok(evidence.map(Into::into))
+	}
+
+	async fn fragment_chains(
+		&self,
+		ext: &Extensions,
+		para_id: u32,
+	) -> RpcResult<Vec<FragmentChainIntrospection>> {
+		check_if_safe(ext)?;
+
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle
+			.clone()
+			.send_msg(
+				ProspectiveParachainsMessage::IntrospectFragmentChains(ParaId::from(para_id), tx),
+				"parachains-rpc",
+			)
+			.await;
+
+		rx.await.map_err(|_| request_dropped("Prospective parachains subsystem").into())
+	}
+}
+
+fn request_dropped(subsystem: &str) -> ErrorObject<'static> {
+	ErrorObject::owned(
+		ErrorCode::InternalError.code(),
+		format!("{subsystem} dropped the request"),
+		None::<()>,
+	)
+}
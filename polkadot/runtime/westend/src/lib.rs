@@ -115,7 +115,10 @@ use xcm::{
 	VersionedLocation, VersionedXcm,
 };
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	dry_run::{
+		CallDryRunEffects, Error as XcmDryRunApiError, TracedCallDryRunEffects,
+		TracedXcmDryRunEffects, XcmDryRunEffects,
+	},
 	fees::Error as XcmPaymentApiError,
 };
 
@@ -1644,6 +1647,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type AutoCompoundBatchSize = ConstU32<64>;
 }
 
 parameter_types! {
@@ -2806,6 +2810,15 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::fees::TransferQuoteApi<Block, RuntimeCall, OriginCaller> for Runtime {
+		fn quote_transfer(origin: OriginCaller, call: RuntimeCall, asset_id: VersionedAssetId, result_xcms_version: XcmVersion) -> Result<xcm_runtime_apis::fees::TransferQuote, XcmPaymentApiError> {
+			type Trader = <XcmConfig as xcm_executor::Config>::Trader;
+			type AssetExchanger = <XcmConfig as xcm_executor::Config>::AssetExchanger;
+			XcmPallet::quote_transfer::<Runtime, xcm_config::XcmRouter, Trader, AssetExchanger, OriginCaller, RuntimeCall>(origin, call, asset_id, result_xcms_version)
+		}
+	}
+
+	#[api_version(3)]
 	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
 		fn dry_run_call(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			XcmPallet::dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
@@ -2814,6 +2827,14 @@ sp_api::impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			XcmPallet::dry_run_xcm::<xcm_config::XcmRouter>(origin_location, xcm)
 		}
+
+		fn dry_run_call_traced(origin: OriginCaller, call: RuntimeCall, result_xcms_version: XcmVersion) -> Result<TracedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			XcmPallet::dry_run_call_traced::<Runtime, xcm_config::XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
+
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>, result_xcms_version: XcmVersion) -> Result<TracedXcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			XcmPallet::dry_run_xcm_traced::<xcm_config::XcmRouter>(origin_location, xcm, result_xcms_version)
+		}
 	}
 
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
@@ -3198,4 +3219,42 @@ sp_api::impl_runtime_apis! {
 			XcmPallet::is_trusted_teleporter(asset, location)
 		}
 	}
+
+	impl xcm_runtime_apis::trapped_assets::TrappedAssetsApi<Block> for Runtime {
+		fn trapped_assets(origin: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::trapped_assets::TrappedAssets>,
+			xcm_runtime_apis::trapped_assets::Error
+		> {
+			XcmPallet::trapped_assets(origin)
+		}
+	}
+
+	impl xcm_runtime_apis::topic_events::XcmTopicEventsApi<Block> for Runtime {
+		fn topic_events() -> Vec<xcm_runtime_apis::topic_events::XcmTopicEvent> {
+			use xcm_runtime_apis::topic_events::XcmTopicEventKind;
+
+			XcmPallet::topic_events(|event| match event {
+				RuntimeEvent::XcmPallet(pallet_xcm::Event::Sent { message_id, .. }) => {
+					Some((Some(*message_id), XcmTopicEventKind::Sent))
+				},
+				RuntimeEvent::XcmPallet(
+					pallet_xcm::Event::SendFailed { message_id, .. } |
+					pallet_xcm::Event::ProcessXcmError { message_id, .. },
+				) => Some((Some(*message_id), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					id, success, ..
+				}) => Some((
+					Some(id.0),
+					if *success { XcmTopicEventKind::Processed } else { XcmTopicEventKind::Failed },
+				)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::ProcessingFailed {
+					id, ..
+				}) => Some((Some(id.0), XcmTopicEventKind::Failed)),
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::OverweightEnqueued {
+					id, ..
+				}) => Some((Some(*id), XcmTopicEventKind::Queued)),
+				_ => None,
+			})
+		}
+	}
 }
@@ -892,4 +892,68 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `NominationPools::BondedPools` (r:1 w:0)
+	/// Proof: `NominationPools::BondedPools` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::PoolAutoCompound` (r:0 w:1)
+	/// Proof: `NominationPools::PoolAutoCompound` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn set_pool_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `3719`
+		// Minimum execution time: 14_108_000 picoseconds.
+		Weight::from_parts(14_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 3719))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `NominationPools::PoolMembers` (r:1 w:0)
+	/// Proof: `NominationPools::PoolMembers` (`max_values`: None, `max_size`: Some(237), added: 2712, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::BondedPools` (r:1 w:0)
+	/// Proof: `NominationPools::BondedPools` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::MemberAutoCompound` (r:0 w:1)
+	/// Proof: `NominationPools::MemberAutoCompound` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1027`
+		//  Estimated: `3719`
+		// Minimum execution time: 21_946_000 picoseconds.
+		Weight::from_parts(22_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 3719))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `NominationPools::AutoCompoundCursor` (r:1 w:1)
+	/// Proof: `NominationPools::AutoCompoundCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::AutoCompoundEra` (r:1 w:1)
+	/// Proof: `NominationPools::AutoCompoundEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Proof: `Staking::CurrentEra` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::PoolAutoCompound` (r:n w:0)
+	/// Proof: `NominationPools::PoolAutoCompound` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::MemberAutoCompound` (r:n w:0)
+	/// Proof: `NominationPools::MemberAutoCompound` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::PoolMembers` (r:n w:n)
+	/// Proof: `NominationPools::PoolMembers` (`max_values`: None, `max_size`: Some(237), added: 2712, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::BondedPools` (r:1 w:1)
+	/// Proof: `NominationPools::BondedPools` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::RewardPools` (r:1 w:1)
+	/// Proof: `NominationPools::RewardPools` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `NominationPools::PoolAutoCompounded` (r:1 w:1)
+	/// Proof: `NominationPools::PoolAutoCompounded` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn auto_compound(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3624 + n * (356 ±0)`
+		//  Estimated: `8877 + n * (2712 ±0)`
+		// Minimum execution time: 248_305_000 picoseconds.
+		Weight::from_parts(22_867_412, 0)
+			.saturating_add(Weight::from_parts(0, 8877))
+			// Standard Error: 41_625
+			.saturating_add(Weight::from_parts(231_448_960, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((20_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2712).saturating_mul(n.into()))
+	}
 }
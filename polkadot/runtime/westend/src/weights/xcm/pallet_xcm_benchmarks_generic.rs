@@ -185,6 +185,8 @@ impl<T: frame_system::Config> WeightInfo<T> {
 	}
 	/// Storage: `XcmPallet::AssetTraps` (r:1 w:1)
 	/// Proof: `XcmPallet::AssetTraps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::AssetTrapsByOrigin` (r:0 w:1)
+	/// Proof: `XcmPallet::AssetTrapsByOrigin` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	pub(crate) fn claim_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `23`
@@ -192,7 +194,7 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		// Minimum execution time: 9_550_000 picoseconds.
 		Weight::from_parts(9_797_000, 3488)
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	pub(crate) fn trap() -> Weight {
 		// Proof Size summary in bytes:
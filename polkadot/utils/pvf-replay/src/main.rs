@@ -0,0 +1,234 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Replay failed candidate validations dumped by a node started with `--pvf-dump-dir`.
+//!
+//! Every dump is prepared and executed through the same prepare and execute workers the node
+//! uses, with the executor parameters and timeouts recorded in the dump. For each dump the
+//! outcome, the wall clock time and the resource usage reported by the workers are printed, along
+//! with the error the node originally observed.
+//!
+//! The workers are looked up next to this binary by default, so the tool should be run from a
+//! directory containing `polkadot-prepare-worker` and `polkadot-execute-worker` of the version
+//! being investigated, or be pointed to them with `--workers-path`.
+
+use clap::Parser;
+use futures::channel::oneshot;
+use polkadot_node_core_pvf::{
+	start, Config, Metrics, PrepareJobKind, Priority, PvfPrepData, ValidationDump, ValidationHost,
+	EXECUTE_BINARY_NAME, PREPARE_BINARY_NAME,
+};
+use polkadot_node_metrics::metrics::{prometheus::Registry, Metrics as _};
+use polkadot_node_subsystem::messages::PvfExecKind;
+use polkadot_primitives::CandidateCommitments;
+use std::{collections::HashMap, path::PathBuf, time::Instant};
+
+/// Histograms of the validation host reported after every replay, with their unit.
+const REPORTED_METRICS: &[(&str, &str)] = &[
+	("polkadot_pvf_preparation_time", "s"),
+	("polkadot_pvf_execution_time", "s"),
+	("polkadot_pvf_preparation_max_rss", "KiB"),
+	("polkadot_pvf_preparation_max_resident", "KiB"),
+	("polkadot_pvf_preparation_max_allocated", "KiB"),
+	("polkadot_pvf_preparation_peak_tracked_allocation", "KiB"),
+];
+
+#[derive(Debug, Parser)]
+struct Opt {
+	/// The directory containing the prepare and execute worker binaries.
+	///
+	/// Defaults to the directory of this binary.
+	#[arg(long)]
+	workers_path: Option<PathBuf>,
+
+	/// The directory where compiled artifacts are stored.
+	///
+	/// Defaults to a temporary directory, so that every run prepares the PVFs from scratch.
+	#[arg(long)]
+	cache_dir: Option<PathBuf>,
+
+	/// The dumps to replay.
+	#[arg(required = true)]
+	dumps: Vec<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let opt = Opt::parse();
+
+	let workers_path = match opt.workers_path {
+		Some(path) => path,
+		None => std::env::current_exe()
+			.map_err(|e| format!("cannot determine the path of the current binary: {e}"))?
+			.parent()
+			.ok_or("the current binary has no parent directory")?
+			.to_path_buf(),
+	};
+	let cache_dir = tempfile::tempdir()
+		.map_err(|e| format!("cannot create a temporary cache directory: {e}"))?;
+	let cache_path = opt.cache_dir.unwrap_or_else(|| cache_dir.path().to_path_buf());
+
+	let registry = Registry::new();
+	let metrics = Metrics::try_register(&registry).map_err(|e| e.to_string())?;
+	let config = Config::new(
+		cache_path,
+		// No version check, the workers are expected to be provided by the user.
+		None,
+		false,
+		workers_path.join(PREPARE_BINARY_NAME),
+		workers_path.join(EXECUTE_BINARY_NAME),
+		1,
+		1,
+		1,
+	);
+	let (mut host, task) = start(config, metrics)
+		.await
+		.map_err(|e| format!("cannot start the validation host: {e}"))?;
+	tokio::spawn(task);
+
+	let mut last_metrics = HashMap::new();
+	let mut failed = 0;
+	for path in &opt.dumps {
+		println!("{}", path.display());
+		let dump = match ValidationDump::read(path) {
+			Ok(dump) => dump,
+			Err(e) => {
+				println!("  cannot read dump: {e}");
+				failed += 1;
+				continue;
+			},
+		};
+		if !replay(&mut host, &registry, &mut last_metrics, dump).await {
+			failed += 1;
+		}
+	}
+
+	if failed > 0 {
+		return Err(format!("{failed} of {} dumps could not be replayed", opt.dumps.len()));
+	}
+	Ok(())
+}
+
+/// Prepare and execute a single dump, printing the outcome. Returns `false` if the dump could not
+/// be replayed at all.
+async fn replay(
+	host: &mut ValidationHost,
+	registry: &Registry,
+	last: &mut HashMap<String, (u64, f64)>,
+	dump: ValidationDump,
+) -> bool {
+	let candidate_receipt = dump.validation_context.candidate_receipt.clone();
+	println!("  candidate:       {:?}", candidate_receipt.hash());
+	println!("  para:            {}", candidate_receipt.descriptor.para_id());
+	println!("  execution kind:  {:?}", dump.exec_kind);
+	println!("  original error:  {}", dump.error);
+
+	let pvf = PvfPrepData::from_code(
+		dump.validation_code.0,
+		dump.validation_context.executor_params.clone(),
+		dump.prep_timeout,
+		PrepareJobKind::Compilation,
+		dump.validation_code_bomb_limit,
+	);
+
+	let started = Instant::now();
+	let (tx, rx) = oneshot::channel();
+	if let Err(e) = host.precheck_pvf(pvf.clone(), tx).await {
+		println!("  cannot send the PVF to the validation host: {e}");
+		return false;
+	}
+	match rx.await {
+		Ok(Ok(())) => println!("  prepared:        {}", elapsed(started)),
+		Ok(Err(e)) => {
+			println!("  preparation failed after {}: {e:?}", elapsed(started));
+			report_metrics(registry, last);
+			return true;
+		},
+		Err(_) => {
+			println!("  preparation was cancelled");
+			return false;
+		},
+	}
+
+	// Executing as a dispute keeps the job from being dropped for exceeding a backing deadline,
+	// the execution timeout from the dump still applies.
+	let started = Instant::now();
+	let (tx, rx) = oneshot::channel();
+	if let Err(e) = host
+		.execute_pvf(pvf, dump.validation_context, Priority::Critical, PvfExecKind::Dispute, tx)
+		.await
+	{
+		println!("  cannot send the PVF to the validation host: {e}");
+		return false;
+	}
+	match rx.await {
+		Ok(Ok(res)) => {
+			println!("  executed:        {}", elapsed(started));
+			let commitments = CandidateCommitments {
+				head_data: res.head_data,
+				upward_messages: res.upward_messages,
+				horizontal_messages: res.horizontal_messages,
+				new_validation_code: res.new_validation_code,
+				processed_downward_messages: res.processed_downward_messages,
+				hrmp_watermark: res.hrmp_watermark,
+			};
+			if commitments.head_data.hash() != candidate_receipt.descriptor.para_head() {
+				println!("  result:          invalid, para head hash mismatch");
+			} else if commitments.hash() != candidate_receipt.commitments_hash {
+				println!("  result:          invalid, commitments hash mismatch");
+			} else {
+				println!("  result:          valid");
+			}
+		},
+		Ok(Err(e)) => println!("  execution failed after {}: {e:?}", elapsed(started)),
+		Err(_) => {
+			println!("  execution was cancelled");
+			return false;
+		},
+	}
+
+	report_metrics(registry, last);
+	true
+}
+
+fn elapsed(started: Instant) -> String {
+	format!("{:.3}s", started.elapsed().as_secs_f64())
+}
+
+/// Print the values observed by the validation host for the last job.
+///
+/// Every histogram is observed at most once per job, so the difference between the current sample
+/// sum and the one seen by the previous report is the value of the last job.
+fn report_metrics(registry: &Registry, last: &mut HashMap<String, (u64, f64)>) {
+	for family in registry.gather() {
+		let Some((_, unit)) = REPORTED_METRICS.iter().find(|(name, _)| *name == family.get_name())
+		else {
+			continue;
+		};
+		let Some(histogram) = family.get_metric().first().map(|m| m.get_histogram()) else {
+			continue;
+		};
+		let (count, sum) = (histogram.get_sample_count(), histogram.get_sample_sum());
+		let (last_count, last_sum) =
+			last.insert(family.get_name().to_string(), (count, sum)).unwrap_or_default();
+		if count > last_count {
+			let value = sum - last_sum;
+			let value =
+				if *unit == "s" { format!("{value:.3}s") } else { format!("{value:.0} {unit}") };
+			println!("  {:<50} {value}", family.get_name());
+		}
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use xcm::latest::prelude::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_rate_limit() -> Result<(), BenchmarkError> {
+		let admin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let origin = Location::new(1, [Parachain(1000)]);
+		let limit = RateLimit {
+			messages: 10,
+			weight: Weight::from_parts(1_000_000_000, 100_000),
+			period: 10u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(admin as T::RuntimeOrigin, Box::new(origin.clone().into()), Some(limit));
+
+		assert_eq!(RateLimits::<T>::get(VersionedLocation::from(origin)), Some(limit));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn process_message() {
+		let origin = Location::new(1, [Parachain(1000)]);
+		let limit = RateLimit {
+			messages: 10,
+			weight: Weight::from_parts(1_000_000_000, 100_000),
+			period: 10u32.into(),
+		};
+		RateLimits::<T>::insert(VersionedLocation::from(origin.clone()), limit);
+
+		// The origin has no bucket yet, so one is added.
+		#[block]
+		{
+			assert!(<Pallet<T> as RateLimiter>::can_process(&origin));
+			<Pallet<T> as RateLimiter>::note_processed(&origin, Weight::from_parts(1_000, 1_000));
+		}
+
+		assert_eq!(Buckets::<T>::count(), 1);
+	}
+
+	#[benchmark]
+	fn prune_bucket() {
+		let key = VersionedLocation::from(Location::new(1, [Parachain(1000)]));
+		let limit = RateLimit {
+			messages: 10,
+			weight: Weight::from_parts(1_000_000_000, 100_000),
+			period: 10u32.into(),
+		};
+		RateLimits::<T>::insert(&key, limit);
+		Buckets::<T>::insert(&key, Bucket::full(&limit, Zero::zero()));
+		let mut meter = WeightMeter::with_limit(T::WeightInfo::prune_bucket());
+
+		#[block]
+		{
+			Pallet::<T>::prune_buckets(&mut meter);
+		}
+
+		assert_eq!(Buckets::<T>::count(), 0);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
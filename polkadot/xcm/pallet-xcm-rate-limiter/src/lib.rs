@@ -0,0 +1,328 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet limiting the number and weight of the XCM executed on behalf of every origin.
+//!
+//! The pallet implements [`RateLimiter`], to be used with the `RateLimitedProcessor` message
+//! processor of `xcm-builder`:
+//!
+//! ```ignore
+//! type MessageProcessor = RateLimitedProcessor<ProcessXcmMessage<..>, XcmRateLimiter>;
+//! ```
+//!
+//! Every origin has a [`RateLimit`], either set by the `AdminOrigin` or the `DefaultRateLimit`,
+//! enforced with a token bucket: the bucket of an origin holds up to `RateLimit::messages` XCM and
+//! `RateLimit::weight`, is replenished over `RateLimit::period` blocks, and every processed XCM
+//! takes one XCM and the weight it used from it. Once the bucket of an origin is exhausted, its
+//! XCM are yielded by the processor and stay in their queue until the bucket is replenished.
+//!
+//! At most `MaxBuckets` origins have a bucket at a time. The buckets which were replenished to
+//! full are removed in `on_idle`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	weights::{Weight, WeightMeter},
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::AtLeast32BitUnsigned, SaturatedConversion};
+use xcm::{latest::Location, VersionedLocation};
+use xcm_executor::traits::RateLimiter;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+const LOG_TARGET: &str = "xcm::rate_limiter";
+
+/// The limits of the XCM executed on behalf of an origin.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct RateLimit<BlockNumber> {
+	/// The maximum number of XCM executed over `period`.
+	pub messages: u32,
+	/// The maximum weight of the XCM executed over `period`. No XCM is executed if either
+	/// component is zero.
+	pub weight: Weight,
+	/// The number of blocks over which the limits are replenished, once exhausted.
+	pub period: BlockNumber,
+}
+
+/// The token bucket of an origin.
+///
+/// Tokens are counted in `1 / period` units, so the bucket is replenished by exactly
+/// `RateLimit::messages` and `RateLimit::weight` units every block, and holds up to `period`
+/// times these.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Bucket<BlockNumber> {
+	/// The XCM left in the bucket.
+	pub messages: u64,
+	/// The weight left in the bucket.
+	pub weight: Weight,
+	/// The block at which the bucket was last replenished.
+	pub updated_at: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> Bucket<BlockNumber> {
+	/// A full bucket for `limit`.
+	pub fn full(limit: &RateLimit<BlockNumber>, now: BlockNumber) -> Self {
+		let period = limit.period.saturated_into::<u64>();
+		Self {
+			messages: (limit.messages as u64).saturating_mul(period),
+			weight: limit.weight.saturating_mul(period),
+			updated_at: now,
+		}
+	}
+
+	/// Replenish the bucket for the blocks elapsed since it was last replenished.
+	pub fn replenish(&mut self, limit: &RateLimit<BlockNumber>, now: BlockNumber) {
+		let elapsed = now.saturating_sub(self.updated_at).saturated_into::<u64>();
+		let full = Self::full(limit, now);
+		self.messages = self
+			.messages
+			.saturating_add((limit.messages as u64).saturating_mul(elapsed))
+			.min(full.messages);
+		self.weight = self
+			.weight
+			.saturating_add(limit.weight.saturating_mul(elapsed))
+			.min(full.weight);
+		self.updated_at = now;
+	}
+
+	/// Take an XCM which used `weight` from the bucket, emptying the bucket if it doesn't fit.
+	pub fn take(&mut self, limit: &RateLimit<BlockNumber>, weight: Weight) {
+		let period = limit.period.saturated_into::<u64>();
+		self.messages = self.messages.saturating_sub(period);
+		self.weight = self.weight.saturating_sub(weight.saturating_mul(period));
+	}
+
+	/// Whether no other XCM can be taken from the bucket until it is replenished.
+	pub fn is_exhausted(&self, limit: &RateLimit<BlockNumber>) -> bool {
+		self.messages < limit.period.saturated_into::<u64>() ||
+			self.weight.ref_time() == 0 ||
+			self.weight.proof_size() == 0
+	}
+
+	/// Whether the bucket holds as much as a full bucket for `limit`.
+	pub fn is_full(&self, limit: &RateLimit<BlockNumber>) -> bool {
+		let full = Self::full(limit, self.updated_at);
+		self.messages >= full.messages && self.weight.all_gte(full.weight)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	pub type RateLimitOf<T> = RateLimit<BlockNumberFor<T>>;
+	pub type BucketOf<T> = Bucket<BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin allowed to set the limits of origins.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The limits of the origins without limits set by `AdminOrigin`. `None` if they have no
+		/// limits.
+		#[pallet::constant]
+		type DefaultRateLimit: Get<Option<RateLimitOf<Self>>>;
+
+		/// The maximum number of origins with a bucket at a time. The XCM of other origins with
+		/// limits are yielded until a bucket is removed.
+		#[pallet::constant]
+		type MaxBuckets: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The limits of origins, as set by `AdminOrigin`.
+	///
+	/// Keys are always in the latest XCM version at the time they were set.
+	#[pallet::storage]
+	pub type RateLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, VersionedLocation, RateLimitOf<T>, OptionQuery>;
+
+	/// The token buckets of the origins with limits, which had XCM executed and weren't
+	/// replenished to full since.
+	///
+	/// Keys are always in the latest XCM version at the time the XCM was executed.
+	#[pallet::storage]
+	pub type Buckets<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, VersionedLocation, BucketOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The limits of `origin` were set, or reset to the default if `limit` is `None`.
+		RateLimitSet { origin: Location, limit: Option<RateLimitOf<T>> },
+		/// `origin` exhausted its limits, its XCM are yielded until they are replenished.
+		RateLimitExhausted { origin: Location },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location could not be converted to the latest XCM version.
+		BadVersion,
+		/// The period of the limit is zero.
+		ZeroPeriod,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::prune_buckets(&mut meter);
+			meter.consumed()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the limits of the XCM executed on behalf of `location`, or reset them to the
+		/// default if `limit` is `None`.
+		///
+		/// The token bucket of `location` is reset to full.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+			limit: Option<RateLimitOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let origin: Location = (*location).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(limit.map_or(true, |limit| !limit.period.is_zero()), Error::<T>::ZeroPeriod);
+
+			let key = VersionedLocation::from(origin.clone());
+			match limit {
+				Some(limit) => RateLimits::<T>::insert(&key, limit),
+				None => RateLimits::<T>::remove(&key),
+			}
+			Buckets::<T>::remove(&key);
+			Self::deposit_event(Event::RateLimitSet { origin, limit });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The limits of `origin`, if any.
+	pub fn rate_limit(origin: &VersionedLocation) -> Option<RateLimitOf<T>> {
+		RateLimits::<T>::get(origin).or_else(T::DefaultRateLimit::get)
+	}
+
+	/// Remove the buckets which were replenished to full, as long as `meter` allows.
+	pub(crate) fn prune_buckets(meter: &mut WeightMeter) {
+		let weight = T::WeightInfo::prune_bucket();
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut buckets = Buckets::<T>::iter();
+		while meter.try_consume(weight).is_ok() {
+			let Some((key, mut bucket)) = buckets.next() else { break };
+			let full = match Self::rate_limit(&key) {
+				Some(limit) => {
+					bucket.replenish(&limit, now);
+					bucket.is_full(&limit)
+				},
+				None => true,
+			};
+			if full {
+				Buckets::<T>::remove(&key);
+			}
+		}
+	}
+}
+
+impl<T: Config> RateLimiter for Pallet<T> {
+	fn weight() -> Weight {
+		T::WeightInfo::process_message()
+	}
+
+	fn can_process(origin: &Location) -> bool {
+		let key = VersionedLocation::from(origin.clone());
+		let Some(limit) = Self::rate_limit(&key) else { return true };
+		if limit.period.is_zero() {
+			tracing::warn!(target: LOG_TARGET, ?origin, "Ignoring limit with a zero period");
+			return true;
+		}
+
+		match Buckets::<T>::get(&key) {
+			Some(mut bucket) => {
+				bucket.replenish(&limit, frame_system::Pallet::<T>::block_number());
+				!bucket.is_exhausted(&limit)
+			},
+			None if Buckets::<T>::count() >= T::MaxBuckets::get() => {
+				tracing::debug!(target: LOG_TARGET, ?origin, "No room for the bucket of the origin");
+				false
+			},
+			None => !Bucket::full(&limit, Zero::zero()).is_exhausted(&limit),
+		}
+	}
+
+	fn note_processed(origin: &Location, weight: Weight) {
+		let key = VersionedLocation::from(origin.clone());
+		let Some(limit) = Self::rate_limit(&key) else { return };
+		if limit.period.is_zero() {
+			return;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut bucket = match Buckets::<T>::get(&key) {
+			Some(mut bucket) => {
+				bucket.replenish(&limit, now);
+				bucket
+			},
+			None => Bucket::full(&limit, now),
+		};
+		bucket.take(&limit, weight);
+		tracing::trace!(target: LOG_TARGET, ?origin, ?weight, ?bucket, "Charged the limits of the origin");
+
+		if bucket.is_exhausted(&limit) {
+			Self::deposit_event(Event::RateLimitExhausted { origin: origin.clone() });
+		}
+		Buckets::<T>::insert(&key, bucket);
+	}
+}
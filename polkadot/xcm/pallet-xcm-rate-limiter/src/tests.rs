@@ -0,0 +1,243 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Buckets, Error, Event, RateLimit, RateLimitOf, RateLimits};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_runtime::DispatchError;
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_executor::traits::RateLimiter;
+
+fn sibling(id: u32) -> Location {
+	Location::new(1, [Parachain(id)])
+}
+
+fn weight(units: u64) -> Weight {
+	Weight::from_parts(units * 1_000, units * 10)
+}
+
+fn limit(messages: u32, weight_units: u64, period: u64) -> RateLimitOf<Test> {
+	RateLimit { messages, weight: weight(weight_units), period }
+}
+
+fn set_rate_limit(origin: &Location, limit: Option<RateLimitOf<Test>>) {
+	assert_ok!(XcmRateLimiter::set_rate_limit(
+		RuntimeOrigin::root(),
+		Box::new(origin.clone().into()),
+		limit,
+	));
+}
+
+fn process(origin: &Location, units: u64) -> bool {
+	if !<XcmRateLimiter as RateLimiter>::can_process(origin) {
+		return false;
+	}
+	<XcmRateLimiter as RateLimiter>::note_processed(origin, weight(units));
+	true
+}
+
+#[test]
+fn origins_without_limits_are_not_limited() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..100 {
+			assert!(process(&sibling(1000), 1_000));
+		}
+		assert_eq!(Buckets::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn limits_the_number_of_messages() {
+	new_test_ext().execute_with(|| {
+		set_rate_limit(&sibling(1000), Some(limit(3, 100, 10)));
+
+		assert!(process(&sibling(1000), 1));
+		assert!(process(&sibling(1000), 1));
+		assert!(process(&sibling(1000), 1));
+		assert!(!process(&sibling(1000), 1));
+		// Other origins are not affected.
+		assert!(process(&sibling(2000), 1));
+	});
+}
+
+#[test]
+fn limits_the_weight_of_messages() {
+	new_test_ext().execute_with(|| {
+		set_rate_limit(&sibling(1000), Some(limit(10, 100, 10)));
+
+		// The weight is only known once the message is processed, so the last message may
+		// exceed the limits.
+		assert!(process(&sibling(1000), 60));
+		assert!(process(&sibling(1000), 60));
+		assert!(!process(&sibling(1000), 1));
+
+		System::set_block_number(2);
+		assert!(process(&sibling(1000), 1));
+	});
+}
+
+#[test]
+fn limits_are_replenished_over_the_period() {
+	new_test_ext().execute_with(|| {
+		set_rate_limit(&sibling(1000), Some(limit(10, 100, 10)));
+		for _ in 0..10 {
+			assert!(process(&sibling(1000), 1));
+		}
+		assert!(!process(&sibling(1000), 1));
+
+		// One message per block is replenished.
+		System::set_block_number(2);
+		assert!(process(&sibling(1000), 1));
+		assert!(!process(&sibling(1000), 1));
+
+		System::set_block_number(5);
+		for _ in 0..3 {
+			assert!(process(&sibling(1000), 1));
+		}
+		assert!(!process(&sibling(1000), 1));
+
+		// The bucket holds at most the limits.
+		System::set_block_number(100);
+		for _ in 0..10 {
+			assert!(process(&sibling(1000), 1));
+		}
+		assert!(!process(&sibling(1000), 1));
+	});
+}
+
+#[test]
+fn weight_is_replenished_per_block() {
+	new_test_ext().execute_with(|| {
+		// Less than one message per block.
+		set_rate_limit(&sibling(1000), Some(limit(1, 10, 4)));
+		assert!(process(&sibling(1000), 10));
+
+		for block in 2..5 {
+			System::set_block_number(block);
+			assert!(!process(&sibling(1000), 1));
+		}
+		System::set_block_number(5);
+		assert!(process(&sibling(1000), 10));
+	});
+}
+
+#[test]
+fn default_limit_applies_to_origins_without_limits() {
+	new_test_ext().execute_with(|| {
+		DefaultRateLimit::set(Some(limit(1, 100, 10)));
+		set_rate_limit(&sibling(1000), Some(limit(2, 100, 10)));
+
+		assert!(process(&sibling(1000), 1));
+		assert!(process(&sibling(1000), 1));
+		assert!(!process(&sibling(1000), 1));
+
+		assert!(process(&sibling(2000), 1));
+		assert!(!process(&sibling(2000), 1));
+	});
+}
+
+#[test]
+fn set_rate_limit_works() {
+	new_test_ext().execute_with(|| {
+		let origin = sibling(1000);
+		let key = VersionedLocation::from(origin.clone());
+
+		set_rate_limit(&origin, Some(limit(1, 100, 10)));
+		assert_eq!(RateLimits::<Test>::get(&key), Some(limit(1, 100, 10)));
+		System::assert_last_event(
+			Event::RateLimitSet { origin: origin.clone(), limit: Some(limit(1, 100, 10)) }.into(),
+		);
+
+		assert!(process(&origin, 1));
+		System::assert_last_event(Event::RateLimitExhausted { origin: origin.clone() }.into());
+		assert!(!process(&origin, 1));
+
+		// Setting the limits resets the bucket.
+		set_rate_limit(&origin, Some(limit(2, 100, 10)));
+		assert_eq!(Buckets::<Test>::get(&key), None);
+		assert!(process(&origin, 1));
+		assert!(process(&origin, 1));
+		assert!(!process(&origin, 1));
+
+		set_rate_limit(&origin, None);
+		assert_eq!(RateLimits::<Test>::get(&key), None);
+		System::assert_last_event(
+			Event::RateLimitSet { origin: origin.clone(), limit: None }.into(),
+		);
+		assert!(process(&origin, 1));
+	});
+}
+
+#[test]
+fn set_rate_limit_checks_its_arguments() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(
+				RuntimeOrigin::signed(1),
+				Box::new(sibling(1000).into()),
+				Some(limit(1, 100, 10)),
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(
+				RuntimeOrigin::root(),
+				Box::new(sibling(1000).into()),
+				Some(limit(1, 100, 0)),
+			),
+			Error::<Test>::ZeroPeriod
+		);
+	});
+}
+
+#[test]
+fn origins_without_room_for_a_bucket_are_limited() {
+	new_test_ext().execute_with(|| {
+		DefaultRateLimit::set(Some(limit(10, 100, 10)));
+
+		assert!(process(&sibling(1000), 1));
+		assert!(process(&sibling(2000), 1));
+		assert_eq!(Buckets::<Test>::count(), 2);
+		assert!(!process(&sibling(3000), 1));
+		// Origins with a bucket are not affected.
+		assert!(process(&sibling(1000), 1));
+		assert_eq!(Buckets::<Test>::count(), 2);
+	});
+}
+
+#[test]
+fn full_buckets_are_pruned_when_idle() {
+	new_test_ext().execute_with(|| {
+		set_rate_limit(&sibling(1000), Some(limit(10, 100, 10)));
+		set_rate_limit(&sibling(2000), Some(limit(10, 100, 10)));
+		assert!(process(&sibling(1000), 1));
+		for _ in 0..3 {
+			assert!(process(&sibling(2000), 1));
+		}
+
+		System::set_block_number(2);
+		XcmRateLimiter::on_idle(2, Weight::MAX);
+		assert!(!Buckets::<Test>::contains_key(VersionedLocation::from(sibling(1000))));
+		assert!(Buckets::<Test>::contains_key(VersionedLocation::from(sibling(2000))));
+
+		// Only as many buckets as the weight allows are visited.
+		System::set_block_number(10);
+		assert_eq!(XcmRateLimiter::on_idle(10, Weight::zero()), Weight::zero());
+		assert_eq!(Buckets::<Test>::count(), 1);
+
+		XcmRateLimiter::on_idle(10, Weight::MAX);
+		assert_eq!(Buckets::<Test>::count(), 0);
+	});
+}
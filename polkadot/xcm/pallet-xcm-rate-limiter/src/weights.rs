@@ -0,0 +1,154 @@
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_xcm_rate_limiter`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/release/wbuild/kitchensink-runtime/kitchensink_runtime.compact.compressed.wasm
+// --pallet=pallet_xcm_rate_limiter
+// --header=polkadot/file_header.txt
+// --output=polkadot/xcm/pallet-xcm-rate-limiter/src/weights.rs
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=substrate/.maintain/frame-weight-template.hbs
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_xcm_rate_limiter`.
+pub trait WeightInfo {
+	fn set_rate_limit() -> Weight;
+	fn process_message() -> Weight;
+	fn prune_bucket() -> Weight;
+}
+
+/// Weights for `pallet_xcm_rate_limiter` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `XcmRateLimiter::Buckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::RateLimits` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `4111`
+		// Minimum execution time: 10_531_000 picoseconds.
+		Weight::from_parts(12_963_000, 4111)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::RateLimits` (r:1 w:0)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::Buckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::CounterForBuckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::CounterForBuckets` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn process_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67`
+		//  Estimated: `4111`
+		// Minimum execution time: 13_445_000 picoseconds.
+		Weight::from_parts(16_409_000, 4111)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::Buckets` (r:2 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::RateLimits` (r:1 w:0)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::CounterForBuckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::CounterForBuckets` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn prune_bucket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `7232`
+		// Minimum execution time: 13_045_000 picoseconds.
+		Weight::from_parts(15_832_000, 7232)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `XcmRateLimiter::Buckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::RateLimits` (r:0 w:1)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `4111`
+		// Minimum execution time: 10_531_000 picoseconds.
+		Weight::from_parts(12_963_000, 4111)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::RateLimits` (r:1 w:0)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::Buckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::CounterForBuckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::CounterForBuckets` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn process_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67`
+		//  Estimated: `4111`
+		// Minimum execution time: 13_445_000 picoseconds.
+		Weight::from_parts(16_409_000, 4111)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmRateLimiter::Buckets` (r:2 w:1)
+	/// Proof: `XcmRateLimiter::Buckets` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::RateLimits` (r:1 w:0)
+	/// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(642), added: 3117, mode: `MaxEncodedLen`)
+	/// Storage: `XcmRateLimiter::CounterForBuckets` (r:1 w:1)
+	/// Proof: `XcmRateLimiter::CounterForBuckets` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn prune_bucket() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `156`
+		//  Estimated: `7232`
+		// Minimum execution time: 13_045_000 picoseconds.
+		Weight::from_parts(15_832_000, 7232)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -643,6 +643,40 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn drop_assets() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Signed(whitelisted_caller());
+		let origin_location = T::ExecuteXcmOrigin::try_origin(origin.into())
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let asset: Asset = T::get_asset();
+		let context = XcmContext { origin: None, message_id: [0u8; 32], topic: None };
+		let holdings =
+			<T::XcmExecutor as XcmAssetTransfers>::AssetTransactor::mint_asset(&asset, &context)
+				.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let versioned_assets = VersionedAssets::from(Assets::from(asset));
+		let hash = BlakeTwo256::hash_of(&(&origin_location, &versioned_assets));
+
+		// worst case: the asset traps index of the origin is full, so the oldest one is dropped
+		let versioned_origin = VersionedLocation::from(origin_location.clone());
+		let indexed: Vec<H256> = (0..MaxAssetTrapsPerOrigin::get())
+			.map(|i| H256::from_low_u64_be(i.into()))
+			.collect();
+		for trap in &indexed {
+			AssetTrapsByOrigin::<T>::insert(&versioned_origin, trap, versioned_assets.clone());
+		}
+		AssetTrapKeysByOrigin::<T>::insert(&versioned_origin, BoundedVec::truncate_from(indexed));
+
+		#[block]
+		{
+			crate::Pallet::<T>::drop_assets(&origin_location, holdings, &context);
+		}
+
+		assert_eq!(crate::Pallet::<T>::asset_trap(&hash), 1);
+		assert!(!AssetTrapsByOrigin::<T>::contains_key(&versioned_origin, H256::zero()));
+		assert!(AssetTrapsByOrigin::<T>::contains_key(&versioned_origin, hash));
+		Ok(())
+	}
+
 	#[benchmark]
 	fn add_authorized_alias() -> Result<(), BenchmarkError> {
 		let who: T::AccountId = whitelisted_caller();
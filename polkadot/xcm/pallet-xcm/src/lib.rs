@@ -32,7 +32,7 @@ pub mod xcm_helpers;
 
 extern crate alloc;
 
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, collections::btree_set::BTreeSet, vec, vec::Vec};
 use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use core::{marker::PhantomData, result::Result};
 use frame_support::{
@@ -65,16 +65,21 @@ use xcm_builder::{
 use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
-		DropAssets, EventEmitter, FeeManager, FeeReason, MatchesFungible, OnResponse, Properties,
-		QueryHandler, QueryResponseStatus, RecordXcm, TransactAsset, TransferType,
-		VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
+		DropAssets, EventEmitter, FeeManager, FeeReason, InstructionTrace, MatchesFungible,
+		OnResponse, Properties, QueryHandler, QueryResponseStatus, RecordXcm, TransactAsset,
+		TransferType, VersionChangeNotifier, WeightBounds, XcmAssetTransfers,
 	},
 	AssetsInHolding,
 };
 use xcm_runtime_apis::{
 	authorized_aliases::{Error as AuthorizedAliasersApiError, OriginAliaser},
-	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
-	fees::Error as XcmPaymentApiError,
+	dry_run::{
+		CallDryRunEffects, Error as XcmDryRunApiError, TracedCallDryRunEffects,
+		TracedXcmDryRunEffects, XcmDryRunEffects,
+	},
+	fees::{Error as XcmPaymentApiError, HopQuote, TransferQuote},
+	topic_events::{XcmTopicEvent, XcmTopicEventKind},
+	trapped_assets::{Error as TrappedAssetsApiError, TrappedAssets},
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -105,6 +110,7 @@ pub trait WeightInfo {
 	fn new_query() -> Weight;
 	fn take_response() -> Weight;
 	fn claim_assets() -> Weight;
+	fn drop_assets() -> Weight;
 	fn add_authorized_alias() -> Weight;
 	fn remove_authorized_alias() -> Weight;
 
@@ -194,6 +200,10 @@ impl WeightInfo for TestWeightInfo {
 		Weight::from_parts(100_000_000, 0)
 	}
 
+	fn drop_assets() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
 	fn add_authorized_alias() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
@@ -236,6 +246,10 @@ pub mod pallet {
 		#[derive(Debug, TypeInfo)]
 		/// The maximum number of distinct locations allowed as authorized aliases for a local origin.
 		pub const MaxAuthorizedAliases: u32 = 10;
+
+		#[derive(Debug, TypeInfo)]
+		/// The maximum number of asset traps indexed for a single origin.
+		pub const MaxAssetTrapsPerOrigin: u32 = 64;
 	}
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -809,6 +823,36 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type AssetTraps<T: Config> = StorageMap<_, Identity, H256, u32, ValueQuery>;
 
+	/// The existing asset traps, indexed by their origin.
+	///
+	/// Keys are the origin and the `AssetTraps` key, value is the trapped assets. An entry is
+	/// removed once its assets were claimed as many times as they were trapped. Only the last
+	/// [`MaxAssetTrapsPerOrigin`] asset traps of an origin are indexed, see
+	/// [`AssetTrapKeysByOrigin`].
+	#[pallet::storage]
+	pub(super) type AssetTrapsByOrigin<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedLocation,
+		Identity,
+		H256,
+		VersionedAssets,
+		OptionQuery,
+	>;
+
+	/// The `AssetTraps` keys indexed in `AssetTrapsByOrigin` for every origin, oldest first.
+	///
+	/// When an origin already has [`MaxAssetTrapsPerOrigin`] asset traps indexed, the oldest one is
+	/// dropped from the index to make room for a new one.
+	#[pallet::storage]
+	pub(super) type AssetTrapKeysByOrigin<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		VersionedLocation,
+		BoundedVec<H256, MaxAssetTrapsPerOrigin>,
+		ValueQuery,
+	>;
+
 	/// Default version to encode XCM when latest version of destination is unknown. If `None`,
 	/// then the destinations whose XCM version is unknown are considered unreachable.
 	#[pallet::storage]
@@ -944,6 +988,30 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// Whether or not the execution of every XCM instruction should be traced.
+	/// Like [`ShouldRecordXcm`], this is meant to be used in runtime APIs only.
+	///
+	/// The value is transient: it is only set by runtime APIs, which changes are discarded, so it
+	/// is never part of the on-chain state. It is whitelisted, as the XCM executor reads it before
+	/// executing every program.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(crate) type ShouldTraceXcm<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// If [`ShouldTraceXcm`] is set to true, the XCM instructions executed since the trace was last
+	/// taken will be stored here, in the order they finished executing.
+	///
+	/// Like [`ShouldTraceXcm`], the value is transient and whitelisted.
+	///
+	/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+	/// implementation in the XCM executor configuration.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(crate) type XcmTrace<T: Config> = StorageValue<_, Vec<InstructionTrace>, ValueQuery>;
+
 	/// Map of authorized aliasers of local origins. Each local location can authorize a list of
 	/// other locations to alias into it. Each aliaser is only valid until its inner `expiry`
 	/// block number.
@@ -1993,6 +2061,122 @@ impl<T: Config> Pallet<T> {
 		AssetTraps::<T>::get(trap_id)
 	}
 
+	/// Returns the assets trapped for `origin`, as indexed in `AssetTrapsByOrigin`.
+	pub fn trapped_assets(
+		origin: VersionedLocation,
+	) -> Result<Vec<TrappedAssets>, TrappedAssetsApiError> {
+		// storage entries are always latest version
+		let origin: VersionedLocation = origin.into_version(XCM_VERSION).map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::trapped_assets",
+				?e, "Failed to convert versioned location",
+			);
+			TrappedAssetsApiError::LocationVersionConversionFailed
+		})?;
+		Ok(AssetTrapKeysByOrigin::<T>::get(&origin)
+			.into_iter()
+			.filter_map(|hash| {
+				let assets = AssetTrapsByOrigin::<T>::get(&origin, hash)?;
+				let count = AssetTraps::<T>::get(hash);
+				(count > 0).then_some(TrappedAssets { assets, count })
+			})
+			.collect())
+	}
+
+	/// Returns the events of the current block, which are tied to XCM topics.
+	///
+	/// `classify` maps the events of the runtime to the topic and the kind of event. Since the
+	/// `Sent` event is also emitted when messages are sent by the XCM executor, a
+	/// [`XcmTopicEventKind::Sent`] event is reported as [`XcmTopicEventKind::Forwarded`] if it
+	/// is followed by the processing outcome of a received message with the same topic.
+	///
+	/// Some events (e.g. the events of XCMP or UMP queues and of bridge lanes) don't carry the
+	/// topic. `classify` returns no topic for them and they are tied to the topic of the next
+	/// event with topic, deposited in the same phase: routers deposit them right before the
+	/// `Sent` event, and events deposited while processing a received message come before its
+	/// processing outcome. Events that are not followed by any event with topic are skipped.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::topic_events::XcmTopicEventsApi` runtime API.
+	pub fn topic_events(
+		classify: impl Fn(
+			&<T as frame_system::Config>::RuntimeEvent,
+		) -> Option<(Option<XcmHash>, XcmTopicEventKind)>,
+	) -> Vec<XcmTopicEvent> {
+		let classified = frame_system::Pallet::<T>::read_events_no_consensus()
+			.enumerate()
+			.filter_map(|(event_index, record)| {
+				let (topic, kind) = classify(&record.event)?;
+				Some((event_index as u32, record.phase, topic, kind))
+			})
+			.collect::<Vec<_>>();
+
+		let mut events = Vec::with_capacity(classified.len());
+		let mut next_topic = None;
+		let mut processed_topics = BTreeSet::new();
+		for (event_index, phase, topic, kind) in classified.into_iter().rev() {
+			let topic = match (topic, &next_topic) {
+				(Some(topic), _) => {
+					next_topic = Some((phase, topic));
+					topic
+				},
+				(None, Some((next_phase, next_topic))) if same_phase(next_phase, &phase) => {
+					*next_topic
+				},
+				(None, _) => continue,
+			};
+			let kind = match kind {
+				XcmTopicEventKind::Processed | XcmTopicEventKind::Failed => {
+					processed_topics.insert(topic);
+					kind
+				},
+				XcmTopicEventKind::Sent if processed_topics.contains(&topic) => {
+					XcmTopicEventKind::Forwarded
+				},
+				_ => kind,
+			};
+			events.push(XcmTopicEvent { topic, kind, event_index });
+		}
+
+		events.reverse();
+		events
+	}
+
+	/// Index the asset trap `hash` of `assets` under `origin`, dropping the oldest asset trap of
+	/// `origin` from the index if it is full.
+	pub(crate) fn index_asset_trap(origin: &Location, hash: H256, assets: VersionedAssets) {
+		let versioned_origin = VersionedLocation::from(origin.clone());
+		AssetTrapKeysByOrigin::<T>::mutate(&versioned_origin, |keys| {
+			if keys.contains(&hash) {
+				return;
+			}
+			if keys.is_full() {
+				let oldest = keys.remove(0);
+				tracing::debug!(
+					target: "xcm::pallet_xcm::index_asset_trap",
+					?origin, dropped = ?oldest, "Asset traps index full, dropping the oldest",
+				);
+				AssetTrapsByOrigin::<T>::remove(&versioned_origin, oldest);
+			}
+			// can't fail, as there is room for one more key
+			let _ = keys.try_push(hash);
+			AssetTrapsByOrigin::<T>::insert(&versioned_origin, hash, assets);
+		});
+	}
+
+	/// Remove the asset trap `hash` of `origin` from the index.
+	fn unindex_asset_trap(origin: &Location, hash: H256) {
+		let versioned_origin = VersionedLocation::from(origin.clone());
+		AssetTrapsByOrigin::<T>::remove(&versioned_origin, hash);
+		AssetTrapKeysByOrigin::<T>::mutate_exists(&versioned_origin, |keys| {
+			if let Some(list) = keys {
+				list.retain(|key| *key != hash);
+				if list.is_empty() {
+					*keys = None;
+				}
+			}
+		});
+	}
+
 	/// Find `TransferType`s for `assets` and fee identified through `fee_asset_item`, when
 	/// transferring to `dest`.
 	///
@@ -3152,6 +3336,80 @@ impl<T: Config> Pallet<T> {
 		Ok(XcmDryRunEffects { forwarded_xcms, emitted_events: events, execution_result: result })
 	}
 
+	/// Dry-runs `call` with the given `origin`, like [`Self::dry_run_call`], tracing the execution
+	/// of every XCM instruction.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_call_traced<Runtime, Router, OriginCaller, RuntimeCall>(
+		origin: OriginCaller,
+		call: RuntimeCall,
+		result_xcms_version: XcmVersion,
+	) -> Result<
+		TracedCallDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>,
+		XcmDryRunApiError,
+	>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+		<RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+	{
+		crate::Pallet::<Runtime>::set_trace_xcm(true);
+		let effects = Self::dry_run_call::<Runtime, Router, OriginCaller, RuntimeCall>(
+			origin,
+			call,
+			result_xcms_version,
+		);
+		crate::Pallet::<Runtime>::set_trace_xcm(false);
+		let trace = crate::Pallet::<Runtime>::take_trace();
+
+		Ok(TracedCallDryRunEffects {
+			effects: effects?,
+			trace: Self::convert_trace(result_xcms_version, trace)?,
+		})
+	}
+
+	/// Dry-runs `xcm` with the given `origin_location`, like [`Self::dry_run_xcm`], tracing the
+	/// execution of every instruction.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::dry_run::DryRunApi` runtime API.
+	pub fn dry_run_xcm_traced<Router>(
+		origin_location: VersionedLocation,
+		xcm: VersionedXcm<<T as Config>::RuntimeCall>,
+		result_xcms_version: XcmVersion,
+	) -> Result<TracedXcmDryRunEffects<<T as frame_system::Config>::RuntimeEvent>, XcmDryRunApiError>
+	where
+		Router: InspectMessageQueues,
+	{
+		Self::set_trace_xcm(true);
+		let effects = Self::dry_run_xcm::<Router>(origin_location, xcm);
+		Self::set_trace_xcm(false);
+		let trace = Self::take_trace();
+
+		Ok(TracedXcmDryRunEffects {
+			effects: effects?,
+			trace: Self::convert_trace(result_xcms_version, trace)?,
+		})
+	}
+
+	fn convert_trace(
+		xcm_version: XcmVersion,
+		trace: Vec<InstructionTrace>,
+	) -> Result<Vec<xcm_runtime_apis::dry_run::InstructionTrace>, XcmDryRunApiError> {
+		trace
+			.into_iter()
+			.map(|instruction| {
+				xcm_runtime_apis::dry_run::InstructionTrace::from_executor(instruction, xcm_version)
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.inspect_err(|error| {
+				tracing::debug!(
+					target: "xcm::pallet_xcm::convert_trace",
+					?error, "Failed to convert the trace to the requested version",
+				);
+			})
+	}
+
 	fn convert_xcms(
 		xcm_version: XcmVersion,
 		xcms: Vec<VersionedXcm<()>>,
@@ -3324,6 +3582,74 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Quotes the fees of the XCM transfer `call` dispatched by `origin`, in `asset_id`.
+	///
+	/// Dry-runs `call` like [`Self::dry_run_call`], then weighs its local XCM and quotes the
+	/// delivery of every XCM it forwards to other locations. The remote execution fees are quoted
+	/// by the destinations of the forwarded XCMs.
+	///
+	/// `Trader` and `AssetExchanger` must be the ones used in the XcmExecutor.
+	/// Meant to be used in the `xcm_runtime_apis::fees::TransferQuoteApi` runtime API.
+	pub fn quote_transfer<Runtime, Router, Trader, AssetExchanger, OriginCaller, RuntimeCall>(
+		origin: OriginCaller,
+		call: RuntimeCall,
+		asset_id: VersionedAssetId,
+		result_xcms_version: XcmVersion,
+	) -> Result<TransferQuote, XcmPaymentApiError>
+	where
+		Runtime: crate::Config,
+		Router: InspectMessageQueues,
+		Trader: xcm_executor::traits::WeightTrader,
+		AssetExchanger: xcm_executor::traits::AssetExchange,
+		RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+		<RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+	{
+		let effects = Self::dry_run_call::<Runtime, Router, OriginCaller, RuntimeCall>(
+			origin,
+			call,
+			result_xcms_version,
+		)
+		.map_err(|error| {
+			tracing::debug!(target: "xcm::pallet_xcm::quote_transfer", ?error, "Failed to dry-run the transfer");
+			match error {
+				XcmDryRunApiError::Unimplemented => XcmPaymentApiError::Unimplemented,
+				XcmDryRunApiError::VersionedConversionFailed =>
+					XcmPaymentApiError::VersionedConversionFailed,
+			}
+		})?;
+		effects.execution_result.map_err(|error| {
+			tracing::debug!(target: "xcm::pallet_xcm::quote_transfer", ?error, "The transfer failed");
+			XcmPaymentApiError::TransferFailed
+		})?;
+
+		let (local_execution_weight, local_execution_fee) = match &effects.local_xcm {
+			Some(local_xcm) => {
+				let weight = Self::query_xcm_weight(local_xcm.clone())?;
+				(weight, Self::query_weight_to_asset_fee::<Trader>(weight, asset_id.clone())?)
+			},
+			None => (Weight::zero(), 0),
+		};
+
+		let mut hops = Vec::new();
+		for (destination, messages) in effects.forwarded_xcms {
+			for message in messages {
+				let delivery_fees = Self::query_delivery_fees::<AssetExchanger>(
+					destination.clone(),
+					message.clone(),
+					asset_id.clone(),
+				)?;
+				hops.push(HopQuote { destination: destination.clone(), message, delivery_fees });
+			}
+		}
+
+		Ok(TransferQuote {
+			local_xcm: effects.local_xcm,
+			local_execution_weight,
+			local_execution_fee,
+			hops,
+		})
+	}
+
 	/// Given an Asset and a Location, returns if the provided location is a trusted reserve for the
 	/// given asset.
 	pub fn is_trusted_reserve(
@@ -3914,13 +4240,13 @@ impl<T: Config> DropAssets for Pallet<T> {
 		let versioned = VersionedAssets::from(Assets::from(assets));
 		let hash = BlakeTwo256::hash_of(&(&origin, &versioned));
 		AssetTraps::<T>::mutate(hash, |n| *n += 1);
+		Self::index_asset_trap(origin, hash, versioned.clone());
 		Self::deposit_event(Event::AssetsTrapped {
 			hash,
 			origin: origin.clone(),
 			assets: versioned,
 		});
-		// TODO #3735: Put the real weight in there.
-		Weight::zero()
+		T::WeightInfo::drop_assets()
 	}
 }
 
@@ -3945,7 +4271,10 @@ impl<T: Config> ClaimAssets for Pallet<T> {
 		let hash = BlakeTwo256::hash_of(&(origin.clone(), versioned.clone()));
 		match AssetTraps::<T>::get(hash) {
 			0 => return None,
-			1 => AssetTraps::<T>::remove(hash),
+			1 => {
+				AssetTraps::<T>::remove(hash);
+				Self::unindex_asset_trap(origin, hash);
+			},
 			n => AssetTraps::<T>::insert(hash, n - 1),
 		}
 		let mut claimed = AssetsInHolding::new();
@@ -4196,6 +4525,35 @@ impl<T: Config> RecordXcm for Pallet<T> {
 	fn record(xcm: Xcm<()>) {
 		RecordedXcm::<T>::put(xcm);
 	}
+
+	fn should_trace() -> bool {
+		ShouldTraceXcm::<T>::get()
+	}
+
+	fn set_trace_xcm(enabled: bool) {
+		ShouldTraceXcm::<T>::put(enabled);
+	}
+
+	fn take_trace() -> Vec<InstructionTrace> {
+		XcmTrace::<T>::take()
+	}
+
+	fn trace(instruction: InstructionTrace) {
+		XcmTrace::<T>::append(instruction);
+	}
+}
+
+/// Whether two events were deposited in the same phase.
+///
+/// `frame_system::Phase` implements `PartialEq` only with `std`.
+fn same_phase(a: &frame_system::Phase, b: &frame_system::Phase) -> bool {
+	use frame_system::Phase;
+	match (a, b) {
+		(Phase::ApplyExtrinsic(a), Phase::ApplyExtrinsic(b)) => a == b,
+		(Phase::Finalization, Phase::Finalization) |
+		(Phase::Initialization, Phase::Initialization) => true,
+		_ => false,
+	}
 }
 
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
@@ -15,14 +15,17 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	pallet::CurrentMigration, Config, CurrentXcmVersion, Pallet, VersionMigrationStage,
+	pallet::CurrentMigration, AssetTraps, Config, CurrentXcmVersion, Pallet, VersionMigrationStage,
 	VersionNotifyTargets,
 };
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{OnRuntimeUpgrade, StorageVersion, UncheckedOnRuntimeUpgrade},
 	weights::Weight,
 };
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use xcm::{latest::Location, VersionedAssets, VersionedLocation};
 
 const DEFAULT_PROOF_SIZE: u64 = 64 * 1024;
 
@@ -400,10 +403,139 @@ pub mod data {
 			}
 			// two writes per key, one to remove old entry, one to write new entry
 			weight.saturating_accrue(T::DbWeight::get().writes(count * 2));
+
+			// check and migrate `AssetTrapsByOrigin` origins, the trapped assets must stay in the
+			// version they were trapped with to be claimable
+			let asset_traps_to_migrate =
+				AssetTrapsByOrigin::<T>::iter().filter_map(|(origin, hash, assets)| {
+					weight.saturating_accrue(T::DbWeight::get().reads(1));
+					if origin.identify_version() == required_xcm_version {
+						return None;
+					}
+					match origin.clone().into_version(required_xcm_version) {
+						Ok(new_origin) => Some((origin, new_origin, hash, assets)),
+						Err(_) => {
+							tracing::error!(
+								target: LOG_TARGET,
+								?origin,
+								?required_xcm_version,
+								"`AssetTrapsByOrigin` origin cannot be migrated!"
+							);
+							None
+						},
+					}
+				});
+			for (old_origin, new_origin, hash, assets) in asset_traps_to_migrate.collect::<Vec<_>>()
+			{
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				// make sure, that we don't override accidentally other data
+				if AssetTrapsByOrigin::<T>::contains_key(&new_origin, hash) {
+					tracing::error!(
+						target: LOG_TARGET,
+						?old_origin,
+						?new_origin,
+						?hash,
+						"`AssetTrapsByOrigin` already contains data for a `new_origin`!"
+					);
+					continue;
+				}
+
+				tracing::info!(
+					target: LOG_TARGET,
+					?old_origin,
+					?new_origin,
+					?hash,
+					"Migrating `AssetTrapsByOrigin` origin"
+				);
+				AssetTrapsByOrigin::<T>::remove(&old_origin, hash);
+				AssetTrapsByOrigin::<T>::insert(new_origin, hash, assets);
+				// two writes per entry, one to remove old entry, one to write new entry
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+			}
+
+			// check and migrate `AssetTrapKeysByOrigin` keys, the keys are merged with the ones
+			// already indexed for the migrated origin
+			let asset_trap_keys_to_migrate =
+				AssetTrapKeysByOrigin::<T>::iter_keys().filter_map(|key| {
+					weight.saturating_accrue(T::DbWeight::get().reads(1));
+					if key.identify_version() == required_xcm_version {
+						return None;
+					}
+					match key.clone().into_version(required_xcm_version) {
+						Ok(new_key) => Some((key, new_key)),
+						Err(_) => {
+							tracing::error!(
+								target: LOG_TARGET,
+								?key,
+								?required_xcm_version,
+								"`AssetTrapKeysByOrigin` key cannot be migrated!"
+							);
+							None
+						},
+					}
+				});
+			for (old_key, new_key) in asset_trap_keys_to_migrate.collect::<Vec<_>>() {
+				tracing::info!(
+					target: LOG_TARGET,
+					?old_key,
+					?new_key,
+					"Migrating `AssetTrapKeysByOrigin` key"
+				);
+				// the asset traps of `old_key` are older than the ones indexed for `new_key`
+				let mut merged = AssetTrapKeysByOrigin::<T>::take(&old_key).into_inner();
+				let existing = AssetTrapKeysByOrigin::<T>::get(&new_key);
+				merged.retain(|hash| !existing.contains(hash));
+				merged.extend(existing);
+				let excess = merged.len().saturating_sub(MaxAssetTrapsPerOrigin::get() as usize);
+				for dropped in merged.drain(..excess) {
+					AssetTrapsByOrigin::<T>::remove(&new_key, dropped);
+				}
+				AssetTrapKeysByOrigin::<T>::insert(&new_key, BoundedVec::truncate_from(merged));
+				weight.saturating_accrue(
+					T::DbWeight::get().reads_writes(1, 2u64.saturating_add(excess as u64)),
+				);
+			}
 		}
 	}
 }
 
+/// Adds known asset traps to the `AssetTrapsByOrigin` index.
+///
+/// The asset traps created before the index existed are only known by their hash, so they can't
+/// be indexed without knowing their origin and assets. `KnownTraps` provides them, e.g. as
+/// collected from the `AssetsTrapped` events. Every pair whose asset trap still exists is indexed,
+/// the others are ignored.
+///
+/// NOTE: This migration is idempotent, it can be run multiple times with different `KnownTraps`.
+pub struct IndexAssetTraps<T, KnownTraps>(core::marker::PhantomData<(T, KnownTraps)>);
+impl<T: Config, KnownTraps: Get<Vec<(VersionedLocation, VersionedAssets)>>> OnRuntimeUpgrade
+	for IndexAssetTraps<T, KnownTraps>
+{
+	fn on_runtime_upgrade() -> Weight {
+		const LOG_TARGET: &str = "runtime::xcm::pallet_xcm::index_asset_traps";
+
+		let mut weight = Weight::zero();
+		let mut indexed = 0u32;
+		for (origin, assets) in KnownTraps::get() {
+			let Ok(origin) = Location::try_from(origin.clone()) else {
+				tracing::warn!(target: LOG_TARGET, ?origin, "Unsupported origin, skipping");
+				continue;
+			};
+			let hash = BlakeTwo256::hash_of(&(&origin, &assets));
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+			if !AssetTraps::<T>::contains_key(hash) {
+				tracing::debug!(target: LOG_TARGET, ?origin, ?assets, "No such asset trap, skipping");
+				continue;
+			}
+			Pallet::<T>::index_asset_trap(&origin, hash, assets);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+			indexed += 1;
+		}
+		tracing::info!(target: LOG_TARGET, ?indexed, "Indexed asset traps");
+		weight
+	}
+}
+
 pub mod v1 {
 	use super::*;
 	use crate::{CurrentMigration, VersionMigrationStage};
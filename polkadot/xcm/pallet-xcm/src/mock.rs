@@ -316,6 +316,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
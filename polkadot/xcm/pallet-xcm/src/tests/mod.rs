@@ -24,8 +24,9 @@ use crate::{
 	mock::*,
 	pallet::{LockedFungibles, RemoteLockedFungibles, SupportedVersion},
 	xcm_helpers::find_xcm_sent_message_id,
-	AssetTraps, AuthorizedAliasers, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
-	LatestVersionedLocation, MaxAuthorizedAliases, Pallet, Queries, QueryStatus, RecordedXcm,
+	AssetTrapKeysByOrigin, AssetTraps, AssetTrapsByOrigin, AuthorizedAliasers, Config,
+	CurrentMigration, Error, ExecuteControllerWeightInfo, LatestVersionedLocation,
+	MaxAssetTrapsPerOrigin, MaxAuthorizedAliases, Pallet, Queries, QueryStatus, RecordedXcm,
 	RemoteLockedFungibleRecord, ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage,
 	VersionNotifiers, VersionNotifyTargets, WeightInfo,
 };
@@ -36,6 +37,7 @@ use frame_support::{
 	weights::Weight,
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
+use sp_core::H256;
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, BlockNumberProvider, Hash},
 	SaturatedConversion, TokenError,
@@ -46,6 +48,10 @@ use xcm_executor::{
 	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
 	XcmExecutor,
 };
+use xcm_runtime_apis::{
+	topic_events::{XcmTopicEvent, XcmTopicEventKind},
+	trapped_assets::TrappedAssets,
+};
 use xcm_simulator::fake_message_hash;
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
@@ -622,7 +628,10 @@ fn trapped_assets_can_be_claimed() {
 					assets: vma
 				}),
 				RuntimeEvent::XcmPallet(crate::Event::Attempted {
-					outcome: Outcome::Complete { used: BaseXcmWeight::get() * 5 }
+					outcome: Outcome::Complete {
+						used: BaseXcmWeight::get() * 5 +
+							<Test as Config>::WeightInfo::drop_assets()
+					}
 				}),
 			]
 		);
@@ -697,7 +706,10 @@ fn claim_assets_works() {
 					assets: versioned_assets
 				}),
 				RuntimeEvent::XcmPallet(crate::Event::Attempted {
-					outcome: Outcome::Complete { used: BaseXcmWeight::get() * 1 }
+					outcome: Outcome::Complete {
+						used: BaseXcmWeight::get() * 1 +
+							<Test as Config>::WeightInfo::drop_assets()
+					}
 				})
 			],
 		);
@@ -718,6 +730,127 @@ fn claim_assets_works() {
 	});
 }
 
+/// Test the asset traps of an origin are indexed until they are claimed.
+#[test]
+fn trapped_assets_are_indexed_by_origin() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let trap = |amount: u128| {
+			assert_ok!(XcmPallet::execute(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedXcm::from(
+					Xcm::<RuntimeCall>::builder_unsafe().withdraw_asset((Here, amount)).build()
+				)),
+				BaseXcmWeight::get() * 2,
+			));
+		};
+		let claim = |amount: u128| {
+			assert_ok!(XcmPallet::claim_assets(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedAssets::from(Assets::from((Here, amount)))),
+				Box::new(VersionedLocation::from(Location::from(AccountId32 {
+					network: None,
+					id: ALICE.clone().into()
+				}))),
+			));
+		};
+		let source: VersionedLocation =
+			Location::from(Junction::AccountId32 { network: None, id: ALICE.into() }).into();
+		let trapped = |amount: u128, count: u32| TrappedAssets {
+			assets: VersionedAssets::from(Assets::from((Here, amount))),
+			count,
+		};
+		// The asset traps of an origin are listed oldest first.
+		let assert_trapped = |expected: Vec<TrappedAssets>| {
+			assert_eq!(XcmPallet::trapped_assets(source.clone()), Ok(expected));
+		};
+
+		trap(SEND_AMOUNT);
+		trap(SEND_AMOUNT);
+		trap(FEE_AMOUNT);
+		assert_trapped(vec![trapped(SEND_AMOUNT, 2), trapped(FEE_AMOUNT, 1)]);
+		// Other origins have nothing trapped.
+		assert_eq!(
+			XcmPallet::trapped_assets(
+				Location::from(Junction::AccountId32 { network: None, id: BOB.into() }).into()
+			),
+			Ok(vec![])
+		);
+
+		claim(SEND_AMOUNT);
+		assert_trapped(vec![trapped(SEND_AMOUNT, 1), trapped(FEE_AMOUNT, 1)]);
+		claim(SEND_AMOUNT);
+		assert_trapped(vec![trapped(FEE_AMOUNT, 1)]);
+		claim(FEE_AMOUNT);
+		assert_trapped(vec![]);
+		assert_eq!(AssetTrapsByOrigin::<Test>::iter().count(), 0);
+		assert_eq!(AssetTrapKeysByOrigin::<Test>::iter().count(), 0);
+	});
+}
+
+/// Test only the last `MaxAssetTrapsPerOrigin` asset traps of an origin are indexed.
+#[test]
+fn asset_traps_index_is_bounded() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let origin: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+		let max = MaxAssetTrapsPerOrigin::get() as u128;
+		let trap = |amount: u128| {
+			let assets = VersionedAssets::from(Assets::from((Here, amount)));
+			let hash = BlakeTwo256::hash_of(&(&origin, &assets));
+			AssetTraps::<Test>::insert(hash, 1);
+			XcmPallet::index_asset_trap(&origin, hash, assets);
+			hash
+		};
+		let oldest = trap(1);
+		for amount in 2..=max + 1 {
+			trap(amount);
+		}
+
+		let indexed = XcmPallet::trapped_assets(origin.clone().into()).unwrap();
+		assert_eq!(indexed.len() as u128, max);
+		// The oldest asset trap was dropped from the index, but can still be claimed.
+		assert_eq!(indexed[0].assets, VersionedAssets::from(Assets::from((Here, 2u128))));
+		assert_eq!(indexed[indexed.len() - 1].assets, Assets::from((Here, max + 1)).into());
+		assert_eq!(AssetTrapsByOrigin::<Test>::get(VersionedLocation::from(origin), oldest), None);
+		assert_eq!(AssetTrapsByOrigin::<Test>::iter().count() as u128, max);
+		assert_eq!(XcmPallet::asset_trap(&oldest), 1);
+	});
+}
+
+/// Test `IndexAssetTraps` only indexes the known asset traps which still exist.
+#[test]
+fn index_asset_traps_migration_works() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	let origin: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+	let trapped = VersionedAssets::from(Assets::from((Here, SEND_AMOUNT)));
+	frame_support::parameter_types! {
+		pub KnownTraps: Vec<(VersionedLocation, VersionedAssets)> = vec![
+			(
+				Location::from(Junction::AccountId32 { network: None, id: ALICE.into() }).into(),
+				Assets::from((Here, SEND_AMOUNT)).into(),
+			),
+			(
+				Location::from(Junction::AccountId32 { network: None, id: ALICE.into() }).into(),
+				Assets::from((Here, FEE_AMOUNT)).into(),
+			),
+		];
+	}
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		// Only the first known asset trap still exists.
+		AssetTraps::<Test>::insert(BlakeTwo256::hash_of(&(&origin, &trapped)), 2);
+
+		crate::migration::IndexAssetTraps::<Test, KnownTraps>::on_runtime_upgrade();
+		let expected = vec![TrappedAssets { assets: trapped.clone(), count: 2 }];
+		assert_eq!(XcmPallet::trapped_assets(origin.clone().into()), Ok(expected.clone()));
+
+		// Running it again doesn't index the asset trap twice.
+		crate::migration::IndexAssetTraps::<Test, KnownTraps>::on_runtime_upgrade();
+		assert_eq!(XcmPallet::trapped_assets(origin.into()), Ok(expected));
+	});
+}
+
 /// Test failure to complete execution reverts intermediate side-effects.
 ///
 /// XCM program will withdraw and deposit some assets, then fail execution of a further withdraw.
@@ -1640,6 +1773,47 @@ fn migrate_data_to_xcm_version_works() {
 			);
 			assert!(Pallet::<Test>::do_try_state().is_ok());
 		}
+
+		// `AssetTrapsByOrigin` migration
+		{
+			let origin_as_latest = VersionedLocation::from(Location::parent());
+			let origin_as_previous =
+				origin_as_latest.clone().into_version(previous_version).unwrap();
+			let hash1 = H256::repeat_byte(1);
+			let hash2 = H256::repeat_byte(2);
+			// the trapped assets stay in the version they were trapped with
+			let assets = VersionedAssets::from(Assets::from((Here, SEND_AMOUNT)))
+				.into_version(previous_version)
+				.unwrap();
+
+			// store two asset traps: migrated and not migrated
+			AssetTrapsByOrigin::<Test>::insert(&origin_as_latest, hash1, assets.clone());
+			AssetTrapsByOrigin::<Test>::insert(&origin_as_previous, hash2, assets.clone());
+			AssetTrapKeysByOrigin::<Test>::insert(
+				&origin_as_latest,
+				BoundedVec::truncate_from(vec![hash1]),
+			);
+			AssetTrapKeysByOrigin::<Test>::insert(
+				&origin_as_previous,
+				BoundedVec::truncate_from(vec![hash2]),
+			);
+
+			// trigger migration
+			Pallet::<Test>::migrate_data_to_xcm_version(&mut Weight::zero(), latest_version);
+
+			// no change for hash1
+			assert_eq!(
+				AssetTrapsByOrigin::<Test>::get(&origin_as_latest, hash1),
+				Some(assets.clone())
+			);
+			// origin changed for hash2
+			assert_eq!(AssetTrapsByOrigin::<Test>::get(&origin_as_previous, hash2), None);
+			assert_eq!(AssetTrapsByOrigin::<Test>::get(&origin_as_latest, hash2), Some(assets));
+			// keys are merged, the migrated ones are older
+			assert!(!AssetTrapKeysByOrigin::<Test>::contains_key(&origin_as_previous));
+			assert_eq!(AssetTrapKeysByOrigin::<Test>::get(&origin_as_latest), vec![hash2, hash1]);
+			assert!(Pallet::<Test>::do_try_state().is_ok());
+		}
 	})
 }
 
@@ -1786,3 +1960,101 @@ fn query_weight_to_asset_fee_noop() {
 		.unwrap());
 	})
 }
+
+#[test]
+fn topic_events_are_classified() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let sent = |message_id: XcmHash| crate::Event::Sent {
+			origin: Here.into(),
+			destination: Parent.into(),
+			message: Xcm(vec![]),
+			message_id,
+		};
+		let classify = |event: &RuntimeEvent| match event {
+			RuntimeEvent::XcmPallet(crate::Event::Sent { message_id, .. }) => {
+				Some((Some(*message_id), XcmTopicEventKind::Sent))
+			},
+			RuntimeEvent::XcmPallet(crate::Event::ProcessXcmError { message_id, .. }) => {
+				Some((Some(*message_id), XcmTopicEventKind::Failed))
+			},
+			_ => None,
+		};
+
+		System::reset_events();
+		XcmPallet::deposit_event(sent([1; 32]));
+		XcmPallet::deposit_event(crate::Event::NotifyTargetMigrationFail {
+			location: Location::parent().into(),
+			query_id: 0,
+		});
+		XcmPallet::deposit_event(sent([2; 32]));
+		XcmPallet::deposit_event(crate::Event::ProcessXcmError {
+			origin: Parent.into(),
+			error: XcmError::Unimplemented,
+			message_id: [2; 32],
+		});
+		XcmPallet::deposit_event(sent([2; 32]));
+
+		assert_eq!(
+			XcmPallet::topic_events(classify),
+			vec![
+				XcmTopicEvent { topic: [1; 32], kind: XcmTopicEventKind::Sent, event_index: 0 },
+				XcmTopicEvent {
+					topic: [2; 32],
+					kind: XcmTopicEventKind::Forwarded,
+					event_index: 2
+				},
+				XcmTopicEvent { topic: [2; 32], kind: XcmTopicEventKind::Failed, event_index: 3 },
+				XcmTopicEvent { topic: [2; 32], kind: XcmTopicEventKind::Sent, event_index: 4 },
+			],
+		);
+	})
+}
+
+#[test]
+fn topic_events_without_topic_are_tied_to_the_next_topic_in_the_same_phase() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		// `UnexpectedResponse` stands for the events of transport (e.g. XCMP queue), that don't
+		// carry the topic
+		let routed = || crate::Event::UnexpectedResponse { origin: Parent.into(), query_id: 0 };
+		let classify = |event: &RuntimeEvent| match event {
+			RuntimeEvent::XcmPallet(crate::Event::Sent { message_id, .. }) => {
+				Some((Some(*message_id), XcmTopicEventKind::Sent))
+			},
+			RuntimeEvent::XcmPallet(crate::Event::ProcessXcmError { message_id, .. }) => {
+				Some((Some(*message_id), XcmTopicEventKind::Failed))
+			},
+			RuntimeEvent::XcmPallet(crate::Event::UnexpectedResponse { .. }) => {
+				Some((None, XcmTopicEventKind::Routed))
+			},
+			_ => None,
+		};
+
+		System::reset_events();
+		XcmPallet::deposit_event(routed());
+		XcmPallet::deposit_event(crate::Event::Sent {
+			origin: Here.into(),
+			destination: Parent.into(),
+			message: Xcm(vec![]),
+			message_id: [1; 32],
+		});
+		XcmPallet::deposit_event(routed());
+		System::note_finished_extrinsics();
+		XcmPallet::deposit_event(routed());
+		XcmPallet::deposit_event(crate::Event::ProcessXcmError {
+			origin: Parent.into(),
+			error: XcmError::Unimplemented,
+			message_id: [2; 32],
+		});
+		XcmPallet::deposit_event(routed());
+
+		assert_eq!(
+			XcmPallet::topic_events(classify),
+			vec![
+				XcmTopicEvent { topic: [1; 32], kind: XcmTopicEventKind::Routed, event_index: 0 },
+				XcmTopicEvent { topic: [1; 32], kind: XcmTopicEventKind::Sent, event_index: 1 },
+				XcmTopicEvent { topic: [2; 32], kind: XcmTopicEventKind::Routed, event_index: 3 },
+				XcmTopicEvent { topic: [2; 32], kind: XcmTopicEventKind::Failed, event_index: 4 },
+			],
+		);
+	})
+}
@@ -84,6 +84,7 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type Holder = ();
+	type Compliance = ();
 	type CallbackHandle = ();
 }
 
@@ -100,6 +101,7 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type Holder = ();
+	type Compliance = ();
 	type CallbackHandle = ();
 }
 
@@ -132,7 +132,7 @@ pub use pay::{
 };
 
 mod process_xcm_message;
-pub use process_xcm_message::ProcessXcmMessage;
+pub use process_xcm_message::{ProcessXcmMessage, RateLimitedProcessor};
 
 mod routing;
 pub use routing::{
@@ -25,6 +25,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_weights::{Weight, WeightMeter};
 use xcm::{prelude::*, MAX_XCM_DECODE_DEPTH};
+use xcm_executor::traits::RateLimiter;
 
 const LOG_TARGET: &str = "xcm::process-message";
 
@@ -128,6 +129,55 @@ impl<
 	}
 }
 
+/// A message processor that delegates to `Inner` the messages of the origins within the limits
+/// of `Limiter`.
+///
+/// The messages of an origin which exhausted its limits are yielded, so they stay in the queue
+/// until the limits are replenished. As the limits are charged the weight a message used once it
+/// was processed, an origin can exceed its weight limit by the weight of one message.
+pub struct RateLimitedProcessor<Inner, Limiter>(PhantomData<(Inner, Limiter)>);
+impl<Inner, Limiter> ProcessMessage for RateLimitedProcessor<Inner, Limiter>
+where
+	Inner: ProcessMessage,
+	Inner::Origin: Into<Location> + Clone,
+	Limiter: RateLimiter,
+{
+	type Origin = Inner::Origin;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &mut XcmHash,
+	) -> Result<bool, ProcessMessageError> {
+		let limiter_weight = Limiter::weight();
+		if meter.try_consume(limiter_weight).is_err() {
+			return Err(ProcessMessageError::Overweight(limiter_weight));
+		}
+
+		let location: Location = origin.clone().into();
+		if !Limiter::can_process(&location) {
+			tracing::trace!(
+				target: LOG_TARGET,
+				?location,
+				"Origin exhausted its limits, yielding",
+			);
+			return Err(ProcessMessageError::Yield);
+		}
+
+		let consumed = meter.consumed();
+		let processed =
+			Inner::process_message(message, origin, meter, id).map_err(|error| match error {
+				ProcessMessageError::Overweight(required) => {
+					ProcessMessageError::Overweight(required.saturating_add(limiter_weight))
+				},
+				error => error,
+			})?;
+		Limiter::note_processed(&location, meter.consumed().saturating_sub(consumed));
+		Ok(processed)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -240,6 +290,55 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn rate_limited_processor_works() {
+		frame_support::parameter_types! {
+			pub static Budget: u32 = 2;
+			pub static Charged: Vec<(Location, Weight)> = vec![];
+		}
+		struct TestRateLimiter;
+		impl RateLimiter for TestRateLimiter {
+			fn weight() -> Weight {
+				10.into()
+			}
+			fn can_process(_origin: &Location) -> bool {
+				Budget::get() > 0
+			}
+			fn note_processed(origin: &Location, weight: Weight) {
+				Budget::set(Budget::get() - 1);
+				Charged::mutate(|charged| charged.push((origin.clone(), weight)));
+			}
+		}
+		type LimitedProcessor = RateLimitedProcessor<Processor, TestRateLimiter>;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let msg = &v5_xcm(true).encode()[..];
+			let process = |meter: &mut WeightMeter| {
+				LimitedProcessor::process_message(msg, ORIGIN, meter, &mut [0; 32])
+			};
+
+			// The weight of the limiter is required on top of the weight of the message.
+			assert_err!(
+				process(&mut WeightMeter::with_limit(1000.into())),
+				Overweight(1010.into())
+			);
+			assert_eq!(Budget::get(), 2);
+
+			for _ in 0..2 {
+				let meter = &mut WeightMeter::with_limit(1010.into());
+				assert_ok!(process(meter), true);
+				assert_eq!(meter.consumed(), 1010.into());
+			}
+			assert_eq!(Charged::get(), vec![(ORIGIN.into(), 1000.into()); 2]);
+
+			// The message stays in the queue once the limits are exhausted.
+			let meter = &mut WeightMeter::with_limit(1010.into());
+			assert_err!(process(meter), Yield);
+			assert_eq!(meter.consumed(), 10.into());
+			assert_eq!(Charged::get().len(), 2);
+		});
+	}
+
 	fn v3_xcm(success: bool) -> VersionedXcm<RuntimeCall> {
 		let instr = if success {
 			v3::Instruction::<RuntimeCall>::ClearOrigin
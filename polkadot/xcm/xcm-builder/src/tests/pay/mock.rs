@@ -112,6 +112,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
@@ -0,0 +1,448 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A chain loaded from a state snapshot, and the runtime APIs called on it.
+
+use std::{fmt, path::Path};
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use remote_externalities::{Builder, Mode, OfflineConfig, SnapshotConfig};
+use sc_executor::WasmExecutor;
+use scale_value::Value;
+use sp_api::RuntimeApiInfo;
+use sp_core::{
+	traits::{CallContext, ReadRuntimeVersionExt},
+	OpaqueMetadata,
+};
+use sp_externalities::Extensions;
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+use sp_state_machine::{
+	backend::BackendRuntimeCode, InMemoryBackend, OverlayedChanges, StateMachine,
+};
+use sp_version::RuntimeVersion;
+use xcm::{
+	latest::{InteriorLocation, Location, Outcome, Weight},
+	VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
+};
+use xcm_runtime_apis::{
+	dry_run::{self, DryRunApi},
+	fees::{self, XcmPaymentApi},
+};
+
+use crate::{
+	error::Error,
+	metadata::{Metadata, METADATA_VERSION},
+	report::ExecutionFee,
+};
+
+/// The block type the snapshots are decoded with. Only the header is used, and all the chains of
+/// an XCM network use the same header type.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+type HostFunctions = (
+	sp_io::SubstrateHostFunctions,
+	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+);
+
+/// The effects of dry-running a call, with the events decoded.
+pub struct CallEffects {
+	pub execution_result: DispatchResultWithPostInfo,
+	pub emitted_events: Vec<Value<u32>>,
+	pub local_xcm: Option<VersionedXcm<()>>,
+	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+}
+
+/// The effects of dry-running an XCM program, with the events decoded.
+pub struct XcmEffects {
+	pub execution_result: Outcome,
+	pub emitted_events: Vec<Value<u32>>,
+	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+}
+
+impl CallEffects {
+	/// Decode a SCALE encoded `CallDryRunEffects`, with the events described by `metadata`.
+	fn decode(metadata: &Metadata, input: &mut &[u8]) -> Result<Self, String> {
+		Ok(Self {
+			execution_result: Decode::decode(input).map_err(|error| error.to_string())?,
+			emitted_events: metadata.decode_events(input)?,
+			local_xcm: Decode::decode(input).map_err(|error| error.to_string())?,
+			forwarded_xcms: Decode::decode(input).map_err(|error| error.to_string())?,
+		})
+	}
+}
+
+impl XcmEffects {
+	/// Decode a SCALE encoded `XcmDryRunEffects`, with the events described by `metadata`.
+	fn decode(metadata: &Metadata, input: &mut &[u8]) -> Result<Self, String> {
+		Ok(Self {
+			execution_result: Decode::decode(input).map_err(|error| error.to_string())?,
+			emitted_events: metadata.decode_events(input)?,
+			forwarded_xcms: Decode::decode(input).map_err(|error| error.to_string())?,
+		})
+	}
+}
+
+/// A chain whose runtime is executed on top of the state loaded from a snapshot.
+///
+/// The state changes made by the runtime calls are kept, so every dry-run sees the effects of the
+/// previous ones.
+pub struct Chain {
+	name: String,
+	location: InteriorLocation,
+	backend: InMemoryBackend<BlakeTwo256>,
+	overlay: OverlayedChanges<BlakeTwo256>,
+	executor: WasmExecutor<HostFunctions>,
+	version: RuntimeVersion,
+	metadata: Option<Metadata>,
+	fee_asset: Option<Result<VersionedAssetId, String>>,
+}
+
+impl Chain {
+	/// Load the chain found at the universal `location` from the state snapshot at `path`. The
+	/// chain is named after its runtime and `label`.
+	pub async fn load(
+		label: impl fmt::Display,
+		location: InteriorLocation,
+		path: &Path,
+	) -> Result<Self, Error> {
+		let mut ext = Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+			.build()
+			.await
+			.map_err(|error| Error::Snapshot(path.into(), error))?;
+
+		let mut chain = Self {
+			name: label.to_string(),
+			location,
+			backend: ext.as_backend(),
+			overlay: Default::default(),
+			executor: WasmExecutor::builder().with_allow_missing_host_functions(true).build(),
+			version: Default::default(),
+			metadata: None,
+			fee_asset: None,
+		};
+
+		chain.version = chain.call("Core_version", &())?;
+		chain.name = format!("{} ({label})", chain.version.spec_name);
+		let metadata: Option<OpaqueMetadata> =
+			chain.call("Metadata_metadata_at_version", &METADATA_VERSION)?;
+		let metadata = metadata.ok_or_else(|| Error::Metadata {
+			chain: chain.name.clone(),
+			error: format!("metadata V{METADATA_VERSION} is not available"),
+		})?;
+		chain.metadata = Some(
+			Metadata::decode(&metadata)
+				.map_err(|error| Error::Metadata { chain: chain.name.clone(), error })?,
+		);
+
+		log::info!(
+			target: crate::LOG_TARGET,
+			"Loaded {} at block #{} from {}",
+			chain.name,
+			ext.header.number,
+			path.display(),
+		);
+
+		Ok(chain)
+	}
+
+	/// The name of the chain.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// The universal location of the chain.
+	pub fn location(&self) -> &InteriorLocation {
+		&self.location
+	}
+
+	pub fn metadata(&self) -> &Metadata {
+		self.metadata.as_ref().expect("metadata is set when the chain is loaded; qed")
+	}
+
+	/// Dry-run `call` dispatched from the SCALE encoded `OriginCaller`.
+	pub fn dry_run_call(&mut self, origin: &[u8], call: &[u8]) -> Result<CallEffects, Error> {
+		const METHOD: &str = "DryRunApi_dry_run_call";
+
+		let mut args = [origin, call].concat();
+		match self.api_version::<dyn DryRunApi<Block, (), (), ()>>("DryRunApi")? {
+			1 => {},
+			_ => xcm::latest::VERSION.encode_to(&mut args),
+		}
+
+		let output = self.call_raw(METHOD, &args)?;
+		decode_result::<dry_run::Error, _>(&self.name, METHOD, &output, |input| {
+			CallEffects::decode(self.metadata(), input)
+		})
+	}
+
+	/// Dry-run `xcm` sent by `origin`.
+	pub fn dry_run_xcm(
+		&mut self,
+		origin: Location,
+		xcm: &VersionedXcm<()>,
+	) -> Result<XcmEffects, Error> {
+		const METHOD: &str = "DryRunApi_dry_run_xcm";
+
+		self.api_version::<dyn DryRunApi<Block, (), (), ()>>("DryRunApi")?;
+		// `VersionedXcm<()>` and `VersionedXcm<RuntimeCall>` have the same encoding, as the calls
+		// of `Transact` are kept encoded.
+		let output = self.call_raw(METHOD, &(VersionedLocation::from(origin), xcm).encode())?;
+		decode_result::<dry_run::Error, _>(&self.name, METHOD, &output, |input| {
+			XcmEffects::decode(self.metadata(), input)
+		})
+	}
+
+	/// The weight of executing `xcm`, and its price in the first asset accepted for fee payment.
+	pub fn execution_fee(&mut self, xcm: &VersionedXcm<()>) -> Result<ExecutionFee, Error> {
+		self.api_version::<dyn XcmPaymentApi<Block>>("XcmPaymentApi")?;
+
+		let weight: Weight =
+			self.call_api::<fees::Error, _>("XcmPaymentApi_query_xcm_weight", xcm)?;
+		let asset = self.fee_asset()?;
+		let amount = self.call_api::<fees::Error, _>(
+			"XcmPaymentApi_query_weight_to_asset_fee",
+			&(weight, &asset),
+		)?;
+
+		Ok(ExecutionFee { weight, asset, amount })
+	}
+
+	/// The fees of delivering `xcm` to `destination`.
+	pub fn delivery_fees(
+		&mut self,
+		destination: &VersionedLocation,
+		xcm: &VersionedXcm<()>,
+	) -> Result<VersionedAssets, Error> {
+		const METHOD: &str = "XcmPaymentApi_query_delivery_fees";
+
+		match self.api_version::<dyn XcmPaymentApi<Block>>("XcmPaymentApi")? {
+			1 => self.call_api::<fees::Error, _>(METHOD, &(destination, xcm)),
+			_ => {
+				let fee_asset = self.fee_asset()?;
+				self.call_api::<fees::Error, _>(METHOD, &(destination, xcm, fee_asset))
+			},
+		}
+	}
+
+	/// The asset fees are quoted in: the first one accepted for fee payment.
+	fn fee_asset(&mut self) -> Result<VersionedAssetId, Error> {
+		const METHOD: &str = "XcmPaymentApi_query_acceptable_payment_assets";
+
+		if self.fee_asset.is_none() {
+			let assets =
+				self.call_api::<fees::Error, Vec<VersionedAssetId>>(METHOD, &xcm::latest::VERSION);
+			self.fee_asset = Some(match assets {
+				Ok(assets) => assets.into_iter().next().ok_or_else(|| "no assets".into()),
+				Err(error) => Err(error.to_string()),
+			});
+		}
+
+		self.fee_asset.clone().expect("set above; qed").map_err(|error| Error::Api {
+			chain: self.name.clone(),
+			method: METHOD,
+			error,
+		})
+	}
+
+	/// The version of the runtime API `Api`, or an error if the runtime does not implement it.
+	fn api_version<Api: RuntimeApiInfo + ?Sized>(&self, name: &'static str) -> Result<u32, Error> {
+		self.version
+			.api_version(&Api::ID)
+			.ok_or_else(|| Error::Unsupported { chain: self.name.clone(), api: name })
+	}
+
+	/// Call a runtime API returning `Result<R, E>`.
+	fn call_api<E: Decode + std::fmt::Debug, R: Decode>(
+		&mut self,
+		method: &'static str,
+		args: &impl Encode,
+	) -> Result<R, Error> {
+		let output = self.call_raw(method, &args.encode())?;
+		decode_result::<E, _>(&self.name, method, &output, |input| {
+			R::decode(input).map_err(|error| error.to_string())
+		})
+	}
+
+	fn call<R: Decode>(&mut self, method: &'static str, args: &impl Encode) -> Result<R, Error> {
+		let output = self.call_raw(method, &args.encode())?;
+		R::decode(&mut &output[..]).map_err(|error| Error::Decode {
+			chain: self.name.clone(),
+			method,
+			error: error.to_string(),
+		})
+	}
+
+	fn call_raw(&mut self, method: &'static str, args: &[u8]) -> Result<Vec<u8>, Error> {
+		let execution_error =
+			|chain: &str, error: String| Error::Execution { chain: chain.into(), method, error };
+
+		let runtime_code = BackendRuntimeCode::new(&self.backend, CallContext::Offchain.into());
+		let runtime_code =
+			runtime_code.runtime_code().map_err(|e| execution_error(&self.name, e.into()))?;
+		let mut extensions = Extensions::default();
+		extensions.register(ReadRuntimeVersionExt::new(self.executor.clone()));
+
+		let result = StateMachine::new(
+			&self.backend,
+			&mut self.overlay,
+			&self.executor,
+			method,
+			args,
+			&mut extensions,
+			&runtime_code,
+			CallContext::Offchain,
+		)
+		.execute();
+
+		result.map_err(|error| execution_error(&self.name, error.to_string()))
+	}
+}
+
+/// Decode the `Result<R, E>` returned by `method` on `chain`, using `decode` for `R`.
+fn decode_result<E: Decode + std::fmt::Debug, R>(
+	chain: &str,
+	method: &'static str,
+	mut output: &[u8],
+	decode: impl FnOnce(&mut &[u8]) -> Result<R, String>,
+) -> Result<R, Error> {
+	let decode_error = |error| Error::Decode { chain: chain.into(), method, error };
+
+	match u8::decode(&mut output).map_err(|error| decode_error(error.to_string()))? {
+		0 => decode(&mut output).map_err(decode_error),
+		1 => {
+			let error = E::decode(&mut output).map_err(|error| decode_error(error.to_string()))?;
+			Err(Error::Api { chain: chain.into(), method, error: format!("{error:?}") })
+		},
+		_ => Err(decode_error("invalid `Result` variant".into())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::metadata::{
+		is_assets_trapped,
+		tests::{assets_trapped, metadata, RuntimeEvent, SystemEvent},
+		variant_name,
+	};
+	use frame_support::dispatch::PostDispatchInfo;
+	use xcm::latest::{Junction::Parachain, Xcm};
+	use xcm_runtime_apis::dry_run::{CallDryRunEffects, XcmDryRunEffects};
+
+	const METHOD: &str = "DryRunApi_dry_run_call";
+
+	fn forwarded_xcms() -> Vec<(VersionedLocation, Vec<VersionedXcm<()>>)> {
+		vec![(
+			Location::new(0, [Parachain(1000)]).into(),
+			vec![VersionedXcm::from(Xcm::<()>::builder_unsafe().clear_origin().build())],
+		)]
+	}
+
+	fn decode_call_effects(output: &[u8]) -> Result<CallEffects, Error> {
+		let metadata = metadata();
+		decode_result::<dry_run::Error, _>("relay", METHOD, output, |input| {
+			CallEffects::decode(&metadata, input)
+		})
+	}
+
+	#[test]
+	fn call_effects_are_decoded() {
+		let post_info = PostDispatchInfo {
+			actual_weight: Some(Weight::from_parts(7, 0)),
+			pays_fee: Default::default(),
+		};
+		let output = Ok::<_, dry_run::Error>(CallDryRunEffects {
+			execution_result: Ok(post_info),
+			emitted_events: vec![
+				RuntimeEvent::System(SystemEvent::Remarked { sender: [2; 32], hash: [3; 32] }),
+				assets_trapped(),
+			],
+			local_xcm: Some(VersionedXcm::from(Xcm::<()>::new())),
+			forwarded_xcms: forwarded_xcms(),
+		})
+		.encode();
+
+		let effects = decode_call_effects(&output).unwrap();
+
+		assert_eq!(effects.execution_result, Ok(post_info));
+		assert_eq!(effects.emitted_events.len(), 2);
+		assert_eq!(variant_name(&effects.emitted_events[0]), Some("System"));
+		assert!(is_assets_trapped(&effects.emitted_events[1]));
+		assert_eq!(effects.local_xcm, Some(VersionedXcm::from(Xcm::<()>::new())));
+		assert_eq!(effects.forwarded_xcms, forwarded_xcms());
+	}
+
+	#[test]
+	fn xcm_effects_are_decoded() {
+		let outcome = Outcome::Complete { used: Weight::from_parts(3, 4) };
+		let output = Ok::<_, dry_run::Error>(XcmDryRunEffects {
+			execution_result: outcome.clone(),
+			emitted_events: vec![assets_trapped()],
+			forwarded_xcms: forwarded_xcms(),
+		})
+		.encode();
+		let metadata = metadata();
+
+		let effects = decode_result::<dry_run::Error, _>(
+			"asset-hub",
+			"DryRunApi_dry_run_xcm",
+			&output,
+			|input| XcmEffects::decode(&metadata, input),
+		)
+		.unwrap();
+
+		assert_eq!(effects.execution_result, outcome);
+		assert_eq!(effects.emitted_events.len(), 1);
+		assert!(is_assets_trapped(&effects.emitted_events[0]));
+		assert_eq!(effects.forwarded_xcms, forwarded_xcms());
+	}
+
+	#[test]
+	fn api_errors_are_reported() {
+		let output = Err::<(), _>(dry_run::Error::VersionedConversionFailed).encode();
+
+		assert!(matches!(
+			decode_call_effects(&output),
+			Err(Error::Api { chain, method: METHOD, error })
+				if chain == "relay" && error == "VersionedConversionFailed"
+		));
+	}
+
+	#[test]
+	fn invalid_outputs_are_reported() {
+		let output = Ok::<_, dry_run::Error>(CallDryRunEffects::<RuntimeEvent> {
+			execution_result: Ok(Default::default()),
+			emitted_events: vec![assets_trapped()],
+			local_xcm: None,
+			forwarded_xcms: vec![],
+		})
+		.encode();
+
+		// Truncated in the events.
+		assert!(matches!(
+			decode_call_effects(&output[..output.len() - 8]),
+			Err(Error::Decode { method: METHOD, .. })
+		));
+		// Not a `Result`.
+		assert!(matches!(
+			decode_call_effects(&[2]),
+			Err(Error::Decode { method: METHOD, error, .. }) if error == "invalid `Result` variant"
+		));
+		assert!(matches!(decode_call_effects(&[]), Err(Error::Decode { method: METHOD, .. })));
+	}
+}
@@ -0,0 +1,42 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::path::PathBuf;
+
+/// Errors which abort the dry-run.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Failed to load the state snapshot {0}: {1}")]
+	Snapshot(PathBuf, &'static str),
+
+	#[error("No snapshot is loaded for {0}")]
+	UnknownChain(String),
+
+	#[error("Calling `{method}` on {chain} failed: {error}")]
+	Execution { chain: String, method: &'static str, error: String },
+
+	#[error("Failed to decode the result of `{method}` on {chain}: {error}")]
+	Decode { chain: String, method: &'static str, error: String },
+
+	#[error("{chain} does not implement the `{api}` runtime API")]
+	Unsupported { chain: String, api: &'static str },
+
+	#[error("`{method}` on {chain} returned {error}")]
+	Api { chain: String, method: &'static str, error: String },
+
+	#[error("Invalid metadata of {chain}: {error}")]
+	Metadata { chain: String, error: String },
+}
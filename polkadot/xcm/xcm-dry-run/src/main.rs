@@ -0,0 +1,187 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-run a call and the XCM programs it sends across several chains.
+//!
+//! The chains are loaded from state snapshots, as created by `try-runtime create-snapshot`. The
+//! call is dry-run with `DryRunApi::dry_run_call` on the chain it is dispatched on, then every
+//! forwarded message is dry-run with `DryRunApi::dry_run_xcm` on its destination, and so on. See
+//! the README for an example.
+
+mod chain;
+mod error;
+mod metadata;
+mod orchestrator;
+mod report;
+
+use std::{fmt, path::PathBuf, process::ExitCode, str::FromStr};
+
+use clap::Parser;
+use xcm::latest::{InteriorLocation, Junction, NetworkId};
+
+use crate::{chain::Chain, error::Error, orchestrator::Orchestrator, report::Report};
+
+const LOG_TARGET: &str = "xcm-dry-run";
+
+/// Dry-run a call and the XCM programs it sends across several chains.
+#[derive(Debug, Parser)]
+struct Cli {
+	/// The global consensus of the chains: `polkadot`, `kusama`, `westend` or `rococo`.
+	#[arg(long, default_value = "polkadot", value_parser = parse_network)]
+	network: NetworkId,
+
+	/// A state snapshot of the relay chain.
+	#[arg(long)]
+	relay: Option<PathBuf>,
+
+	/// A state snapshot of a parachain, as `<para id>=<path>`. Can be given multiple times.
+	#[arg(long = "para", value_parser = parse_para)]
+	paras: Vec<(u32, PathBuf)>,
+
+	/// The chain to dispatch the call on: `relay`, or the id of a parachain.
+	#[arg(long)]
+	from: ChainId,
+
+	/// The hex encoded `OriginCaller` to dispatch the call from.
+	#[arg(long, value_parser = parse_hex, required_unless_present = "signed")]
+	origin: Option<Bytes>,
+
+	/// The hex encoded account to dispatch the call from, with a signed origin.
+	#[arg(long, value_parser = parse_hex, conflicts_with = "origin")]
+	signed: Option<Bytes>,
+
+	/// The hex encoded call to dry-run.
+	#[arg(long, value_parser = parse_hex)]
+	call: Bytes,
+
+	/// The maximum number of hops to dry-run, including the call.
+	#[arg(long, default_value_t = 16)]
+	max_hops: usize,
+}
+
+/// A chain of the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChainId {
+	Relay,
+	Para(u32),
+}
+
+impl ChainId {
+	fn location(self, network: NetworkId) -> InteriorLocation {
+		match self {
+			ChainId::Relay => [Junction::GlobalConsensus(network)].into(),
+			ChainId::Para(id) => {
+				[Junction::GlobalConsensus(network), Junction::Parachain(id)].into()
+			},
+		}
+	}
+}
+
+impl fmt::Display for ChainId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ChainId::Relay => write!(f, "relay chain"),
+			ChainId::Para(id) => write!(f, "para {id}"),
+		}
+	}
+}
+
+impl FromStr for ChainId {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"relay" => Ok(ChainId::Relay),
+			id => id.parse().map(ChainId::Para).map_err(|_| format!("invalid chain: {id}")),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Bytes(Vec<u8>);
+
+fn parse_hex(s: &str) -> Result<Bytes, String> {
+	array_bytes::hex2bytes(s)
+		.map(Bytes)
+		.map_err(|error| format!("invalid hex: {error:?}"))
+}
+
+fn parse_network(s: &str) -> Result<NetworkId, String> {
+	match s {
+		"polkadot" => Ok(NetworkId::Polkadot),
+		"kusama" => Ok(NetworkId::Kusama),
+		"westend" => Ok(NetworkId::ByGenesis(xcm::latest::WESTEND_GENESIS_HASH)),
+		"rococo" => Ok(NetworkId::ByGenesis(xcm::latest::ROCOCO_GENESIS_HASH)),
+		network => Err(format!("unknown network: {network}")),
+	}
+}
+
+fn parse_para(s: &str) -> Result<(u32, PathBuf), String> {
+	let (id, path) = s.split_once('=').ok_or("expected `<para id>=<path>`")?;
+	Ok((id.parse().map_err(|_| format!("invalid para id: {id}"))?, path.into()))
+}
+
+async fn run(cli: Cli) -> Result<Report, Error> {
+	let snapshots = cli
+		.relay
+		.map(|path| (ChainId::Relay, path))
+		.into_iter()
+		.chain(cli.paras.into_iter().map(|(id, path)| (ChainId::Para(id), path)))
+		.collect::<Vec<_>>();
+	if !snapshots.iter().any(|(id, _)| *id == cli.from) {
+		return Err(Error::UnknownChain(cli.from.to_string()));
+	}
+
+	let mut chains = Vec::new();
+	for (id, path) in snapshots {
+		chains.push(Chain::load(id, id.location(cli.network), &path).await?);
+	}
+
+	let origin = |chain: &Chain| match (cli.origin, cli.signed) {
+		(Some(Bytes(origin)), _) => Ok(origin),
+		(None, Some(Bytes(account))) => chain
+			.metadata()
+			.signed_origin(&account)
+			.map_err(|error| Error::Metadata { chain: chain.name().into(), error }),
+		(None, None) => unreachable!("clap requires either `--origin` or `--signed`; qed"),
+	};
+
+	Orchestrator::new(chains, cli.max_hops).dry_run_call(
+		&cli.from.location(cli.network),
+		origin,
+		&cli.call.0,
+	)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+	sp_tracing::try_init_simple();
+
+	match run(Cli::parse()).await {
+		Ok(report) => {
+			println!("{report}");
+			if report.is_success() {
+				ExitCode::SUCCESS
+			} else {
+				ExitCode::FAILURE
+			}
+		},
+		Err(error) => {
+			eprintln!("Error: {error}");
+			ExitCode::from(2)
+		},
+	}
+}
@@ -0,0 +1,342 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The parts of the runtime metadata needed to make sense of the dry-run results.
+//!
+//! The `DryRunApi` is generic over the runtime call, event and origin types, which differ between
+//! runtimes. The metadata describes them, which lets the same tool handle any runtime.
+
+use codec::{Compact, Decode};
+use frame_metadata::{v15::RuntimeMetadataV15, RuntimeMetadata, RuntimeMetadataPrefixed};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, Variant};
+use scale_value::{Value, ValueDef};
+use sp_runtime::DispatchError;
+
+/// The metadata version requested from the runtimes.
+pub const METADATA_VERSION: u32 = 15;
+
+/// The types of a runtime, as described by its metadata.
+pub struct Metadata {
+	types: PortableRegistry,
+	event_ty: u32,
+	/// The index, name and error type of every pallet.
+	pallets: Vec<(u8, String, Option<u32>)>,
+}
+
+impl Metadata {
+	/// Decode the metadata returned by `Metadata_metadata_at_version`.
+	pub fn decode(mut encoded: &[u8]) -> Result<Self, String> {
+		let metadata =
+			RuntimeMetadataPrefixed::decode(&mut encoded).map_err(|error| error.to_string())?;
+		let RuntimeMetadata::V15(RuntimeMetadataV15 { types, pallets, outer_enums, .. }) =
+			metadata.1
+		else {
+			return Err(format!("expected metadata V{METADATA_VERSION}"));
+		};
+
+		Ok(Self {
+			types,
+			event_ty: outer_enums.event_enum_ty.id,
+			pallets: pallets
+				.into_iter()
+				.map(|pallet| (pallet.index, pallet.name, pallet.error.map(|error| error.ty.id)))
+				.collect(),
+		})
+	}
+
+	/// Decode a SCALE encoded `Vec` of runtime events.
+	pub fn decode_events(&self, input: &mut &[u8]) -> Result<Vec<Value<u32>>, String> {
+		let Compact(len) = Compact::<u32>::decode(input).map_err(|error| error.to_string())?;
+		(0..len)
+			.map(|_| {
+				scale_value::scale::decode_as_type(input, self.event_ty, &self.types)
+					.map_err(|error| error.to_string())
+			})
+			.collect()
+	}
+
+	/// Describe a dispatch error, naming the pallet error if it is a module error.
+	pub fn describe_dispatch_error(&self, error: &DispatchError) -> String {
+		let DispatchError::Module(module_error) = error else { return format!("{error:?}") };
+
+		self.pallets
+			.iter()
+			.find(|(index, ..)| *index == module_error.index)
+			.and_then(|(_, pallet, error_ty)| {
+				let variant = self.variant((*error_ty)?, module_error.error[0])?;
+				Some(format!("{pallet}::{}", variant.name))
+			})
+			.unwrap_or_else(|| format!("{error:?}"))
+	}
+
+	/// SCALE encode the `OriginCaller` of a signed origin, i.e.
+	/// `OriginCaller::system(RawOrigin::Signed(account))`.
+	///
+	/// The encoded `account` is used as is, so it must match the account id type of the runtime.
+	pub fn signed_origin(&self, account: &[u8]) -> Result<Vec<u8>, String> {
+		let origin_caller = self
+			.types
+			.types
+			.iter()
+			.find(|ty| ty.ty.path.segments.last().is_some_and(|name| name == "OriginCaller"))
+			.ok_or("no `OriginCaller` type")?;
+		let system = self
+			.variants(origin_caller.id)
+			.and_then(|variants| variants.iter().find(|variant| variant.name == "system"))
+			.ok_or("no `OriginCaller::system` variant")?;
+		let raw_origin = system.fields.first().ok_or("no `RawOrigin` type")?.ty.id;
+		let signed = self
+			.variants(raw_origin)
+			.and_then(|variants| variants.iter().find(|variant| variant.name == "Signed"))
+			.ok_or("no `RawOrigin::Signed` variant")?;
+
+		Ok([&[system.index, signed.index][..], account].concat())
+	}
+
+	fn variants(&self, ty: u32) -> Option<&[Variant<PortableForm>]> {
+		match &self.types.resolve(ty)?.type_def {
+			TypeDef::Variant(def) => Some(&def.variants),
+			_ => None,
+		}
+	}
+
+	fn variant(&self, ty: u32, index: u8) -> Option<&Variant<PortableForm>> {
+		self.variants(ty)?.iter().find(|variant| variant.index == index)
+	}
+}
+
+/// The name of the variant of an enum value.
+pub fn variant_name<T>(value: &Value<T>) -> Option<&str> {
+	match &value.value {
+		ValueDef::Variant(variant) => Some(&variant.name),
+		_ => None,
+	}
+}
+
+/// Whether `event` is the `AssetsTrapped` event of the XCM pallet.
+pub fn is_assets_trapped<T>(event: &Value<T>) -> bool {
+	let ValueDef::Variant(pallet_event) = &event.value else { return false };
+	pallet_event
+		.values
+		.values()
+		.any(|event| variant_name(event) == Some("AssetsTrapped"))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use codec::Encode;
+	use frame_metadata::{
+		v14::{PalletErrorMetadata, PalletEventMetadata},
+		v15::{
+			CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata,
+			RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata,
+		},
+	};
+	use scale_info::{meta_type, TypeInfo};
+	use sp_runtime::ModuleError;
+	use xcm::{latest::Location, VersionedAssets};
+
+	pub const XCM_PALLET_INDEX: u8 = 31;
+
+	/// The events of a runtime with the system and the XCM pallets, encoded like the
+	/// `RuntimeEvent` of `construct_runtime`.
+	#[derive(Encode, TypeInfo)]
+	pub enum RuntimeEvent {
+		#[codec(index = 0)]
+		System(SystemEvent),
+		#[codec(index = 31)]
+		XcmPallet(XcmEvent),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	pub enum SystemEvent {
+		Remarked { sender: [u8; 32], hash: [u8; 32] },
+	}
+
+	#[derive(Encode, TypeInfo)]
+	pub enum XcmEvent {
+		AssetsTrapped { hash: [u8; 32], origin: Location, assets: VersionedAssets },
+	}
+
+	// Only described by the metadata, the variants set the indices of the errors.
+	#[allow(dead_code)]
+	#[derive(Encode, TypeInfo)]
+	pub enum XcmError {
+		Unreachable,
+		SendFailure,
+		Filtered,
+	}
+
+	#[allow(non_camel_case_types)]
+	#[derive(Encode, TypeInfo)]
+	pub enum OriginCaller {
+		#[codec(index = 0)]
+		system(RawOrigin),
+	}
+
+	#[allow(dead_code)]
+	#[derive(Encode, TypeInfo)]
+	pub enum RawOrigin {
+		Root,
+		Signed([u8; 32]),
+		None,
+	}
+
+	/// The metadata of a runtime with the types above.
+	pub fn metadata() -> Metadata {
+		let pallet = |name: &'static str, index, event, error| PalletMetadata {
+			name,
+			storage: None,
+			calls: None,
+			event: Some(PalletEventMetadata { ty: event }),
+			constants: vec![],
+			error,
+			index,
+			docs: vec![],
+		};
+		let metadata = RuntimeMetadataV15::new(
+			vec![
+				pallet("System", 0, meta_type::<SystemEvent>(), None),
+				pallet(
+					"XcmPallet",
+					XCM_PALLET_INDEX,
+					meta_type::<XcmEvent>(),
+					Some(PalletErrorMetadata { ty: meta_type::<XcmError>() }),
+				),
+			],
+			ExtrinsicMetadata {
+				version: 4,
+				address_ty: meta_type::<()>(),
+				call_ty: meta_type::<()>(),
+				signature_ty: meta_type::<()>(),
+				extra_ty: meta_type::<()>(),
+				signed_extensions: vec![],
+			},
+			meta_type::<()>(),
+			vec![RuntimeApiMetadata {
+				name: "DryRunApi",
+				methods: vec![RuntimeApiMethodMetadata {
+					name: "dry_run_call",
+					inputs: vec![RuntimeApiMethodParamMetadata {
+						name: "origin",
+						ty: meta_type::<OriginCaller>(),
+					}],
+					output: meta_type::<()>(),
+					docs: vec![],
+				}],
+				docs: vec![],
+			}],
+			OuterEnums {
+				call_enum_ty: meta_type::<()>(),
+				event_enum_ty: meta_type::<RuntimeEvent>(),
+				error_enum_ty: meta_type::<()>(),
+			},
+			CustomMetadata { map: Default::default() },
+		);
+
+		Metadata::decode(&RuntimeMetadataPrefixed::from(metadata).encode()).unwrap()
+	}
+
+	pub fn assets_trapped() -> RuntimeEvent {
+		RuntimeEvent::XcmPallet(XcmEvent::AssetsTrapped {
+			hash: [1; 32],
+			origin: Location::parent(),
+			assets: VersionedAssets::from(xcm::latest::Assets::new()),
+		})
+	}
+
+	#[test]
+	fn decode_rejects_other_metadata_versions() {
+		let metadata = RuntimeMetadataPrefixed::from(frame_metadata::v14::RuntimeMetadataV14::new(
+			vec![],
+			frame_metadata::v14::ExtrinsicMetadata {
+				ty: meta_type::<()>(),
+				version: 4,
+				signed_extensions: vec![],
+			},
+			meta_type::<()>(),
+		));
+
+		assert_eq!(
+			Metadata::decode(&metadata.encode()).err(),
+			Some(format!("expected metadata V{METADATA_VERSION}"))
+		);
+	}
+
+	#[test]
+	fn decode_events_works() {
+		let events = vec![
+			RuntimeEvent::System(SystemEvent::Remarked { sender: [2; 32], hash: [3; 32] }),
+			assets_trapped(),
+		];
+		let encoded = events.encode();
+		let input = &mut &encoded[..];
+
+		let events = metadata().decode_events(input).unwrap();
+
+		assert!(input.is_empty());
+		assert_eq!(events.len(), 2);
+		assert_eq!(variant_name(&events[0]), Some("System"));
+		assert!(!is_assets_trapped(&events[0]));
+		assert_eq!(variant_name(&events[1]), Some("XcmPallet"));
+		assert!(is_assets_trapped(&events[1]));
+	}
+
+	#[test]
+	fn decode_events_fails_on_unknown_event() {
+		let encoded = (Compact(1u32), 7u8).encode();
+
+		assert!(metadata().decode_events(&mut &encoded[..]).is_err());
+	}
+
+	#[test]
+	fn describe_dispatch_error_names_pallet_errors() {
+		let metadata = metadata();
+		let module_error = |index, error| {
+			DispatchError::Module(ModuleError { index, error: [error, 0, 0, 0], message: None })
+		};
+
+		assert_eq!(
+			metadata.describe_dispatch_error(&module_error(XCM_PALLET_INDEX, 2)),
+			"XcmPallet::Filtered"
+		);
+		// Unknown error variant, pallet without errors and unknown pallet.
+		assert_eq!(
+			metadata.describe_dispatch_error(&module_error(XCM_PALLET_INDEX, 9)),
+			format!("{:?}", module_error(XCM_PALLET_INDEX, 9))
+		);
+		assert_eq!(
+			metadata.describe_dispatch_error(&module_error(0, 0)),
+			format!("{:?}", module_error(0, 0))
+		);
+		assert_eq!(
+			metadata.describe_dispatch_error(&module_error(99, 0)),
+			format!("{:?}", module_error(99, 0))
+		);
+		assert_eq!(metadata.describe_dispatch_error(&DispatchError::BadOrigin), "BadOrigin");
+	}
+
+	#[test]
+	fn signed_origin_matches_origin_caller_encoding() {
+		let account = [5; 32];
+
+		assert_eq!(
+			metadata().signed_origin(&account).unwrap(),
+			OriginCaller::system(RawOrigin::Signed(account)).encode()
+		);
+	}
+}
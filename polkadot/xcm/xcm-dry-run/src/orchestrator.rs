@@ -0,0 +1,264 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Follows the messages sent by a dry-run from chain to chain.
+//!
+//! Every message forwarded by a hop is dry-run on its destination, if a snapshot of the
+//! destination is loaded, which yields the next hops. Messages are followed breadth first, so the
+//! hops of the report are ordered by their distance from the dispatched call.
+
+use std::collections::VecDeque;
+
+use scale_value::Value;
+use xcm::{
+	latest::{InteriorLocation, Location},
+	VersionedLocation, VersionedXcm,
+};
+
+use crate::{
+	chain::Chain,
+	error::Error,
+	report::{Execution, ExecutionFee, Forwarded, Hop, Next, Report},
+};
+
+/// A forwarded message waiting to be dry-run on its destination.
+struct Pending {
+	/// The hop which sent the message.
+	hop: usize,
+	/// The index of the message in the forwarded messages of the hop.
+	index: usize,
+	/// The chain which sent the message.
+	sender: usize,
+	destination: VersionedLocation,
+	xcm: VersionedXcm<()>,
+}
+
+/// Dry-runs calls and the messages they send on a set of chains.
+pub struct Orchestrator {
+	chains: Vec<Chain>,
+	max_hops: usize,
+}
+
+impl Orchestrator {
+	/// Create an orchestrator of `chains`, which stops following messages after `max_hops` hops.
+	pub fn new(chains: Vec<Chain>, max_hops: usize) -> Self {
+		Self { chains, max_hops }
+	}
+
+	/// Dry-run `call` on the chain at the universal `location`, dispatched from the SCALE encoded
+	/// `origin`, and follow the messages it sends.
+	///
+	/// `origin` is given the chain, to let the caller build it from the metadata of the chain.
+	pub fn dry_run_call(
+		&mut self,
+		location: &InteriorLocation,
+		origin: impl FnOnce(&Chain) -> Result<Vec<u8>, Error>,
+		call: &[u8],
+	) -> Result<Report, Error> {
+		let chain = self
+			.chains
+			.iter()
+			.position(|chain| chain.location() == location)
+			.ok_or_else(|| Error::UnknownChain(format!("{location:?}")))?;
+		let origin = origin(&self.chains[chain])?;
+
+		let effects = self.chains[chain].dry_run_call(&origin, call)?;
+		let execution = Execution::Call(match effects.execution_result {
+			Ok(post_info) => Ok(post_info.actual_weight),
+			Err(error) => Err(self.chains[chain].metadata().describe_dispatch_error(&error.error)),
+		});
+		let execution_fee = effects.local_xcm.map(|xcm| self.execution_fee(chain, &xcm));
+
+		let mut report = Report { hops: Vec::new() };
+		let mut queue = VecDeque::new();
+		self.push_hop(
+			&mut report,
+			&mut queue,
+			chain,
+			None,
+			execution,
+			effects.emitted_events,
+			execution_fee,
+			effects.forwarded_xcms,
+		);
+
+		while let Some(pending) = queue.pop_front() {
+			let (hop, index) = (pending.hop, pending.index);
+			let next = if report.hops.len() < self.max_hops {
+				self.follow(&mut report, &mut queue, pending)
+			} else {
+				Next::HopLimit
+			};
+			report.hops[hop].forwarded[index].next = next;
+		}
+
+		Ok(report)
+	}
+
+	/// Dry-run a forwarded message on its destination.
+	fn follow(
+		&mut self,
+		report: &mut Report,
+		queue: &mut VecDeque<Pending>,
+		pending: Pending,
+	) -> Next {
+		let Ok(destination) = Location::try_from(pending.destination) else {
+			return Next::Failed("unsupported XCM version".into());
+		};
+		let locations = self.chains.iter().map(Chain::location);
+		let Some((chain, origin)) =
+			route(locations, self.chains[pending.sender].location(), &destination)
+		else {
+			return Next::UnknownDestination;
+		};
+
+		let execution_fee = self.execution_fee(chain, &pending.xcm);
+		let effects = match self.chains[chain].dry_run_xcm(origin.clone(), &pending.xcm) {
+			Ok(effects) => effects,
+			Err(error) => return Next::Failed(error.to_string()),
+		};
+
+		Next::Hop(self.push_hop(
+			report,
+			queue,
+			chain,
+			Some((pending.hop, origin)),
+			Execution::Xcm(effects.execution_result),
+			effects.emitted_events,
+			Some(execution_fee),
+			effects.forwarded_xcms,
+		))
+	}
+
+	/// Add a hop to the report, and queue the messages it sent. Returns the index of the hop.
+	#[allow(clippy::too_many_arguments)]
+	fn push_hop(
+		&mut self,
+		report: &mut Report,
+		queue: &mut VecDeque<Pending>,
+		chain: usize,
+		sender: Option<(usize, Location)>,
+		execution: Execution,
+		events: Vec<Value<u32>>,
+		execution_fee: Option<Result<ExecutionFee, String>>,
+		forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+	) -> usize {
+		let hop = report.hops.len();
+		let mut forwarded = Vec::new();
+		for (destination, messages) in forwarded_xcms {
+			for xcm in messages {
+				let delivery_fees = self.chains[chain]
+					.delivery_fees(&destination, &xcm)
+					.map_err(|error| error.to_string());
+				queue.push_back(Pending {
+					hop,
+					index: forwarded.len(),
+					sender: chain,
+					destination: destination.clone(),
+					xcm,
+				});
+				forwarded.push(Forwarded {
+					destination: destination.clone(),
+					delivery_fees,
+					next: Next::HopLimit,
+				});
+			}
+		}
+
+		report.hops.push(Hop {
+			chain: self.chains[chain].name().into(),
+			sender,
+			execution,
+			events,
+			execution_fee,
+			forwarded,
+		});
+		hop
+	}
+
+	fn execution_fee(
+		&mut self,
+		chain: usize,
+		xcm: &VersionedXcm<()>,
+	) -> Result<ExecutionFee, String> {
+		self.chains[chain].execution_fee(xcm).map_err(|error| error.to_string())
+	}
+}
+
+/// Find the chain at `destination`, as seen by `sender`, among the chains at the universal
+/// `locations`.
+///
+/// Returns the index of the chain, and the location of `sender` as seen by the chain.
+fn route<'a>(
+	locations: impl IntoIterator<Item = &'a InteriorLocation>,
+	sender: &InteriorLocation,
+	destination: &Location,
+) -> Option<(usize, Location)> {
+	let universal = sender.clone().within_global(destination.clone()).ok()?;
+	let chain = locations.into_iter().position(|location| *location == universal)?;
+	Some((chain, sender.clone().relative_to(&universal)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use xcm::latest::prelude::*;
+
+	fn locations() -> Vec<InteriorLocation> {
+		vec![
+			[GlobalConsensus(Polkadot)].into(),
+			[GlobalConsensus(Polkadot), Parachain(1000)].into(),
+			[GlobalConsensus(Polkadot), Parachain(2000)].into(),
+		]
+	}
+
+	#[test]
+	fn routes_between_relay_chain_and_parachains() {
+		let locations = locations();
+
+		assert_eq!(
+			route(&locations, &locations[0], &Location::new(0, [Parachain(1000)])),
+			Some((1, Location::parent())),
+		);
+		assert_eq!(
+			route(&locations, &locations[1], &Location::parent()),
+			Some((0, Location::new(0, [Parachain(1000)]))),
+		);
+		assert_eq!(
+			route(&locations, &locations[2], &Location::new(1, [Parachain(1000)])),
+			Some((1, Location::new(1, [Parachain(2000)]))),
+		);
+	}
+
+	#[test]
+	fn does_not_route_to_unknown_chains() {
+		let locations = locations();
+
+		assert_eq!(route(&locations, &locations[1], &Location::new(1, [Parachain(3000)])), None);
+		assert_eq!(
+			route(&locations, &locations[1], &Location::new(2, [GlobalConsensus(Kusama)])),
+			None,
+		);
+		assert_eq!(
+			route(
+				&locations,
+				&locations[1],
+				&Location::new(0, [AccountId32 { network: None, id: [0; 32] }])
+			),
+			None,
+		);
+	}
+}
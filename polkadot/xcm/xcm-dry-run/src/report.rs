@@ -0,0 +1,271 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The cross-chain effects of a dry-run.
+
+use std::fmt;
+
+use scale_value::Value;
+use xcm::{
+	latest::{Location, Outcome, Weight},
+	VersionedAssetId, VersionedAssets, VersionedLocation,
+};
+
+use crate::metadata::is_assets_trapped;
+
+/// The effects of a dry-run on every chain it reached.
+pub struct Report {
+	/// The hops, in the order they were executed. The first one is the dispatched call.
+	pub hops: Vec<Hop>,
+}
+
+/// The execution of a call or an XCM program on one chain.
+pub struct Hop {
+	/// The name of the chain.
+	pub chain: String,
+	/// The hop which sent the executed program, and the location of the sender as seen by this
+	/// chain. `None` for the dispatched call.
+	pub sender: Option<(usize, Location)>,
+	/// The outcome of the execution.
+	pub execution: Execution,
+	/// The events emitted by the execution.
+	pub events: Vec<Value<u32>>,
+	/// The fee of executing the XCM program, if any was executed.
+	pub execution_fee: Option<Result<ExecutionFee, String>>,
+	/// The messages sent by the execution.
+	pub forwarded: Vec<Forwarded>,
+}
+
+/// The outcome of a hop.
+pub enum Execution {
+	/// A dispatched call, with its actual weight or the dispatch error.
+	Call(Result<Option<Weight>, String>),
+	/// An executed XCM program.
+	Xcm(Outcome),
+}
+
+/// The fee of executing an XCM program.
+pub struct ExecutionFee {
+	pub weight: Weight,
+	/// The asset the fee is quoted in.
+	pub asset: VersionedAssetId,
+	pub amount: u128,
+}
+
+/// A message sent by a hop.
+pub struct Forwarded {
+	/// The destination, as seen by the sender.
+	pub destination: VersionedLocation,
+	/// The fees of delivering the message.
+	pub delivery_fees: Result<VersionedAssets, String>,
+	/// What happened to the message.
+	pub next: Next,
+}
+
+/// What happened to a forwarded message.
+pub enum Next {
+	/// It was executed by the given hop.
+	Hop(usize),
+	/// It was not executed, as no snapshot of the destination is loaded.
+	UnknownDestination,
+	/// It was not executed, as the maximum number of hops was reached.
+	HopLimit,
+	/// It could not be executed.
+	Failed(String),
+}
+
+impl Report {
+	/// Whether the dispatched call and every executed XCM program succeeded.
+	pub fn is_success(&self) -> bool {
+		self.hops.iter().all(|hop| match &hop.execution {
+			Execution::Call(result) => result.is_ok(),
+			Execution::Xcm(outcome) => matches!(outcome, Outcome::Complete { .. }),
+		})
+	}
+
+	/// The `AssetsTrapped` events emitted on all chains, with the index of their hop.
+	pub fn trapped_assets(&self) -> impl Iterator<Item = (usize, &Value<u32>)> {
+		self.hops.iter().enumerate().flat_map(|(index, hop)| {
+			hop.events
+				.iter()
+				.filter(|event| is_assets_trapped(event))
+				.map(move |event| (index, event))
+		})
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (index, hop) in self.hops.iter().enumerate() {
+			writeln!(f, "#{index} {}", hop.chain)?;
+			match &hop.sender {
+				None => writeln!(f, "  dispatched call")?,
+				Some((hop, location)) => writeln!(f, "  received from #{hop} as {location:?}")?,
+			}
+			match &hop.execution {
+				Execution::Call(Ok(weight)) => {
+					writeln!(f, "  result: Ok, actual weight {weight:?}")?
+				},
+				Execution::Call(Err(error)) => writeln!(f, "  result: Err({error})")?,
+				Execution::Xcm(outcome) => writeln!(f, "  outcome: {outcome:?}")?,
+			}
+			match &hop.execution_fee {
+				None => {},
+				Some(Ok(ExecutionFee { weight, asset, amount })) => {
+					writeln!(f, "  execution fee: {amount} of {asset:?} for {weight:?}")?
+				},
+				Some(Err(error)) => writeln!(f, "  execution fee: unknown, {error}")?,
+			}
+			writeln!(f, "  events:")?;
+			for event in &hop.events {
+				writeln!(f, "    {event}")?;
+			}
+			for forwarded in &hop.forwarded {
+				writeln!(f, "  sent to {:?}", forwarded.destination)?;
+				match &forwarded.delivery_fees {
+					Ok(fees) => writeln!(f, "    delivery fees: {fees:?}")?,
+					Err(error) => writeln!(f, "    delivery fees: unknown, {error}")?,
+				}
+				match &forwarded.next {
+					Next::Hop(hop) => writeln!(f, "    executed in #{hop}")?,
+					Next::UnknownDestination => {
+						writeln!(f, "    not executed: no snapshot loaded")?
+					},
+					Next::HopLimit => writeln!(f, "    not executed: too many hops")?,
+					Next::Failed(error) => writeln!(f, "    not executed: {error}")?,
+				}
+			}
+			writeln!(f)?;
+		}
+
+		let mut trapped = self.trapped_assets().peekable();
+		if trapped.peek().is_none() {
+			writeln!(f, "No assets trapped")?;
+		} else {
+			writeln!(f, "Trapped assets:")?;
+			for (hop, event) in trapped {
+				writeln!(f, "  #{hop} {event}")?;
+			}
+		}
+
+		write!(f, "{}", if self.is_success() { "Success" } else { "Failure" })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::metadata::tests::{assets_trapped, metadata, RuntimeEvent, SystemEvent};
+	use codec::Encode;
+	use xcm::latest::{Error as XcmError, InstructionError, Parent};
+
+	fn events(events: Vec<RuntimeEvent>) -> Vec<Value<u32>> {
+		metadata().decode_events(&mut &events.encode()[..]).unwrap()
+	}
+
+	fn remarked() -> RuntimeEvent {
+		RuntimeEvent::System(SystemEvent::Remarked { sender: [2; 32], hash: [3; 32] })
+	}
+
+	/// A call sending a message which is executed on a second chain with `outcome`.
+	fn report(outcome: Outcome, received_events: Vec<RuntimeEvent>) -> Report {
+		let weight = Weight::from_parts(1_000, 10);
+		Report {
+			hops: vec![
+				Hop {
+					chain: "relay".into(),
+					sender: None,
+					execution: Execution::Call(Ok(Some(weight))),
+					events: events(vec![remarked()]),
+					execution_fee: None,
+					forwarded: vec![
+						Forwarded {
+							destination: Location::new(0, [xcm::latest::Junction::Parachain(1000)])
+								.into(),
+							delivery_fees: Ok(VersionedAssets::from(xcm::latest::Assets::new())),
+							next: Next::Hop(1),
+						},
+						Forwarded {
+							destination: Location::new(0, [xcm::latest::Junction::Parachain(2000)])
+								.into(),
+							delivery_fees: Err("no route".into()),
+							next: Next::UnknownDestination,
+						},
+					],
+				},
+				Hop {
+					chain: "asset-hub".into(),
+					sender: Some((0, Parent.into())),
+					execution: Execution::Xcm(outcome),
+					events: events(received_events),
+					execution_fee: Some(Ok(ExecutionFee {
+						weight,
+						asset: VersionedAssetId::from(xcm::latest::AssetId(Location::parent())),
+						amount: 42,
+					})),
+					forwarded: vec![],
+				},
+			],
+		}
+	}
+
+	#[test]
+	fn successful_report() {
+		let report =
+			report(Outcome::Complete { used: Weight::from_parts(500, 5) }, vec![remarked()]);
+
+		assert!(report.is_success());
+		assert_eq!(report.trapped_assets().count(), 0);
+
+		let output = report.to_string();
+		assert!(output.starts_with("#0 relay\n  dispatched call\n  result: Ok, actual weight"));
+		assert!(output.contains("\n#1 asset-hub\n  received from #0 as "));
+		assert!(output.contains("\n  execution fee: 42 of "));
+		assert!(output.contains("\n    executed in #1\n"));
+		assert!(output.contains("\n    delivery fees: unknown, no route\n"));
+		assert!(output.contains("\n    not executed: no snapshot loaded\n"));
+		assert!(output.ends_with("\nNo assets trapped\nSuccess"));
+	}
+
+	#[test]
+	fn failed_report_lists_trapped_assets() {
+		let report = report(
+			Outcome::Incomplete {
+				used: Weight::from_parts(500, 5),
+				error: InstructionError { index: 0, error: XcmError::Barrier },
+			},
+			vec![remarked(), assets_trapped()],
+		);
+
+		assert!(!report.is_success());
+		let trapped: Vec<_> = report.trapped_assets().map(|(hop, _)| hop).collect();
+		assert_eq!(trapped, vec![1]);
+
+		let output = report.to_string();
+		assert!(output.contains("\n  outcome: Incomplete"));
+		assert!(output.contains("\nTrapped assets:\n  #1 "));
+		assert!(output.ends_with("\nFailure"));
+	}
+
+	#[test]
+	fn failed_call_fails_the_report() {
+		let mut report = report(Outcome::Complete { used: Weight::zero() }, vec![]);
+		report.hops[0].execution = Execution::Call(Err("XcmPallet::Filtered".into()));
+
+		assert!(!report.is_success());
+		assert!(report.to_string().contains("\n  result: Err(XcmPallet::Filtered)\n"));
+	}
+}
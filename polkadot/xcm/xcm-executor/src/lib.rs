@@ -37,9 +37,9 @@ pub mod traits;
 use traits::{
 	validate_export, AssetExchange, AssetLock, CallDispatcher, ClaimAssets, ConvertOrigin,
 	DropAssets, Enact, EventEmitter, ExportXcm, FeeManager, FeeReason, HandleHrmpChannelAccepted,
-	HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest, OnResponse, ProcessTransaction,
-	Properties, ShouldExecute, TransactAsset, VersionChangeNotifier, WeightBounds, WeightTrader,
-	XcmAssetTransfers,
+	HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest, InstructionTrace, OnResponse,
+	ProcessTransaction, Properties, ShouldExecute, TransactAsset, VersionChangeNotifier,
+	WeightBounds, WeightTrader, XcmAssetTransfers,
 };
 
 pub use traits::RecordXcm;
@@ -104,6 +104,8 @@ pub struct XcmExecutor<Config: config::Config> {
 	message_weight: Weight,
 	asset_claimer: Option<Location>,
 	already_paid_fees: bool,
+	/// Whether the execution of every instruction is recorded with `Config::XcmRecorder`.
+	trace_instructions: bool,
 	_config: PhantomData<Config>,
 }
 
@@ -297,6 +299,7 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 
 		let mut vm = Self::new(origin, *id);
 		vm.message_weight = xcm_weight;
+		vm.trace_instructions = Config::XcmRecorder::should_trace();
 
 		while !message.0.is_empty() {
 			let result = vm.process(message);
@@ -390,6 +393,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			message_weight: Weight::zero(),
 			asset_claimer: None,
 			already_paid_fees: false,
+			trace_instructions: false,
 			_config: PhantomData,
 		}
 	}
@@ -853,6 +857,10 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		for (i, mut instr) in xcm.0.into_iter().enumerate() {
 			match &mut result {
 				r @ Ok(()) => {
+					let traced = self.trace_instructions.then(|| {
+						let weight = Config::Weigher::instr_weight(&mut instr).unwrap_or_default();
+						(Instruction::<()>::from(instr.clone()), weight, self.total_surplus)
+					});
 					// Initialize the recursion count only the first time we hit this code in our
 					// potential recursive execution.
 					let inst_res = recursion_count::using_once(&mut 1, || {
@@ -876,6 +884,10 @@ impl<Config: config::Config> XcmExecutor<Config> {
 
 						self.process_instruction(instr)
 					});
+					if let Some((instruction, weight, surplus_before)) = traced {
+						let surplus = self.total_surplus.saturating_sub(surplus_before);
+						self.trace(i, instruction, weight.saturating_sub(surplus), inst_res);
+					}
 					if let Err(error) = inst_res {
 						tracing::debug!(
 							target: "xcm::process",
@@ -903,6 +915,25 @@ impl<Config: config::Config> XcmExecutor<Config> {
 		result
 	}
 
+	/// Record the execution of an instruction, along with the registers it left behind.
+	fn trace(
+		&self,
+		index: usize,
+		instruction: Instruction<()>,
+		weight_used: Weight,
+		result: Result<(), XcmError>,
+	) {
+		Config::XcmRecorder::trace(InstructionTrace {
+			index: index as u32,
+			instruction,
+			weight_used,
+			result,
+			origin: self.context.origin.clone(),
+			holding: self.holding.assets_iter().collect::<Vec<_>>().into(),
+			fees: self.fees.assets_iter().collect::<Vec<_>>().into(),
+		});
+	}
+
 	/// Execute `f` inside a transactional context that backs up and restores `holding` and
 	/// `fees` on failure.
 	fn transactional_process(
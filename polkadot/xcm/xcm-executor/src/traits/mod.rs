@@ -42,6 +42,8 @@ mod on_response;
 pub use on_response::{OnResponse, QueryHandler, QueryResponseStatus, VersionChangeNotifier};
 mod process_transaction;
 pub use process_transaction::ProcessTransaction;
+mod rate_limiter;
+pub use rate_limiter::RateLimiter;
 mod should_execute;
 pub use should_execute::{CheckSuspension, DenyExecution, Properties, ShouldExecute};
 mod transact_asset;
@@ -55,7 +57,7 @@ mod record_xcm;
 mod weight;
 pub use event_emitter::EventEmitter;
 
-pub use record_xcm::RecordXcm;
+pub use record_xcm::{InstructionTrace, RecordXcm};
 #[deprecated = "Use `sp_runtime::traits::` instead"]
 pub use sp_runtime::traits::{Identity, TryConvertInto as JustTry};
 pub use weight::{WeightBounds, WeightTrader};
@@ -0,0 +1,44 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use xcm::latest::{Location, Weight};
+
+/// Trait to limit the number and weight of the XCM processed on behalf of a given origin.
+///
+/// The limits of an origin are checked before processing one of its XCM, and charged the weight
+/// it used once processed.
+pub trait RateLimiter {
+	/// The weight of checking the limits of an origin and charging them for one XCM.
+	fn weight() -> Weight;
+
+	/// Whether an XCM from `origin` can be processed now.
+	fn can_process(origin: &Location) -> bool;
+
+	/// Charges the limits of `origin` for an XCM which used `weight` when processed.
+	fn note_processed(origin: &Location, weight: Weight);
+}
+
+impl RateLimiter for () {
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn can_process(_origin: &Location) -> bool {
+		true
+	}
+
+	fn note_processed(_origin: &Location, _weight: Weight) {}
+}
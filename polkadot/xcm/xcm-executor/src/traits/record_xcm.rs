@@ -16,9 +16,37 @@
 
 //! Trait for recording XCMs and a dummy implementation.
 
-use xcm::latest::Xcm;
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_weights::Weight;
+use xcm::latest::{Assets, Error as XcmError, Instruction, Location, Xcm};
+
+/// The execution of an XCM instruction, as recorded while tracing.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct InstructionTrace {
+	/// The index of the instruction in the program it is part of.
+	pub index: u32,
+	/// The executed instruction.
+	pub instruction: Instruction<()>,
+	/// The weight used by the instruction: the weight given by the weigher, or zero if it could
+	/// not be weighed, less the surplus reported while executing it, e.g. the unused weight of a
+	/// `Transact`.
+	pub weight_used: Weight,
+	/// The result of executing the instruction.
+	pub result: Result<(), XcmError>,
+	/// The origin after executing the instruction.
+	pub origin: Option<Location>,
+	/// The holding register after executing the instruction.
+	pub holding: Assets,
+	/// The fees register after executing the instruction.
+	pub fees: Assets,
+}
 
 /// Trait for recording XCMs.
+///
+/// Besides the XCM programs, the execution of every instruction can be traced. Tracing is off by
+/// default, for implementations which do not support it.
 pub trait RecordXcm {
 	/// Whether or not we should record incoming XCMs.
 	fn should_record() -> bool;
@@ -29,6 +57,19 @@ pub trait RecordXcm {
 	fn recorded_xcm() -> Option<Xcm<()>>;
 	/// Record `xcm`.
 	fn record(xcm: Xcm<()>);
+
+	/// Whether or not we should trace the execution of XCM instructions.
+	fn should_trace() -> bool {
+		false
+	}
+	/// Enable or disable tracing.
+	fn set_trace_xcm(_enabled: bool) {}
+	/// Take the instructions traced so far, in the order they finished executing.
+	fn take_trace() -> Vec<InstructionTrace> {
+		Vec::new()
+	}
+	/// Record the execution of an instruction.
+	fn trace(_instruction: InstructionTrace) {}
 }
 
 impl RecordXcm for () {
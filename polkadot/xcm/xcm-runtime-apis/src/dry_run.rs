@@ -21,6 +21,7 @@
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::{DispatchResultWithPostInfo, TypeInfo};
+use sp_weights::Weight;
 use xcm::prelude::*;
 
 /// Effects of dry-running an extrinsic.
@@ -47,6 +48,73 @@ pub struct XcmDryRunEffects<Event> {
 	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
 }
 
+/// The execution of an XCM instruction during a dry-run, and the state of the executor it left
+/// behind.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct InstructionTrace {
+	/// The index of the instruction in the program it is part of. Instructions of the error
+	/// handler, the appendix and nested programs are indexed within their own program.
+	pub index: u32,
+	/// The executed instruction, as a single instruction program.
+	pub instruction: VersionedXcm<()>,
+	/// The weight used by the instruction: the weight given by the weigher, less the surplus
+	/// reported while executing it, e.g. the unused weight of a `Transact`.
+	pub weight_used: Weight,
+	/// The result of executing the instruction.
+	pub result: Result<(), XcmError>,
+	/// The origin after executing the instruction.
+	pub origin: Option<VersionedLocation>,
+	/// The holding register after executing the instruction.
+	pub holding: VersionedAssets,
+	/// The fees register after executing the instruction.
+	pub fees: VersionedAssets,
+}
+
+impl InstructionTrace {
+	/// Convert the trace recorded by the executor to the given XCM version.
+	pub fn from_executor(
+		trace: xcm_executor::traits::InstructionTrace,
+		version: XcmVersion,
+	) -> Result<Self, Error> {
+		let into_version = |_| Error::VersionedConversionFailed;
+		Ok(Self {
+			index: trace.index,
+			instruction: VersionedXcm::from(Xcm(alloc::vec![trace.instruction]))
+				.into_version(version)
+				.map_err(into_version)?,
+			weight_used: trace.weight_used,
+			result: trace.result,
+			origin: trace
+				.origin
+				.map(|origin| VersionedLocation::from(origin).into_version(version))
+				.transpose()
+				.map_err(into_version)?,
+			holding: VersionedAssets::from(trace.holding)
+				.into_version(version)
+				.map_err(into_version)?,
+			fees: VersionedAssets::from(trace.fees).into_version(version).map_err(into_version)?,
+		})
+	}
+}
+
+/// Effects of dry-running an extrinsic, with the execution of every XCM instruction traced.
+#[derive(Encode, Decode, Debug, TypeInfo)]
+pub struct TracedCallDryRunEffects<Event> {
+	/// The effects of the extrinsic.
+	pub effects: CallDryRunEffects<Event>,
+	/// The XCM instructions executed locally, in the order they finished executing.
+	pub trace: Vec<InstructionTrace>,
+}
+
+/// Effects of dry-running an XCM program, with the execution of every instruction traced.
+#[derive(Encode, Decode, Debug, TypeInfo)]
+pub struct TracedXcmDryRunEffects<Event> {
+	/// The effects of the XCM program.
+	pub effects: XcmDryRunEffects<Event>,
+	/// The executed instructions, in the order they finished executing.
+	pub trace: Vec<InstructionTrace>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// API for dry-running extrinsics and XCM programs to get the programs that need to be passed to the fees API.
 	///
@@ -73,6 +141,20 @@ sp_api::decl_runtime_apis! {
 
 		/// Dry run XCM program
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<Call>) -> Result<XcmDryRunEffects<Event>, Error>;
+
+		/// Dry run call, tracing the execution of every XCM instruction.
+		///
+		/// The trace is only recorded if the runtime uses `pallet-xcm` as the `XcmRecorder` of
+		/// its XCM executor.
+		#[api_version(3)]
+		fn dry_run_call_traced(origin: OriginCaller, call: Call, result_xcms_version: XcmVersion) -> Result<TracedCallDryRunEffects<Event>, Error>;
+
+		/// Dry run XCM program, tracing the execution of every instruction.
+		///
+		/// The trace is only recorded if the runtime uses `pallet-xcm` as the `XcmRecorder` of
+		/// its XCM executor.
+		#[api_version(3)]
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<Call>, result_xcms_version: XcmVersion) -> Result<TracedXcmDryRunEffects<Event>, Error>;
 	}
 }
 
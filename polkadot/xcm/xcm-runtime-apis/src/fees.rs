@@ -83,6 +83,54 @@ sp_api::decl_runtime_apis! {
 		///   different senders that charge different fees.
 		fn query_delivery_fees(destination: VersionedLocation, message: VersionedXcm<()>, asset_id: VersionedAssetId) -> Result<VersionedAssets, Error>;
 	}
+
+	/// API for quoting the fees of XCM transfers.
+	///
+	/// The transfer is dry-run, so the quote is based on the actual programs it executes locally and
+	/// sends to the next hops. The remote execution fees of every hop can then be quoted by calling
+	/// the `XcmPaymentApi` and `DryRunApi` of the destination of its message, which in turn
+	/// gives the messages sent to the following hops.
+	pub trait TransferQuoteApi<Call, OriginCaller>
+	where
+		Call: Encode,
+		OriginCaller: Encode
+	{
+		/// Quote the fees of an XCM transfer.
+		///
+		/// # Arguments
+		///
+		/// * `origin`: The origin dispatching `call`.
+		/// * `call`: The transfer, e.g. `pallet_xcm::Call::transfer_assets_using_type_and_then`.
+		/// * `asset_id`: The asset in which fees are quoted.
+		/// * `result_xcms_version`: The XCM version of the returned messages.
+		fn quote_transfer(origin: OriginCaller, call: Call, asset_id: VersionedAssetId, result_xcms_version: Version) -> Result<TransferQuote, Error>;
+	}
+}
+
+/// The fees of an XCM transfer, as returned by [`TransferQuoteApi::quote_transfer`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct TransferQuote {
+	/// The XCM executed locally by the transfer, if any.
+	pub local_xcm: Option<VersionedXcm<()>>,
+	/// The weight of `local_xcm`.
+	pub local_execution_weight: Weight,
+	/// The fee for `local_execution_weight`.
+	///
+	/// Extrinsics already account for this weight in their transaction fee.
+	pub local_execution_fee: u128,
+	/// The messages sent to the next hops.
+	pub hops: Vec<HopQuote>,
+}
+
+/// A message sent by an XCM transfer, with the fees of its delivery.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct HopQuote {
+	/// The destination of the message.
+	pub destination: VersionedLocation,
+	/// The message received by `destination`.
+	pub message: VersionedXcm<()>,
+	/// The fees for delivering `message` to `destination`.
+	pub delivery_fees: VersionedAssets,
 }
 
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
@@ -110,4 +158,8 @@ pub enum Error {
 	/// Destination is known to be unroutable.
 	#[codec(index = 5)]
 	Unroutable,
+
+	/// The transfer to quote failed.
+	#[codec(index = 6)]
+	TransferFailed,
 }
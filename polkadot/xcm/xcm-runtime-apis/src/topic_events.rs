@@ -0,0 +1,69 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Contains runtime APIs for listing the events of a block which are tied to XCM topics.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::latest::XcmHash;
+
+/// What happened to an XCM message at this chain.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum XcmTopicEventKind {
+	/// The message has been sent from this chain.
+	#[codec(index = 0)]
+	Sent,
+	/// The message has been received, but it is waiting in a queue of this chain, e.g. because
+	/// it is overweight.
+	#[codec(index = 1)]
+	Queued,
+	/// The message has been received and processed successfully.
+	#[codec(index = 2)]
+	Processed,
+	/// The message has failed to be processed or sent.
+	#[codec(index = 3)]
+	Failed,
+	/// The message has been sent further while processing a received message with the same topic.
+	#[codec(index = 4)]
+	Forwarded,
+	/// The message has been handed over to the transport that delivers it to the next chain,
+	/// e.g. the XCMP or UMP queue, or the outbound lane of a bridge.
+	#[codec(index = 5)]
+	Routed,
+}
+
+/// An event tied to an XCM topic.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct XcmTopicEvent {
+	/// The topic of the message, as set by the `SetTopic` instruction.
+	pub topic: XcmHash,
+	/// What happened to the message.
+	pub kind: XcmTopicEventKind,
+	/// The index of the event in the block.
+	pub event_index: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for listing the events tied to XCM topics.
+	///
+	/// Used by nodes to index the lifecycle of messages across chains.
+	pub trait XcmTopicEventsApi {
+		/// Returns the events of the block, which are tied to XCM topics, in the order they have
+		/// been deposited.
+		fn topic_events() -> Vec<XcmTopicEvent>;
+	}
+}
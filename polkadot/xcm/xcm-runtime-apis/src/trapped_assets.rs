@@ -0,0 +1,51 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Contains runtime APIs for querying the assets trapped for a location.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::{VersionedAssets, VersionedLocation};
+
+/// Assets trapped for a location, which it can claim back.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct TrappedAssets {
+	/// The trapped assets, in the XCM version they were trapped with. They must be claimed in
+	/// this exact version, e.g. by passing them as is to `pallet_xcm::claim_assets`.
+	pub assets: VersionedAssets,
+	/// The number of times these assets were trapped, i.e. how many times they can be claimed.
+	pub count: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the assets trapped for a location.
+	pub trait TrappedAssetsApi {
+		/// Returns the assets trapped for `origin`, which it can claim.
+		///
+		/// Only the most recent traps of every location are indexed, so this may not list all of
+		/// them.
+		fn trapped_assets(origin: VersionedLocation) -> Result<Vec<TrappedAssets>, Error>;
+	}
+}
+
+/// `TrappedAssetsApi` Runtime APIs errors.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a location from one version to another failed.
+	#[codec(index = 0)]
+	LocationVersionConversionFailed,
+}
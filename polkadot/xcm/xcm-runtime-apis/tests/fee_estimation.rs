@@ -21,16 +21,17 @@ use frame_system::RawOrigin;
 use sp_api::ProvideRuntimeApi;
 use xcm::prelude::*;
 use xcm_runtime_apis::{
-	dry_run::{CallDryRunEffects, DryRunApi},
-	fees::XcmPaymentApi,
+	dry_run::{CallDryRunEffects, DryRunApi, InstructionTrace, TracedXcmDryRunEffects},
+	fees::{Error as XcmPaymentApiError, HopQuote, TransferQuoteApi, XcmPaymentApi},
 };
 
 mod mock;
 use mock::{
-	new_test_ext_with_balances, new_test_ext_with_balances_and_assets, DeliveryFees,
+	new_test_ext_with_balances, new_test_ext_with_balances_and_assets, BaseXcmWeight, DeliveryFees,
 	ExistentialDeposit, HereLocation, OriginCaller, RuntimeCall, RuntimeEvent, TestClient,
 	ASSET_HUB_ASSETS_PALLET_INSTANCE, ASSET_HUB_PARA_ID, USDT_ID,
 };
+use xcm_executor::{traits::TransferType, RecordXcm};
 use xcm_simulator::fake_message_hash;
 
 // Scenario: User `1` in the local chain (id 2000) wants to transfer assets to account `[0u8; 32]`
@@ -458,6 +459,129 @@ fn dry_run_xcm_versions() {
 	}
 }
 
+// Scenario: User `1` in the local chain sends a program which pays for its execution, then fails
+// because it withdraws assets after clearing its origin. The traced dry-run shows the registers of
+// the executor after every instruction, up to the failing one.
+#[test]
+fn dry_run_xcm_traced_shows_every_instruction() {
+	sp_tracing::init_for_tests();
+	let who = 1; // AccountId = u64.
+	let transfer_amount = 100u128;
+	let origin = Location::new(0, [AccountIndex64 { index: who, network: None }]);
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+	let xcm_to_weigh = Xcm::<()>::builder_unsafe()
+		.withdraw_asset((Here, transfer_amount))
+		.clear_origin()
+		.buy_execution((Here, transfer_amount), Unlimited)
+		.withdraw_asset((Here, 1u128))
+		.build();
+	let xcm_weight = runtime_api
+		.query_xcm_weight(H256::zero(), VersionedXcm::from(xcm_to_weigh))
+		.unwrap()
+		.unwrap();
+	let execution_fees = runtime_api
+		.query_weight_to_asset_fee(H256::zero(), xcm_weight, AssetId(Here.into()).into())
+		.unwrap()
+		.unwrap();
+	let xcm = Xcm::<RuntimeCall>::builder_unsafe()
+		.withdraw_asset((Here, transfer_amount + execution_fees))
+		.clear_origin()
+		.buy_execution((Here, execution_fees), Limited(xcm_weight))
+		.withdraw_asset((Here, 1u128))
+		.build();
+	let balances = vec![(who, transfer_amount + execution_fees + ExistentialDeposit::get())];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let TracedXcmDryRunEffects { effects, trace } = runtime_api
+			.dry_run_xcm_traced(
+				H256::zero(),
+				origin.clone().into(),
+				VersionedXcm::from(xcm.clone()),
+				XCM_VERSION,
+			)
+			.unwrap()
+			.unwrap();
+
+		assert!(matches!(
+			effects.execution_result,
+			Outcome::Incomplete {
+				error: InstructionError { index: 3, error: XcmError::BadOrigin },
+				..
+			}
+		));
+		let expected = |index: usize,
+		                result: Result<(), XcmError>,
+		                origin: Option<Location>,
+		                holding: u128| InstructionTrace {
+			index: index as u32,
+			instruction: VersionedXcm::from(Xcm::<()>(vec![xcm.0[index].clone().into()])),
+			weight_used: BaseXcmWeight::get(),
+			result,
+			origin: origin.map(Into::into),
+			holding: Assets::from((Here, holding)).into(),
+			fees: Assets::new().into(),
+		};
+		assert_eq!(
+			trace,
+			vec![
+				expected(0, Ok(()), Some(origin), transfer_amount + execution_fees),
+				expected(1, Ok(()), None, transfer_amount + execution_fees),
+				expected(2, Ok(()), None, transfer_amount + execution_fees),
+				expected(3, Err(XcmError::BadOrigin), None, transfer_amount + execution_fees),
+			]
+		);
+	});
+}
+
+// The traced weight of an instruction doesn't include the surplus reported while executing it.
+#[test]
+fn dry_run_xcm_traced_reports_weight_used() {
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+	let who = 1; // AccountId = u64.
+	let origin = Location::new(0, [AccountIndex64 { index: who, network: None }]);
+	let fees = 10_000u128;
+	let xcm = Xcm::<RuntimeCall>::builder_unsafe()
+		.withdraw_asset((Here, fees))
+		.buy_execution((Here, fees), Unlimited)
+		.set_appendix(Xcm(vec![ClearOrigin]))
+		// Replacing the appendix reports the weight of the previous one as surplus.
+		.set_appendix(Xcm(vec![]))
+		.build();
+	let balances = vec![(who, fees + ExistentialDeposit::get())];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let TracedXcmDryRunEffects { trace, .. } = runtime_api
+			.dry_run_xcm_traced(H256::zero(), origin.into(), VersionedXcm::from(xcm), XCM_VERSION)
+			.unwrap()
+			.unwrap();
+
+		let weights_used = trace.iter().map(|trace| trace.weight_used).collect::<Vec<_>>();
+		assert_eq!(
+			weights_used,
+			vec![
+				BaseXcmWeight::get(),
+				BaseXcmWeight::get(),
+				BaseXcmWeight::get() * 2,
+				Weight::zero()
+			]
+		);
+	});
+}
+
+#[test]
+fn dry_run_xcm_does_not_trace() {
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+	let xcm = Xcm::<RuntimeCall>::builder_unsafe().clear_origin().build();
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		runtime_api
+			.dry_run_xcm(H256::zero(), Location::here().into(), VersionedXcm::from(xcm))
+			.unwrap()
+			.unwrap();
+		assert!(<pallet_xcm::Pallet<mock::TestRuntime> as RecordXcm>::take_trace().is_empty());
+	});
+}
+
 #[test]
 fn calling_payment_api_with_a_lower_version_works() {
 	let transfer_amount = 100u128;
@@ -581,3 +705,112 @@ fn fee_estimation_for_usdt_reserve_transfer_in_usdt() {
 		assert_eq!(delivery_fees_usdt, expected_usdt_fees);
 	});
 }
+
+// Scenario: User `1` in the local chain (id 2000) wants to teleport its native tokens to account
+// `[0u8; 32]` on "AssetHub", paying fees in relay tokens. It uses the `TransferQuoteApi` to get
+// the fees of the local execution and the message delivery, along with the message "AssetHub"
+// receives, in one call. The quote matches what the `DryRunApi` and `XcmPaymentApi` return.
+//
+//                 Teleport Parachain(2000) Token
+//                 Reserve Asset Transfer Relay Token for fees
+// Parachain(2000) -------------------------------------------> Parachain(1000)
+#[test]
+fn quote_transfer_works() {
+	sp_tracing::init_for_tests();
+	let who = 1; // AccountId = u64.
+	let balances = vec![(who, 100 + DeliveryFees::get() + ExistentialDeposit::get())];
+	let assets = vec![(1, who, 50)];
+	let call = RuntimeCall::XcmPallet(pallet_xcm::Call::transfer_assets_using_type_and_then {
+		dest: Box::new(VersionedLocation::from((Parent, Parachain(1000)))),
+		assets: Box::new(VersionedAssets::from(vec![
+			(Here, 100u128).into(),
+			(Parent, 20u128).into(),
+		])),
+		assets_transfer_type: Box::new(TransferType::Teleport),
+		remote_fees_id: Box::new(VersionedAssetId::from(AssetId(Location::parent()))),
+		fees_transfer_type: Box::new(TransferType::DestinationReserve),
+		custom_xcm_on_dest: Box::new(VersionedXcm::from(
+			Xcm::<()>::builder_unsafe().deposit_asset(AllCounted(2), [0u8; 32]).build(),
+		)),
+		weight_limit: Unlimited,
+	});
+	let origin = OriginCaller::system(RawOrigin::Signed(who));
+	let asset_id = VersionedAssetId::from(AssetId(HereLocation::get()));
+	let client = TestClient;
+	let runtime_api = client.runtime_api();
+
+	let quote = new_test_ext_with_balances_and_assets(balances.clone(), assets.clone())
+		.execute_with(|| {
+			runtime_api
+				.quote_transfer(
+					H256::zero(),
+					origin.clone(),
+					call.clone(),
+					asset_id.clone(),
+					XCM_VERSION,
+				)
+				.unwrap()
+				.unwrap()
+		});
+
+	new_test_ext_with_balances_and_assets(balances, assets).execute_with(|| {
+		let dry_run_effects = runtime_api
+			.dry_run_call(H256::zero(), origin, call, XCM_VERSION)
+			.unwrap()
+			.unwrap();
+		assert_eq!(quote.local_xcm, dry_run_effects.local_xcm);
+
+		let local_xcm = quote.local_xcm.clone().unwrap();
+		let weight = runtime_api.query_xcm_weight(H256::zero(), local_xcm).unwrap().unwrap();
+		assert_eq!(quote.local_execution_weight, weight);
+		let execution_fees = runtime_api
+			.query_weight_to_asset_fee(H256::zero(), weight, asset_id)
+			.unwrap()
+			.unwrap();
+		assert_eq!(quote.local_execution_fee, execution_fees);
+
+		let (destination, messages) = dry_run_effects.forwarded_xcms[0].clone();
+		assert_eq!(destination, VersionedLocation::from((Parent, Parachain(1000))));
+		let message = messages[0].clone();
+		assert_eq!(
+			quote.hops,
+			vec![HopQuote {
+				destination,
+				message,
+				delivery_fees: VersionedAssets::from((Here, DeliveryFees::get())),
+			}],
+		);
+	});
+}
+
+#[test]
+fn quote_transfer_fails_for_failed_transfer() {
+	let who = 1; // AccountId = u64.
+			  // Not enough to pay for the delivery fees.
+	let balances = vec![(who, 100 + ExistentialDeposit::get())];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let client = TestClient;
+		let runtime_api = client.runtime_api();
+		let call = RuntimeCall::XcmPallet(pallet_xcm::Call::transfer_assets {
+			dest: Box::new(VersionedLocation::from((Parent, Parachain(1000)))),
+			beneficiary: Box::new(VersionedLocation::from(AccountId32 {
+				id: [0u8; 32],
+				network: None,
+			})),
+			assets: Box::new(VersionedAssets::from((Here, 100u128))),
+			fee_asset_item: 0,
+			weight_limit: Unlimited,
+		});
+		let origin = OriginCaller::system(RawOrigin::Signed(who));
+		let result = runtime_api
+			.quote_transfer(
+				H256::zero(),
+				origin,
+				call,
+				VersionedAssetId::from(AssetId(HereLocation::get())),
+				XCM_VERSION,
+			)
+			.unwrap();
+		assert_eq!(result, Err(XcmPaymentApiError::TransferFailed));
+	});
+}
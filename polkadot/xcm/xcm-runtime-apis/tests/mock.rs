@@ -36,7 +36,7 @@ use xcm::{prelude::*, Version as XcmVersion};
 use xcm_builder::{
 	AllowTopLevelPaidExecutionFrom, ConvertedConcreteId, EnsureXcmOrigin, FixedRateOfFungible,
 	FixedWeightBounds, FungibleAdapter, FungiblesAdapter, InspectMessageQueues, IsConcrete,
-	MintLocation, NoChecking, TakeWeightCredit,
+	MintLocation, NoChecking, TakeWeightCredit, UsingComponents,
 };
 use xcm_executor::{
 	traits::{ConvertLocation, JustTry},
@@ -45,8 +45,11 @@ use xcm_executor::{
 
 use xcm_runtime_apis::{
 	conversions::{Error as LocationToAccountApiError, LocationToAccountApi},
-	dry_run::{CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError, XcmDryRunEffects},
-	fees::{Error as XcmPaymentApiError, XcmPaymentApi},
+	dry_run::{
+		CallDryRunEffects, DryRunApi, Error as XcmDryRunApiError, TracedCallDryRunEffects,
+		TracedXcmDryRunEffects, XcmDryRunEffects,
+	},
+	fees::{Error as XcmPaymentApiError, TransferQuote, TransferQuoteApi, XcmPaymentApi},
 	trusted_query::{Error as TrustedQueryApiError, TrustedQueryApi},
 };
 use xcm_simulator::helpers::derive_topic_id;
@@ -99,6 +102,7 @@ impl pallet_assets::Config for TestRuntime {
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type AssetDeposit = ConstU128<1>;
 	type AssetAccountDeposit = ConstU128<10>;
@@ -598,6 +602,26 @@ sp_api::mock_impl_runtime_apis! {
 		}
 	}
 
+	impl TransferQuoteApi<Block, RuntimeCall, OriginCaller> for RuntimeApi {
+		fn quote_transfer(
+			origin: OriginCaller,
+			call: RuntimeCall,
+			asset_id: VersionedAssetId,
+			result_xcms_version: XcmVersion,
+		) -> Result<TransferQuote, XcmPaymentApiError> {
+			// Quotes local execution like `query_weight_to_asset_fee`.
+			type Trader = UsingComponents<WeightToFee, HereLocation, AccountId, Balances, ()>;
+			XcmPallet::quote_transfer::<
+				TestRuntime,
+				XcmRouter,
+				Trader,
+				<XcmConfig as xcm_executor::Config>::AssetExchanger,
+				OriginCaller,
+				RuntimeCall,
+			>(origin, call, asset_id, result_xcms_version)
+		}
+	}
+
 	impl DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for RuntimeApi {
 		fn dry_run_call(
 			origin: OriginCaller,
@@ -617,5 +641,17 @@ sp_api::mock_impl_runtime_apis! {
 		fn dry_run_xcm(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
 			pallet_xcm::Pallet::<TestRuntime>::dry_run_xcm::<XcmRouter>(origin_location, xcm)
 		}
+
+		fn dry_run_call_traced(
+			origin: OriginCaller,
+			call: RuntimeCall,
+			result_xcms_version: XcmVersion,
+		) -> Result<TracedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			pallet_xcm::Pallet::<TestRuntime>::dry_run_call_traced::<TestRuntime, XcmRouter, OriginCaller, RuntimeCall>(origin, call, result_xcms_version)
+		}
+
+		fn dry_run_xcm_traced(origin_location: VersionedLocation, xcm: VersionedXcm<RuntimeCall>, result_xcms_version: XcmVersion) -> Result<TracedXcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			pallet_xcm::Pallet::<TestRuntime>::dry_run_xcm_traced::<XcmRouter>(origin_location, xcm, result_xcms_version)
+		}
 	}
 }
@@ -37,7 +37,7 @@ pub const INITIAL_BALANCE: u128 = 1_000_000_000;
 decl_test_parachain! {
 	pub struct ParaA {
 		Runtime = parachain::Runtime,
-		XcmpMessageHandler = parachain::MsgQueue,
+		XcmpMessageHandler = parachain::XcmpQueue,
 		DmpMessageHandler = parachain::MsgQueue,
 		new_ext = para_ext(1),
 	}
@@ -46,7 +46,7 @@ decl_test_parachain! {
 decl_test_parachain! {
 	pub struct ParaB {
 		Runtime = parachain::Runtime,
-		XcmpMessageHandler = parachain::MsgQueue,
+		XcmpMessageHandler = parachain::XcmpQueue,
 		DmpMessageHandler = parachain::MsgQueue,
 		new_ext = para_ext(2),
 	}
@@ -19,24 +19,30 @@
 mod xcm_config;
 pub use xcm_config::*;
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstU128, ContainsPair, Disabled, EnsureOrigin, EnsureOriginWithArg, Everything, Nothing,
+		ConstU128, ContainsPair, Disabled, EnqueueMessage, EnsureOrigin, EnsureOriginWithArg,
+		Everything, Nothing, ServiceQueues,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::EnsureRoot;
+use polkadot_parachain_primitives::primitives::{
+	Id as ParaId, XcmpMessageFormat, XcmpMessageHandler,
+};
+use scale_info::TypeInfo;
 use sp_core::ConstU32;
 use sp_runtime::{
 	traits::{Get, IdentityLookup},
 	AccountId32,
 };
-use xcm::latest::prelude::*;
-use xcm_builder::{EnsureXcmOrigin, SignedToAccountId32};
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_builder::{EnsureXcmOrigin, ProcessXcmMessage, RateLimitedProcessor, SignedToAccountId32};
 use xcm_executor::{traits::ConvertLocation, XcmExecutor};
-use xcm_simulator::mock_message_queue;
+use xcm_simulator::{mock_message_queue, RelayBlockNumber};
 
 pub type AccountId = AccountId32;
 pub type Balance = u128;
@@ -171,6 +177,93 @@ impl pallet_xcm::Config for Runtime {
 	type AuthorizedAliasConsideration = Disabled;
 }
 
+impl pallet_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type DefaultRateLimit = ();
+	type MaxBuckets = ConstU32<16>;
+	type WeightInfo = ();
+}
+
+/// The sibling parachain which sent a message in the `MessageQueue`.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct SiblingPara(pub ParaId);
+
+impl From<SiblingPara> for Location {
+	fn from(para: SiblingPara) -> Self {
+		(Parent, Parachain(para.0.into())).into()
+	}
+}
+
+parameter_types! {
+	pub const MessageQueueServiceWeight: Weight = Weight::from_parts(1_000_000_000, 1_000_000);
+	pub const MessageQueueHeapSize: u32 = 65_536;
+	pub const MessageQueueMaxStale: u32 = 16;
+}
+
+/// Message processor to handle the XCMP messages of the sibling parachains.
+///
+/// The messages of a sibling which exhausted its rate limit are yielded, so they stay in the
+/// queue until the limit is replenished.
+pub type MessageProcessor = RateLimitedProcessor<
+	ProcessXcmMessage<SiblingPara, XcmExecutor<XcmConfig>, RuntimeCall>,
+	XcmRateLimiter,
+>;
+
+impl pallet_message_queue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Size = u32;
+	type HeapSize = MessageQueueHeapSize;
+	type MaxStale = MessageQueueMaxStale;
+	type ServiceWeight = MessageQueueServiceWeight;
+	type IdleMaxServiceWeight = ();
+	type MessageProcessor = MessageProcessor;
+	type QueueChangeHandler = ();
+	type QueuePausedQuery = ();
+	type WeightInfo = ();
+}
+
+/// Enqueues the XCMP messages into the `MessageQueue` and services the queues.
+pub struct XcmpQueue;
+impl XcmpMessageHandler for XcmpQueue {
+	fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+		iter: I,
+		max_weight: Weight,
+	) -> Weight {
+		for (sender, _sent_at, data) in iter {
+			let mut data_ref = data;
+			let _ = XcmpMessageFormat::decode(&mut data_ref)
+				.expect("Simulator encodes with versioned xcm format; qed");
+
+			let mut remaining_fragments = data_ref;
+			while !remaining_fragments.is_empty() {
+				let fragment = remaining_fragments;
+				if VersionedXcm::<RuntimeCall>::decode(&mut remaining_fragments).is_err() {
+					debug_assert!(false, "Invalid incoming XCMP message data");
+					break;
+				}
+				let message = &fragment[..fragment.len() - remaining_fragments.len()];
+				MessageQueue::enqueue_message(
+					message.try_into().expect("Message too long"),
+					SiblingPara(sender),
+				);
+			}
+		}
+		MessageQueue::service_queues(max_weight)
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 construct_runtime!(
@@ -180,5 +273,7 @@ construct_runtime!(
 		MsgQueue: mock_message_queue,
 		PolkadotXcm: pallet_xcm,
 		ForeignUniques: pallet_uniques,
+		MessageQueue: pallet_message_queue,
+		XcmRateLimiter: pallet_xcm_rate_limiter,
 	}
 );
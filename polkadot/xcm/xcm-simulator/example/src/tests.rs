@@ -17,7 +17,11 @@
 use crate::*;
 
 use codec::Encode;
-use frame_support::{assert_ok, weights::Weight};
+use frame_support::{
+	assert_ok,
+	traits::{QueueFootprintQuery, ServiceQueues},
+	weights::Weight,
+};
 use xcm::latest::QueryResponseInfo;
 use xcm_simulator::{mock_message_queue::ReceivedDmp, TestExt};
 
@@ -120,6 +124,88 @@ fn xcmp() {
 	});
 }
 
+#[test]
+fn xcmp_rate_limit() {
+	MockNet::reset();
+
+	let remark = parachain::RuntimeCall::System(
+		frame_system::Call::<parachain::Runtime>::remark_with_event { remark: vec![1, 2, 3] },
+	);
+	let send_remark = || {
+		ParaA::execute_with(|| {
+			assert_ok!(ParachainPalletXcm::send_xcm(
+				Here,
+				(Parent, Parachain(2)),
+				Xcm(vec![Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					call: remark.encode().into(),
+					fallback_max_weight: None,
+				}]),
+			));
+		});
+	};
+	let remarks = || {
+		parachain::System::events()
+			.iter()
+			.filter(|e| {
+				matches!(
+					e.event,
+					parachain::RuntimeEvent::System(frame_system::Event::Remarked { .. })
+				)
+			})
+			.count()
+	};
+
+	// Para B accepts 2 messages every 10 blocks from para A.
+	let para_a: Location = (Parent, Parachain(1)).into();
+	ParaB::execute_with(|| {
+		assert_ok!(parachain::XcmRateLimiter::set_rate_limit(
+			parachain::RuntimeOrigin::root(),
+			Box::new(para_a.clone().into()),
+			Some(pallet_xcm_rate_limiter::RateLimit {
+				messages: 2,
+				weight: Weight::from_parts(1_000_000_000, 1_000_000),
+				period: 10,
+			}),
+		));
+		assert!(system_contains_event!(
+			parachain,
+			XcmRateLimiter(pallet_xcm_rate_limiter::Event::RateLimitSet { .. })
+		));
+	});
+
+	for _ in 0..3 {
+		send_remark();
+	}
+	ParaB::execute_with(|| {
+		assert_eq!(remarks(), 2);
+		assert!(system_contains_event!(
+			parachain,
+			XcmRateLimiter(pallet_xcm_rate_limiter::Event::RateLimitExhausted { .. })
+		));
+		// The third message is yielded and stays queued.
+		assert_eq!(
+			parachain::MessageQueue::footprint(parachain::SiblingPara(1.into()))
+				.storage
+				.count,
+			1
+		);
+	});
+
+	// The queued message is processed once the limit is replenished after 5 blocks.
+	ParaB::execute_with(|| {
+		parachain::System::set_block_number(6);
+		parachain::MessageQueue::service_queues(Weight::MAX);
+		assert_eq!(remarks(), 3);
+		assert_eq!(
+			parachain::MessageQueue::footprint(parachain::SiblingPara(1.into()))
+				.storage
+				.count,
+			0
+		);
+	});
+}
+
 #[test]
 fn reserve_transfer() {
 	MockNet::reset();
@@ -0,0 +1,160 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Approval assignment equivocation
+//!
+//! Test that honest validators accept the validly signed, conflicting approval assignments sent by
+//! a malicious validator, and that approval checking and finality are not affected.
+//!
+//! Assignment equivocations are not an offence, approval voting counts whichever assignment of a
+//! validator it imports first and ignores the other one. The candidates checked by the malicious
+//! validator must therefore neither be disputed nor lead to a slash.
+
+use crate::utils::{
+	assert_nodes_are_validators, check_metrics, env_or_default, initialize_network,
+	MetricCheckSetup, APPROVAL_CHECKING_FINALITY_LAG_METRIC, BLOCK_HEIGHT_FINALIZED_METRIC,
+	COL_IMAGE_ENV, INTEGRATION_IMAGE_ENV, MALUS_IMAGE_ENV,
+};
+use anyhow::anyhow;
+use cumulus_zombienet_sdk_helpers::assert_para_throughput;
+use polkadot_primitives::Id as ParaId;
+use serde_json::json;
+use std::{ops::Range, time::Duration};
+use zombienet_orchestrator::network::node::{CountOptions, LogLineCountOptions};
+use zombienet_sdk::{NetworkConfig, NetworkConfigBuilder};
+
+const MALUS_VALIDATOR: &str = "malus";
+const PARAS: [u32; 2] = [2000, 2001];
+const MALUS_LOG_PATTERN: &str = "*Equivocating approval assignment*";
+const BAD_ASSIGNMENTS_METRIC: &str =
+	"polkadot_parachain_assignments_received_result{status=\"bad\"}";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn approval_assignment_equivocation_test() -> Result<(), anyhow::Error> {
+	let _ = env_logger::try_init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let config = build_network_config()?;
+	let network = initialize_network(config).await?;
+	let mut validator_nodes = network.relaychain().nodes();
+
+	// Check authority status
+	log::info!("Checking validator node roles");
+	assert_nodes_are_validators(&validator_nodes).await?;
+	log::info!("All validators confirmed as authorities");
+
+	let honest = network.get_node("honest-0")?;
+	let relay_client = honest.wait_client().await?;
+
+	log::info!("Checking parachain block production");
+	let para_throughput: [(ParaId, Range<u32>); 2] = PARAS.map(|id| (ParaId::from(id), 2..11));
+	assert_para_throughput(&relay_client, 10, para_throughput, []).await?;
+	log::info!("All parachains producing blocks");
+
+	log::info!("Check that the malus node equivocates");
+	let malus = network.get_node(MALUS_VALIDATOR)?;
+	let result = malus
+		.wait_log_line_count_with_timeout(
+			MALUS_LOG_PATTERN,
+			true,
+			LogLineCountOptions::new(|n| n >= 1, Duration::from_secs(120), false),
+		)
+		.await?;
+	assert!(
+		result.success(),
+		"Can't find a matching line ({MALUS_LOG_PATTERN}) in {MALUS_VALIDATOR}"
+	);
+
+	// Use the honest validators for the rest of the checks.
+	validator_nodes.retain(|n| n.name() != MALUS_VALIDATOR);
+
+	let metric_checks: Vec<MetricCheckSetup> = vec![
+		(BLOCK_HEIGHT_FINALIZED_METRIC, Box::new(|v| v >= 20.0), 300),
+		(APPROVAL_CHECKING_FINALITY_LAG_METRIC, Box::new(|v| v < 3.0), 0),
+		// The conflicting assignments are validly signed, none of them may fail the checks.
+		(BAD_ASSIGNMENTS_METRIC, Box::new(|v| v == 0.0), 0),
+		("polkadot_parachain_candidate_disputes_total", Box::new(|v| v == 0.0), 0),
+	];
+	check_metrics(&validator_nodes, &metric_checks).await?;
+
+	log::info!("Check that no offence is reported");
+	let result = honest
+		.wait_event_count_with_timeout(
+			"Offences",
+			"Offence",
+			CountOptions::no_occurences_within_timeout(Duration::from_secs(60)),
+		)
+		.await?;
+	assert!(result.success(), "Found an offence event (Offences Offence) in honest-0");
+
+	log::info!("Test finished successfully");
+	Ok(())
+}
+
+fn build_network_config() -> Result<NetworkConfig, anyhow::Error> {
+	let images = zombienet_sdk::environment::get_images_from_env();
+	let polkadot_image = env_or_default(INTEGRATION_IMAGE_ENV, images.polkadot.as_str());
+	let col_image = env_or_default(COL_IMAGE_ENV, images.cumulus.as_str());
+	let malus_image = env_or_default(MALUS_IMAGE_ENV, images.malus.as_str());
+
+	let mut builder = NetworkConfigBuilder::new().with_relaychain(|r| {
+		r.with_chain("rococo-local")
+			.with_default_command("polkadot")
+			.with_default_image(polkadot_image.as_str())
+			.with_default_args(vec!["-lparachain=debug".into()])
+			.with_genesis_overrides(json!({
+				"patch": {
+					"configuration": {
+						"config": {
+							"needed_approvals": 2,
+							"relay_vrf_modulo_samples": 2,
+							"scheduler_params": {
+								"max_validators_per_core": 2
+							}
+						}
+					}
+				}
+			}))
+			.with_default_resources(|r| {
+				r.with_limit_memory("4G")
+					.with_limit_cpu("2")
+					.with_request_memory("2G")
+					.with_request_cpu("1")
+			})
+			.with_node_group(|g| g.with_count(5).with_base_node(|node| node.with_name("honest")))
+			.with_validator(|node| {
+				node.with_name(MALUS_VALIDATOR)
+					.with_image(malus_image.as_str())
+					.with_command("malus")
+					.with_subcommand("equivocate-assignments")
+					.with_args(vec![
+						"-lMALUS=trace,parachain=debug".into(),
+						"--insecure-validator-i-know-what-i-do".into(),
+					])
+			})
+	});
+
+	builder = PARAS.into_iter().fold(builder, |acc, para_id| {
+		acc.with_parachain(|p| {
+			p.with_id(para_id)
+				.cumulus_based(false)
+				.with_default_image(col_image.as_str())
+				.with_default_command("undying-collator")
+				.with_default_args(vec!["-lparachain=debug".into()])
+				.with_collator(|n| n.with_name(&format!("collator-{para_id}")))
+		})
+	});
+
+	builder = builder.with_global_settings(|global_settings| {
+		match std::env::var("ZOMBIENET_SDK_BASE_DIR") {
+			Ok(val) => global_settings.with_base_dir(val),
+			_ => global_settings,
+		}
+	});
+
+	builder.build().map_err(|e| {
+		let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+		anyhow!("config errs: {errs}")
+	})
+}
@@ -0,0 +1,161 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Chunk withholding
+//!
+//! Test that parachains keep making progress and approval checking keeps up when a backer refuses
+//! to serve the chunks and available data of the candidates it backed. Validators have to fetch
+//! their chunks from the honest backers and approval checkers have to recover the candidates from
+//! the chunks held by honest validators.
+//!
+//! Withholding data is not an offence as long as the candidate can be recovered, none of the
+//! candidates backed by the malicious validator may be disputed or lead to a slash.
+
+use crate::utils::{
+	assert_nodes_are_validators, check_metrics, env_or_default, initialize_network,
+	MetricCheckSetup, APPROVAL_CHECKING_FINALITY_LAG_METRIC,
+	AVAILABILITY_RECOVERY_RECOVERIES_FINISHED, BLOCK_HEIGHT_FINALIZED_METRIC, COL_IMAGE_ENV,
+	INTEGRATION_IMAGE_ENV, MALUS_IMAGE_ENV,
+};
+use anyhow::anyhow;
+use cumulus_zombienet_sdk_helpers::assert_para_throughput;
+use polkadot_primitives::Id as ParaId;
+use serde_json::json;
+use std::{ops::Range, time::Duration};
+use zombienet_orchestrator::network::node::{CountOptions, LogLineCountOptions};
+use zombienet_sdk::{NetworkConfig, NetworkConfigBuilder};
+
+const MALUS_VALIDATOR: &str = "malus";
+const PARAS: [u32; 2] = [2000, 2001];
+const MALUS_LOG_PATTERN: &str = "*Withholding candidate data*";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn chunk_withholding_test() -> Result<(), anyhow::Error> {
+	let _ = env_logger::try_init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let config = build_network_config()?;
+	let network = initialize_network(config).await?;
+	let mut validator_nodes = network.relaychain().nodes();
+
+	// Check authority status
+	log::info!("Checking validator node roles");
+	assert_nodes_are_validators(&validator_nodes).await?;
+	log::info!("All validators confirmed as authorities");
+
+	let honest = network.get_node("honest-0")?;
+	let relay_client = honest.wait_client().await?;
+
+	log::info!("Checking parachain block production");
+	let para_throughput: [(ParaId, Range<u32>); 2] = PARAS.map(|id| (ParaId::from(id), 2..11));
+	assert_para_throughput(&relay_client, 10, para_throughput, []).await?;
+	log::info!("All parachains producing blocks");
+
+	log::info!("Check that the malus node withholds candidate data");
+	let malus = network.get_node(MALUS_VALIDATOR)?;
+	let result = malus
+		.wait_log_line_count_with_timeout(
+			MALUS_LOG_PATTERN,
+			true,
+			LogLineCountOptions::new(|n| n >= 1, Duration::from_secs(120), false),
+		)
+		.await?;
+	assert!(
+		result.success(),
+		"Can't find a matching line ({MALUS_LOG_PATTERN}) in {MALUS_VALIDATOR}"
+	);
+
+	// Use the honest validators for the rest of the checks.
+	validator_nodes.retain(|n| n.name() != MALUS_VALIDATOR);
+
+	let metric_checks: Vec<MetricCheckSetup> = vec![
+		(BLOCK_HEIGHT_FINALIZED_METRIC, Box::new(|v| v >= 20.0), 300),
+		(APPROVAL_CHECKING_FINALITY_LAG_METRIC, Box::new(|v| v < 3.0), 0),
+		// Every candidate is recovered from the chunks of the honest validators.
+		(AVAILABILITY_RECOVERY_RECOVERIES_FINISHED, Box::new(|v| v == 0.0), 0),
+		// Withheld data must not make honest validators dispute valid candidates.
+		("polkadot_parachain_candidate_disputes_total", Box::new(|v| v == 0.0), 0),
+	];
+	check_metrics(&validator_nodes, &metric_checks).await?;
+
+	log::info!("Check that no offence is reported");
+	let result = honest
+		.wait_event_count_with_timeout(
+			"Offences",
+			"Offence",
+			CountOptions::no_occurences_within_timeout(Duration::from_secs(60)),
+		)
+		.await?;
+	assert!(result.success(), "Found an offence event (Offences Offence) in honest-0");
+
+	log::info!("Test finished successfully");
+	Ok(())
+}
+
+fn build_network_config() -> Result<NetworkConfig, anyhow::Error> {
+	let images = zombienet_sdk::environment::get_images_from_env();
+	let polkadot_image = env_or_default(INTEGRATION_IMAGE_ENV, images.polkadot.as_str());
+	let col_image = env_or_default(COL_IMAGE_ENV, images.cumulus.as_str());
+	let malus_image = env_or_default(MALUS_IMAGE_ENV, images.malus.as_str());
+
+	let mut builder = NetworkConfigBuilder::new().with_relaychain(|r| {
+		r.with_chain("rococo-local")
+			.with_default_command("polkadot")
+			.with_default_image(polkadot_image.as_str())
+			.with_default_args(vec!["-lparachain=debug".into()])
+			.with_genesis_overrides(json!({
+				"patch": {
+					"configuration": {
+						"config": {
+							"needed_approvals": 2,
+							"scheduler_params": {
+								"max_validators_per_core": 2
+							}
+						}
+					}
+				}
+			}))
+			.with_default_resources(|r| {
+				r.with_limit_memory("4G")
+					.with_limit_cpu("2")
+					.with_request_memory("2G")
+					.with_request_cpu("1")
+			})
+			.with_node_group(|g| g.with_count(5).with_base_node(|node| node.with_name("honest")))
+			.with_validator(|node| {
+				node.with_name(MALUS_VALIDATOR)
+					.with_image(malus_image.as_str())
+					.with_command("malus")
+					.with_subcommand("withhold-chunks")
+					.with_args(vec![
+						"--percentage=100".into(),
+						"-lMALUS=trace,parachain=debug".into(),
+						"--insecure-validator-i-know-what-i-do".into(),
+					])
+			})
+	});
+
+	builder = PARAS.into_iter().fold(builder, |acc, para_id| {
+		acc.with_parachain(|p| {
+			p.with_id(para_id)
+				.cumulus_based(false)
+				.with_default_image(col_image.as_str())
+				.with_default_command("undying-collator")
+				.with_default_args(vec!["-lparachain=debug".into()])
+				.with_collator(|n| n.with_name(&format!("collator-{para_id}")))
+		})
+	});
+
+	builder = builder.with_global_settings(|global_settings| {
+		match std::env::var("ZOMBIENET_SDK_BASE_DIR") {
+			Ok(val) => global_settings.with_base_dir(val),
+			_ => global_settings,
+		}
+	});
+
+	builder.build().map_err(|e| {
+		let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+		anyhow!("config errs: {errs}")
+	})
+}
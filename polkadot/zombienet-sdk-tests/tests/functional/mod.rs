@@ -1,11 +1,13 @@
 // Copyright (C) Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: Apache-2.0
 
+mod approval_assignment_equivocation;
 mod approval_voting_coalescing;
 mod approved_peer_mixed_validators;
 mod async_backing_6_seconds_rate;
 mod beefy_and_mmr;
 mod chunk_fetching_network_compatibility;
+mod chunk_withholding;
 mod collators_reputation_persistence;
 mod coretime_collation_fetching_fairness;
 mod coretime_partitioning;
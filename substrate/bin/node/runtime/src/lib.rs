@@ -1027,6 +1027,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	>;
 	type BlockNumberProvider = System;
 	type Filter = Nothing;
+	type AutoCompoundBatchSize = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1932,6 +1933,7 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = (pallet_assets_precompiles::ForeignAssetId<Runtime, Instance1>,);
@@ -1961,6 +1963,7 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -2231,6 +2234,7 @@ impl pallet_nft_fractionalization::Config for Runtime {
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(25);
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -2252,6 +2256,7 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxRoyalty = MaxRoyalty;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -3020,6 +3025,7 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_nfts::migration::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -3716,6 +3722,20 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_assets_runtime_api::AssetsSnapshotApi<Block, u32, AccountId, u128> for Runtime {
+		fn balance_at(id: u32, who: AccountId, snapshot_id: u32) -> Option<u128> {
+			Assets::balance_at(id, who, snapshot_id)
+		}
+
+		fn total_supply_at(id: u32, snapshot_id: u32) -> Option<u128> {
+			Assets::total_supply_at(id, snapshot_id)
+		}
+
+		fn current_snapshot(id: u32) -> Option<u32> {
+			pallet_assets::CurrentSnapshot::<Runtime, Instance1>::get(id)
+		}
+	}
+
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
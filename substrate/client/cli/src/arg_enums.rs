@@ -144,6 +144,25 @@ pub enum OutputType {
 	Text,
 }
 
+/// The format of the log lines.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum LogFormat {
+	/// Human readable lines of text.
+	Text,
+	/// One JSON object per line, with every field of the log line as a separate key.
+	Json,
+}
+
+impl Into<sc_tracing::logging::LogFormat> for LogFormat {
+	fn into(self) -> sc_tracing::logging::LogFormat {
+		match self {
+			LogFormat::Text => sc_tracing::logging::LogFormat::Text,
+			LogFormat::Json => sc_tracing::logging::LogFormat::Json,
+		}
+	}
+}
+
 /// How to execute blocks
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
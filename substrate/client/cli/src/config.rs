@@ -606,6 +606,11 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(self.shared_params().disable_log_color())
 	}
 
+	/// The format of the log output.
+	fn log_format(&self) -> Result<sc_tracing::logging::LogFormat> {
+		Ok(self.shared_params().log_format().into())
+	}
+
 	/// Initialize substrate. This must be done only once per process.
 	///
 	/// This method:
@@ -643,7 +648,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let mut logger = LoggerBuilder::new(self.log_filters()?);
 		logger
 			.with_log_reloading(self.enable_log_reloading()?)
-			.with_detailed_output(self.detailed_log_output()?);
+			.with_detailed_output(self.detailed_log_output()?)
+			.with_log_format(self.log_format()?);
 
 		if let Some(tracing_targets) = self.tracing_targets()? {
 			let tracing_receiver = self.tracing_receiver()?;
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arg_enums::TracingReceiver;
+use crate::arg_enums::{LogFormat, TracingReceiver};
 use clap::Args;
 use sc_service::config::BasePath;
 use std::path::PathBuf;
@@ -70,6 +70,13 @@ pub struct SharedParams {
 	#[arg(long)]
 	pub disable_log_color: bool,
 
+	/// Sets the format of the log output.
+	///
+	/// With `json`, every log line is a JSON object in which the target, the level, the span
+	/// context and every field of the line are separate keys. Colors are disabled in this case.
+	#[arg(long, value_name = "FORMAT", value_enum, ignore_case = true, default_value_t = LogFormat::Text)]
+	pub log_format: LogFormat,
+
 	/// Enable feature to dynamically update and reload the log filter.
 	///
 	/// Be aware that enabling this feature can lead to a performance decrease up to factor six or
@@ -131,6 +138,11 @@ impl SharedParams {
 		self.disable_log_color
 	}
 
+	/// The format of the log output.
+	pub fn log_format(&self) -> LogFormat {
+		self.log_format
+	}
+
 	/// Is log reloading enabled
 	pub fn enable_log_reloading(&self) -> bool {
 		self.enable_log_reloading
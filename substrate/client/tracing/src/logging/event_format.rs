@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::logging::fast_local_time::FastLocalTime;
+use crate::logging::{fast_local_time::FastLocalTime, json_format, LogFormat};
 use console::style;
 use std::fmt;
 use tracing::{Event, Level, Subscriber};
@@ -38,6 +38,10 @@ pub struct EventFormat<T = FastLocalTime> {
 	pub display_thread_name: bool,
 	/// Duplicate INFO, WARN and ERROR messages to stdout.
 	pub dup_to_stdout: bool,
+	/// The format of the log lines.
+	///
+	/// The display options above only apply to [`LogFormat::Text`].
+	pub log_format: LogFormat,
 }
 
 impl<T> EventFormat<T>
@@ -57,6 +61,10 @@ where
 		S: Subscriber + for<'a> LookupSpan<'a>,
 		N: for<'a> FormatFields<'a> + 'static,
 	{
+		if self.log_format == LogFormat::Json {
+			return json_format::format_event(ctx, writer, event);
+		}
+
 		let normalized_meta = event.normalized_metadata();
 		let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
 		time::write(&self.timer, &mut format::Writer::new(&mut writer))?;
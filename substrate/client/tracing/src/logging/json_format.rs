@@ -0,0 +1,145 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Rendering of events as JSON objects, one per line.
+
+use super::layers::{SpanFields, PREFIX_LOG_SPAN};
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+use std::fmt;
+use tracing::{
+	field::{Field, Visit},
+	Event, Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{
+	fmt::{format, FmtContext, FormatFields},
+	registry::LookupSpan,
+};
+
+/// Field correlating all events about the same candidate, added by `tracing-gum`.
+const TRACE_ID_FIELD: &str = "traceID";
+
+/// Write `event` as a single line JSON object.
+///
+/// Every field of the event becomes a key of the object, next to the `timestamp`, `level`,
+/// `target` and `thread` keys, which take precedence over fields of the same name. The fields of
+/// the enclosing spans are listed under `spans`, from the outermost to the innermost span, and
+/// the name of the node is set as `node` if the logs are prefixed with one. An event without a
+/// `traceID` inherits the one of the innermost span that has it.
+pub(crate) fn format_event<S, N>(
+	ctx: &FmtContext<'_, S, N>,
+	mut writer: format::Writer<'_>,
+	event: &Event,
+) -> fmt::Result
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+	N: for<'a> FormatFields<'a> + 'static,
+{
+	let normalized_meta = event.normalized_metadata();
+	let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
+
+	let mut object = Map::new();
+	event.record(&mut JsonVisitor(&mut object));
+
+	let mut spans = Vec::new();
+	if let Some(span) = ctx.lookup_current() {
+		for span in span.scope().from_root() {
+			let exts = span.extensions();
+			let fields =
+				exts.get::<SpanFields>().map(|fields| fields.0.clone()).unwrap_or_default();
+
+			if span.name() == PREFIX_LOG_SPAN {
+				if let Some(name) = fields.get("name") {
+					object.insert("node".into(), name.clone());
+				}
+				continue;
+			}
+
+			if let Some(trace_id) = fields.get(TRACE_ID_FIELD) {
+				if !event.fields().any(|field| field.name() == TRACE_ID_FIELD) {
+					object.insert(TRACE_ID_FIELD.into(), trace_id.clone());
+				}
+			}
+
+			let mut span_object = Map::new();
+			span_object.insert("name".into(), span.name().into());
+			span_object.extend(fields);
+			spans.push(Value::Object(span_object));
+		}
+	}
+	if !spans.is_empty() {
+		object.insert("spans".into(), Value::Array(spans));
+	}
+
+	object
+		.insert("timestamp".into(), Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true).into());
+	object.insert("level".into(), meta.level().as_str().into());
+	object.insert("target".into(), meta.target().into());
+	let current_thread = std::thread::current();
+	object.insert(
+		"thread".into(),
+		match current_thread.name() {
+			Some(name) => name.into(),
+			None => format!("{:?}", current_thread.id()).into(),
+		},
+	);
+
+	writeln!(writer, "{}", Value::Object(object))
+}
+
+/// Records the fields of events and spans as JSON values.
+pub(crate) struct JsonVisitor<'a>(pub(crate) &'a mut Map<String, Value>);
+
+impl JsonVisitor<'_> {
+	fn insert(&mut self, field: &Field, value: Value) {
+		// Skip the metadata `tracing-log` attaches to events of the `log` crate, it is already
+		// part of the normalized metadata.
+		if field.name().starts_with("log.") {
+			return;
+		}
+
+		self.0.insert(field.name().into(), value);
+	}
+}
+
+impl Visit for JsonVisitor<'_> {
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		self.insert(field, format!("{:?}", value).into());
+	}
+
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.insert(field, value.into());
+	}
+
+	fn record_i64(&mut self, field: &Field, value: i64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_f64(&mut self, field: &Field, value: f64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_bool(&mut self, field: &Field, value: bool) {
+		self.insert(field, value.into());
+	}
+}
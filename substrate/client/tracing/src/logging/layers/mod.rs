@@ -17,5 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 mod prefix_layer;
+mod span_fields_layer;
 
 pub use prefix_layer::*;
+pub use span_fields_layer::*;
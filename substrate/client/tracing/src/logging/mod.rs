@@ -25,6 +25,7 @@
 mod directives;
 mod event_format;
 mod fast_local_time;
+mod json_format;
 mod layers;
 mod stderr_writer;
 
@@ -77,6 +78,17 @@ macro_rules! enable_log_reloading {
 	}};
 }
 
+/// The format of the log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+	/// Human readable lines of text.
+	#[default]
+	Text,
+	/// One JSON object per line, with the target, level, span context and every field of the
+	/// event as separate keys.
+	Json,
+}
+
 /// Convert a `Option<LevelFilter>` to a [`log::LevelFilter`].
 ///
 /// `None` is interpreted as `Info`.
@@ -97,6 +109,7 @@ fn prepare_subscriber<N, E, F, W>(
 	profiling_targets: Option<&str>,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	log_format: LogFormat,
 	builder_hook: impl Fn(
 		SubscriberBuilder<format::DefaultFields, EventFormat, EnvFilter, DefaultLogger>,
 	) -> SubscriberBuilder<N, E, F, W>,
@@ -185,7 +198,8 @@ where
 		_ => true,
 	} || detailed_output;
 
-	let enable_color = force_colors.unwrap_or_else(|| io::stderr().is_terminal());
+	let enable_color =
+		log_format == LogFormat::Text && force_colors.unwrap_or_else(|| io::stderr().is_terminal());
 	let timer = fast_local_time::FastLocalTime { with_fractional: detailed_output };
 
 	// We need to set both together, because we are may printing to `stdout` and `stderr`.
@@ -198,6 +212,7 @@ where
 		display_level: detailed_output,
 		display_thread_name: detailed_output,
 		dup_to_stdout: !io::stderr().is_terminal() && io::stdout().is_terminal(),
+		log_format,
 	};
 	let builder = FmtSubscriber::builder().with_env_filter(env_filter);
 
@@ -209,7 +224,10 @@ where
 
 	let builder = builder_hook(builder);
 
-	let subscriber = builder.finish().with(PrefixLayer);
+	let subscriber = builder
+		.finish()
+		.with(PrefixLayer)
+		.with((log_format == LogFormat::Json).then_some(SpanFieldsLayer));
 
 	Ok(subscriber)
 }
@@ -222,6 +240,7 @@ pub struct LoggerBuilder {
 	log_reloading: bool,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	log_format: LogFormat,
 }
 
 impl LoggerBuilder {
@@ -234,6 +253,7 @@ impl LoggerBuilder {
 			log_reloading: false,
 			force_colors: None,
 			detailed_output: false,
+			log_format: LogFormat::Text,
 		}
 	}
 
@@ -273,6 +293,14 @@ impl LoggerBuilder {
 		self
 	}
 
+	/// Set the format of the log lines.
+	///
+	/// Colors are always disabled for [`LogFormat::Json`].
+	pub fn with_log_format(&mut self, log_format: LogFormat) -> &mut Self {
+		self.log_format = log_format;
+		self
+	}
+
 	/// Force enable/disable colors.
 	pub fn with_colors(&mut self, enable: bool) -> &mut Self {
 		self.force_colors = Some(enable);
@@ -290,6 +318,7 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.log_format,
 					|builder| enable_log_reloading!(builder),
 				)?;
 				let mut profiling =
@@ -308,6 +337,7 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.log_format,
 					|builder| builder,
 				)?;
 				let mut profiling =
@@ -327,6 +357,7 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.log_format,
 				|builder| enable_log_reloading!(builder),
 			)?;
 
@@ -339,6 +370,7 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.log_format,
 				|builder| builder,
 			)?;
 
@@ -496,6 +528,58 @@ mod tests {
 		log::info!("{}", EXPECTED_LOG_MESSAGE);
 	}
 
+	#[test]
+	fn json_log_lines() {
+		let executable = env::current_exe().unwrap();
+		let output = Command::new(executable)
+			.env("ENABLE_LOGGING", "1")
+			.args(&["--nocapture", "json_log_lines_entrypoint"])
+			.output()
+			.unwrap();
+
+		let output = String::from_utf8(output.stderr).unwrap();
+		let line: serde_json::Value = serde_json::from_str(output.trim())
+			.unwrap_or_else(|e| panic!("Expected a JSON line, got:\n{}\n{}", output, e));
+
+		assert_eq!(line["message"], EXPECTED_LOG_MESSAGE);
+		assert_eq!(line["level"], "INFO");
+		assert_eq!(line["target"], "test-target");
+		assert_eq!(line["node"], EXPECTED_NODE_NAME);
+		assert_eq!(line["block_number"], 42);
+		assert_eq!(line["candidate_hash"], "0x01");
+		// Inherited from the enclosing span.
+		assert_eq!(line["traceID"], "1");
+		assert_eq!(line["spans"][0]["name"], "import");
+		assert_eq!(line["spans"][0]["peer"], "alice");
+		assert!(line["timestamp"].is_string());
+	}
+
+	/// This is not an actual test, it is used by the `json_log_lines` test.
+	/// The given test will call the test executable and only execute this one test that
+	/// only prints a log line with some fields in a span, formatted as JSON.
+	#[test]
+	fn json_log_lines_entrypoint() {
+		if env::var("ENABLE_LOGGING").is_ok() {
+			let mut builder = LoggerBuilder::new("");
+			builder.with_log_format(LogFormat::Json);
+			builder.init().unwrap();
+			json_log_lines_process();
+		}
+	}
+
+	#[crate::logging::prefix_logs_with(EXPECTED_NODE_NAME)]
+	fn json_log_lines_process() {
+		let span = tracing::info_span!("import", peer = "alice", traceID = %1);
+		let _enter = span.enter();
+		tracing::info!(
+			target: "test-target",
+			block_number = 42,
+			candidate_hash = %"0x01",
+			"{}",
+			EXPECTED_LOG_MESSAGE,
+		);
+	}
+
 	/// This is not an actual test, it is used by the `do_not_write_with_colors_on_tty` test.
 	/// The given test will call the test executable and only execute this one test that
 	/// only prints a log line with some colors in it.
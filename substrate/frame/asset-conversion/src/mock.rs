@@ -85,6 +85,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
@@ -112,6 +113,7 @@ impl pallet_assets::Config<Instance2> for Test {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
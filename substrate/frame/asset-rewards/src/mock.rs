@@ -92,6 +92,7 @@ impl pallet_assets::Config<Instance1> for MockRuntime {
 	type StringLimit = ConstU32<50>;
 	type Freezer = AssetsFreezer;
 	type Holder = ();
+	type Compliance = ();
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
@@ -106,6 +106,7 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 	type Currency = Balances;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = AssetsFreezer;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -114,6 +114,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Test {
 	type CallbackHandle = ();
 	type ReserveData = ();
 	type Holder = ();
+	type Compliance = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = LocationBenchmarkHelper;
 }
@@ -174,6 +175,7 @@ fn create_asset_details(
 		sufficients: 0,
 		approvals: 0,
 		status: pallet_assets::AssetStatus::Live,
+		has_snapshots: false,
 	}
 }
 
@@ -0,0 +1,48 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME assets pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+
+/// Identifier of a balance snapshot of an asset.
+pub type SnapshotId = u32;
+
+sp_api::decl_runtime_apis! {
+	/// Query balance snapshots of assets.
+	pub trait AssetsSnapshotApi<AssetId, AccountId, Balance>
+	where
+		AssetId: Encode,
+		AccountId: Encode,
+		Balance: Decode,
+	{
+		/// The balance of `who` in asset `id` at snapshot `snapshot_id`.
+		///
+		/// Returns `None` if no such snapshot was taken.
+		fn balance_at(id: AssetId, who: AccountId, snapshot_id: SnapshotId) -> Option<Balance>;
+
+		/// The total supply of asset `id` at snapshot `snapshot_id`.
+		///
+		/// Returns `None` if no such snapshot was taken.
+		fn total_supply_at(id: AssetId, snapshot_id: SnapshotId) -> Option<Balance>;
+
+		/// The latest snapshot taken of asset `id`, if any.
+		fn current_snapshot(id: AssetId) -> Option<SnapshotId>;
+	}
+}
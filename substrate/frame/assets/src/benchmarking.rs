@@ -89,6 +89,38 @@ pub fn create_default_minted_asset<T: Config<I>, I: 'static>(
 	(asset_id, caller, caller_lookup)
 }
 
+/// Enforce the allowlist of the default asset and allowlist `accounts`, so that transfers
+/// between them are checked by [`AllowlistPolicy`], if it is configured.
+fn allowlist<T: Config<I>, I: 'static>(issuer: T::AccountId, accounts: &[&T::AccountId]) {
+	let asset_id = default_asset_id::<T, I>();
+	let origin = SystemOrigin::Signed(issuer);
+	assert!(Assets::<T, I>::set_allowlist_enforced(origin.clone().into(), asset_id.clone(), true)
+		.is_ok());
+	for who in accounts {
+		let who_lookup = T::Lookup::unlookup((*who).clone());
+		assert!(Assets::<T, I>::add_to_allowlist(
+			origin.clone().into(),
+			asset_id.clone(),
+			who_lookup
+		)
+		.is_ok());
+	}
+}
+
+/// Take [`MAX_SNAPSHOTS`] snapshots of the default asset and record a balance of `who` for all
+/// but the current one, so that the next balance change fills its records.
+fn take_snapshots<T: Config<I>, I: 'static>(issuer: T::AccountId, who: &T::AccountId) {
+	let asset_id = default_asset_id::<T, I>();
+	let origin = SystemOrigin::Signed(issuer);
+	for _ in 0..MAX_SNAPSHOTS {
+		assert!(Assets::<T, I>::snapshot(origin.clone().into(), asset_id.clone()).is_ok());
+	}
+	let id: T::AssetId = asset_id.into();
+	let held = Assets::<T, I>::balance(id.clone(), who.clone());
+	let records = (1..MAX_SNAPSHOTS).map(|snapshot_id| (snapshot_id, held)).collect::<Vec<_>>();
+	SnapshotBalances::<T, I>::insert(&id, who, frame_support::BoundedVec::truncate_from(records));
+}
+
 fn swap_is_sufficient<T: Config<I>, I: 'static>(s: &mut bool) {
 	let asset_id = default_asset_id::<T, I>();
 	Asset::<T, I>::mutate(&asset_id.into(), |maybe_a| {
@@ -260,6 +292,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		allowlist::<T, I>(caller.clone(), &[&caller, &target]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
@@ -271,6 +304,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, mint_amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		allowlist::<T, I>(caller.clone(), &[&caller, &target]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, amount)
 	verify {
 		assert!(frame_system::Pallet::<T>::account_exists(&caller));
@@ -282,6 +316,7 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		allowlist::<T, I>(caller.clone(), &[&caller, &target]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, target_lookup, amount)
 	verify {
 		assert_last_event::<T, I>(
@@ -475,6 +510,7 @@ benchmarks_instance_pallet! {
 
 		let dest: T::AccountId = account("dest", 0, SEED);
 		let dest_lookup = T::Lookup::unlookup(dest.clone());
+		allowlist::<T, I>(owner.clone(), &[&owner, &dest]);
 	}: _(SystemOrigin::Signed(delegate.clone()), asset_id.clone(), owner_lookup, dest_lookup, amount)
 	verify {
 		assert!(T::Currency::reserved_balance(&owner).is_zero());
@@ -590,11 +626,64 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		allowlist::<T, I>(caller.clone(), &[&caller, &target]);
 	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), target_lookup, false)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
 	}
 
+	set_allowlist_enforced {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), true)
+	verify {
+		assert_last_event::<T, I>(
+			Event::AllowlistEnforcementChanged { asset_id: asset_id.into(), enforced: true }.into()
+		);
+	}
+
+	add_to_allowlist {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowlistAdded { asset_id: asset_id.into(), who: target }.into());
+	}
+
+	remove_from_allowlist {
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		Assets::<T, I>::add_to_allowlist(
+			SystemOrigin::Signed(caller.clone()).into(),
+			asset_id.clone(),
+			target_lookup.clone(),
+		)?;
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowlistRemoved { asset_id: asset_id.into(), who: target }.into());
+	}
+
+	snapshot {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+	}: _(SystemOrigin::Signed(caller), asset_id.clone())
+	verify {
+		assert_last_event::<T, I>(Event::SnapshotTaken { asset_id: asset_id.into(), snapshot_id: 1 }.into());
+	}
+
+	record_snapshot_balance {
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		take_snapshots::<T, I>(caller.clone(), &caller);
+		let id: T::AssetId = asset_id.into();
+		let details = Asset::<T, I>::get(&id).unwrap();
+		let amount = Assets::<T, I>::balance(id.clone(), &caller);
+	}: {
+		Assets::<T, I>::note_balance_change(&id, &details, &caller, amount);
+	}
+	verify {
+		assert_eq!(SnapshotBalances::<T, I>::get(&id, &caller).len() as u32, MAX_SNAPSHOTS);
+	}
+
 	total_issuance {
 		use frame_support::traits::fungibles::Inspect;
 		let (asset_id, _, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
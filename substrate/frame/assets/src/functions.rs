@@ -19,7 +19,9 @@
 
 use super::*;
 use alloc::vec;
-use frame_support::{defensive, traits::Get, BoundedVec};
+use frame_support::{
+	defensive, dispatch::PostDispatchInfo, traits::Get, weights::Weight, BoundedVec,
+};
 use sp_runtime::traits::ConstU32;
 
 #[must_use]
@@ -385,6 +387,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Remove = Self::dead_account(&who, &mut details, &account.reason, false) {
 			if !account.balance.is_zero() {
+				Self::note_balance_change(&id, &details, &who, account.balance);
 				debug_assert!(details.supply >= account.balance, "supply < balance; qed");
 				details.supply = details.supply.saturating_sub(account.balance);
 			}
@@ -501,6 +504,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			check(details)?;
 
 			Account::<T, I>::try_mutate(&id, beneficiary, |maybe_account| -> DispatchResult {
+				Self::note_balance_change(
+					&id,
+					details,
+					beneficiary,
+					maybe_account.as_ref().map_or(Zero::zero(), |a| a.balance),
+				);
 				match maybe_account {
 					Some(ref mut account) => {
 						account.balance.saturating_accrue(amount);
@@ -594,6 +603,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Account::<T, I>::try_mutate(&id, target, |maybe_account| -> DispatchResult {
 				let mut account = maybe_account.take().ok_or(Error::<T, I>::NoAccount)?;
 				debug_assert!(account.balance >= actual, "checked in prep; qed");
+				Self::note_balance_change(&id, details, target, account.balance);
 
 				// Make the debit.
 				account.balance = account.balance.saturating_sub(actual);
@@ -662,6 +672,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
+		// Transfers forced by the Admin are not subject to the transfer policy.
+		if maybe_need_admin.is_none() {
+			Self::ensure_transfer_allowed(&id, source, dest, amount)?;
+		}
+
 		// Figure out the debit and credit, together with side-effects.
 		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
@@ -691,6 +706,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				details.supply = details.supply.saturating_sub(burn);
 			}
 
+			Self::note_balance_change(&id, details, source, source_account.balance);
+
 			// Debit balance from source; this will not saturate since it's already checked in prep.
 			debug_assert!(source_account.balance >= debit, "checked in prep; qed");
 			source_account.balance = source_account.balance.saturating_sub(debit);
@@ -702,6 +719,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			Account::<T, I>::try_mutate(&id, &dest, |maybe_account| -> DispatchResult {
+				Self::note_balance_change(
+					&id,
+					details,
+					dest,
+					maybe_account.as_ref().map_or(Zero::zero(), |a| a.balance),
+				);
 				match maybe_account {
 					Some(ref mut account) => {
 						// Calculate new balance; this will not saturate since it's already
@@ -747,6 +770,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok((credit, source_died))
 	}
 
+	/// Ensure the configured [`Config::Compliance`] policy allows moving `amount` of asset `id`
+	/// from `source` to `dest`.
+	pub(super) fn ensure_transfer_allowed(
+		id: &T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		T::Compliance::check_transfer(id, source, dest, amount).map_err(|rejection| {
+			match rejection {
+				TransferRejection::SourceNotAllowed => Error::<T, I>::SourceNotAllowed,
+				TransferRejection::DestinationNotAllowed => Error::<T, I>::DestinationNotAllowed,
+				TransferRejection::AmountExceedsLimit => Error::<T, I>::TransferLimitExceeded,
+				TransferRejection::Restricted => Error::<T, I>::TransferRestricted,
+			}
+			.into()
+		})
+	}
+
+	/// Ensure `who` may manage the allowlist of asset `id`, i.e. it is the asset's Issuer.
+	pub(super) fn ensure_allowlist_manager(id: &T::AssetId, who: &T::AccountId) -> DispatchResult {
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(who == &d.issuer, Error::<T, I>::NoPermission);
+		Ok(())
+	}
+
 	/// Create a new asset without taking a deposit.
 	///
 	/// * `id`: The `AssetId` you want the new asset to have. Must not already be in use.
@@ -782,6 +832,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				sufficients: 0,
 				approvals: 0,
 				status: AssetStatus::Live,
+				has_snapshots: false,
 			},
 		);
 		ensure!(T::CallbackHandle::created(&id, &owner).is_ok(), Error::<T, I>::CallbackFailed);
@@ -852,6 +903,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		// Allowlist and snapshot entries are cleaned up with whatever is left of the budget.
+		let budget = max_items.saturating_sub(dead_accounts.len() as u32);
+		let removed_auxiliary = Self::clear_auxiliary_data(&id, budget);
+
 		for who in &dead_accounts {
 			T::Freezer::died(id.clone(), &who);
 			T::Holder::died(id.clone(), &who);
@@ -862,7 +917,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			accounts_destroyed: dead_accounts.len() as u32,
 			accounts_remaining: remaining_accounts as u32,
 		});
-		Ok(dead_accounts.len() as u32)
+		Ok((dead_accounts.len() as u32).saturating_add(removed_auxiliary))
 	}
 
 	/// Destroy approvals associated with a given asset up to the max (T::RemoveItemsLimit).
@@ -910,6 +965,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(details.status == AssetStatus::Destroying, Error::<T, I>::IncorrectStatus);
 			ensure!(details.accounts == 0, Error::<T, I>::InUse);
 			ensure!(details.approvals == 0, Error::<T, I>::InUse);
+			ensure!(!Self::has_auxiliary_data(&id), Error::<T, I>::InUse);
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
 			let metadata = Metadata::<T, I>::take(&id);
@@ -918,12 +974,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				details.deposit.saturating_add(metadata.deposit),
 			);
 			Reserves::<T, I>::remove(&id);
+			AllowlistEnforced::<T, I>::remove(&id);
+			CurrentSnapshot::<T, I>::remove(&id);
 			Self::deposit_event(Event::Destroyed { asset_id: id });
 
 			Ok(())
 		})
 	}
 
+	/// Remove up to `limit` allowlist and snapshot entries of asset `id`.
+	///
+	/// Returns the number of entries removed.
+	fn clear_auxiliary_data(id: &T::AssetId, limit: u32) -> u32 {
+		let mut removed = Allowlist::<T, I>::drain_prefix(id).take(limit as usize).count() as u32;
+		removed.saturating_accrue(
+			SnapshotBalances::<T, I>::drain_prefix(id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32,
+		);
+		removed.saturating_accrue(
+			SnapshotSupply::<T, I>::drain_prefix(id)
+				.take(limit.saturating_sub(removed) as usize)
+				.count() as u32,
+		);
+		removed
+	}
+
+	/// Returns `true` if any allowlist or snapshot entries of asset `id` are left.
+	fn has_auxiliary_data(id: &T::AssetId) -> bool {
+		Allowlist::<T, I>::iter_key_prefix(id).next().is_some() ||
+			SnapshotBalances::<T, I>::iter_key_prefix(id).next().is_some() ||
+			SnapshotSupply::<T, I>::iter_key_prefix(id).next().is_some()
+	}
+
 	/// Creates an approval from `owner` to spend `amount` of asset `id` tokens by 'delegate'
 	/// while reserving `T::ApprovalDeposit` from owner
 	///
@@ -1103,6 +1186,128 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.saturating_add(T::MetadataDepositBase::get())
 	}
 
+	/// Take a snapshot of the balances and the total supply of asset `id`.
+	///
+	/// If `maybe_check_issuer` is given, it must be the Issuer of the asset.
+	///
+	/// Returns the identifier of the new snapshot.
+	pub fn do_snapshot(
+		id: T::AssetId,
+		maybe_check_issuer: Option<T::AccountId>,
+	) -> Result<SnapshotId, DispatchError> {
+		let mut d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		if let Some(check_issuer) = maybe_check_issuer {
+			ensure!(check_issuer == d.issuer, Error::<T, I>::NoPermission);
+		}
+
+		let snapshot_id = CurrentSnapshot::<T, I>::get(&id)
+			.unwrap_or_default()
+			.checked_add(1)
+			.ok_or(ArithmeticError::Overflow)?;
+		CurrentSnapshot::<T, I>::insert(&id, snapshot_id);
+		SnapshotSupply::<T, I>::insert(&id, snapshot_id, d.supply);
+		if !d.has_snapshots {
+			d.has_snapshots = true;
+			Asset::<T, I>::insert(&id, d);
+		}
+		if let Some(expired) = snapshot_id.checked_sub(MAX_SNAPSHOTS) {
+			SnapshotSupply::<T, I>::remove(&id, expired);
+		}
+
+		Self::deposit_event(Event::SnapshotTaken { asset_id: id, snapshot_id });
+		Ok(snapshot_id)
+	}
+
+	/// Record `balance` as the balance of `who` at the current snapshot of asset `id`, unless a
+	/// balance was already recorded for it.
+	///
+	/// Must be called with the balance `who` holds before it is changed. Nothing is read or
+	/// written for assets without snapshots.
+	pub(super) fn note_balance_change(
+		id: &T::AssetId,
+		details: &AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+		who: &T::AccountId,
+		balance: T::Balance,
+	) {
+		if !details.has_snapshots {
+			return;
+		}
+		let Some(current) = CurrentSnapshot::<T, I>::get(id) else {
+			defensive!("`has_snapshots` is only set when a snapshot is taken; qed");
+			return;
+		};
+		let mut records = SnapshotBalances::<T, I>::get(id, who);
+		if records.last().is_some_and(|(snapshot_id, _)| *snapshot_id == current) {
+			return;
+		}
+		// A record is only needed for the snapshots since the previous record, drop the ones
+		// that only cover expired snapshots.
+		records.retain(|(snapshot_id, _)| !Self::is_snapshot_expired(*snapshot_id, current));
+		if records.try_push((current, balance)).is_err() {
+			defensive!("at most `MAX_SNAPSHOTS` snapshots are not expired; qed");
+			return;
+		}
+		SnapshotBalances::<T, I>::insert(id, who, records);
+	}
+
+	/// The `weight` of a call that changes the balances of `accounts` accounts of an asset,
+	/// including the weight of recording them for snapshots.
+	pub(super) fn weight_with_snapshots(weight: Weight, accounts: u32) -> Weight {
+		weight.saturating_add(
+			T::WeightInfo::record_snapshot_balance().saturating_mul(accounts.into()),
+		)
+	}
+
+	/// The actual weight of a call that has changed balances of asset `id`, given its `weight`
+	/// without recording the balances for snapshots.
+	///
+	/// Recording the balances is only charged for assets with snapshots.
+	pub(super) fn snapshot_post_info(id: &T::AssetId, weight: Weight) -> PostDispatchInfo {
+		if Asset::<T, I>::get(id).is_some_and(|d| d.has_snapshots) {
+			None.into()
+		} else {
+			Some(weight).into()
+		}
+	}
+
+	/// Whether `snapshot_id` is older than the last [`MAX_SNAPSHOTS`] snapshots, given the
+	/// `current` one.
+	fn is_snapshot_expired(snapshot_id: SnapshotId, current: SnapshotId) -> bool {
+		snapshot_id.saturating_add(MAX_SNAPSHOTS) <= current
+	}
+
+	/// Get the asset `id` balance of `who` at snapshot `snapshot_id`.
+	///
+	/// Returns `None` if no such snapshot was taken or it expired.
+	pub fn balance_at(
+		id: T::AssetId,
+		who: impl core::borrow::Borrow<T::AccountId>,
+		snapshot_id: SnapshotId,
+	) -> Option<T::Balance> {
+		let current = CurrentSnapshot::<T, I>::get(&id)?;
+		if snapshot_id == 0 ||
+			snapshot_id > current ||
+			Self::is_snapshot_expired(snapshot_id, current)
+		{
+			return None;
+		}
+		// The first balance recorded at or after `snapshot_id` is the balance held at
+		// `snapshot_id`, since it did not change in between. Without one it did not change since.
+		let recorded = SnapshotBalances::<T, I>::get(&id, who.borrow())
+			.into_iter()
+			.find(|(recorded_id, _)| *recorded_id >= snapshot_id)
+			.map(|(_, balance)| balance);
+		Some(recorded.unwrap_or_else(|| Self::balance(id, who)))
+	}
+
+	/// Get the total supply of asset `id` at snapshot `snapshot_id`.
+	///
+	/// Returns `None` if no such snapshot was taken or it expired.
+	pub fn total_supply_at(id: T::AssetId, snapshot_id: SnapshotId) -> Option<T::Balance> {
+		SnapshotSupply::<T, I>::get(id, snapshot_id)
+	}
+
 	/// Returns all the non-zero balances for all assets of the given `account`.
 	pub fn account_balances(account: T::AccountId) -> Vec<(T::AssetId, T::Balance)> {
 		Asset::<T, I>::iter_keys()
@@ -84,6 +84,50 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &<T as SystemConfig>::AccountId,
+		dest: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		// Same as the default implementation, but subject to the configured transfer policy.
+		if !amount.is_zero() {
+			Self::ensure_transfer_allowed(&asset, source, dest, amount)?;
+		}
+		let _extra = <Self as fungibles::Inspect<_>>::can_withdraw(asset.clone(), source, amount)
+			.into_result(preservation != Expendable)?;
+		<Self as fungibles::Inspect<_>>::can_deposit(
+			asset.clone(),
+			dest,
+			amount,
+			Provenance::Extant,
+		)
+		.into_result()?;
+		if source == dest {
+			return Ok(amount);
+		}
+
+		<Self as fungibles::Unbalanced<_>>::decrease_balance(
+			asset.clone(),
+			source,
+			amount,
+			BestEffort,
+			preservation,
+			Fortitude::Polite,
+		)?;
+		// This should never fail as we checked `can_deposit` earlier. But we do a best-effort
+		// anyway.
+		let _ = <Self as fungibles::Unbalanced<_>>::increase_balance(
+			asset.clone(),
+			dest,
+			amount,
+			BestEffort,
+		);
+		Self::done_transfer(asset, source, dest, amount);
+		Ok(amount)
+	}
+
 	fn done_mint_into(
 		asset_id: Self::AssetId,
 		beneficiary: &<T as SystemConfig>::AccountId,
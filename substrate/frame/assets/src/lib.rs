@@ -121,6 +121,14 @@
 //!   Owner.
 //! * `block`: Disallows further `transfer`s to and from an account; called by the asset class's
 //!   Freezer.
+//! * `set_allowlist_enforced`: Restricts `transfer`s to allowlisted accounts; called by the asset
+//!   class's Issuer.
+//! * `add_to_allowlist`: Allows an account to send and receive the asset while the allowlist is
+//!   enforced; called by the asset class's Issuer.
+//! * `remove_from_allowlist`: Removes an account from the allowlist; called by the asset class's
+//!   Issuer.
+//! * `snapshot`: Records the balances of the asset class at this point for later queries; called by
+//!   the asset class's Issuer.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -130,6 +138,8 @@
 //!
 //! * `balance` - Get the asset `id` balance of `who`.
 //! * `total_supply` - Get the total supply of an asset `id`.
+//! * `balance_at` - Get the asset `id` balance of `who` at a snapshot.
+//! * `total_supply_at` - Get the total supply of an asset `id` at a snapshot.
 //!
 //! Please refer to the [`Pallet`] struct for details on publicly available functions.
 //!
@@ -138,6 +148,12 @@
 //! Using `CallbackHandle` associated type, user can configure custom callback functions which are
 //! executed when new asset is created or an existing asset is destroyed.
 //!
+//! ### Transfer Policies
+//!
+//! Using `Compliance` associated type, user can configure a [`TransferPolicy`] which is checked on
+//! every unprivileged transfer, e.g. to enforce KYC allowlists or per-transfer caps. The
+//! [`AllowlistPolicy`] enforces the allowlist managed by each asset class's Issuer.
+//!
 //! ## Related Modules
 //!
 //! * [`System`](../frame_system/index.html)
@@ -258,11 +274,16 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// The maximum number of configurable reserve locations for one asset class.
 	pub const MAX_RESERVES: u32 = 5;
 
+	/// The number of the latest snapshots of an asset class that can be queried.
+	///
+	/// Older snapshots expire and the balances recorded for them are dropped.
+	pub const MAX_SNAPSHOTS: u32 = 16;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
@@ -308,6 +329,7 @@ pub mod pallet {
 			type StringLimit = ConstU32<50>;
 			type Freezer = ();
 			type Holder = ();
+			type Compliance = ();
 			type Extra = ();
 			type CallbackHandle = ();
 			type WeightInfo = ();
@@ -414,6 +436,13 @@ pub mod pallet {
 		/// accordance with balance model.
 		type Holder: BalanceOnHold<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// A hook to enforce a per-asset policy on every unprivileged transfer, e.g. an allowlist
+		/// or a per-transfer cap.
+		///
+		/// Use [`AllowlistPolicy`] to enforce the issuer-managed [`Allowlist`]. Policies can be
+		/// combined by listing them together as a tuple.
+		type Compliance: TransferPolicy<Self::AssetId, Self::AccountId, Self::Balance>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -499,6 +528,60 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AssetId, OptionQuery>;
 
+	/// Assets whose transfers are restricted to the accounts in their [`Allowlist`].
+	///
+	/// This is only enforced if [`AllowlistPolicy`] is part of the configured
+	/// [`Config::Compliance`].
+	#[pallet::storage]
+	pub type AllowlistEnforced<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, (), OptionQuery>;
+
+	/// Accounts allowed by the asset's Issuer to send and receive an asset.
+	#[pallet::storage]
+	pub type Allowlist<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The latest snapshot taken of an asset's balances, if any.
+	#[pallet::storage]
+	pub type CurrentSnapshot<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, SnapshotId, OptionQuery>;
+
+	/// The total supply of an asset at the time a snapshot was taken.
+	#[pallet::storage]
+	pub type SnapshotSupply<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Twox64Concat,
+		SnapshotId,
+		T::Balance,
+		OptionQuery,
+	>;
+
+	/// The balances an account held at the time snapshots were taken, ordered by snapshot.
+	///
+	/// Balances are only recorded (copy-on-write) the first time they change after a snapshot,
+	/// against the snapshot that was current at that time. An account without a record for a
+	/// later snapshot held the same balance it holds now. Only the records of the last
+	/// [`MAX_SNAPSHOTS`] snapshots are kept.
+	#[pallet::storage]
+	pub type SnapshotBalances<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(SnapshotId, T::Balance), ConstU32<MAX_SNAPSHOTS>>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -540,6 +623,7 @@ pub mod pallet {
 						sufficients: 0,
 						approvals: 0,
 						status: AssetStatus::Live,
+						has_snapshots: false,
 					},
 				);
 			}
@@ -690,6 +774,14 @@ pub mod pallet {
 		IssuedDebt { asset_id: T::AssetId, amount: T::Balance },
 		/// Some assets Debt was destroyed (and assets issued).
 		BurnedDebt { asset_id: T::AssetId, amount: T::Balance },
+		/// Enforcement of the allowlist of `asset_id` was switched on or off.
+		AllowlistEnforcementChanged { asset_id: T::AssetId, enforced: bool },
+		/// Some account `who` was added to the allowlist of `asset_id`.
+		AllowlistAdded { asset_id: T::AssetId, who: T::AccountId },
+		/// Some account `who` was removed from the allowlist of `asset_id`.
+		AllowlistRemoved { asset_id: T::AssetId, who: T::AccountId },
+		/// A snapshot of the balances of `asset_id` was taken.
+		SnapshotTaken { asset_id: T::AssetId, snapshot_id: SnapshotId },
 	}
 
 	#[pallet::error]
@@ -745,6 +837,14 @@ pub mod pallet {
 		ContainsHolds,
 		/// Tried setting too many reserves.
 		TooManyReserves,
+		/// The transfer policy of the asset does not allow the source account to send it.
+		SourceNotAllowed,
+		/// The transfer policy of the asset does not allow the destination account to receive it.
+		DestinationNotAllowed,
+		/// The transfer policy of the asset does not allow this amount to be transferred at once.
+		TransferLimitExceeded,
+		/// The transfer policy of the asset rejected the transfer.
+		TransferRestricted,
 	}
 
 	#[pallet::hooks]
@@ -812,6 +912,7 @@ pub mod pallet {
 					sufficients: 0,
 					approvals: 0,
 					status: AssetStatus::Live,
+					has_snapshots: false,
 				},
 			);
 			ensure!(T::CallbackHandle::created(&id, &owner).is_ok(), Error::<T, I>::CallbackFailed);
@@ -957,17 +1058,18 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		/// Modes: Pre-existing balance of `beneficiary`; Account pre-existence of `beneficiary`.
 		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::mint(), 1))]
 		pub fn mint(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			beneficiary: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			let id: T::AssetId = id.into();
-			Self::do_mint(id, &beneficiary, amount, Some(origin))?;
-			Ok(())
+			Self::do_mint(id.clone(), &beneficiary, amount, Some(origin))?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::mint()))
 		}
 
 		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
@@ -986,19 +1088,20 @@ pub mod pallet {
 		/// Weight: `O(1)`
 		/// Modes: Post-existence of `who`; Pre & post Zombie-status of `who`.
 		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::burn(), 1))]
 		pub fn burn(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			who: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let who = T::Lookup::lookup(who)?;
 			let id: T::AssetId = id.into();
 
 			let f = DebitFlags { keep_alive: false, best_effort: true };
-			Self::do_burn(id, &who, amount, Some(origin), f)?;
-			Ok(())
+			Self::do_burn(id.clone(), &who, amount, Some(origin), f)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::burn()))
 		}
 
 		/// Move some assets from the sender account to another.
@@ -1020,18 +1123,20 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(8)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::transfer(), 2))]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			target: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(target)?;
 			let id: T::AssetId = id.into();
 
 			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &origin, &dest, amount, None, f).map(|_| ())
+			Self::do_transfer(id.clone(), &origin, &dest, amount, None, f)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::transfer()))
 		}
 
 		/// Move some assets from the sender account to another, keeping the sender account alive.
@@ -1053,18 +1158,23 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(9)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(
+			T::WeightInfo::transfer_keep_alive(),
+			2,
+		))]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			target: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let source = ensure_signed(origin)?;
 			let dest = T::Lookup::lookup(target)?;
 			let id: T::AssetId = id.into();
 
 			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, None, f).map(|_| ())
+			Self::do_transfer(id.clone(), &source, &dest, amount, None, f)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::transfer_keep_alive()))
 		}
 
 		/// Move some assets from one account to another.
@@ -1087,20 +1197,22 @@ pub mod pallet {
 		/// Modes: Pre-existence of `dest`; Post-existence of `source`; Account pre-existence of
 		/// `dest`.
 		#[pallet::call_index(10)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::force_transfer(), 2))]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			source: AccountIdLookupOf<T>,
 			dest: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let origin = ensure_signed(origin)?;
 			let source = T::Lookup::lookup(source)?;
 			let dest = T::Lookup::lookup(dest)?;
 			let id: T::AssetId = id.into();
 
 			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
-			Self::do_transfer(id, &source, &dest, amount, Some(origin), f).map(|_| ())
+			Self::do_transfer(id.clone(), &source, &dest, amount, Some(origin), f)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::force_transfer()))
 		}
 
 		/// Disallow further unprivileged transfers of an asset `id` from an account `who`. `who`
@@ -1623,18 +1735,23 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(
+			T::WeightInfo::transfer_approved(),
+			2,
+		))]
 		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			owner: AccountIdLookupOf<T>,
 			destination: AccountIdLookupOf<T>,
 			#[pallet::compact] amount: T::Balance,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let delegate = ensure_signed(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
 			let destination = T::Lookup::lookup(destination)?;
 			let id: T::AssetId = id.into();
-			Self::do_transfer_approved(id, &owner, &delegate, &destination, amount)
+			Self::do_transfer_approved(id.clone(), &owner, &delegate, &destination, amount)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::transfer_approved()))
 		}
 
 		/// Create an asset account for non-provider assets.
@@ -1668,14 +1785,15 @@ pub mod pallet {
 		///
 		/// Emits `Refunded` event when successful.
 		#[pallet::call_index(27)]
-		#[pallet::weight(T::WeightInfo::refund())]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::refund(), 1))]
 		pub fn refund(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			allow_burn: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let id: T::AssetId = id.into();
-			Self::do_refund(id, ensure_signed(origin)?, allow_burn)
+			Self::do_refund(id.clone(), ensure_signed(origin)?, allow_burn)?;
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::refund()))
 		}
 
 		/// Sets the minimum balance of an asset.
@@ -1828,13 +1946,13 @@ pub mod pallet {
 		///   (false), or transfer everything except at least the minimum balance, which will
 		///   guarantee to keep the sender asset account alive (true).
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::transfer_all())]
+		#[pallet::weight(Pallet::<T, I>::weight_with_snapshots(T::WeightInfo::transfer_all(), 2))]
 		pub fn transfer_all(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
 			dest: AccountIdLookupOf<T>,
 			keep_alive: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let transactor = ensure_signed(origin)?;
 			let keep_alive = if keep_alive { Preserve } else { Expendable };
 			let reducible_balance = <Self as fungibles::Inspect<_>>::reducible_balance(
@@ -1844,14 +1962,15 @@ pub mod pallet {
 				Fortitude::Polite,
 			);
 			let dest = T::Lookup::lookup(dest)?;
+			let id: T::AssetId = id.into();
 			<Self as fungibles::Mutate<_>>::transfer(
-				id.into(),
+				id.clone(),
 				&transactor,
 				&dest,
 				reducible_balance,
 				keep_alive,
 			)?;
-			Ok(())
+			Ok(Self::snapshot_post_info(&id, T::WeightInfo::transfer_all()))
 		}
 
 		/// Sets the trusted reserve information of an asset.
@@ -1880,6 +1999,113 @@ pub mod pallet {
 			Self::unchecked_update_reserves(id, reserves)?;
 			Ok(())
 		}
+
+		/// Switch on or off enforcement of the allowlist of an asset.
+		///
+		/// While enforced, only accounts in the [`Allowlist`] of the asset may send or receive it
+		/// through unprivileged transfers. This has no effect unless [`AllowlistPolicy`] is part of
+		/// the configured `Compliance`.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `enforced`: Whether the allowlist should be enforced.
+		///
+		/// Emits `AllowlistEnforcementChanged`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(34)]
+		pub fn set_allowlist_enforced(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			enforced: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::ensure_allowlist_manager(&id, &origin)?;
+
+			if enforced {
+				AllowlistEnforced::<T, I>::insert(&id, ());
+			} else {
+				AllowlistEnforced::<T, I>::remove(&id);
+			}
+
+			Self::deposit_event(Event::AllowlistEnforcementChanged { asset_id: id, enforced });
+			Ok(())
+		}
+
+		/// Allow an account to send and receive an asset with an enforced allowlist.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be added to the allowlist.
+		///
+		/// Emits `AllowlistAdded`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(35)]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::ensure_allowlist_manager(&id, &origin)?;
+
+			Allowlist::<T, I>::insert(&id, &who, ());
+			Self::deposit_event(Event::AllowlistAdded { asset_id: id, who });
+			Ok(())
+		}
+
+		/// Remove an account from the allowlist of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account to be removed from the allowlist.
+		///
+		/// Emits `AllowlistRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(36)]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let id: T::AssetId = id.into();
+			Self::ensure_allowlist_manager(&id, &origin)?;
+
+			ensure!(Allowlist::<T, I>::take(&id, &who).is_some(), Error::<T, I>::NoAccount);
+			Self::deposit_event(Event::AllowlistRemoved { asset_id: id, who });
+			Ok(())
+		}
+
+		/// Take a snapshot of the balances and the total supply of an asset.
+		///
+		/// The balances held at the snapshot can later be queried with [`Pallet::balance_at`] and
+		/// [`Pallet::total_supply_at`]. Nothing is copied when the snapshot is taken; balances are
+		/// recorded lazily when they first change afterwards. Only the last [`MAX_SNAPSHOTS`]
+		/// snapshots can be queried.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		///
+		/// Emits `SnapshotTaken` with the identifier of the new snapshot.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(37)]
+		pub fn snapshot(origin: OriginFor<T>, id: T::AssetIdParameter) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_snapshot(id, Some(origin)).map(|_| ())
+		}
 	}
 
 	#[pallet::view_functions]
@@ -1907,6 +2133,15 @@ pub mod pallet {
 		pub fn get_reserves_data(id: T::AssetId) -> Vec<T::ReserveData> {
 			Self::reserves(&id)
 		}
+
+		/// Provide the balance of `who` for asset `id` at snapshot `snapshot_id`.
+		pub fn balance_of_at(
+			who: T::AccountId,
+			id: T::AssetId,
+			snapshot_id: SnapshotId,
+		) -> Option<<T as Config<I>>::Balance> {
+			Self::balance_at(id, who, snapshot_id)
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -1974,11 +2209,38 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(Asset::<T, I>::contains_key(asset_id.clone()), "Orphaned Account found");
 		}
 
+		for asset_id in AllowlistEnforced::<T, I>::iter_keys() {
+			ensure!(Asset::<T, I>::contains_key(asset_id.clone()), "Orphaned Allowlist found");
+		}
+
+		for (asset_id, _) in Allowlist::<T, I>::iter_keys() {
+			ensure!(Asset::<T, I>::contains_key(asset_id.clone()), "Orphaned Allowlist found");
+		}
+
+		for (asset_id, _, records) in SnapshotBalances::<T, I>::iter() {
+			let current = CurrentSnapshot::<T, I>::get(&asset_id);
+			ensure!(
+				records
+					.iter()
+					.all(|(snapshot_id, _)| current.is_some_and(|c| *snapshot_id <= c)),
+				"Snapshot balance recorded for unknown snapshot"
+			);
+			ensure!(
+				records.windows(2).all(|w| w[0].0 < w[1].0),
+				"Snapshot balances are not ordered by snapshot"
+			);
+		}
+
 		for (asset_id, details) in Asset::<T, I>::iter() {
 			if details.status == AssetStatus::Destroying {
 				continue;
 			}
 
+			ensure!(
+				details.has_snapshots == CurrentSnapshot::<T, I>::contains_key(&asset_id),
+				"Snapshot flag does not match the snapshots taken"
+			);
+
 			let mut calculated_supply = T::Balance::zero();
 			let mut calculated_accounts = 0u32;
 			let mut calculated_sufficients = 0u32;
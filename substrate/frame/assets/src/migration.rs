@@ -84,6 +84,7 @@ pub mod v1 {
 				sufficients: self.sufficients,
 				approvals: self.approvals,
 				status,
+				has_snapshots: false,
 			}
 		}
 	}
@@ -160,3 +161,99 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{
+		migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+		weights::Weight,
+	};
+
+	/// The [`AssetDetails`] before [`AssetDetails::has_snapshots`] was added.
+	#[derive(Encode, Decode)]
+	pub struct OldAssetDetails<Balance, AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub supply: Balance,
+		pub deposit: DepositBalance,
+		pub min_balance: Balance,
+		pub is_sufficient: bool,
+		pub accounts: u32,
+		pub sufficients: u32,
+		pub approvals: u32,
+		pub status: AssetStatus,
+	}
+
+	impl<Balance, AccountId, DepositBalance> OldAssetDetails<Balance, AccountId, DepositBalance> {
+		fn migrate_to_v2(
+			self,
+			has_snapshots: bool,
+		) -> AssetDetails<Balance, AccountId, DepositBalance> {
+			AssetDetails {
+				owner: self.owner,
+				issuer: self.issuer,
+				admin: self.admin,
+				freezer: self.freezer,
+				supply: self.supply,
+				deposit: self.deposit,
+				min_balance: self.min_balance,
+				is_sufficient: self.is_sufficient,
+				accounts: self.accounts,
+				sufficients: self.sufficients,
+				approvals: self.approvals,
+				status: self.status,
+				has_snapshots,
+			}
+		}
+	}
+
+	/// Add [`AssetDetails::has_snapshots`] to all assets. It is set for the assets of which a
+	/// snapshot has already been taken.
+	///
+	/// Use [`MigrateV1ToV2`] instead, which only runs once.
+	pub struct UncheckedMigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Asset::<T, I>::translate::<
+				OldAssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>,
+				_,
+			>(|id, old_value| {
+				translated.saturating_inc();
+				Some(old_value.migrate_to_v2(CurrentSnapshot::<T, I>::contains_key(&id)))
+			});
+			log::info!(target: LOG_TARGET, "Upgraded {} assets to storage version 2", translated);
+			T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let prev_count = Asset::<T, I>::iter_keys().count() as u32;
+			Ok(prev_count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Asset::<T, I>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the asset count before and after the migration should be the same"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrate the pallet storage from version 1 to version 2.
+	pub type MigrateV1ToV2<T, I = ()> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T, I>,
+		Pallet<T, I>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
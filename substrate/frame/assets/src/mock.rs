@@ -104,6 +104,7 @@ impl Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = TestFreezer;
 	type Holder = TestHolder;
+	type Compliance = (AllowlistPolicy<Test>, TestTransferCap);
 	type CallbackHandle = (AssetsCallbackHandle, AutoIncAssetId<Test>);
 	type ReserveData = u128;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -134,6 +135,28 @@ parameter_types! {
 	static Frozen: HashMap<(u32, u64), u64> = Default::default();
 	static OnHold: HashMap<(u32, u64), u64> = Default::default();
 	static Hooks: Vec<Hook> = Default::default();
+	static TransferCap: HashMap<u32, u64> = Default::default();
+}
+
+pub struct TestTransferCap;
+impl TransferPolicy<u32, u64, u64> for TestTransferCap {
+	fn check_transfer(
+		asset: &u32,
+		_source: &u64,
+		_dest: &u64,
+		amount: u64,
+	) -> Result<(), TransferRejection> {
+		match TransferCap::get().get(asset) {
+			Some(cap) if amount > *cap => Err(TransferRejection::AmountExceedsLimit),
+			_ => Ok(()),
+		}
+	}
+}
+
+pub(crate) fn set_transfer_cap(asset: u32, cap: u64) {
+	TransferCap::mutate(|v| {
+		v.insert(asset, cap);
+	});
 }
 
 pub struct TestHolder;
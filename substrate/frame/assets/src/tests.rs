@@ -2265,3 +2265,381 @@ fn setting_too_many_reserves_fails() {
 		assert_eq!(Reserves::<Test>::get(0), vec![]);
 	});
 }
+
+#[test]
+fn allowlist_can_only_be_managed_by_issuer() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_team(RuntimeOrigin::signed(1), 0, 2, 1, 1));
+
+		assert_noop!(
+			Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::add_to_allowlist(RuntimeOrigin::signed(2), 1, 3),
+			Error::<Test>::Unknown
+		);
+
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(2), 0, true));
+		System::assert_last_event(RuntimeEvent::Assets(
+			crate::Event::AllowlistEnforcementChanged { asset_id: 0, enforced: true },
+		));
+		assert!(AllowlistEnforced::<Test>::contains_key(0));
+
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(2), 0, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AllowlistAdded {
+			asset_id: 0,
+			who: 3,
+		}));
+		assert!(Allowlist::<Test>::contains_key(0, 3));
+
+		assert_ok!(Assets::remove_from_allowlist(RuntimeOrigin::signed(2), 0, 3));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::AllowlistRemoved {
+			asset_id: 0,
+			who: 3,
+		}));
+		assert!(!Allowlist::<Test>::contains_key(0, 3));
+		assert_noop!(
+			Assets::remove_from_allowlist(RuntimeOrigin::signed(2), 0, 3),
+			Error::<Test>::NoAccount
+		);
+	});
+}
+
+#[test]
+fn enforced_allowlist_restricts_transfers() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true));
+
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+			Error::<Test>::SourceNotAllowed
+		);
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 1));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10),
+			Error::<Test>::DestinationNotAllowed
+		);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 2, 10),
+			Error::<Test>::DestinationNotAllowed
+		);
+		assert_noop!(
+			Assets::transfer_all(RuntimeOrigin::signed(1), 0, 2, false),
+			Error::<Test>::DestinationNotAllowed
+		);
+		assert_noop!(
+			<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 10, Protect),
+			Error::<Test>::DestinationNotAllowed
+		);
+
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(<Assets as fungibles::Mutate<_>>::transfer(0, &1, &2, 10, Protect));
+		assert_eq!(Assets::balance(0, 2), 20);
+
+		// Switching enforcement off lifts the restriction.
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, false));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(2), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 10);
+	});
+}
+
+#[test]
+fn enforced_allowlist_restricts_approved_transfers() {
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true));
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 1));
+
+		// The delegate does not need to be allowlisted, but the destination does.
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 50),
+			Error::<Test>::DestinationNotAllowed
+		);
+		assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 50));
+		assert_eq!(Assets::balance(0, 3), 50);
+	});
+}
+
+#[test]
+fn force_transfer_and_mint_bypass_transfer_policy() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 2, 100));
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 2, 3, 40));
+		assert_eq!(Assets::balance(0, 2), 60);
+		assert_eq!(Assets::balance(0, 3), 40);
+	});
+}
+
+#[test]
+fn transfer_policies_are_combined() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		set_transfer_cap(0, 20);
+
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
+			Error::<Test>::TransferLimitExceeded
+		);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+
+		// The allowlist is checked before the cap.
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 21),
+			Error::<Test>::SourceNotAllowed
+		);
+
+		// Other assets are unaffected.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 999, 2, 50));
+	});
+}
+
+#[test]
+fn destroy_should_remove_allowlist() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::set_allowlist_enforced(RuntimeOrigin::signed(1), 0, true));
+		for who in 1..=7 {
+			assert_ok!(Assets::add_to_allowlist(RuntimeOrigin::signed(1), 0, who));
+		}
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		// `RemoveItemsLimit` is 5, so some entries are left.
+		assert_eq!(Allowlist::<Test>::iter_prefix(0).count(), 2);
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Allowlist::<Test>::iter_prefix(0).count(), 0);
+		assert!(!AllowlistEnforced::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn snapshot_permissions_and_ids() {
+	build_and_execute(|| {
+		assert_noop!(Assets::snapshot(RuntimeOrigin::signed(1), 0), Error::<Test>::Unknown);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_noop!(Assets::snapshot(RuntimeOrigin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_eq!(Assets::balance_at(0, 1, 1), None);
+		assert_eq!(Assets::total_supply_at(0, 1), None);
+
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::SnapshotTaken {
+			asset_id: 0,
+			snapshot_id: 1,
+		}));
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+		assert_eq!(CurrentSnapshot::<Test>::get(0), Some(2));
+		assert_eq!(Assets::balance_at(0, 1, 0), None);
+		assert_eq!(Assets::balance_at(0, 1, 3), None);
+
+		assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_noop!(Assets::snapshot(RuntimeOrigin::signed(1), 0), Error::<Test>::AssetNotLive);
+	});
+}
+
+#[test]
+fn balance_at_tracks_balance_changes() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 50));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 1, 20));
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+
+		// Only the balance before the first change after each snapshot is recorded.
+		assert_eq!(SnapshotBalances::<Test>::get(0, 1).len(), 2);
+
+		assert_eq!(Assets::balance_at(0, 1, 1), Some(100));
+		assert_eq!(Assets::balance_at(0, 2, 1), Some(0));
+		assert_eq!(Assets::balance_at(0, 3, 1), Some(0));
+		assert_eq!(Assets::total_supply_at(0, 1), Some(100));
+
+		assert_eq!(Assets::balance_at(0, 1, 2), Some(60));
+		assert_eq!(Assets::balance_at(0, 2, 2), Some(40));
+		assert_eq!(Assets::balance_at(0, 3, 2), Some(0));
+		assert_eq!(Assets::total_supply_at(0, 2), Some(100));
+
+		assert_eq!(Assets::balance_at(0, 1, 3), Some(40));
+		assert_eq!(Assets::balance_at(0, 2, 3), Some(40));
+		assert_eq!(Assets::balance_at(0, 3, 3), Some(50));
+		assert_eq!(Assets::total_supply_at(0, 3), Some(130));
+
+		// Balances of dead accounts are kept.
+		assert_ok!(Assets::transfer_all(RuntimeOrigin::signed(2), 0, 3, false));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::balance_at(0, 2, 3), Some(40));
+		assert_eq!(Assets::balance_at(0, 3, 3), Some(50));
+		assert_eq!(Assets::balance_at(0, 3, 1), Some(0));
+	});
+}
+
+#[test]
+fn balance_at_tracks_fungibles_and_refunds() {
+	use frame_support::traits::fungibles::Mutate;
+	build_and_execute(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+		assert_ok!(<Assets as Mutate<_>>::mint_into(0, &2, 50));
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+
+		assert_ok!(<Assets as Mutate<_>>::transfer(
+			0,
+			&2,
+			&1,
+			20,
+			frame_support::traits::tokens::Preservation::Expendable
+		));
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(2), 0, true));
+		assert_eq!(Assets::balance(0, 2), 0);
+
+		assert_eq!(Assets::balance_at(0, 1, 1), Some(0));
+		assert_eq!(Assets::balance_at(0, 2, 1), Some(50));
+		assert_eq!(Assets::total_supply_at(0, 1), Some(50));
+	});
+}
+
+#[test]
+fn old_snapshots_expire() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		for _ in 0..MAX_SNAPSHOTS {
+			assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
+		}
+		assert_eq!(SnapshotBalances::<Test>::get(0, 1).len(), MAX_SNAPSHOTS as usize);
+		assert_eq!(Assets::balance_at(0, 1, 1), Some(100));
+		assert_eq!(Assets::total_supply_at(0, 1), Some(100));
+
+		// The first snapshot expires and its record is dropped with the next balance change.
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::balance_at(0, 1, 1), None);
+		assert_eq!(Assets::total_supply_at(0, 1), None);
+		assert_eq!(Assets::balance_at(0, 1, 2), Some(99));
+
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
+		let records = SnapshotBalances::<Test>::get(0, 1);
+		assert_eq!(records.len(), MAX_SNAPSHOTS as usize);
+		assert_eq!(records.first(), Some(&(2, 99)));
+		assert_eq!(records.last(), Some(&(MAX_SNAPSHOTS + 1, 100 - MAX_SNAPSHOTS as u64)));
+		assert_eq!(Assets::balance_at(0, 1, MAX_SNAPSHOTS + 1), Some(100 - MAX_SNAPSHOTS as u64));
+	});
+}
+
+#[test]
+fn destroy_should_remove_snapshots() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		for _ in 0..3 {
+			assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+			assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		}
+		assert_eq!(SnapshotBalances::<Test>::iter_prefix(0).count(), 2);
+		assert_eq!(SnapshotBalances::<Test>::get(0, 1).len(), 3);
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_noop!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0), Error::<Test>::InUse);
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(1), 0));
+
+		assert_eq!(SnapshotBalances::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(SnapshotSupply::<Test>::iter_prefix(0).count(), 0);
+		assert!(!CurrentSnapshot::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn balance_changes_are_only_recorded_for_assets_with_snapshots() {
+	build_and_execute(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert!(!Asset::<Test>::get(0).unwrap().has_snapshots);
+
+		// Without snapshots nothing is recorded and the bookkeeping is refunded.
+		let info = Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(<<Test as Config>::WeightInfo as WeightInfo>::transfer())
+		);
+		assert_eq!(SnapshotBalances::<Test>::iter_prefix(0).count(), 0);
+
+		assert_ok!(Assets::snapshot(RuntimeOrigin::signed(1), 0));
+		assert!(Asset::<Test>::get(0).unwrap().has_snapshots);
+		let info = Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10).unwrap();
+		assert_eq!(info.actual_weight, None);
+		assert_eq!(SnapshotBalances::<Test>::iter_prefix(0).count(), 2);
+	});
+}
+
+#[test]
+fn migrate_to_v2_flags_assets_with_snapshots() {
+	use crate::migration::v2::{MigrateV1ToV2, OldAssetDetails};
+	use codec::Encode;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Assets>();
+		for id in 0..2u32 {
+			let old = OldAssetDetails {
+				owner: 1u64,
+				issuer: 1u64,
+				admin: 1u64,
+				freezer: 1u64,
+				supply: 100u64,
+				deposit: 0u64,
+				min_balance: 1u64,
+				is_sufficient: true,
+				accounts: 1,
+				sufficients: 1,
+				approvals: 0,
+				status: AssetStatus::Live,
+			};
+			storage::set(&Asset::<Test>::hashed_key_for(id), &old.encode());
+		}
+		CurrentSnapshot::<Test>::insert(1, 3);
+
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Assets>(), 2);
+		assert!(!Asset::<Test>::get(0).unwrap().has_snapshots);
+		let details = Asset::<Test>::get(1).unwrap();
+		assert!(details.has_snapshots);
+		assert_eq!(details.supply, 100);
+		assert_eq!(details.status, AssetStatus::Live);
+	});
+}
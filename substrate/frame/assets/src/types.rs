@@ -35,6 +35,9 @@ pub type AssetAccountOf<T, I> = AssetAccount<
 pub type ExistenceReasonOf<T, I> =
 	ExistenceReason<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 
+/// Identifier of a snapshot of an asset's balances. Snapshots of each asset are numbered from 1.
+pub type SnapshotId = u32;
+
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, MaxEncodedLen, TypeInfo)]
@@ -75,6 +78,9 @@ pub struct AssetDetails<Balance, AccountId, DepositBalance> {
 	pub approvals: u32,
 	/// The status of the asset
 	pub status: AssetStatus,
+	/// Whether a snapshot of the balances of the asset has been taken. Balance changes are only
+	/// recorded for snapshots of assets that opted in this way.
+	pub has_snapshots: bool,
 }
 
 /// Data concerning an approval.
@@ -274,6 +280,77 @@ impl<AssetId, AccountId, Balance> BalanceOnHold<AssetId, AccountId, Balance> for
 	}
 }
 
+/// The reason a [`TransferPolicy`] refused a transfer.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TransferRejection {
+	/// The account the funds are moved from is not allowed to send the asset.
+	SourceNotAllowed,
+	/// The account the funds are moved to is not allowed to receive the asset.
+	DestinationNotAllowed,
+	/// The amount exceeds what the policy allows to be moved in a single transfer.
+	AmountExceedsLimit,
+	/// The transfer is refused for some other policy-specific reason.
+	Restricted,
+}
+
+/// Trait for enforcing a per-asset policy on unprivileged transfers, e.g. KYC allowlists,
+/// jurisdiction restrictions or per-transfer caps.
+///
+/// The policy is consulted for every transfer between two accounts that is not forced by the
+/// asset's Admin, including those done through the `fungibles` traits. Minting and burning are not
+/// transfers and are not subject to it.
+pub trait TransferPolicy<AssetId, AccountId, Balance> {
+	/// Return `Ok` if `amount` of `asset` may be moved from `source` to `dest`.
+	fn check_transfer(
+		asset: &AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> Result<(), TransferRejection>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AssetId, AccountId, Balance: Copy> TransferPolicy<AssetId, AccountId, Balance> for Tuple {
+	fn check_transfer(
+		asset: &AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+	) -> Result<(), TransferRejection> {
+		for_tuples!( #( Tuple::check_transfer(asset, source, dest, amount)?; )* );
+		Ok(())
+	}
+}
+
+/// A [`TransferPolicy`] backed by the issuer-managed [`Allowlist`].
+///
+/// For assets with an enforced allowlist (see [`AllowlistEnforced`]), both the source and the
+/// destination of a transfer must be allowlisted. Other assets are not restricted.
+pub struct AllowlistPolicy<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> TransferPolicy<T::AssetId, T::AccountId, T::Balance>
+	for AllowlistPolicy<T, I>
+{
+	fn check_transfer(
+		asset: &T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		_amount: T::Balance,
+	) -> Result<(), TransferRejection> {
+		if !AllowlistEnforced::<T, I>::contains_key(asset) {
+			return Ok(());
+		}
+		ensure!(
+			Allowlist::<T, I>::contains_key(asset, source),
+			TransferRejection::SourceNotAllowed
+		);
+		ensure!(
+			Allowlist::<T, I>::contains_key(asset, dest),
+			TransferRejection::DestinationNotAllowed
+		);
+		Ok(())
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -124,6 +124,7 @@ impl pallet_assets::Config for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type WeightInfo = ();
@@ -252,6 +252,70 @@ benchmarks_instance_pallet! {
 		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
 	}
 
+	delegate_all {
+		let c in 1 .. ClassCountOf::<T::Polls, TallyOf<T, I>>::get();
+
+		// Every class is delegated to a voter with as many votes as possible.
+		let all_polls = fill_voting::<T, I>().1;
+		let classes: Vec<ClassOf<T, I>> = T::Polls::classes().into_iter().take(c as usize).collect();
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// We need to create existing delegations
+		for class in classes.iter() {
+			for i in all_polls.get(class).into_iter().flatten() {
+				ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+			}
+		}
+		let bounded_classes = classes.clone().try_into().map_err(|_| "Too many classes")?;
+	}: _(RawOrigin::Signed(caller.clone()), voter_lookup, Conviction::Locked1x, delegated_balance, Some(bounded_classes))
+	verify {
+		for class in classes.iter() {
+			assert_matches!(VotingFor::<T, I>::get(&caller, class), Voting::Delegating(_));
+		}
+	}
+
+	undelegate_all {
+		let c in 1 .. ClassCountOf::<T::Polls, TallyOf<T, I>>::get();
+
+		let all_polls = fill_voting::<T, I>().1;
+		let classes: Vec<ClassOf<T, I>> = T::Polls::classes().into_iter().take(c as usize).collect();
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		ConvictionVoting::<T, I>::delegate_all(
+			RawOrigin::Signed(caller.clone()).into(),
+			voter_lookup,
+			Conviction::Locked1x,
+			delegated_balance,
+			Some(classes.clone().try_into().map_err(|_| "Too many classes")?),
+		)?;
+
+		// We need to create delegations
+		for class in classes.iter() {
+			for i in all_polls.get(class).into_iter().flatten() {
+				ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+			}
+			assert_matches!(VotingFor::<T, I>::get(&caller, class), Voting::Delegating(_));
+		}
+		let bounded_classes = classes.clone().try_into().map_err(|_| "Too many classes")?;
+	}: _(RawOrigin::Signed(caller.clone()), Some(bounded_classes))
+	verify {
+		for class in classes.iter() {
+			assert_matches!(VotingFor::<T, I>::get(&caller, class), Voting::Casting(_));
+		}
+	}
+
 	unlock {
 		let caller = funded_account::<T, I>("caller", 0);
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
@@ -29,12 +29,13 @@
 
 extern crate alloc;
 
+use alloc::vec::Vec;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{
-		fungible, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
-		ReservableCurrency, WithdrawReasons,
+		fungible, ClassCountOf, Currency, Get, LockIdentifier, LockableCurrency, PollStatus,
+		Polling, ReservableCurrency, WithdrawReasons,
 	},
 };
 use sp_runtime::{
@@ -96,7 +97,6 @@ pub mod pallet {
 		pallet_prelude::{
 			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
 		},
-		traits::ClassCountOf,
 		Twox64Concat,
 	};
 	use frame_system::pallet_prelude::{ensure_signed, OriginFor};
@@ -270,8 +270,8 @@ pub mod pallet {
 		///     `remove_vote`).
 		///
 		/// - `to`: The account whose voting the `target` account's voting power will follow.
-		/// - `class`: The class of polls to delegate. To delegate multiple classes, multiple calls
-		///   to this function are required.
+		/// - `class`: The class of polls to delegate. To delegate multiple classes, either use
+		///   `delegate_all` or make multiple calls to this function.
 		/// - `conviction`: The conviction that will be attached to the delegated votes. When the
 		///   account is undelegated, the funds will be locked for the corresponding period.
 		/// - `balance`: The amount of the account's balance to be used in delegating. This must not
@@ -419,6 +419,96 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Delegate the voting power (with some given conviction) of the sending account for
+		/// several classes of polls at once.
+		///
+		/// This is equivalent to calling `delegate` for each of the `classes`, except that classes
+		/// for which the account is already delegating are left untouched. A delegation for a
+		/// particular class can therefore be overridden either before or after this call by
+		/// `undelegate` and `delegate` on that class.
+		///
+		/// The same `balance` is delegated for every class; since all classes share the same
+		/// lock, it is only locked once.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must have no
+		/// voting activity in any of the `classes` it is not delegating for yet.
+		///
+		/// - `to`: The account whose voting the `target` account's voting power will follow.
+		/// - `conviction`: The conviction that will be attached to the delegated votes.
+		/// - `balance`: The amount of the account's balance to be used in delegating. This must not
+		///   be more than the account's current balance.
+		/// - `classes`: The classes of polls to delegate, or `None` for all classes.
+		///
+		/// Emits `Delegated` for each class delegated.
+		///
+		/// Weight: `O(C * R)` where C is the number of classes and R is the number of polls the
+		///   voter delegating to has voted on. Weight is charged as if all classes had maximum
+		///   votes.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::delegate_all(Pallet::<T, I>::classes_count(&classes)))]
+		pub fn delegate_all(
+			origin: OriginFor<T>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+			classes: Option<BoundedVec<ClassOf<T, I>, ClassCountOf<T::Polls, TallyOf<T, I>>>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let classes = Self::classes_or_all(classes);
+			let classes_count = classes.len() as u32;
+			let mut delegated = false;
+			for class in classes {
+				if matches!(VotingFor::<T, I>::get(&who, &class), Voting::Delegating(_)) {
+					continue;
+				}
+				Self::try_delegate(who.clone(), class, to.clone(), conviction, balance)?;
+				delegated = true;
+			}
+			ensure!(delegated, Error::<T, I>::AlreadyDelegating);
+
+			Ok(Some(T::WeightInfo::delegate_all(classes_count)).into())
+		}
+
+		/// Undelegate the voting power of the sending account for several classes of polls at
+		/// once.
+		///
+		/// This is equivalent to calling `undelegate` for each of the `classes` the account is
+		/// delegating for; other classes are ignored.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating for at least one of the `classes`.
+		///
+		/// - `classes`: The classes of polls to remove the delegation from, or `None` for all
+		///   classes.
+		///
+		/// Emits `Undelegated` for each class undelegated.
+		///
+		/// Weight: `O(C * R)` where C is the number of classes and R is the number of polls the
+		///   voter delegating to has voted on. Weight is charged as if all classes had maximum
+		///   votes.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::undelegate_all(Pallet::<T, I>::classes_count(&classes)))]
+		pub fn undelegate_all(
+			origin: OriginFor<T>,
+			classes: Option<BoundedVec<ClassOf<T, I>, ClassCountOf<T::Polls, TallyOf<T, I>>>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let classes = Self::classes_or_all(classes);
+			let classes_count = classes.len() as u32;
+			let mut undelegated = false;
+			for class in classes {
+				if !matches!(VotingFor::<T, I>::get(&who, &class), Voting::Delegating(_)) {
+					continue;
+				}
+				Self::try_undelegate(who.clone(), class)?;
+				undelegated = true;
+			}
+			ensure!(undelegated, Error::<T, I>::NotDelegating);
+
+			Ok(Some(T::WeightInfo::undelegate_all(classes_count)).into())
+		}
 	}
 }
 
@@ -666,6 +756,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(votes)
 	}
 
+	/// The number of classes `classes_or_all` may return for the given `classes`.
+	fn classes_count(
+		classes: &Option<sp_runtime::BoundedVec<ClassOf<T, I>, impl Get<u32>>>,
+	) -> u32 {
+		classes
+			.as_ref()
+			.map_or(ClassCountOf::<T::Polls, TallyOf<T, I>>::get(), |classes| classes.len() as u32)
+	}
+
+	/// The given `classes` without duplicates, or all classes if `None`.
+	fn classes_or_all(
+		classes: Option<sp_runtime::BoundedVec<ClassOf<T, I>, impl Get<u32>>>,
+	) -> Vec<ClassOf<T, I>> {
+		match classes {
+			Some(classes) => {
+				let mut classes = classes.into_inner();
+				classes.sort();
+				classes.dedup();
+				classes
+			},
+			None => T::Polls::classes(),
+		}
+	}
+
 	/// Attempt to end the current delegation.
 	///
 	/// Return the number of votes of upstream.
@@ -891,6 +891,143 @@ fn errors_with_delegating_work() {
 	});
 }
 
+#[test]
+fn delegate_all_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![
+				(0, Ongoing(Tally::new(0), 0)),
+				(1, Ongoing(Tally::new(0), 1)),
+				(2, Ongoing(Tally::new(0), 2)),
+			]
+			.into_iter()
+			.collect(),
+		);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, nay(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 2, aye(10, 0)));
+
+		// Class 2 follows a different delegate.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 2, 3, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate_all(
+			RuntimeOrigin::signed(1),
+			2,
+			Conviction::Locked1x,
+			5,
+			None
+		));
+		// The balance is locked once for all classes.
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		assert_eq!(
+			Polls::get(),
+			vec![
+				(0, Ongoing(Tally::from_parts(6, 0, 15), 0)),
+				(1, Ongoing(Tally::from_parts(0, 6, 0), 1)),
+				(2, Ongoing(Tally::from_parts(6, 0, 15), 2)),
+			]
+			.into_iter()
+			.collect()
+		);
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 2),
+			crate::Voting::Delegating(Delegating { target: 3, .. })
+		));
+
+		// Nothing left to delegate.
+		assert_noop!(
+			Voting::delegate_all(RuntimeOrigin::signed(1), 4, Conviction::None, 5, None),
+			Error::<Test>::AlreadyDelegating
+		);
+
+		assert_ok!(Voting::undelegate_all(RuntimeOrigin::signed(1), None));
+		assert_eq!(tally(0), Tally::from_parts(1, 0, 10));
+		assert_eq!(tally(1), Tally::from_parts(0, 1, 0));
+		assert_eq!(tally(2), Tally::from_parts(1, 0, 10));
+		assert_noop!(
+			Voting::undelegate_all(RuntimeOrigin::signed(1), None),
+			Error::<Test>::NotDelegating
+		);
+
+		// The lock is kept for the conviction period and released for all classes at once.
+		run_to(3);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 1, 1));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 5);
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 1, 1));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::usable_balance(1), 10);
+	});
+}
+
+#[test]
+fn delegate_all_with_chosen_classes_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_all(
+			RuntimeOrigin::signed(1),
+			2,
+			Conviction::None,
+			10,
+			Some(vec![2, 0, 2].try_into().unwrap())
+		));
+		assert!(matches!(VotingFor::<Test>::get(1, 0), crate::Voting::Delegating(_)));
+		assert!(matches!(VotingFor::<Test>::get(1, 1), crate::Voting::Casting(_)));
+		assert!(matches!(VotingFor::<Test>::get(1, 2), crate::Voting::Delegating(_)));
+
+		assert_ok!(Voting::undelegate_all(
+			RuntimeOrigin::signed(1),
+			Some(vec![1, 2].try_into().unwrap())
+		));
+		assert!(matches!(VotingFor::<Test>::get(1, 0), crate::Voting::Delegating(_)));
+		assert!(matches!(VotingFor::<Test>::get(1, 2), crate::Voting::Casting(_)));
+		assert_noop!(
+			Voting::undelegate_all(RuntimeOrigin::signed(1), Some(vec![1, 2].try_into().unwrap())),
+			Error::<Test>::NotDelegating
+		);
+	});
+}
+
+#[test]
+fn errors_with_delegate_all_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::delegate_all(RuntimeOrigin::signed(1), 1, Conviction::None, 10, None),
+			Error::<Test>::Nonsense
+		);
+		assert_noop!(
+			Voting::delegate_all(RuntimeOrigin::signed(1), 2, Conviction::None, 11, None),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			Voting::delegate_all(
+				RuntimeOrigin::signed(1),
+				2,
+				Conviction::None,
+				10,
+				Some(vec![0, 3].try_into().unwrap())
+			),
+			Error::<Test>::BadClass
+		);
+
+		// Voting in one class prevents delegating all of them.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)));
+		assert_noop!(
+			Voting::delegate_all(RuntimeOrigin::signed(1), 2, Conviction::None, 10, None),
+			Error::<Test>::AlreadyVoting
+		);
+		assert_ok!(Voting::delegate_all(
+			RuntimeOrigin::signed(1),
+			2,
+			Conviction::None,
+			10,
+			Some(vec![1, 2].try_into().unwrap())
+		));
+	});
+}
+
 #[test]
 fn remove_other_vote_works() {
 	new_test_ext().execute_with(|| {
@@ -79,6 +79,8 @@ pub trait WeightInfo {
 	fn delegate(r: u32, ) -> Weight;
 	fn undelegate(r: u32, ) -> Weight;
 	fn unlock() -> Weight;
+	fn delegate_all(c: u32, ) -> Weight;
+	fn undelegate_all(c: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_conviction_voting` using the Substrate node and recommended hardware.
@@ -237,6 +239,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	fn delegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
+	fn undelegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
 }
 
 // For backwards compatibility and tests.
@@ -394,4 +402,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	fn delegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
+	fn undelegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
 }
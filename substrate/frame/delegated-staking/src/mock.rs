@@ -166,6 +166,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = pallet_staking::AllStakers<Runtime>;
+	type AutoCompoundBatchSize = ConstU32<16>;
 }
 
 frame_support::construct_runtime!(
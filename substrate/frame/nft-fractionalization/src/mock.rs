@@ -20,7 +20,11 @@
 use super::*;
 use crate as pallet_nft_fractionalization;
 
-use frame::{deps::sp_runtime::MultiSignature, testing_prelude::*, traits::Verify};
+use frame::{
+	deps::sp_runtime::{MultiSignature, Perbill},
+	testing_prelude::*,
+	traits::Verify,
+};
 use pallet_nfts::PalletFeatures;
 
 type Block = MockBlock<Test>;
@@ -70,6 +74,7 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
 	type Holder = ();
+	type Compliance = ();
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
@@ -81,6 +86,7 @@ impl pallet_assets::Config for Test {
 
 parameter_types! {
 	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_nfts::Config for Test {
@@ -104,6 +110,7 @@ impl pallet_nfts::Config for Test {
 	type MaxTips = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxRoyalty = MaxRoyalty;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
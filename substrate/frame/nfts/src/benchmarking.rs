@@ -50,6 +50,19 @@ fn create_collection<T: Config<I>, I: 'static>(
 	(collection, caller, caller_lookup)
 }
 
+fn set_item_royalty<T: Config<I>, I: 'static>(collection: T::CollectionId, item: T::ItemId) {
+	let owner = Collection::<T, I>::get(collection).unwrap().owner;
+	let recipient: T::AccountId = account("royalty", 0, SEED);
+	T::Currency::make_free_balance_be(&recipient, T::Currency::minimum_balance());
+	assert_ok!(Nfts::<T, I>::set_royalty(
+		SystemOrigin::Signed(owner).into(),
+		collection,
+		Some(item),
+		T::Lookup::unlookup(recipient),
+		T::MaxRoyalty::get(),
+	));
+}
+
 fn add_collection_metadata<T: Config<I>, I: 'static>() -> (T::AccountId, AccountIdLookupOf<T>) {
 	let caller = Collection::<T, I>::get(T::Helper::collection(0)).unwrap().owner;
 	if caller != whitelisted_caller() {
@@ -666,10 +679,13 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
 		let origin = SystemOrigin::Signed(seller.clone()).into();
 		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
-		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+		set_item_royalty::<T, I>(collection, item);
+		let half_max = DepositBalanceOf::<T, I>::max_value() / 2u32.into();
+		T::Currency::make_free_balance_be(&buyer, half_max);
+		T::Currency::make_free_balance_be(&seller, half_max);
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price)
 	verify {
 		assert_last_event::<T, I>(Event::ItemBought {
@@ -753,13 +769,16 @@ benchmarks_instance_pallet! {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let half_max = DepositBalanceOf::<T, I>::max_value() / 2u32.into();
+		T::Currency::make_free_balance_be(&target, half_max);
+		T::Currency::make_free_balance_be(&caller, half_max);
+		set_item_royalty::<T, I>(collection, item1);
 		let origin = SystemOrigin::Signed(caller.clone());
 		T::BlockNumberProvider::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
@@ -878,5 +897,30 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let percentage = T::MaxRoyalty::get();
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), recipient_lookup, percentage)
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltySet {
+			collection,
+			maybe_item: Some(item),
+			recipient,
+			percentage,
+		}.into());
+	}
+
+	clear_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		set_item_royalty::<T, I>(collection, item);
+	}: _(SystemOrigin::Signed(caller), collection, Some(item))
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltyCleared { collection, maybe_item: Some(item) }.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -146,8 +146,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `send_item_id`, `receive_collection_id`, and `receive_item_id`. The `caller` account must be
 	/// the owner of the item specified by `send_collection_id` and `send_item_id`. If the claimed
 	/// swap has an associated `price`, it will be transferred between the owners of the two items
-	/// based on the `price.direction`. After the swap is completed, the function emits the
-	/// `SwapClaimed` event.
+	/// based on the `price.direction`, and the royalty of the item being paid for is deducted from
+	/// it. After the swap is completed, the function emits the `SwapClaimed` event.
 	///
 	/// - `caller`: The account claiming the swap offer, which must be the owner of the sent item.
 	/// - `send_collection_id`: The identifier of the collection containing the item being sent.
@@ -191,20 +191,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
 
 		if let Some(ref price) = swap.price {
-			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
-					&receive_item.owner,
-					&send_item.owner,
-					price.amount,
-					KeepAlive,
-				)?,
-				PriceDirection::Receive => T::Currency::transfer(
-					&send_item.owner,
-					&receive_item.owner,
-					price.amount,
-					KeepAlive,
-				)?,
+			// The payer buys the item of the other party, so the royalty of that item is due.
+			let (payer, payee, collection, item) = match price.direction {
+				PriceDirection::Send => {
+					(&receive_item.owner, &send_item.owner, send_collection_id, send_item_id)
+				},
+				PriceDirection::Receive => {
+					(&send_item.owner, &receive_item.owner, receive_collection_id, receive_item_id)
+				},
 			};
+			let payee_amount = Self::do_pay_royalty(payer, collection, item, price.amount)?;
+			T::Currency::transfer(payer, payee, payee_amount, KeepAlive)?;
 		}
 
 		// This also removes the swap.
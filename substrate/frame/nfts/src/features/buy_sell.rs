@@ -119,7 +119,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// receive the bid price if it is equal to or higher than the item's set price. If
 	/// `whitelisted_buyer` is specified in the item's price information, only that account is
 	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
-	/// function will return an error.
+	/// function will return an error. If a royalty is set for the item or its collection, it is
+	/// paid out of the price and the owner receives the rest.
 	///
 	/// - `collection`: The identifier of the collection containing the item to be bought.
 	/// - `item`: The identifier of the item to be bought.
@@ -148,10 +149,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
+		let seller_amount = Self::do_pay_royalty(&buyer, collection, item, price_info.0)?;
 		T::Currency::transfer(
 			&buyer,
 			&details.owner,
-			price_info.0,
+			seller_amount,
 			ExistenceRequirement::KeepAlive,
 		)?;
 
@@ -0,0 +1,136 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! This module contains helper methods to configure and pay the royalties of collections and
+//! items in the NFTs pallet.
+//! Royalties are paid on sales made through the functionality of [`PalletFeature::Trading`] and
+//! [`PalletFeature::Swaps`].
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set the royalty of a collection or an item.
+	///
+	/// - `maybe_check_owner`: An optional account that must be the owner of the collection.
+	/// - `collection`: The identifier of the collection.
+	/// - `maybe_item`: The identifier of the item, or `None` to set the collection royalty.
+	/// - `royalty`: The royalty to set.
+	///
+	/// The royalties of the collection must not be locked by disabling
+	/// [`CollectionSetting::UnlockedRoyalties`], and the item must exist if given. The
+	/// `percentage` of the royalty must not exceed [`Config::MaxRoyalty`].
+	pub(crate) fn do_set_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		royalty: RoyaltyOf<T>,
+	) -> DispatchResult {
+		ensure!(royalty.percentage <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+		Self::ensure_royalty_management(&maybe_check_owner, &collection, &maybe_item)?;
+
+		let Royalty { recipient, percentage } = royalty.clone();
+		match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::insert(&collection, &item, royalty),
+			None => CollectionRoyaltyOf::<T, I>::insert(&collection, royalty),
+		}
+
+		Self::deposit_event(Event::RoyaltySet { collection, maybe_item, recipient, percentage });
+		Ok(())
+	}
+
+	/// Clear the royalty of a collection or an item.
+	///
+	/// - `maybe_check_owner`: An optional account that must be the owner of the collection.
+	/// - `collection`: The identifier of the collection.
+	/// - `maybe_item`: The identifier of the item, or `None` to clear the collection royalty.
+	///
+	/// The same restrictions as in [`Self::do_set_royalty`] apply.
+	pub(crate) fn do_clear_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+	) -> DispatchResult {
+		Self::ensure_royalty_management(&maybe_check_owner, &collection, &maybe_item)?;
+
+		let removed = match maybe_item {
+			Some(item) => ItemRoyaltyOf::<T, I>::take(&collection, &item),
+			None => CollectionRoyaltyOf::<T, I>::take(&collection),
+		};
+		ensure!(removed.is_some(), Error::<T, I>::NoRoyalty);
+
+		Self::deposit_event(Event::RoyaltyCleared { collection, maybe_item });
+		Ok(())
+	}
+
+	/// Get the royalty paid on the sale of an item.
+	///
+	/// The royalty of the item takes precedence over the royalty of its collection.
+	pub fn royalty(collection: &T::CollectionId, item: &T::ItemId) -> Option<RoyaltyOf<T>> {
+		ItemRoyaltyOf::<T, I>::get(collection, item)
+			.or_else(|| CollectionRoyaltyOf::<T, I>::get(collection))
+	}
+
+	/// Pay the royalty of an item sold for `price` from the `payer` account.
+	///
+	/// Returns the part of the `price` left for the seller.
+	pub(crate) fn do_pay_royalty(
+		payer: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		price: ItemPrice<T, I>,
+	) -> Result<ItemPrice<T, I>, DispatchError> {
+		let Some(Royalty { recipient, percentage }) = Self::royalty(&collection, &item) else {
+			return Ok(price);
+		};
+		let amount = percentage.mul_floor(price);
+		if amount.is_zero() {
+			return Ok(price);
+		}
+
+		T::Currency::transfer(payer, &recipient, amount, KeepAlive)?;
+		Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+
+		Ok(price.saturating_sub(amount))
+	}
+
+	fn ensure_royalty_management(
+		maybe_check_owner: &Option<T::AccountId>,
+		collection: &T::CollectionId,
+		maybe_item: &Option<T::ItemId>,
+	) -> DispatchResult {
+		let collection_config = Self::get_collection_config(collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::RoyaltiesLocked
+		);
+
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(
+				Self::collection_owner(*collection).as_ref() == Some(check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		if let Some(item) = maybe_item {
+			ensure!(Item::<T, I>::contains_key(collection, item), Error::<T, I>::UnknownItem);
+		}
+		Ok(())
+	}
+}
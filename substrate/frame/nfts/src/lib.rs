@@ -59,7 +59,7 @@ use frame_support::traits::{
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
 	traits::{BlockNumberProvider, IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
-	Debug,
+	Debug, Perbill,
 };
 
 pub use pallet::*;
@@ -79,7 +79,7 @@ pub mod pallet {
 	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -217,6 +217,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max part of the sale price that can be paid as a royalty.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -362,6 +366,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The royalty paid on sales of the items of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyOf<T>, OptionQuery>;
+
+	/// The royalty paid on sales of an item, taking precedence over the royalty of its collection.
+	#[pallet::storage]
+	pub type ItemRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		RoyaltyOf<T>,
+		OptionQuery,
+	>;
+
 	/// Item attribute approvals.
 	#[pallet::storage]
 	pub type ItemAttributesApprovalsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -543,6 +564,22 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
+		/// A royalty was paid on the sale of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// A royalty was set for the `collection` or an `item` within that `collection`.
+		RoyaltySet {
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			recipient: T::AccountId,
+			percentage: Perbill,
+		},
+		/// A royalty was cleared for the `collection` or an `item` within that `collection`.
+		RoyaltyCleared { collection: T::CollectionId, maybe_item: Option<T::ItemId> },
 		/// A tip was sent.
 		TipSent {
 			collection: T::CollectionId,
@@ -688,6 +725,12 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// The royalties of the collection are locked and can't be changed.
+		RoyaltiesLocked,
+		/// No royalty is set.
+		NoRoyalty,
+		/// The royalty exceeds the max royalty.
+		RoyaltyTooHigh,
 	}
 
 	#[pallet::call]
@@ -1932,6 +1975,69 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set the royalty paid on sales of the items of a `collection`, or of a single `item`.
+		///
+		/// The royalty is paid by the buyer out of the sale price when the item is bought with
+		/// `buy_item`, or when it is swapped for a price with `claim_swap`. A royalty set for an
+		/// item takes precedence over the royalty of its collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `maybe_item`: The identifier of the item, or `None` to set the collection royalty.
+		/// - `recipient`: The account receiving the royalty.
+		/// - `percentage`: The part of the sale price paid to the `recipient`, at most
+		///   `MaxRoyalty`.
+		///
+		/// Emits `RoyaltySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			recipient: AccountIdLookupOf<T>,
+			percentage: Perbill,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let recipient = T::Lookup::lookup(recipient)?;
+			Self::do_set_royalty(
+				maybe_check_owner,
+				collection,
+				maybe_item,
+				Royalty { recipient, percentage },
+			)
+		}
+
+		/// Clear the royalty of a `collection`, or of a single `item`.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `maybe_item`: The identifier of the item, or `None` to clear the collection royalty.
+		///
+		/// Emits `RoyaltyCleared`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::clear_royalty())]
+		pub fn clear_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_royalty(maybe_check_owner, collection, maybe_item)
+		}
 	}
 }
 
@@ -16,7 +16,10 @@
 // limitations under the License.
 
 use super::*;
-use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{OnRuntimeUpgrade, UncheckedOnRuntimeUpgrade},
+};
 use log;
 
 #[cfg(feature = "try-runtime")]
@@ -64,7 +67,7 @@ pub mod v1 {
 				on_chain_version
 			);
 
-			if on_chain_version == 0 && in_code_version == 1 {
+			if on_chain_version == 0 && in_code_version >= 1 {
 				let mut translated = 0u64;
 				let mut configs_iterated = 0u64;
 				Collection::<T>::translate::<
@@ -77,13 +80,12 @@ pub mod v1 {
 					Some(old_value.migrate_to_v1(item_configs))
 				});
 
-				in_code_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 
 				log::info!(
 					target: LOG_TARGET,
-					"Upgraded {} records, storage to version {:?}",
-					translated,
-					in_code_version
+					"Upgraded {} records, storage to version 1",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + configs_iterated + 1, translated + 1)
 			} else {
@@ -118,3 +120,66 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Lock the royalties of the collections created before royalties were introduced.
+	///
+	/// Their owners could not foresee a royalty being charged on the sales of their items, which
+	/// is why [`CollectionSetting::UnlockedRoyalties`] is disabled for them, as if it had been
+	/// locked with the rest of their settings. The `ForceOrigin` can still unlock it.
+	pub struct UncheckedMigrateToV2<T, I = ()>(core::marker::PhantomData<(T, I)>);
+	impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			CollectionConfigOf::<T, I>::translate::<CollectionConfigFor<T, I>, _>(
+				|_, mut config| {
+					config.disable_setting(CollectionSetting::UnlockedRoyalties);
+					translated.saturating_inc();
+					Some(config)
+				},
+			);
+
+			log::info!(target: LOG_TARGET, "Locked the royalties of {} collections", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let prev_count = CollectionConfigOf::<T, I>::iter().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CollectionConfigOf::<T, I>::iter().count() as u32;
+			ensure!(
+				prev_count == post_count,
+				"the records count before and after the migration should be the same"
+			);
+			ensure!(
+				CollectionConfigOf::<T, I>::iter_values()
+					.all(|config| config.has_disabled_setting(CollectionSetting::UnlockedRoyalties)),
+				"the royalties of all collections should be locked"
+			);
+
+			Ok(())
+		}
+	}
+
+	/// [`UncheckedMigrateToV2`] wrapped in a [`VersionedMigration`], so it only runs on storage
+	/// version 1 and then bumps it to 2.
+	pub type MigrateToV2<T, I = ()> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T, I>,
+		Pallet<T, I>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
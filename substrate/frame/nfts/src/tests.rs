@@ -3875,3 +3875,360 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+#[test]
+fn set_and_clear_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 1;
+		let percentage = Perbill::from_percent(10);
+
+		Balances::make_free_balance_be(&user_1, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				None,
+				user_2.clone(),
+				percentage
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				Some(2),
+				user_1.clone(),
+				percentage
+			),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::clear_royalty(RuntimeOrigin::signed(user_1.clone()), collection_id, None),
+			Error::<Test>::NoRoyalty
+		);
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::root(),
+				collection_id,
+				None,
+				user_1.clone(),
+				Perbill::from_percent(51)
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			None,
+			user_1.clone(),
+			percentage
+		));
+		assert!(events().contains(&Event::<Test>::RoyaltySet {
+			collection: collection_id,
+			maybe_item: None,
+			recipient: user_1.clone(),
+			percentage,
+		}));
+		assert_eq!(
+			Nfts::royalty(&collection_id, &item_id),
+			Some(Royalty { recipient: user_1.clone(), percentage })
+		);
+
+		// The item royalty takes precedence over the collection royalty.
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::root(),
+			collection_id,
+			Some(item_id),
+			user_2.clone(),
+			Perbill::from_percent(5)
+		));
+		assert_eq!(
+			Nfts::royalty(&collection_id, &item_id),
+			Some(Royalty { recipient: user_2.clone(), percentage: Perbill::from_percent(5) })
+		);
+
+		assert_ok!(Nfts::clear_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_id)
+		));
+		assert!(events().contains(&Event::<Test>::RoyaltyCleared {
+			collection: collection_id,
+			maybe_item: Some(item_id),
+		}));
+		assert_eq!(
+			Nfts::royalty(&collection_id, &item_id),
+			Some(Royalty { recipient: user_1.clone(), percentage })
+		);
+
+		// Royalties can't be changed once locked.
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into())
+		));
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				None,
+				user_2.clone(),
+				percentage
+			),
+			Error::<Test>::RoyaltiesLocked
+		);
+		assert_noop!(
+			Nfts::clear_royalty(RuntimeOrigin::root(), collection_id, None),
+			Error::<Test>::RoyaltiesLocked
+		);
+	});
+}
+
+#[test]
+fn buy_item_should_pay_royalty() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let creator = account(4);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+		Balances::make_free_balance_be(&creator, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_2.clone(),
+				None
+			));
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item,
+				Some(price),
+				None,
+			));
+		}
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			None,
+			creator.clone(),
+			Perbill::from_percent(10)
+		));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_2),
+			user_1.clone(),
+			Perbill::from_percent(25)
+		));
+
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_1,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_3), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 90);
+		assert_eq!(Balances::total_balance(&creator), initial_balance + 10);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			recipient: creator.clone(),
+			amount: 10,
+		}));
+
+		// The item royalty is paid instead of the collection royalty.
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_2,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 90 + 75);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 25);
+		assert_eq!(Balances::total_balance(&creator), initial_balance + 10);
+
+		// Royalties are paid on every sale, not just the first one.
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_1,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_3), initial_balance - 2 * price + 90);
+		assert_eq!(Balances::total_balance(&creator), initial_balance + 20);
+
+		// Burning an item removes its royalty.
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_3.clone()), collection_id, item_2));
+		assert!(!ItemRoyaltyOf::<Test>::contains_key(collection_id, item_2));
+	});
+}
+
+#[test]
+fn claim_swap_should_pay_royalty() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let creator = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 100;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&creator, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_2.clone(),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(item_2),
+			creator.clone(),
+			Perbill::from_percent(20)
+		));
+
+		// `user_1` pays `price` on top of `item_1` to receive `item_2`, so the royalty of `item_2`
+		// is due.
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			Some(item_1),
+			Some(price_with_direction.clone()),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			item_2,
+			Some(price_with_direction),
+		));
+
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + 80);
+		assert_eq!(Balances::total_balance(&creator), initial_balance + 20);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_2,
+			recipient: creator,
+			amount: 20,
+		}));
+		assert_eq!(Item::<Test>::get(collection_id, item_2).unwrap().owner, user_1);
+	});
+}
+
+#[test]
+fn migrate_to_v2_locks_royalties() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let collection_id = 0;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		StorageVersion::new(1).put::<Nfts>();
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Nfts::on_chain_storage_version(), 2);
+		assert!(CollectionConfigOf::<Test>::get(collection_id)
+			.unwrap()
+			.has_disabled_setting(CollectionSetting::UnlockedRoyalties));
+		assert_noop!(
+			Nfts::set_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				None,
+				user_1.clone(),
+				Perbill::from_percent(10)
+			),
+			Error::<Test>::RoyaltiesLocked
+		);
+
+		// Collections created afterwards can have royalties.
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::set_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id + 1,
+			None,
+			user_1,
+			Perbill::from_percent(10)
+		));
+	});
+}
@@ -63,6 +63,8 @@ pub type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 /// A type alias to represent the price of an item.
 pub type ItemPrice<T, I = ()> = BalanceOf<T, I>;
+/// A type alias for the royalty of a collection or an item.
+pub type RoyaltyOf<T> = Royalty<<T as SystemConfig>::AccountId>;
 /// A type alias for the tips held by a single item.
 pub type ItemTipOf<T, I = ()> = ItemTip<
 	<T as Config<I>>::CollectionId,
@@ -269,6 +271,17 @@ pub struct PriceWithDirection<Amount> {
 	pub direction: PriceDirection,
 }
 
+/// Holds the details about the royalty paid on the sale of an item.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct Royalty<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The part of the sale price paid to the `recipient`.
+	pub percentage: Perbill,
+}
+
 /// Support for up to 64 user-enabled features on a collection.
 #[bitflags]
 #[repr(u64)]
@@ -284,6 +297,11 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection and its items can be modified.
+	///
+	/// Collections created before royalties were introduced have it disabled by
+	/// [`crate::migration::v2::MigrateToV2`].
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
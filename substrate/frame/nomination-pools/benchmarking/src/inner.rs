@@ -25,16 +25,18 @@ use frame_support::{
 	traits::{
 		fungible::{Inspect, Mutate, Unbalanced},
 		tokens::Preservation,
-		Get, Imbalance,
+		Get, Hooks, Imbalance,
 	},
+	weights::Weight,
 };
-use frame_system::RawOrigin as RuntimeOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as RuntimeOrigin};
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
 	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
 	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, MemberAutoCompound, Metadata, MinCreateBond,
+	MinJoinBond, Pallet as Pools, PoolAutoCompound, PoolId, PoolMembers, PoolRoles, PoolState,
+	RewardPools, SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -1173,5 +1175,69 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn set_pool_auto_compound() {
+		// Create a pool.
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor), 1, true);
+
+		assert!(PoolAutoCompound::<T>::get(1));
+	}
+
+	#[benchmark]
+	fn set_auto_compound() {
+		// Create a pool.
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()), Some(true));
+
+		assert_eq!(MemberAutoCompound::<T>::get(&depositor), Some(true));
+	}
+
+	#[benchmark]
+	fn auto_compound(n: Linear<1, { T::AutoCompoundBatchSize::get() }>) {
+		// Create a pool, its depositor is the first member.
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+
+		// Fill the batch with members.
+		MaxPoolMembers::<T>::kill();
+		MaxPoolMembersPerPool::<T>::kill();
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let mut members = vec![depositor.clone()];
+		for i in 1..n {
+			let joiner =
+				create_funded_user_with_balance::<T>("joiner", i, min_join_bond * 2u32.into());
+			Pools::<T>::join(RuntimeOrigin::Signed(joiner.clone()).into(), min_join_bond, 1)
+				.unwrap();
+			members.push(joiner);
+		}
+
+		// Worst case: every member of the batch compounds their rewards.
+		assert_ok!(Pools::<T>::set_pool_auto_compound(
+			RuntimeOrigin::Signed(depositor).into(),
+			1,
+			true
+		));
+		let reward_account = Pools::<T>::generate_reward_account(1);
+		let _ = CurrencyOf::<T>::mint_into(&reward_account, min_join_bond * n.into());
+
+		#[block]
+		{
+			<Pools<T> as Hooks<BlockNumberFor<T>>>::on_idle(Zero::zero(), Weight::MAX);
+		}
+
+		for member in members {
+			assert_eq!(Pools::<T>::api_pending_rewards(member), Some(Zero::zero()));
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -70,6 +70,11 @@
 //! other members to permissionlessly bond or withdraw their rewards by calling
 //! [`Call::bond_extra_other`] or [`Call::claim_payout_other`] respectively.
 //!
+//! Alternatively, rewards can be compounded automatically. The root of a pool can enable this for
+//! all of its members with [`Call::set_pool_auto_compound`], and each member can override the pool
+//! setting with [`Call::set_auto_compound`]. Once per era, the pending rewards of all such members
+//! are re-bonded in bounded batches during `on_idle`, see [`Config::AutoCompoundBatchSize`].
+//!
 //! For design docs see the [reward pool](#reward-pool) section.
 //!
 //! ### Leave
@@ -361,7 +366,7 @@ use core::{fmt::Debug, ops::Div};
 use frame_support::{
 	defensive, defensive_assert, ensure,
 	pallet_prelude::{MaxEncodedLen, *},
-	storage::bounded_btree_map::BoundedBTreeMap,
+	storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 	traits::{
 		fungible::{Inspect, InspectFreeze, Mutate, MutateFreeze},
 		tokens::{Fortitude, Preservation},
@@ -503,6 +508,29 @@ impl ClaimPermission {
 	}
 }
 
+/// The lifetime totals of rewards that have been automatically compounded into a pool.
+///
+/// See [`Config::AutoCompoundBatchSize`] and [`Call::set_pool_auto_compound`].
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	PartialEq,
+	Eq,
+	TypeInfo,
+)]
+pub struct AutoCompoundRecord<Balance> {
+	/// The sum of all pending rewards that were re-bonded on behalf of members.
+	pub rewards: Balance,
+	/// The sum of all points that were issued in return of `rewards`.
+	pub points: Balance,
+}
+
 /// A member in a pool.
 #[derive(
 	Encode,
@@ -1739,6 +1767,15 @@ pub mod pallet {
 
 		/// Restrict some accounts from participating in a nomination pool.
 		type Filter: Contains<Self::AccountId>;
+
+		/// The maximum number of pool members that are inspected for auto-compounding in a single
+		/// `on_idle` call.
+		///
+		/// The pass over all members is resumed in the next block if it could not be completed,
+		/// either because of this limit or because the remaining block weight was too low. A value
+		/// of zero disables auto-compounding altogether.
+		#[pallet::constant]
+		type AutoCompoundBatchSize: Get<u32>;
 	}
 
 	/// The sum of funds across all pools.
@@ -1829,6 +1866,30 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Pools whose root has enabled auto-compounding of rewards for all members.
+	#[pallet::storage]
+	pub type PoolAutoCompound<T: Config> = StorageMap<_, Twox64Concat, PoolId, bool, ValueQuery>;
+
+	/// Map from a pool member account to their auto-compound preference, overriding the one of
+	/// their pool ([`PoolAutoCompound`]) if set.
+	#[pallet::storage]
+	pub type MemberAutoCompound<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, OptionQuery>;
+
+	/// The lifetime totals of the rewards that were auto-compounded into each pool.
+	#[pallet::storage]
+	pub type PoolAutoCompounded<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, AutoCompoundRecord<BalanceOf<T>>, ValueQuery>;
+
+	/// The era in which the last auto-compound pass over all [`PoolMembers`] was started.
+	#[pallet::storage]
+	pub type AutoCompoundEra<T: Config> = StorageValue<_, EraIndex, OptionQuery>;
+
+	/// The last member processed by an ongoing auto-compound pass. The pass continues after this
+	/// member in the next `on_idle`. `None` if no pass is in progress.
+	#[pallet::storage]
+	pub type AutoCompoundCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1958,6 +2019,11 @@ pub mod pallet {
 		MemberClaimPermissionUpdated { member: T::AccountId, permission: ClaimPermission },
 		/// A pool's metadata was updated.
 		MetadataUpdated { pool_id: PoolId, caller: T::AccountId },
+		/// A pool's auto-compound setting has been updated.
+		PoolAutoCompoundUpdated { pool_id: PoolId, enabled: bool },
+		/// A pool member's auto-compound preference has been updated. `None` means the member
+		/// follows the setting of their pool.
+		MemberAutoCompoundUpdated { member: T::AccountId, auto_compound: Option<bool> },
 		/// A pool's nominating account (or the pool's root account) has nominated a validator set
 		/// on behalf of the pool.
 		PoolNominationMade { pool_id: PoolId, caller: T::AccountId },
@@ -2197,7 +2263,7 @@ pub mod pallet {
 				Error::<T>::NotMigrated
 			);
 
-			Self::do_bond_extra(who.clone(), who, extra)
+			Self::do_bond_extra(who.clone(), who, extra).map(|_| ())
 		}
 
 		/// A bonded member can use this to claim their payout based on the rewards that the pool
@@ -2512,8 +2578,10 @@ pub mod pallet {
 			});
 
 			let post_info_weight = if member.total_points().is_zero() {
-				// remove any `ClaimPermission` associated with the member.
+				// remove any `ClaimPermission` and auto-compound preference associated with the
+				// member.
 				ClaimPermissions::<T>::remove(&member_account);
+				MemberAutoCompound::<T>::remove(&member_account);
 
 				// member being reaped.
 				PoolMembers::<T>::remove(&member_account);
@@ -2900,7 +2968,7 @@ pub mod pallet {
 				Error::<T>::NotMigrated
 			);
 
-			Self::do_bond_extra(who, member_account, extra)
+			Self::do_bond_extra(who, member_account, extra).map(|_| ())
 		}
 
 		/// Allows a pool member to set a claim permission to allow or disallow permissionless
@@ -3253,10 +3321,77 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Enable or disable auto-compounding of rewards for all members of a pool.
+		///
+		/// If enabled, the pending rewards of every member of the pool are bonded back into the
+		/// pool once per era, as if each member called [`Call::bond_extra`] with
+		/// [`BondExtra::Rewards`]. Members can opt out (or in) with [`Call::set_auto_compound`].
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Root of the pool.
+		/// * `pool_id` - The pool to configure.
+		/// * `enabled` - Whether rewards should be auto-compounded.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_pool_auto_compound())]
+		pub fn set_pool_auto_compound(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.is_root(&who), Error::<T>::DoesNotHavePermission);
+
+			if enabled {
+				PoolAutoCompound::<T>::insert(pool_id, true);
+			} else {
+				PoolAutoCompound::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::<T>::PoolAutoCompoundUpdated { pool_id, enabled });
+			Ok(())
+		}
+
+		/// Allows a pool member to override the auto-compound setting of their pool.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Member of a pool.
+		/// * `auto_compound` - `Some(true)` to always compound, `Some(false)` to never compound, or
+		///   `None` to follow the setting of the pool.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			auto_compound: Option<bool>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(PoolMembers::<T>::contains_key(&who), Error::<T>::PoolMemberNotFound);
+
+			// ensure member is not in an un-migrated state.
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+
+			MemberAutoCompound::<T>::set(&who, auto_compound);
+
+			Self::deposit_event(Event::<T>::MemberAutoCompoundUpdated {
+				member: who,
+				auto_compound,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<SystemBlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: SystemBlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_auto_compound(remaining_weight)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: SystemBlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state(u8::MAX)
@@ -3415,8 +3550,10 @@ impl<T: Config> Pallet<T> {
 		let _ = T::StakeAdapter::dissolve(Pool::from(bonded_account)).defensive();
 
 		Self::deposit_event(Event::<T>::Destroyed { pool_id: bonded_pool.id });
-		// Remove bonded pool metadata.
+		// Remove bonded pool metadata and auto-compound data.
 		Metadata::<T>::remove(bonded_pool.id);
+		PoolAutoCompound::<T>::remove(bonded_pool.id);
+		PoolAutoCompounded::<T>::remove(bonded_pool.id);
 
 		bonded_pool.remove();
 	}
@@ -3649,11 +3786,14 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Bond `extra` into the pool of `member_account`, on behalf of `signer`.
+	///
+	/// Returns the bonded amount and the number of points issued for it.
 	fn do_bond_extra(
 		signer: T::AccountId,
 		member_account: T::AccountId,
 		extra: BondExtra<BalanceOf<T>>,
-	) -> DispatchResult {
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
 		// ensure account is not restricted from joining the pool.
 		ensure!(!T::Filter::contains(&member_account), Error::<T>::Restricted);
 
@@ -3703,7 +3843,114 @@ impl<T: Config> Pallet<T> {
 		});
 		Self::put_member_with_pools(&member_account, member, bonded_pool, reward_pool);
 
-		Ok(())
+		Ok((bonded, points_issued))
+	}
+
+	/// Auto-compound the pending rewards of the members that opted for it, consuming at most
+	/// `limit` weight.
+	///
+	/// A single pass over all [`PoolMembers`] is made per era. The pass is paginated: at most
+	/// [`Config::AutoCompoundBatchSize`] members are inspected per call, and the pass is resumed
+	/// from [`AutoCompoundCursor`] in the next call. Members whose rewards cannot be bonded (e.g.
+	/// because the pool is not allowed to be open anymore) are skipped.
+	///
+	/// Returns the consumed weight.
+	pub(crate) fn do_auto_compound(limit: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// reading the cursor, the last processed era and the current era.
+		let mut consumed = db_weight.reads(3);
+		let batch_size = T::AutoCompoundBatchSize::get();
+		if batch_size.is_zero() || consumed.any_gt(limit) {
+			return Weight::zero();
+		}
+
+		let maybe_cursor = AutoCompoundCursor::<T>::get();
+		let current_era = T::StakeAdapter::current_era();
+		if maybe_cursor.is_none() {
+			if AutoCompoundEra::<T>::get().map_or(false, |era| era >= current_era) {
+				// the pass of this era is complete.
+				return consumed;
+			}
+
+			// don't bother scanning members if no one opted in.
+			consumed.saturating_accrue(db_weight.reads(2));
+			if PoolAutoCompound::<T>::iter_keys().next().is_none() &&
+				MemberAutoCompound::<T>::iter_keys().next().is_none()
+			{
+				return consumed;
+			}
+		}
+
+		// the benchmarked pass over an empty batch covers the reads above and updating the cursor
+		// and the era.
+		consumed = consumed.max(T::WeightInfo::auto_compound(0));
+		if consumed.any_gt(limit) {
+			return Weight::zero();
+		}
+		// every member is accounted for as if they compound.
+		let member_weight =
+			T::WeightInfo::auto_compound(1).saturating_sub(T::WeightInfo::auto_compound(0));
+
+		let mut members = match maybe_cursor {
+			Some(ref cursor) => {
+				PoolMembers::<T>::iter_from(PoolMembers::<T>::hashed_key_for(cursor))
+			},
+			None => PoolMembers::<T>::iter(),
+		};
+		let mut batch = Vec::new();
+		let mut exhausted = false;
+		while batch.len() < batch_size as usize {
+			let next_consumed = consumed.saturating_add(member_weight);
+			if next_consumed.any_gt(limit) {
+				break;
+			}
+			match members.next() {
+				Some((who, member)) => {
+					consumed = next_consumed;
+					batch.push((who, member.pool_id));
+				},
+				None => {
+					exhausted = true;
+					break;
+				},
+			}
+		}
+
+		if batch.is_empty() && !exhausted {
+			// not enough weight left to make any progress.
+			return Weight::zero();
+		}
+
+		let last = batch.last().map(|(who, _)| who.clone());
+		for (who, pool_id) in batch {
+			let enabled = MemberAutoCompound::<T>::get(&who)
+				.unwrap_or_else(|| PoolAutoCompound::<T>::get(pool_id));
+			if !enabled ||
+				Self::api_member_needs_delegate_migration(who.clone()) ||
+				Self::api_pending_rewards(who.clone()).unwrap_or_default().is_zero()
+			{
+				continue;
+			}
+
+			match with_storage_layer(|| {
+				Self::do_bond_extra(who.clone(), who.clone(), BondExtra::Rewards)
+			}) {
+				Ok((rewards, points)) => PoolAutoCompounded::<T>::mutate(pool_id, |record| {
+					record.rewards.saturating_accrue(rewards);
+					record.points.saturating_accrue(points);
+				}),
+				Err(e) => log!(debug, "failed to auto-compound rewards of {:?}: {:?}", who, e),
+			}
+		}
+
+		// NOTE: a pass that is still ongoing when the era changes completes in the new era, after
+		// which a pass for the new era is started right away.
+		if maybe_cursor.is_none() {
+			AutoCompoundEra::<T>::put(current_era);
+		}
+		AutoCompoundCursor::<T>::set(if exhausted { None } else { last });
+
+		consumed
 	}
 
 	fn do_claim_commission(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
@@ -4090,6 +4337,8 @@ impl<T: Config> Pallet<T> {
 			"TVL must be equal to or less than the total balance of all PoolMembers."
 		);
 
+		Self::do_try_state_auto_compound(&bonded_pools)?;
+
 		if level <= 1 {
 			return Ok(());
 		}
@@ -4126,6 +4375,52 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Sanity checks of the auto-compound storage items:
+	///
+	/// * [`PoolAutoCompound`] and [`PoolAutoCompounded`] keys must be a subset of the bonded pools.
+	/// * [`MemberAutoCompound`] keys must be a subset of the pool members.
+	/// * the rewards compounded into a pool cannot exceed the rewards ever claimed from it.
+	/// * the points issued for compounded rewards cannot be lower than the rewards, as the points
+	///   of a pool are never lower than its balance.
+	/// * the last auto-compounded era cannot be in the future.
+	#[cfg(any(feature = "try-runtime", feature = "fuzzing", test, debug_assertions))]
+	fn do_try_state_auto_compound(bonded_pools: &[PoolId]) -> Result<(), TryRuntimeError> {
+		ensure!(
+			PoolAutoCompound::<T>::iter_keys().all(|k| bonded_pools.contains(&k)),
+			"`PoolAutoCompound` keys must be a subset of `BondedPools`."
+		);
+		ensure!(
+			MemberAutoCompound::<T>::iter_keys().all(|k| PoolMembers::<T>::contains_key(k)),
+			"`MemberAutoCompound` keys must be a subset of `PoolMembers`."
+		);
+
+		PoolAutoCompounded::<T>::iter().try_for_each(
+			|(id, record)| -> Result<(), TryRuntimeError> {
+				ensure!(
+					bonded_pools.contains(&id),
+					"`PoolAutoCompounded` keys must be bonded pools."
+				);
+				let reward_pool = RewardPools::<T>::get(id).ok_or("reward pool must exist")?;
+				ensure!(
+					record.rewards <= reward_pool.total_rewards_claimed,
+					"Auto-compounded rewards must not exceed the total rewards claimed from the pool."
+				);
+				ensure!(
+					record.points >= record.rewards,
+					"Auto-compounded points must never be lower than the compounded rewards."
+				);
+				Ok(())
+			},
+		)?;
+
+		ensure!(
+			AutoCompoundEra::<T>::get().map_or(true, |era| era <= T::StakeAdapter::current_era()),
+			"`AutoCompoundEra` must not be in the future."
+		);
+
+		Ok(())
+	}
+
 	/// Check if any pool have an incorrect amount of ED frozen.
 	///
 	/// This can happen if the ED has changed since the pool was created.
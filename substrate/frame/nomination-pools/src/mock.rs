@@ -474,6 +474,7 @@ parameter_types! {
 	pub static MaxUnbondingPools: u32 = 5;
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
+	pub static AutoCompoundBatchSize: u32 = 2;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
 }
 
@@ -498,6 +499,7 @@ impl pools::Config for Runtime {
 	type AdminOrigin = EnsureSignedBy<Admin, AccountId>;
 	type BlockNumberProvider = System;
 	type Filter = RestrictMock;
+	type AutoCompoundBatchSize = AutoCompoundBatchSize;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
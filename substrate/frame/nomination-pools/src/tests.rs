@@ -7997,3 +7997,136 @@ mod claim_trapped_balance_migration {
 		});
 	}
 }
+
+mod auto_compound {
+	use super::*;
+
+	#[test]
+	fn set_pool_auto_compound_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// only the root of the pool can set the auto-compound setting.
+			assert_noop!(
+				Pools::set_pool_auto_compound(RuntimeOrigin::signed(10), 1, true),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_noop!(
+				Pools::set_pool_auto_compound(RuntimeOrigin::signed(900), 2, true),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			assert_ok!(Pools::set_pool_auto_compound(RuntimeOrigin::signed(900), 1, true));
+			assert!(PoolAutoCompound::<Runtime>::get(1));
+
+			assert_ok!(Pools::set_pool_auto_compound(RuntimeOrigin::signed(900), 1, false));
+			assert!(!PoolAutoCompound::<Runtime>::contains_key(1));
+
+			// members can only override the setting of their own pool.
+			assert_noop!(
+				Pools::set_auto_compound(RuntimeOrigin::signed(20), Some(true)),
+				Error::<Runtime>::PoolMemberNotFound
+			);
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(10), Some(false)));
+			assert_eq!(MemberAutoCompound::<Runtime>::get(10), Some(false));
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(10), None));
+			assert_eq!(MemberAutoCompound::<Runtime>::get(10), None);
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::MetadataUpdated { pool_id: 1, caller: 900 },
+					Event::PoolAutoCompoundUpdated { pool_id: 1, enabled: true },
+					Event::PoolAutoCompoundUpdated { pool_id: 1, enabled: false },
+					Event::MemberAutoCompoundUpdated { member: 10, auto_compound: Some(false) },
+					Event::MemberAutoCompoundUpdated { member: 10, auto_compound: None },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn auto_compound_in_paginated_batches() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (30, 30)])
+			.build_and_execute(|| {
+				assert_eq!(AutoCompoundBatchSize::get(), 2);
+				assert_ok!(Pools::set_pool_auto_compound(RuntimeOrigin::signed(900), 1, true));
+				// 30 opts out of the pool setting.
+				assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(30), Some(false)));
+
+				// 60 points in total, distribute 1 unit of reward per point.
+				Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 60);
+
+				// the first batch of two members is processed and the pass is not over yet.
+				assert!(!Pools::on_idle(1, Weight::MAX).is_zero());
+				assert_eq!(AutoCompoundEra::<Runtime>::get(), Some(0));
+				assert!(AutoCompoundCursor::<Runtime>::get().is_some());
+
+				// the second batch reaches the end of the members.
+				Pools::on_idle(2, Weight::MAX);
+				assert!(AutoCompoundCursor::<Runtime>::get().is_none());
+
+				for (member, points) in [(10, 20), (20, 40), (30, 30)] {
+					assert_eq!(PoolMembers::<Runtime>::get(member).unwrap().points, points);
+				}
+				assert_eq!(Pools::api_pending_rewards(10), Some(0));
+				assert_eq!(Pools::api_pending_rewards(30), Some(30));
+				assert_eq!(TotalValueLocked::<Runtime>::get(), 90);
+				assert_eq!(
+					PoolAutoCompounded::<Runtime>::get(1),
+					AutoCompoundRecord { rewards: 30, points: 30 }
+				);
+
+				// nothing else happens in the same era.
+				Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 200);
+				Pools::on_idle(3, Weight::MAX);
+				assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 20);
+
+				// but a new pass starts in the next era.
+				CurrentEra::set(1);
+				for n in 4..6 {
+					Pools::on_idle(n, Weight::MAX);
+				}
+				assert_eq!(AutoCompoundEra::<Runtime>::get(), Some(1));
+				assert!(PoolMembers::<Runtime>::get(10).unwrap().points > 20);
+				assert_eq!(Pools::api_pending_rewards(10), Some(0));
+			})
+	}
+
+	#[test]
+	fn auto_compound_skips_failures_and_respects_weight() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(10), Some(true)));
+			assert_ok!(Pools::set_auto_compound(RuntimeOrigin::signed(20), Some(true)));
+			Currency::set_balance(&default_reward_account(), ExistentialDeposit::get() + 30);
+
+			// not enough weight to compound a single member.
+			let limit = <Runtime as Config>::WeightInfo::auto_compound(1) / 2;
+			assert_eq!(Pools::on_idle(1, limit), Weight::zero());
+			assert_eq!(AutoCompoundEra::<Runtime>::get(), None);
+
+			// 20 got restricted, their rewards stay pending.
+			add_to_restrict_list(&20);
+			Pools::on_idle(2, Weight::MAX);
+			Pools::on_idle(3, Weight::MAX);
+			assert_eq!(AutoCompoundCursor::<Runtime>::get(), None);
+
+			assert_eq!(PoolMembers::<Runtime>::get(10).unwrap().points, 20);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 20);
+			assert_eq!(Pools::api_pending_rewards(20), Some(20));
+			assert_eq!(
+				PoolAutoCompounded::<Runtime>::get(1),
+				AutoCompoundRecord { rewards: 10, points: 10 }
+			);
+			remove_from_restrict_list(&20);
+
+			// the preference is removed with the member.
+			CurrentEra::set(1);
+			assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 20));
+			CurrentEra::set(1 + BondingDuration::get());
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+			assert_eq!(MemberAutoCompound::<Runtime>::get(20), None);
+		})
+	}
+}
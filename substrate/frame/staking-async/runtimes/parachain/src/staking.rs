@@ -654,6 +654,7 @@ impl pallet_nomination_pools::Config for Runtime {
 	type MaxPointsToBalance = MaxPointsToBalance;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
 	type BlockNumberProvider = RelayChainBlockNumberProvider;
+	type AutoCompoundBatchSize = ConstU32<64>;
 }
 
 parameter_types! {
@@ -165,15 +165,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Account` (r:1 w:1)
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:1 w:1)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `242`
-		//  Estimated: `4273`
-		// Minimum execution time: 22_965_000 picoseconds.
-		Weight::from_parts(23_855_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `547`
+		//  Estimated: `4452`
+		// Minimum execution time: 28_965_000 picoseconds.
+		Weight::from_parts(29_855_000, 0)
+			.saturating_add(Weight::from_parts(0, 4452))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -183,15 +187,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:1 w:1)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn burn() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
-		//  Estimated: `4273`
-		// Minimum execution time: 47_536_000 picoseconds.
-		Weight::from_parts(49_071_000, 0)
-			.saturating_add(Weight::from_parts(0, 4273))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Measured:  `661`
+		//  Estimated: `4452`
+		// Minimum execution time: 53_536_000 picoseconds.
+		Weight::from_parts(55_071_000, 0)
+			.saturating_add(Weight::from_parts(0, 4452))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -203,15 +211,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:2 w:2)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
-		//  Estimated: `7404`
-		// Minimum execution time: 59_854_000 picoseconds.
-		Weight::from_parts(61_262_000, 0)
-			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+		//  Measured:  `966`
+		//  Estimated: `7914`
+		// Minimum execution time: 70_854_000 picoseconds.
+		Weight::from_parts(72_262_000, 0)
+			.saturating_add(Weight::from_parts(0, 7914))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -221,15 +233,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:2 w:2)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn transfer_keep_alive() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
-		//  Estimated: `7404`
-		// Minimum execution time: 45_638_000 picoseconds.
-		Weight::from_parts(46_477_000, 0)
-			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Measured:  `966`
+		//  Estimated: `7914`
+		// Minimum execution time: 56_638_000 picoseconds.
+		Weight::from_parts(57_477_000, 0)
+			.saturating_add(Weight::from_parts(0, 7914))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -241,15 +257,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:2 w:2)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn force_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
-		//  Estimated: `7404`
-		// Minimum execution time: 59_607_000 picoseconds.
-		Weight::from_parts(61_405_000, 0)
-			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+		//  Measured:  `966`
+		//  Estimated: `7914`
+		// Minimum execution time: 70_607_000 picoseconds.
+		Weight::from_parts(72_405_000, 0)
+			.saturating_add(Weight::from_parts(0, 7914))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -441,15 +461,19 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::Freezes` (r:1 w:1)
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:2 w:2)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn transfer_approved() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `526`
-		//  Estimated: `7404`
-		// Minimum execution time: 79_133_000 picoseconds.
-		Weight::from_parts(81_252_000, 0)
-			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+		//  Measured:  `1136`
+		//  Estimated: `7914`
+		// Minimum execution time: 90_133_000 picoseconds.
+		Weight::from_parts(92_252_000, 0)
+			.saturating_add(Weight::from_parts(0, 7914))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -583,15 +607,76 @@ impl<T: frame_system::Config> pallet_assets::WeightInfo for WeightInfo<T> {
 	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:0)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotBalances` (r:2 w:2)
+	/// Proof: `ForeignAssets::SnapshotBalances` (`max_values`: None, `max_size`: Some(987), added: 3462, mode: `MaxEncodedLen`)
 	fn transfer_all() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `356`
-		//  Estimated: `7404`
-		// Minimum execution time: 72_880_000 picoseconds.
-		Weight::from_parts(74_426_000, 0)
-			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+		//  Measured:  `966`
+		//  Estimated: `7914`
+		// Minimum execution time: 83_880_000 picoseconds.
+		Weight::from_parts(85_426_000, 0)
+			.saturating_add(Weight::from_parts(0, 7914))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::AllowlistEnforced` (r:0 w:1)
+	/// Proof: `ForeignAssets::AllowlistEnforced` (`max_values`: None, `max_size`: Some(618), added: 3093, mode: `MaxEncodedLen`)
+	fn set_allowlist_enforced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `4273`
+		// Minimum execution time: 14_102_000 picoseconds.
+		Weight::from_parts(14_630_000, 4273)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:0 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn add_to_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `4273`
+		// Minimum execution time: 14_771_000 picoseconds.
+		Weight::from_parts(15_318_000, 4273)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Allowlist` (r:1 w:1)
+	/// Proof: `ForeignAssets::Allowlist` (`max_values`: None, `max_size`: Some(666), added: 3141, mode: `MaxEncodedLen`)
+	fn remove_from_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `4273`
+		// Minimum execution time: 17_208_000 picoseconds.
+		Weight::from_parts(17_866_000, 4273)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::CurrentSnapshot` (r:1 w:1)
+	/// Proof: `ForeignAssets::CurrentSnapshot` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::SnapshotSupply` (r:0 w:2)
+	/// Proof: `ForeignAssets::SnapshotSupply` (`max_values`: None, `max_size`: Some(646), added: 3121, mode: `MaxEncodedLen`)
+	fn snapshot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `417`
+		//  Estimated: `4273`
+		// Minimum execution time: 16_417_000 picoseconds.
+		Weight::from_parts(17_052_000, 4273)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn record_snapshot_balance() -> Weight {
+		Weight::zero()
 	}
 	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
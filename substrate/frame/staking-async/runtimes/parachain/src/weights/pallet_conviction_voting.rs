@@ -192,4 +192,10 @@ impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	fn delegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
+	fn undelegate_all(c: u32, ) -> Weight {
+		Weight::from_parts(0, 0).saturating_mul(c.into())
+	}
 }
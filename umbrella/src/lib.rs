@@ -357,6 +357,10 @@ pub use pallet_assets_holder;
 #[cfg(feature = "pallet-assets-precompiles")]
 pub use pallet_assets_precompiles;
 
+/// Runtime API for the FRAME assets pallet.
+#[cfg(feature = "pallet-assets-runtime-api")]
+pub use pallet_assets_runtime_api;
+
 /// FRAME atomic swap pallet.
 #[cfg(feature = "pallet-atomic-swap")]
 pub use pallet_atomic_swap;